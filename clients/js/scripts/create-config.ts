@@ -10,7 +10,7 @@ import {
 } from "@metaplex-foundation/umi";
 import { base58 } from "@metaplex-foundation/umi/serializers";
 
-import { createConfigV1 } from "../src";
+import { TiePolicy, TransferFeePolicy, createConfigV1 } from "../src";
 
 import {
   cancel,
@@ -90,7 +90,32 @@ type ConfigArgs = {
   bondFeeBps: Amount<"%", 2>;
   disputeWindow: number;
   votingWindow: number;
+  revealWindow: number;
   arbitrationWindow: number;
+  claimWindow: number;
+  minDisputeWindow: number;
+  maxDisputeWindow: number;
+  minVotingWindow: number;
+  maxVotingWindow: number;
+  minArbitrationWindow: number;
+  maxArbitrationWindow: number;
+  quorumAmount: bigint;
+  quorumBps: Amount<"%", 2>;
+  slashBps: Amount<"%", 2>;
+  slashWindow: number;
+  tiePolicy: TiePolicy;
+  transferFeePolicy: TransferFeePolicy;
+};
+
+const tiePolicyNames: Record<TiePolicy, string> = {
+  [TiePolicy.ExtendVoting]: "Extend voting",
+  [TiePolicy.AssertedValue]: "Resolve with asserted value",
+  [TiePolicy.Unresolvable]: "Resolve as unresolvable",
+};
+
+const transferFeePolicyNames: Record<TransferFeePolicy, string> = {
+  [TransferFeePolicy.Reject]: "Reject mints with transfer fees",
+  [TransferFeePolicy.AllowZeroFee]: "Allow mints with zero transfer fees",
 };
 
 const args: ConfigArgs = {
@@ -122,6 +147,13 @@ const args: ConfigArgs = {
     max: 0xffffffff,
     required: true,
   }),
+  revealWindow: await prompt.integer({
+    message: "Reveal window (secs, 0 to disable commit-reveal voting):",
+    default: 0,
+    min: 0,
+    max: 0xffffffff,
+    required: true,
+  }),
   arbitrationWindow: await prompt.integer({
     message: "Arbitration window (secs):",
     default: 12 * 60 * 60,
@@ -129,6 +161,104 @@ const args: ConfigArgs = {
     max: 0xffffffff,
     required: true,
   }),
+  claimWindow: await prompt.integer({
+    message: "Claim window (secs):",
+    default: 7 * 24 * 60 * 60,
+    min: 0,
+    max: 0xffffffff,
+    required: true,
+  }),
+  minDisputeWindow: await prompt.integer({
+    message: "Minimum request dispute window (secs):",
+    default: 0,
+    min: 0,
+    max: 0xffffffff,
+    required: true,
+  }),
+  maxDisputeWindow: await prompt.integer({
+    message: "Maximum request dispute window (secs):",
+    default: 0xffffffff,
+    min: 0,
+    max: 0xffffffff,
+    required: true,
+  }),
+  minVotingWindow: await prompt.integer({
+    message: "Minimum request voting window (secs):",
+    default: 0,
+    min: 0,
+    max: 0xffffffff,
+    required: true,
+  }),
+  maxVotingWindow: await prompt.integer({
+    message: "Maximum request voting window (secs):",
+    default: 0xffffffff,
+    min: 0,
+    max: 0xffffffff,
+    required: true,
+  }),
+  minArbitrationWindow: await prompt.integer({
+    message: "Minimum request arbitration window (secs):",
+    default: 0,
+    min: 0,
+    max: 0xffffffff,
+    required: true,
+  }),
+  maxArbitrationWindow: await prompt.integer({
+    message: "Maximum request arbitration window (secs):",
+    default: 0xffffffff,
+    min: 0,
+    max: 0xffffffff,
+    required: true,
+  }),
+  quorumAmount: await prompt.integer({
+    message: "Quorum amount (base units):",
+    default: 0,
+    min: 0,
+    max: 0xffffffffffffffffn,
+    bigint: true,
+    required: true,
+  }),
+  quorumBps: await prompt.amount({
+    message: "Quorum (% of total stake):",
+    identifier: "%",
+    decimals: 2,
+    default: createAmount(0n, "%", 2),
+    min: createAmount(0n, "%", 2),
+    max: createAmount(10_000n, "%", 2),
+    required: true,
+  }),
+  slashBps: await prompt.amount({
+    message: "Slash rate (% of incorrect vote stake):",
+    identifier: "%",
+    decimals: 2,
+    default: createAmount(0n, "%", 2),
+    min: createAmount(0n, "%", 2),
+    max: createAmount(10_000n, "%", 2),
+    required: true,
+  }),
+  slashWindow: await prompt.integer({
+    message: "Slash window (secs):",
+    default: 24 * 60 * 60,
+    min: 0,
+    max: 0xffffffff,
+    required: true,
+  }),
+  tiePolicy: await prompt.search<TiePolicy>({
+    message: "Tie policy:",
+    source: () =>
+      [TiePolicy.ExtendVoting, TiePolicy.AssertedValue, TiePolicy.Unresolvable].map((value) => ({
+        value,
+        name: tiePolicyNames[value],
+      })),
+  }),
+  transferFeePolicy: await prompt.search<TransferFeePolicy>({
+    message: "Transfer fee policy:",
+    source: () =>
+      [TransferFeePolicy.Reject, TransferFeePolicy.AllowZeroFee].map((value) => ({
+        value,
+        name: transferFeePolicyNames[value],
+      })),
+  }),
 };
 
 logger.newline();
@@ -144,7 +274,27 @@ logger.entry("Authority", args.authority);
 logger.entry("Bond fee", displayAmount(args.bondFeeBps));
 logger.entry("Dispute window", formatDuration(args.disputeWindow));
 logger.entry("Voting window", formatDuration(args.votingWindow));
+logger.entry("Reveal window", formatDuration(args.revealWindow));
 logger.entry("Arbitration window", formatDuration(args.arbitrationWindow));
+logger.entry("Claim window", formatDuration(args.claimWindow));
+logger.entry(
+  "Request dispute window",
+  `${formatDuration(args.minDisputeWindow)} to ${formatDuration(args.maxDisputeWindow)}`,
+);
+logger.entry(
+  "Request voting window",
+  `${formatDuration(args.minVotingWindow)} to ${formatDuration(args.maxVotingWindow)}`,
+);
+logger.entry(
+  "Request arbitration window",
+  `${formatDuration(args.minArbitrationWindow)} to ${formatDuration(args.maxArbitrationWindow)}`,
+);
+logger.entry("Quorum amount", args.quorumAmount.toString());
+logger.entry("Quorum", displayAmount(args.quorumBps));
+logger.entry("Slash rate", displayAmount(args.slashBps));
+logger.entry("Slash window", formatDuration(args.slashWindow));
+logger.entry("Tie policy", tiePolicyNames[args.tiePolicy]);
+logger.entry("Transfer fee policy", transferFeePolicyNames[args.transferFeePolicy]);
 logger.newline();
 
 if (!(await prompt.confirm({ message: "Send transaction?" }))) {
@@ -161,7 +311,19 @@ const builder = createConfigV1(umi, {
   bondFeeBps: Number(args.bondFeeBps.basisPoints),
   disputeWindow: args.disputeWindow,
   votingWindow: args.votingWindow,
+  revealWindow: args.revealWindow,
   arbitrationWindow: args.arbitrationWindow,
+  claimWindow: args.claimWindow,
+  // The maximum window is inclusive, while the end of the range is exclusive.
+  disputeWindowRange: { start: args.minDisputeWindow, end: args.maxDisputeWindow + 1 },
+  votingWindowRange: { start: args.minVotingWindow, end: args.maxVotingWindow + 1 },
+  arbitrationWindowRange: { start: args.minArbitrationWindow, end: args.maxArbitrationWindow + 1 },
+  quorumAmount: args.quorumAmount,
+  quorumBps: Number(args.quorumBps.basisPoints),
+  slashBps: Number(args.slashBps.basisPoints),
+  slashWindow: args.slashWindow,
+  tiePolicy: args.tiePolicy,
+  transferFeePolicy: args.transferFeePolicy,
 });
 
 const result = await spinner("Sending transaction...", builder.sendAndConfirm(umi));
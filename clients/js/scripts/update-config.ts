@@ -150,7 +150,9 @@ type UpdateArgs =
       newBondFeeBps: Amount<"%", 2>;
       newDisputeWindow: number;
       newVotingWindow: number;
+      newRevealWindow: number;
       newArbitrationWindow: number;
+      newClaimWindow: number;
     };
 
 const args: UpdateArgs = updateAuthority
@@ -187,6 +189,13 @@ const args: UpdateArgs = updateAuthority
         max: 0xffffffff,
         required: true,
       }),
+      newRevealWindow: await prompt.integer({
+        message: "New reveal window (secs, 0 to disable commit-reveal voting):",
+        default: config.revealWindow,
+        min: 0,
+        max: 0xffffffff,
+        required: true,
+      }),
       newArbitrationWindow: await prompt.integer({
         message: "New arbitration window (secs):",
         default: config.arbitrationWindow,
@@ -194,6 +203,13 @@ const args: UpdateArgs = updateAuthority
         max: 0xffffffff,
         required: true,
       }),
+      newClaimWindow: await prompt.integer({
+        message: "New claim window (secs):",
+        default: config.claimWindow,
+        min: 0,
+        max: 0xffffffff,
+        required: true,
+      }),
     };
 
 logger.newline();
@@ -230,7 +246,9 @@ if (args.__kind === "Authority") {
   logger.entry("New bond fee", displayAmount(args.newBondFeeBps));
   logger.entry("New dispute window", formatDuration(args.newDisputeWindow));
   logger.entry("New voting window", formatDuration(args.newVotingWindow));
+  logger.entry("New reveal window", formatDuration(args.newRevealWindow));
   logger.entry("New arbitration window", formatDuration(args.newArbitrationWindow));
+  logger.entry("New claim window", formatDuration(args.newClaimWindow));
 }
 logger.newline();
 
@@ -47,6 +47,7 @@ export type AssertionV1AccountData = {
   expirationTimestamp: DateTime;
   asserter: PublicKey;
   disputer: PublicKey;
+  rentPayer: PublicKey;
   assertedValue: bigint;
 };
 
@@ -56,6 +57,7 @@ export type AssertionV1AccountDataArgs = {
   expirationTimestamp: DateTimeInput;
   asserter: PublicKey;
   disputer: PublicKey;
+  rentPayer: PublicKey;
   assertedValue: number | bigint;
 };
 
@@ -72,6 +74,7 @@ export function getAssertionV1AccountDataSerializer(): Serializer<
         ["expirationTimestamp", mapDateTimeSerializer(i64())],
         ["asserter", publicKeySerializer()],
         ["disputer", publicKeySerializer()],
+        ["rentPayer", publicKeySerializer()],
         ["assertedValue", u64()],
       ],
       { description: "AssertionV1AccountData" },
@@ -145,6 +148,7 @@ export function getAssertionV1GpaBuilder(context: Pick<Context, "rpc" | "program
       expirationTimestamp: DateTimeInput;
       asserter: PublicKey;
       disputer: PublicKey;
+      rentPayer: PublicKey;
       assertedValue: number | bigint;
     }>({
       accountType: [0, getAccountTypeSerializer()],
@@ -153,14 +157,15 @@ export function getAssertionV1GpaBuilder(context: Pick<Context, "rpc" | "program
       expirationTimestamp: [41, mapDateTimeSerializer(i64())],
       asserter: [49, publicKeySerializer()],
       disputer: [81, publicKeySerializer()],
-      assertedValue: [113, u64()],
+      rentPayer: [113, publicKeySerializer()],
+      assertedValue: [145, u64()],
     })
     .deserializeUsing<AssertionV1>((account) => deserializeAssertionV1(account))
     .whereField("accountType", AccountType.AssertionV1);
 }
 
 export function getAssertionV1Size(): number {
-  return 121;
+  return 153;
 }
 
 export function findAssertionV1Pda(
@@ -6,7 +6,15 @@
  * @see https://github.com/kinobi-so/kinobi
  */
 
-import type { AccountTypeArgs } from "../types";
+import type {
+  AccountTypeArgs,
+  Bounds,
+  BoundsArgs,
+  TiePolicy,
+  TiePolicyArgs,
+  TransferFeePolicy,
+  TransferFeePolicyArgs,
+} from "../types";
 import type {
   Account,
   Context,
@@ -30,9 +38,16 @@ import {
   struct,
   u16,
   u32,
+  u64,
 } from "@metaplex-foundation/umi/serializers";
 
-import { AccountType, getAccountTypeSerializer } from "../types";
+import {
+  AccountType,
+  getAccountTypeSerializer,
+  getBoundsSerializer,
+  getTiePolicySerializer,
+  getTransferFeePolicySerializer,
+} from "../types";
 
 export type ConfigV1 = Account<ConfigV1AccountData>;
 
@@ -43,6 +58,17 @@ export type ConfigV1AccountData = {
   disputeWindow: number;
   votingWindow: number;
   arbitrationWindow: number;
+  revealWindow: number;
+  claimWindow: number;
+  disputeWindowRange: Bounds;
+  votingWindowRange: Bounds;
+  arbitrationWindowRange: Bounds;
+  quorumAmount: bigint;
+  quorumBps: number;
+  slashBps: number;
+  tiePolicy: TiePolicy;
+  transferFeePolicy: TransferFeePolicy;
+  slashWindow: number;
 };
 
 export type ConfigV1AccountDataArgs = {
@@ -51,6 +77,17 @@ export type ConfigV1AccountDataArgs = {
   disputeWindow: number;
   votingWindow: number;
   arbitrationWindow: number;
+  revealWindow: number;
+  claimWindow: number;
+  disputeWindowRange: BoundsArgs;
+  votingWindowRange: BoundsArgs;
+  arbitrationWindowRange: BoundsArgs;
+  quorumAmount: number | bigint;
+  quorumBps: number;
+  slashBps: number;
+  tiePolicy: TiePolicyArgs;
+  transferFeePolicy: TransferFeePolicyArgs;
+  slashWindow: number;
 };
 
 export function getConfigV1AccountDataSerializer(): Serializer<
@@ -66,6 +103,17 @@ export function getConfigV1AccountDataSerializer(): Serializer<
         ["disputeWindow", u32()],
         ["votingWindow", u32()],
         ["arbitrationWindow", u32()],
+        ["revealWindow", u32()],
+        ["claimWindow", u32()],
+        ["disputeWindowRange", getBoundsSerializer()],
+        ["votingWindowRange", getBoundsSerializer()],
+        ["arbitrationWindowRange", getBoundsSerializer()],
+        ["quorumAmount", u64()],
+        ["quorumBps", u16()],
+        ["slashBps", u16()],
+        ["tiePolicy", getTiePolicySerializer()],
+        ["transferFeePolicy", getTransferFeePolicySerializer()],
+        ["slashWindow", u32()],
       ],
       { description: "ConfigV1AccountData" },
     ),
@@ -138,6 +186,17 @@ export function getConfigV1GpaBuilder(context: Pick<Context, "rpc" | "programs">
       disputeWindow: number;
       votingWindow: number;
       arbitrationWindow: number;
+      revealWindow: number;
+      claimWindow: number;
+      disputeWindowRange: BoundsArgs;
+      votingWindowRange: BoundsArgs;
+      arbitrationWindowRange: BoundsArgs;
+      quorumAmount: number | bigint;
+      quorumBps: number;
+      slashBps: number;
+      tiePolicy: TiePolicyArgs;
+      transferFeePolicy: TransferFeePolicyArgs;
+      slashWindow: number;
     }>({
       accountType: [0, getAccountTypeSerializer()],
       authority: [1, publicKeySerializer()],
@@ -145,11 +204,22 @@ export function getConfigV1GpaBuilder(context: Pick<Context, "rpc" | "programs">
       disputeWindow: [35, u32()],
       votingWindow: [39, u32()],
       arbitrationWindow: [43, u32()],
+      revealWindow: [47, u32()],
+      claimWindow: [51, u32()],
+      disputeWindowRange: [55, getBoundsSerializer()],
+      votingWindowRange: [71, getBoundsSerializer()],
+      arbitrationWindowRange: [87, getBoundsSerializer()],
+      quorumAmount: [103, u64()],
+      quorumBps: [111, u16()],
+      slashBps: [113, u16()],
+      tiePolicy: [115, getTiePolicySerializer()],
+      transferFeePolicy: [116, getTransferFeePolicySerializer()],
+      slashWindow: [117, u32()],
     })
     .deserializeUsing<ConfigV1>((account) => deserializeConfigV1(account))
     .whereField("accountType", AccountType.ConfigV1);
 }
 
 export function getConfigV1Size(): number {
-  return 47;
+  return 121;
 }
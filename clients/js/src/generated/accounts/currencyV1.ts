@@ -6,7 +6,13 @@
  * @see https://github.com/kinobi-so/kinobi
  */
 
-import type { AccountTypeArgs, Bounds, BoundsArgs } from "../types";
+import type {
+  AccountTypeArgs,
+  Bounds,
+  BoundsArgs,
+  CurrencyStatus,
+  CurrencyStatusArgs,
+} from "../types";
 import type {
   Account,
   Context,
@@ -31,7 +37,12 @@ import {
   struct,
 } from "@metaplex-foundation/umi/serializers";
 
-import { AccountType, getAccountTypeSerializer, getBoundsSerializer } from "../types";
+import {
+  AccountType,
+  getAccountTypeSerializer,
+  getBoundsSerializer,
+  getCurrencyStatusSerializer,
+} from "../types";
 
 export type CurrencyV1 = Account<CurrencyV1AccountData>;
 
@@ -41,6 +52,7 @@ export type CurrencyV1AccountData = {
   mint: PublicKey;
   rewardRange: Bounds;
   bondRange: Bounds;
+  status: CurrencyStatus;
 };
 
 export type CurrencyV1AccountDataArgs = {
@@ -48,6 +60,7 @@ export type CurrencyV1AccountDataArgs = {
   mint: PublicKey;
   rewardRange: BoundsArgs;
   bondRange: BoundsArgs;
+  status: CurrencyStatusArgs;
 };
 
 export function getCurrencyV1AccountDataSerializer(): Serializer<
@@ -62,6 +75,7 @@ export function getCurrencyV1AccountDataSerializer(): Serializer<
         ["mint", publicKeySerializer()],
         ["rewardRange", getBoundsSerializer()],
         ["bondRange", getBoundsSerializer()],
+        ["status", getCurrencyStatusSerializer()],
       ],
       { description: "CurrencyV1AccountData" },
     ),
@@ -133,19 +147,21 @@ export function getCurrencyV1GpaBuilder(context: Pick<Context, "rpc" | "programs
       mint: PublicKey;
       rewardRange: BoundsArgs;
       bondRange: BoundsArgs;
+      status: CurrencyStatusArgs;
     }>({
       accountType: [0, getAccountTypeSerializer()],
       config: [1, publicKeySerializer()],
       mint: [33, publicKeySerializer()],
       rewardRange: [65, getBoundsSerializer()],
       bondRange: [81, getBoundsSerializer()],
+      status: [97, getCurrencyStatusSerializer()],
     })
     .deserializeUsing<CurrencyV1>((account) => deserializeCurrencyV1(account))
     .whereField("accountType", AccountType.CurrencyV1);
 }
 
 export function getCurrencyV1Size(): number {
-  return 97;
+  return 98;
 }
 
 export function findCurrencyV1Pda(
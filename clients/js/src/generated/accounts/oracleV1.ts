@@ -41,12 +41,14 @@ export type OracleV1AccountData = {
   nextIndex: bigint;
   authority: PublicKey;
   governanceMint: PublicKey;
+  totalStaked: bigint;
 };
 
 export type OracleV1AccountDataArgs = {
   nextIndex: number | bigint;
   authority: PublicKey;
   governanceMint: PublicKey;
+  totalStaked: number | bigint;
 };
 
 export function getOracleV1AccountDataSerializer(): Serializer<
@@ -60,6 +62,7 @@ export function getOracleV1AccountDataSerializer(): Serializer<
         ["nextIndex", u64()],
         ["authority", publicKeySerializer()],
         ["governanceMint", publicKeySerializer()],
+        ["totalStaked", u64()],
       ],
       { description: "OracleV1AccountData" },
     ),
@@ -130,18 +133,20 @@ export function getOracleV1GpaBuilder(context: Pick<Context, "rpc" | "programs">
       nextIndex: number | bigint;
       authority: PublicKey;
       governanceMint: PublicKey;
+      totalStaked: number | bigint;
     }>({
       accountType: [0, getAccountTypeSerializer()],
       nextIndex: [1, u64()],
       authority: [9, publicKeySerializer()],
       governanceMint: [41, publicKeySerializer()],
+      totalStaked: [73, u64()],
     })
     .deserializeUsing<OracleV1>((account) => deserializeOracleV1(account))
     .whereField("accountType", AccountType.OracleV1);
 }
 
 export function getOracleV1Size(): number {
-  return 73;
+  return 81;
 }
 
 export function findOracleV1Pda(context: Pick<Context, "eddsa" | "programs">): Pda {
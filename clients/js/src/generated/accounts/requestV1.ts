@@ -10,8 +10,12 @@ import type {
   AccountTypeArgs,
   RequestData,
   RequestDataArgs,
+  RequestDerivation,
+  RequestDerivationArgs,
   RequestState,
   RequestStateArgs,
+  TiePolicy,
+  TiePolicyArgs,
 } from "../types";
 import type {
   Account,
@@ -34,11 +38,14 @@ import {
   publicKey as toPublicKey,
 } from "@metaplex-foundation/umi";
 import {
+  bool,
   i64,
   mapSerializer,
   publicKey as publicKeySerializer,
   string,
   struct,
+  u16,
+  u32,
   u64,
 } from "@metaplex-foundation/umi/serializers";
 
@@ -46,7 +53,9 @@ import {
   AccountType,
   getAccountTypeSerializer,
   getRequestDataSerializer,
+  getRequestDerivationSerializer,
   getRequestStateSerializer,
+  getTiePolicySerializer,
 } from "../types";
 
 export type RequestV1 = Account<RequestV1AccountData>;
@@ -65,6 +74,23 @@ export type RequestV1AccountData = {
   state: RequestState;
   value: bigint;
   arbitrator: PublicKey;
+  round: number;
+  disputeWindow: number;
+  votingWindow: number;
+  revealWindow: number;
+  arbitrationWindow: number;
+  claimWindow: number;
+  bondFeeBps: number;
+  quorumAmount: bigint;
+  quorumBps: number;
+  slashBps: number;
+  tiePolicy: TiePolicy;
+  bondFee: bigint;
+  rentPayer: PublicKey;
+  slashWindow: number;
+  derivation: RequestDerivation;
+  allowTooEarly: boolean;
+  nonce: bigint;
   data: RequestData;
 };
 
@@ -81,6 +107,23 @@ export type RequestV1AccountDataArgs = {
   state: RequestStateArgs;
   value: number | bigint;
   arbitrator: PublicKey;
+  round: number;
+  disputeWindow: number;
+  votingWindow: number;
+  revealWindow: number;
+  arbitrationWindow: number;
+  claimWindow: number;
+  bondFeeBps: number;
+  quorumAmount: number | bigint;
+  quorumBps: number;
+  slashBps: number;
+  tiePolicy: TiePolicyArgs;
+  bondFee: number | bigint;
+  rentPayer: PublicKey;
+  slashWindow: number;
+  derivation: RequestDerivationArgs;
+  allowTooEarly: boolean;
+  nonce: number | bigint;
   data: RequestDataArgs;
 };
 
@@ -104,6 +147,23 @@ export function getRequestV1AccountDataSerializer(): Serializer<
         ["state", getRequestStateSerializer()],
         ["value", u64()],
         ["arbitrator", publicKeySerializer()],
+        ["round", u32()],
+        ["disputeWindow", u32()],
+        ["votingWindow", u32()],
+        ["revealWindow", u32()],
+        ["arbitrationWindow", u32()],
+        ["claimWindow", u32()],
+        ["bondFeeBps", u16()],
+        ["quorumAmount", u64()],
+        ["quorumBps", u16()],
+        ["slashBps", u16()],
+        ["tiePolicy", getTiePolicySerializer()],
+        ["bondFee", u64()],
+        ["rentPayer", publicKeySerializer()],
+        ["slashWindow", u32()],
+        ["derivation", getRequestDerivationSerializer()],
+        ["allowTooEarly", bool()],
+        ["nonce", u64()],
         ["data", getRequestDataSerializer()],
       ],
       { description: "RequestV1AccountData" },
@@ -184,6 +244,23 @@ export function getRequestV1GpaBuilder(context: Pick<Context, "rpc" | "programs"
       state: RequestStateArgs;
       value: number | bigint;
       arbitrator: PublicKey;
+      round: number;
+      disputeWindow: number;
+      votingWindow: number;
+      revealWindow: number;
+      arbitrationWindow: number;
+      claimWindow: number;
+      bondFeeBps: number;
+      quorumAmount: number | bigint;
+      quorumBps: number;
+      slashBps: number;
+      tiePolicy: TiePolicyArgs;
+      bondFee: number | bigint;
+      rentPayer: PublicKey;
+      slashWindow: number;
+      derivation: RequestDerivationArgs;
+      allowTooEarly: boolean;
+      nonce: number | bigint;
       data: RequestDataArgs;
     }>({
       accountType: [0, getAccountTypeSerializer()],
@@ -199,7 +276,24 @@ export function getRequestV1GpaBuilder(context: Pick<Context, "rpc" | "programs"
       state: [169, getRequestStateSerializer()],
       value: [170, u64()],
       arbitrator: [178, publicKeySerializer()],
-      data: [210, getRequestDataSerializer()],
+      round: [210, u32()],
+      disputeWindow: [214, u32()],
+      votingWindow: [218, u32()],
+      revealWindow: [222, u32()],
+      arbitrationWindow: [226, u32()],
+      claimWindow: [230, u32()],
+      bondFeeBps: [234, u16()],
+      quorumAmount: [236, u64()],
+      quorumBps: [244, u16()],
+      slashBps: [246, u16()],
+      tiePolicy: [248, getTiePolicySerializer()],
+      bondFee: [249, u64()],
+      rentPayer: [257, publicKeySerializer()],
+      slashWindow: [289, u32()],
+      derivation: [293, getRequestDerivationSerializer()],
+      allowTooEarly: [294, bool()],
+      nonce: [295, u64()],
+      data: [303, getRequestDataSerializer()],
     })
     .deserializeUsing<RequestV1>((account) => deserializeRequestV1(account))
     .whereField("accountType", AccountType.RequestV1);
@@ -33,6 +33,7 @@ import {
   publicKey as publicKeySerializer,
   string,
   struct,
+  u32,
   u64,
 } from "@metaplex-foundation/umi/serializers";
 
@@ -47,6 +48,7 @@ export type StakeV1AccountData = {
   delegate: PublicKey;
   amount: bigint;
   lockTimestamp: DateTime;
+  openVotes: number;
 };
 
 export type StakeV1AccountDataArgs = {
@@ -55,6 +57,7 @@ export type StakeV1AccountDataArgs = {
   delegate: PublicKey;
   amount: number | bigint;
   lockTimestamp: DateTimeInput;
+  openVotes: number;
 };
 
 export function getStakeV1AccountDataSerializer(): Serializer<
@@ -70,6 +73,7 @@ export function getStakeV1AccountDataSerializer(): Serializer<
         ["delegate", publicKeySerializer()],
         ["amount", u64()],
         ["lockTimestamp", mapDateTimeSerializer(i64())],
+        ["openVotes", u32()],
       ],
       { description: "StakeV1AccountData" },
     ),
@@ -142,6 +146,7 @@ export function getStakeV1GpaBuilder(context: Pick<Context, "rpc" | "programs">)
       delegate: PublicKey;
       amount: number | bigint;
       lockTimestamp: DateTimeInput;
+      openVotes: number;
     }>({
       accountType: [0, getAccountTypeSerializer()],
       mint: [1, publicKeySerializer()],
@@ -149,13 +154,14 @@ export function getStakeV1GpaBuilder(context: Pick<Context, "rpc" | "programs">)
       delegate: [65, publicKeySerializer()],
       amount: [97, u64()],
       lockTimestamp: [105, mapDateTimeSerializer(i64())],
+      openVotes: [113, u32()],
     })
     .deserializeUsing<StakeV1>((account) => deserializeStakeV1(account))
     .whereField("accountType", AccountType.StakeV1);
 }
 
 export function getStakeV1Size(): number {
-  return 113;
+  return 117;
 }
 
 export function findStakeV1Pda(
@@ -25,11 +25,15 @@ import {
   publicKey as toPublicKey,
 } from "@metaplex-foundation/umi";
 import {
+  array,
+  bool,
   mapSerializer,
   publicKey as publicKeySerializer,
   string,
   struct,
+  u32,
   u64,
+  u8,
 } from "@metaplex-foundation/umi/serializers";
 
 import { AccountType, getAccountTypeSerializer } from "../types";
@@ -40,15 +44,25 @@ export type VoteV1AccountData = {
   accountType: AccountType;
   voting: PublicKey;
   stake: PublicKey;
+  round: number;
+  rentPayer: PublicKey;
   value: bigint;
   votes: bigint;
+  commitment: Array<number>;
+  revealed: boolean;
+  votingRent: bigint;
 };
 
 export type VoteV1AccountDataArgs = {
   voting: PublicKey;
   stake: PublicKey;
+  round: number;
+  rentPayer: PublicKey;
   value: number | bigint;
   votes: number | bigint;
+  commitment: Array<number>;
+  revealed: boolean;
+  votingRent: number | bigint;
 };
 
 export function getVoteV1AccountDataSerializer(): Serializer<
@@ -61,8 +75,13 @@ export function getVoteV1AccountDataSerializer(): Serializer<
         ["accountType", getAccountTypeSerializer()],
         ["voting", publicKeySerializer()],
         ["stake", publicKeySerializer()],
+        ["round", u32()],
+        ["rentPayer", publicKeySerializer()],
         ["value", u64()],
         ["votes", u64()],
+        ["commitment", array(u8(), { size: 32 })],
+        ["revealed", bool()],
+        ["votingRent", u64()],
       ],
       { description: "VoteV1AccountData" },
     ),
@@ -132,21 +151,31 @@ export function getVoteV1GpaBuilder(context: Pick<Context, "rpc" | "programs">)
       accountType: AccountTypeArgs;
       voting: PublicKey;
       stake: PublicKey;
+      round: number;
+      rentPayer: PublicKey;
       value: number | bigint;
       votes: number | bigint;
+      commitment: Array<number>;
+      revealed: boolean;
+      votingRent: number | bigint;
     }>({
       accountType: [0, getAccountTypeSerializer()],
       voting: [1, publicKeySerializer()],
       stake: [33, publicKeySerializer()],
-      value: [65, u64()],
-      votes: [73, u64()],
+      round: [65, u32()],
+      rentPayer: [69, publicKeySerializer()],
+      value: [101, u64()],
+      votes: [109, u64()],
+      commitment: [117, array(u8(), { size: 32 })],
+      revealed: [149, bool()],
+      votingRent: [150, u64()],
     })
     .deserializeUsing<VoteV1>((account) => deserializeVoteV1(account))
     .whereField("accountType", AccountType.VoteV1);
 }
 
 export function getVoteV1Size(): number {
-  return 81;
+  return 158;
 }
 
 export function findVoteV1Pda(
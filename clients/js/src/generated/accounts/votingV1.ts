@@ -6,7 +6,7 @@
  * @see https://github.com/kinobi-so/kinobi
  */
 
-import type { AccountTypeArgs } from "../types";
+import type { AccountTypeArgs, RewardPool, RewardPoolArgs } from "../types";
 import type {
   Account,
   Context,
@@ -34,10 +34,11 @@ import {
   publicKey as publicKeySerializer,
   string,
   struct,
+  u32,
   u64,
 } from "@metaplex-foundation/umi/serializers";
 
-import { AccountType, getAccountTypeSerializer } from "../types";
+import { AccountType, getAccountTypeSerializer, getRewardPoolSerializer } from "../types";
 
 export type VotingV1 = Account<VotingV1AccountData>;
 
@@ -50,6 +51,11 @@ export type VotingV1AccountData = {
   voteCount: bigint;
   modeValue: bigint;
   votes: Map<bigint, bigint>;
+  rentPayer: PublicKey;
+  revealWindow: number;
+  unrevealedCount: bigint;
+  rewardPool: RewardPool;
+  openVotes: number;
 };
 
 export type VotingV1AccountDataArgs = {
@@ -60,6 +66,11 @@ export type VotingV1AccountDataArgs = {
   voteCount: number | bigint;
   modeValue: number | bigint;
   votes: Map<number | bigint, number | bigint>;
+  rentPayer: PublicKey;
+  revealWindow: number;
+  unrevealedCount: number | bigint;
+  rewardPool: RewardPoolArgs;
+  openVotes: number;
 };
 
 export function getVotingV1AccountDataSerializer(): Serializer<
@@ -77,6 +88,11 @@ export function getVotingV1AccountDataSerializer(): Serializer<
         ["voteCount", u64()],
         ["modeValue", u64()],
         ["votes", map(u64(), u64())],
+        ["rentPayer", publicKeySerializer()],
+        ["revealWindow", u32()],
+        ["unrevealedCount", u64()],
+        ["rewardPool", getRewardPoolSerializer()],
+        ["openVotes", u32()],
       ],
       { description: "VotingV1AccountData" },
     ),
@@ -151,6 +167,11 @@ export function getVotingV1GpaBuilder(context: Pick<Context, "rpc" | "programs">
       voteCount: number | bigint;
       modeValue: number | bigint;
       votes: Map<number | bigint, number | bigint>;
+      rentPayer: PublicKey;
+      revealWindow: number;
+      unrevealedCount: number | bigint;
+      rewardPool: RewardPoolArgs;
+      openVotes: number;
     }>({
       accountType: [0, getAccountTypeSerializer()],
       request: [1, publicKeySerializer()],
@@ -160,6 +181,11 @@ export function getVotingV1GpaBuilder(context: Pick<Context, "rpc" | "programs">
       voteCount: [81, u64()],
       modeValue: [89, u64()],
       votes: [97, map(u64(), u64())],
+      rentPayer: [null, publicKeySerializer()],
+      revealWindow: [null, u32()],
+      unrevealedCount: [null, u64()],
+      rewardPool: [null, getRewardPoolSerializer()],
+      openVotes: [null, u32()],
     })
     .deserializeUsing<VotingV1>((account) => deserializeVotingV1(account))
     .whereField("accountType", AccountType.VotingV1);
@@ -417,6 +417,474 @@ export class IncorrectVoteError extends ProgramError {
 codeToErrorMap.set(0x1e, IncorrectVoteError);
 nameToErrorMap.set("IncorrectVote", IncorrectVoteError);
 
+/** StakeOwnerMismatch: Stake owner address does not match */
+export class StakeOwnerMismatchError extends ProgramError {
+  override readonly name: string = "StakeOwnerMismatch";
+
+  readonly code: number = 0x1f; // 31
+
+  constructor(program: Program, cause?: Error) {
+    super("Stake owner address does not match", program, cause);
+  }
+}
+codeToErrorMap.set(0x1f, StakeOwnerMismatchError);
+nameToErrorMap.set("StakeOwnerMismatch", StakeOwnerMismatchError);
+
+/** StakeLocked: Stake is locked */
+export class StakeLockedError extends ProgramError {
+  override readonly name: string = "StakeLocked";
+
+  readonly code: number = 0x20; // 32
+
+  constructor(program: Program, cause?: Error) {
+    super("Stake is locked", program, cause);
+  }
+}
+codeToErrorMap.set(0x20, StakeLockedError);
+nameToErrorMap.set("StakeLocked", StakeLockedError);
+
+/** InsufficientStake: Insufficient stake */
+export class InsufficientStakeError extends ProgramError {
+  override readonly name: string = "InsufficientStake";
+
+  readonly code: number = 0x21; // 33
+
+  constructor(program: Program, cause?: Error) {
+    super("Insufficient stake", program, cause);
+  }
+}
+codeToErrorMap.set(0x21, InsufficientStakeError);
+nameToErrorMap.set("InsufficientStake", InsufficientStakeError);
+
+/** StakeMergeSelf: Cannot merge a stake account into itself */
+export class StakeMergeSelfError extends ProgramError {
+  override readonly name: string = "StakeMergeSelf";
+
+  readonly code: number = 0x22; // 34
+
+  constructor(program: Program, cause?: Error) {
+    super("Cannot merge a stake account into itself", program, cause);
+  }
+}
+codeToErrorMap.set(0x22, StakeMergeSelfError);
+nameToErrorMap.set("StakeMergeSelf", StakeMergeSelfError);
+
+/** ArbitratorMismatch: Arbitrator address does not match */
+export class ArbitratorMismatchError extends ProgramError {
+  override readonly name: string = "ArbitratorMismatch";
+
+  readonly code: number = 0x23; // 35
+
+  constructor(program: Program, cause?: Error) {
+    super("Arbitrator address does not match", program, cause);
+  }
+}
+codeToErrorMap.set(0x23, ArbitratorMismatchError);
+nameToErrorMap.set("ArbitratorMismatch", ArbitratorMismatchError);
+
+/** ArbitrationWindowExpired: Arbitration window has expired */
+export class ArbitrationWindowExpiredError extends ProgramError {
+  override readonly name: string = "ArbitrationWindowExpired";
+
+  readonly code: number = 0x24; // 36
+
+  constructor(program: Program, cause?: Error) {
+    super("Arbitration window has expired", program, cause);
+  }
+}
+codeToErrorMap.set(0x24, ArbitrationWindowExpiredError);
+nameToErrorMap.set("ArbitrationWindowExpired", ArbitrationWindowExpiredError);
+
+/** InvalidRequestData: Request data is not valid */
+export class InvalidRequestDataError extends ProgramError {
+  override readonly name: string = "InvalidRequestData";
+
+  readonly code: number = 0x25; // 37
+
+  constructor(program: Program, cause?: Error) {
+    super("Request data is not valid", program, cause);
+  }
+}
+codeToErrorMap.set(0x25, InvalidRequestDataError);
+nameToErrorMap.set("InvalidRequestData", InvalidRequestDataError);
+
+/** CreatorMismatch: Creator address does not match */
+export class CreatorMismatchError extends ProgramError {
+  override readonly name: string = "CreatorMismatch";
+
+  readonly code: number = 0x26; // 38
+
+  constructor(program: Program, cause?: Error) {
+    super("Creator address does not match", program, cause);
+  }
+}
+codeToErrorMap.set(0x26, CreatorMismatchError);
+nameToErrorMap.set("CreatorMismatch", CreatorMismatchError);
+
+/** RequestNotSettled: Request has unclaimed bond or reward */
+export class RequestNotSettledError extends ProgramError {
+  override readonly name: string = "RequestNotSettled";
+
+  readonly code: number = 0x27; // 39
+
+  constructor(program: Program, cause?: Error) {
+    super("Request has unclaimed bond or reward", program, cause);
+  }
+}
+codeToErrorMap.set(0x27, RequestNotSettledError);
+nameToErrorMap.set("RequestNotSettled", RequestNotSettledError);
+
+/** ClaimWindowNotExpired: Claim window has not expired */
+export class ClaimWindowNotExpiredError extends ProgramError {
+  override readonly name: string = "ClaimWindowNotExpired";
+
+  readonly code: number = 0x28; // 40
+
+  constructor(program: Program, cause?: Error) {
+    super("Claim window has not expired", program, cause);
+  }
+}
+codeToErrorMap.set(0x28, ClaimWindowNotExpiredError);
+nameToErrorMap.set("ClaimWindowNotExpired", ClaimWindowNotExpiredError);
+
+/** RentPayerMismatch: Rent payer address does not match */
+export class RentPayerMismatchError extends ProgramError {
+  override readonly name: string = "RentPayerMismatch";
+
+  readonly code: number = 0x29; // 41
+
+  constructor(program: Program, cause?: Error) {
+    super("Rent payer address does not match", program, cause);
+  }
+}
+codeToErrorMap.set(0x29, RentPayerMismatchError);
+nameToErrorMap.set("RentPayerMismatch", RentPayerMismatchError);
+
+/** RewardPoolExhausted: Reward pool has no unclaimed votes remaining */
+export class RewardPoolExhaustedError extends ProgramError {
+  override readonly name: string = "RewardPoolExhausted";
+
+  readonly code: number = 0x2a; // 42
+
+  constructor(program: Program, cause?: Error) {
+    super("Reward pool has no unclaimed votes remaining", program, cause);
+  }
+}
+codeToErrorMap.set(0x2a, RewardPoolExhaustedError);
+nameToErrorMap.set("RewardPoolExhausted", RewardPoolExhaustedError);
+
+/** SlashingDisabled: Slashing is not enabled for the config */
+export class SlashingDisabledError extends ProgramError {
+  override readonly name: string = "SlashingDisabled";
+
+  readonly code: number = 0x2b; // 43
+
+  constructor(program: Program, cause?: Error) {
+    super("Slashing is not enabled for the config", program, cause);
+  }
+}
+codeToErrorMap.set(0x2b, SlashingDisabledError);
+nameToErrorMap.set("SlashingDisabled", SlashingDisabledError);
+
+/** CorrectVote: Value voted for is the resolved value */
+export class CorrectVoteError extends ProgramError {
+  override readonly name: string = "CorrectVote";
+
+  readonly code: number = 0x2c; // 44
+
+  constructor(program: Program, cause?: Error) {
+    super("Value voted for is the resolved value", program, cause);
+  }
+}
+codeToErrorMap.set(0x2c, CorrectVoteError);
+nameToErrorMap.set("CorrectVote", CorrectVoteError);
+
+/** CommitRevealRequired: Votes must be committed and then revealed */
+export class CommitRevealRequiredError extends ProgramError {
+  override readonly name: string = "CommitRevealRequired";
+
+  readonly code: number = 0x2d; // 45
+
+  constructor(program: Program, cause?: Error) {
+    super("Votes must be committed and then revealed", program, cause);
+  }
+}
+codeToErrorMap.set(0x2d, CommitRevealRequiredError);
+nameToErrorMap.set("CommitRevealRequired", CommitRevealRequiredError);
+
+/** CommitRevealDisabled: Commit-reveal voting is not enabled */
+export class CommitRevealDisabledError extends ProgramError {
+  override readonly name: string = "CommitRevealDisabled";
+
+  readonly code: number = 0x2e; // 46
+
+  constructor(program: Program, cause?: Error) {
+    super("Commit-reveal voting is not enabled", program, cause);
+  }
+}
+codeToErrorMap.set(0x2e, CommitRevealDisabledError);
+nameToErrorMap.set("CommitRevealDisabled", CommitRevealDisabledError);
+
+/** RevealWindowNotOpen: Reveal window is not open */
+export class RevealWindowNotOpenError extends ProgramError {
+  override readonly name: string = "RevealWindowNotOpen";
+
+  readonly code: number = 0x2f; // 47
+
+  constructor(program: Program, cause?: Error) {
+    super("Reveal window is not open", program, cause);
+  }
+}
+codeToErrorMap.set(0x2f, RevealWindowNotOpenError);
+nameToErrorMap.set("RevealWindowNotOpen", RevealWindowNotOpenError);
+
+/** VoteAlreadyRevealed: Vote has already been revealed */
+export class VoteAlreadyRevealedError extends ProgramError {
+  override readonly name: string = "VoteAlreadyRevealed";
+
+  readonly code: number = 0x30; // 48
+
+  constructor(program: Program, cause?: Error) {
+    super("Vote has already been revealed", program, cause);
+  }
+}
+codeToErrorMap.set(0x30, VoteAlreadyRevealedError);
+nameToErrorMap.set("VoteAlreadyRevealed", VoteAlreadyRevealedError);
+
+/** CommitmentMismatch: Revealed value and salt do not match commitment */
+export class CommitmentMismatchError extends ProgramError {
+  override readonly name: string = "CommitmentMismatch";
+
+  readonly code: number = 0x31; // 49
+
+  constructor(program: Program, cause?: Error) {
+    super("Revealed value and salt do not match commitment", program, cause);
+  }
+}
+codeToErrorMap.set(0x31, CommitmentMismatchError);
+nameToErrorMap.set("CommitmentMismatch", CommitmentMismatchError);
+
+/** VoteNotRevealed: Vote has not been revealed */
+export class VoteNotRevealedError extends ProgramError {
+  override readonly name: string = "VoteNotRevealed";
+
+  readonly code: number = 0x32; // 50
+
+  constructor(program: Program, cause?: Error) {
+    super("Vote has not been revealed", program, cause);
+  }
+}
+codeToErrorMap.set(0x32, VoteNotRevealedError);
+nameToErrorMap.set("VoteNotRevealed", VoteNotRevealedError);
+
+/** AlreadyVoted: Stake has already voted */
+export class AlreadyVotedError extends ProgramError {
+  override readonly name: string = "AlreadyVoted";
+
+  readonly code: number = 0x33; // 51
+
+  constructor(program: Program, cause?: Error) {
+    super("Stake has already voted", program, cause);
+  }
+}
+codeToErrorMap.set(0x33, AlreadyVotedError);
+nameToErrorMap.set("AlreadyVoted", AlreadyVotedError);
+
+/** VoteRoundMismatch: Vote is from a previous round of the request */
+export class VoteRoundMismatchError extends ProgramError {
+  override readonly name: string = "VoteRoundMismatch";
+
+  readonly code: number = 0x34; // 52
+
+  constructor(program: Program, cause?: Error) {
+    super("Vote is from a previous round of the request", program, cause);
+  }
+}
+codeToErrorMap.set(0x34, VoteRoundMismatchError);
+nameToErrorMap.set("VoteRoundMismatch", VoteRoundMismatchError);
+
+/** WindowBounds: Window must be within valid bounds */
+export class WindowBoundsError extends ProgramError {
+  override readonly name: string = "WindowBounds";
+
+  readonly code: number = 0x35; // 53
+
+  constructor(program: Program, cause?: Error) {
+    super("Window must be within valid bounds", program, cause);
+  }
+}
+codeToErrorMap.set(0x35, WindowBoundsError);
+nameToErrorMap.set("WindowBounds", WindowBoundsError);
+
+/** TransferFeeNotSupported: Mint transfer fee is not supported */
+export class TransferFeeNotSupportedError extends ProgramError {
+  override readonly name: string = "TransferFeeNotSupported";
+
+  readonly code: number = 0x36; // 54
+
+  constructor(program: Program, cause?: Error) {
+    super("Mint transfer fee is not supported", program, cause);
+  }
+}
+codeToErrorMap.set(0x36, TransferFeeNotSupportedError);
+nameToErrorMap.set("TransferFeeNotSupported", TransferFeeNotSupportedError);
+
+/** EscrowAmountMismatch: Escrow did not receive the full amount */
+export class EscrowAmountMismatchError extends ProgramError {
+  override readonly name: string = "EscrowAmountMismatch";
+
+  readonly code: number = 0x37; // 55
+
+  constructor(program: Program, cause?: Error) {
+    super("Escrow did not receive the full amount", program, cause);
+  }
+}
+codeToErrorMap.set(0x37, EscrowAmountMismatchError);
+nameToErrorMap.set("EscrowAmountMismatch", EscrowAmountMismatchError);
+
+/** EmptyBounds: Bounds must not be empty */
+export class EmptyBoundsError extends ProgramError {
+  override readonly name: string = "EmptyBounds";
+
+  readonly code: number = 0x38; // 56
+
+  constructor(program: Program, cause?: Error) {
+    super("Bounds must not be empty", program, cause);
+  }
+}
+codeToErrorMap.set(0x38, EmptyBoundsError);
+nameToErrorMap.set("EmptyBounds", EmptyBoundsError);
+
+/** MintExtensionNotSupported: Mint extension is not supported */
+export class MintExtensionNotSupportedError extends ProgramError {
+  override readonly name: string = "MintExtensionNotSupported";
+
+  readonly code: number = 0x39; // 57
+
+  constructor(program: Program, cause?: Error) {
+    super("Mint extension is not supported", program, cause);
+  }
+}
+codeToErrorMap.set(0x39, MintExtensionNotSupportedError);
+nameToErrorMap.set("MintExtensionNotSupported", MintExtensionNotSupportedError);
+
+/** CurrencyDisabled: Currency is disabled */
+export class CurrencyDisabledError extends ProgramError {
+  override readonly name: string = "CurrencyDisabled";
+
+  readonly code: number = 0x3a; // 58
+
+  constructor(program: Program, cause?: Error) {
+    super("Currency is disabled", program, cause);
+  }
+}
+codeToErrorMap.set(0x3a, CurrencyDisabledError);
+nameToErrorMap.set("CurrencyDisabled", CurrencyDisabledError);
+
+/** StakeHasOpenVotes: Stake has open votes */
+export class StakeHasOpenVotesError extends ProgramError {
+  override readonly name: string = "StakeHasOpenVotes";
+
+  readonly code: number = 0x3b; // 59
+
+  constructor(program: Program, cause?: Error) {
+    super("Stake has open votes", program, cause);
+  }
+}
+codeToErrorMap.set(0x3b, StakeHasOpenVotesError);
+nameToErrorMap.set("StakeHasOpenVotes", StakeHasOpenVotesError);
+
+/** InsufficientFees: Insufficient fees */
+export class InsufficientFeesError extends ProgramError {
+  override readonly name: string = "InsufficientFees";
+
+  readonly code: number = 0x3c; // 60
+
+  constructor(program: Program, cause?: Error) {
+    super("Insufficient fees", program, cause);
+  }
+}
+codeToErrorMap.set(0x3c, InsufficientFeesError);
+nameToErrorMap.set("InsufficientFees", InsufficientFeesError);
+
+/** VotingHasOpenVotes: Voting has open votes */
+export class VotingHasOpenVotesError extends ProgramError {
+  override readonly name: string = "VotingHasOpenVotes";
+
+  readonly code: number = 0x3d; // 61
+
+  constructor(program: Program, cause?: Error) {
+    super("Voting has open votes", program, cause);
+  }
+}
+codeToErrorMap.set(0x3d, VotingHasOpenVotesError);
+nameToErrorMap.set("VotingHasOpenVotes", VotingHasOpenVotesError);
+
+/** SlashWindowExpired: Slash window expired */
+export class SlashWindowExpiredError extends ProgramError {
+  override readonly name: string = "SlashWindowExpired";
+
+  readonly code: number = 0x3e; // 62
+
+  constructor(program: Program, cause?: Error) {
+    super("Slash window expired", program, cause);
+  }
+}
+codeToErrorMap.set(0x3e, SlashWindowExpiredError);
+nameToErrorMap.set("SlashWindowExpired", SlashWindowExpiredError);
+
+/** SlashWindowNotExpired: Slash window not expired */
+export class SlashWindowNotExpiredError extends ProgramError {
+  override readonly name: string = "SlashWindowNotExpired";
+
+  readonly code: number = 0x3f; // 63
+
+  constructor(program: Program, cause?: Error) {
+    super("Slash window not expired", program, cause);
+  }
+}
+codeToErrorMap.set(0x3f, SlashWindowNotExpiredError);
+nameToErrorMap.set("SlashWindowNotExpired", SlashWindowNotExpiredError);
+
+/** TooEarlyNotAllowed: Request cannot be resolved as too early */
+export class TooEarlyNotAllowedError extends ProgramError {
+  override readonly name: string = "TooEarlyNotAllowed";
+
+  readonly code: number = 0x40; // 64
+
+  constructor(program: Program, cause?: Error) {
+    super("Request cannot be resolved as too early", program, cause);
+  }
+}
+codeToErrorMap.set(0x40, TooEarlyNotAllowedError);
+nameToErrorMap.set("TooEarlyNotAllowed", TooEarlyNotAllowedError);
+
+/** TokenOwnerMismatch: Token account owner does not match */
+export class TokenOwnerMismatchError extends ProgramError {
+  override readonly name: string = "TokenOwnerMismatch";
+
+  readonly code: number = 0x41; // 65
+
+  constructor(program: Program, cause?: Error) {
+    super("Token account owner does not match", program, cause);
+  }
+}
+codeToErrorMap.set(0x41, TokenOwnerMismatchError);
+nameToErrorMap.set("TokenOwnerMismatch", TokenOwnerMismatchError);
+
+/** RequestCancelled: Request has been cancelled */
+export class RequestCancelledError extends ProgramError {
+  override readonly name: string = "RequestCancelled";
+
+  readonly code: number = 0x42; // 66
+
+  constructor(program: Program, cause?: Error) {
+    super("Request has been cancelled", program, cause);
+  }
+}
+codeToErrorMap.set(0x42, RequestCancelledError);
+nameToErrorMap.set("RequestCancelled", RequestCancelledError);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
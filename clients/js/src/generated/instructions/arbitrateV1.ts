@@ -0,0 +1,201 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import type { ResolvedAccount, ResolvedAccountsWithIndices } from "../shared";
+import type { Context, Pda, PublicKey, Signer, TransactionBuilder } from "@metaplex-foundation/umi";
+import type { Serializer } from "@metaplex-foundation/umi/serializers";
+
+import { transactionBuilder } from "@metaplex-foundation/umi";
+import { mapSerializer, struct, u64, u8 } from "@metaplex-foundation/umi/serializers";
+
+import { findTreasuryPda } from "../../hooked";
+import { findAssertionV1Pda, findVotingV1Pda } from "../accounts";
+import { expectPublicKey, getAccountMetasAndSigners } from "../shared";
+
+// Accounts.
+export type ArbitrateV1InstructionAccounts = {
+  /** Config */
+  config: PublicKey | Pda;
+  /** Request */
+  request: PublicKey | Pda;
+  /** Voting */
+  voting?: PublicKey | Pda;
+  /** Assertion */
+  assertion?: PublicKey | Pda;
+  /** Bond mint */
+  bondMint: PublicKey | Pda;
+  /** Bond escrow token account of incorrect asserter/disputer */
+  bondEscrow: PublicKey | Pda;
+  /** Fee treasury token account */
+  treasury?: PublicKey | Pda;
+  /** Arbitrator */
+  arbitrator?: Signer;
+  /** Payer */
+  payer?: Signer;
+  /** SPL token program */
+  tokenProgram?: PublicKey | Pda;
+  /** System program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type ArbitrateV1InstructionData = {
+  discriminator: number;
+  value: bigint;
+};
+
+export type ArbitrateV1InstructionDataArgs = { value: number | bigint };
+
+export function getArbitrateV1InstructionDataSerializer(): Serializer<
+  ArbitrateV1InstructionDataArgs,
+  ArbitrateV1InstructionData
+> {
+  return mapSerializer<ArbitrateV1InstructionDataArgs, any, ArbitrateV1InstructionData>(
+    struct<ArbitrateV1InstructionData>(
+      [
+        ["discriminator", u8()],
+        ["value", u64()],
+      ],
+      { description: "ArbitrateV1InstructionData" },
+    ),
+    (value) => ({ ...value, discriminator: 20 }),
+  );
+}
+
+// Args.
+export type ArbitrateV1InstructionArgs = ArbitrateV1InstructionDataArgs;
+
+// Instruction.
+export function arbitrateV1(
+  context: Pick<Context, "eddsa" | "identity" | "payer" | "programs">,
+  input: ArbitrateV1InstructionAccounts & ArbitrateV1InstructionArgs,
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    "optimisticOracle",
+    "DVM2j1a1AJ9hZuEXyMxA5vusKgMR2FcKJyCf3QE5R2ge",
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    config: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.config ?? null,
+    },
+    request: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.request ?? null,
+    },
+    voting: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.voting ?? null,
+    },
+    assertion: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.assertion ?? null,
+    },
+    bondMint: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.bondMint ?? null,
+    },
+    bondEscrow: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.bondEscrow ?? null,
+    },
+    treasury: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.treasury ?? null,
+    },
+    arbitrator: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.arbitrator ?? null,
+    },
+    payer: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    tokenProgram: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    systemProgram: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: ArbitrateV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.voting.value) {
+    resolvedAccounts.voting.value = findVotingV1Pda(context, {
+      request: expectPublicKey(resolvedAccounts.request.value),
+    });
+  }
+  if (!resolvedAccounts.assertion.value) {
+    resolvedAccounts.assertion.value = findAssertionV1Pda(context, {
+      request: expectPublicKey(resolvedAccounts.request.value),
+    });
+  }
+  if (!resolvedAccounts.treasury.value) {
+    resolvedAccounts.treasury.value = findTreasuryPda(context, {
+      config: expectPublicKey(resolvedAccounts.config.value),
+      mint: expectPublicKey(resolvedAccounts.bondMint.value),
+    });
+  }
+  if (!resolvedAccounts.arbitrator.value) {
+    resolvedAccounts.arbitrator.value = context.identity;
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      "splToken",
+      "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      "splSystem",
+      "11111111111111111111111111111111",
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: Array<ResolvedAccount> = Object.values(resolvedAccounts).sort(
+    (a, b) => a.index - b.index,
+  );
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(orderedAccounts, "programId", programId);
+
+  // Data.
+  const data = getArbitrateV1InstructionDataSerializer().serialize(resolvedArgs);
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,145 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import type { ResolvedAccount, ResolvedAccountsWithIndices } from "../shared";
+import type { Context, Pda, PublicKey, Signer, TransactionBuilder } from "@metaplex-foundation/umi";
+import type { Serializer } from "@metaplex-foundation/umi/serializers";
+
+import { findAssociatedTokenPda } from "@metaplex-foundation/mpl-toolbox";
+import { transactionBuilder } from "@metaplex-foundation/umi";
+import { mapSerializer, struct, u8 } from "@metaplex-foundation/umi/serializers";
+
+import { findRewardPda } from "../../hooked";
+import { expectPublicKey, getAccountMetasAndSigners } from "../shared";
+
+// Accounts.
+export type CancelRequestV1InstructionAccounts = {
+  /** Request */
+  request: PublicKey | Pda;
+  /** Reward mint */
+  rewardMint: PublicKey | Pda;
+  /** Reward destination token account */
+  rewardDestination?: PublicKey | Pda;
+  /** Reward escrow token account */
+  rewardEscrow?: PublicKey | Pda;
+  /** Creator */
+  creator?: Signer;
+  /** Request rent payer */
+  requestRentPayer: PublicKey | Pda;
+  /** SPL token program */
+  tokenProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type CancelRequestV1InstructionData = { discriminator: number };
+
+export type CancelRequestV1InstructionDataArgs = {};
+
+export function getCancelRequestV1InstructionDataSerializer(): Serializer<
+  CancelRequestV1InstructionDataArgs,
+  CancelRequestV1InstructionData
+> {
+  return mapSerializer<CancelRequestV1InstructionDataArgs, any, CancelRequestV1InstructionData>(
+    struct<CancelRequestV1InstructionData>([["discriminator", u8()]], {
+      description: "CancelRequestV1InstructionData",
+    }),
+    (value) => ({ ...value, discriminator: 22 }),
+  );
+}
+
+// Instruction.
+export function cancelRequestV1(
+  context: Pick<Context, "eddsa" | "identity" | "programs">,
+  input: CancelRequestV1InstructionAccounts,
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    "optimisticOracle",
+    "DVM2j1a1AJ9hZuEXyMxA5vusKgMR2FcKJyCf3QE5R2ge",
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    request: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.request ?? null,
+    },
+    rewardMint: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.rewardMint ?? null,
+    },
+    rewardDestination: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.rewardDestination ?? null,
+    },
+    rewardEscrow: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.rewardEscrow ?? null,
+    },
+    creator: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.creator ?? null,
+    },
+    requestRentPayer: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.requestRentPayer ?? null,
+    },
+    tokenProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.creator.value) {
+    resolvedAccounts.creator.value = context.identity;
+  }
+  if (!resolvedAccounts.rewardDestination.value) {
+    resolvedAccounts.rewardDestination.value = findAssociatedTokenPda(context, {
+      mint: expectPublicKey(resolvedAccounts.rewardMint.value),
+      owner: expectPublicKey(resolvedAccounts.creator.value),
+    });
+  }
+  if (!resolvedAccounts.rewardEscrow.value) {
+    resolvedAccounts.rewardEscrow.value = findRewardPda(context, {
+      request: expectPublicKey(resolvedAccounts.request.value),
+    });
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      "splToken",
+      "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: Array<ResolvedAccount> = Object.values(resolvedAccounts).sort(
+    (a, b) => a.index - b.index,
+  );
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(orderedAccounts, "programId", programId);
+
+  // Data.
+  const data = getCancelRequestV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
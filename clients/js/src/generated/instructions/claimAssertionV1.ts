@@ -38,6 +38,10 @@ export type ClaimAssertionV1InstructionAccounts = {
   rewardEscrow?: PublicKey | Pda;
   /** Asserter */
   asserter?: Signer;
+  /** Request rent payer */
+  requestRentPayer: PublicKey | Pda;
+  /** Assertion rent payer */
+  assertionRentPayer: PublicKey | Pda;
   /** SPL token program */
   tokenProgram?: PublicKey | Pda;
   /** System program */
@@ -116,16 +120,26 @@ export function claimAssertionV1(
     },
     asserter: {
       index: 8,
-      isWritable: true as boolean,
+      isWritable: false as boolean,
       value: input.asserter ?? null,
     },
-    tokenProgram: {
+    requestRentPayer: {
       index: 9,
+      isWritable: true as boolean,
+      value: input.requestRentPayer ?? null,
+    },
+    assertionRentPayer: {
+      index: 10,
+      isWritable: true as boolean,
+      value: input.assertionRentPayer ?? null,
+    },
+    tokenProgram: {
+      index: 11,
       isWritable: false as boolean,
       value: input.tokenProgram ?? null,
     },
     systemProgram: {
-      index: 10,
+      index: 12,
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
@@ -15,7 +15,7 @@ import { transactionBuilder } from "@metaplex-foundation/umi";
 import { mapSerializer, struct, u8 } from "@metaplex-foundation/umi/serializers";
 
 import { findDisputeBondPda, findRewardPda } from "../../hooked";
-import { findAssertionV1Pda } from "../accounts";
+import { findAssertionV1Pda, findVotingV1Pda } from "../accounts";
 import { expectPublicKey, getAccountMetasAndSigners } from "../shared";
 
 // Accounts.
@@ -24,6 +24,8 @@ export type ClaimDisputeV1InstructionAccounts = {
   request: PublicKey | Pda;
   /** Assertion */
   assertion?: PublicKey | Pda;
+  /** Voting */
+  voting?: PublicKey | Pda;
   /** Bond mint */
   bondMint: PublicKey | Pda;
   /** Reclaimed bond destination token account */
@@ -38,6 +40,10 @@ export type ClaimDisputeV1InstructionAccounts = {
   rewardEscrow?: PublicKey | Pda;
   /** Disputer */
   disputer?: Signer;
+  /** Request rent payer */
+  requestRentPayer: PublicKey | Pda;
+  /** Voting rent payer */
+  votingRentPayer: PublicKey | Pda;
   /** SPL token program */
   tokenProgram?: PublicKey | Pda;
   /** System program */
@@ -84,48 +90,63 @@ export function claimDisputeV1(
       isWritable: true as boolean,
       value: input.assertion ?? null,
     },
-    bondMint: {
+    voting: {
       index: 2,
       isWritable: false as boolean,
+      value: input.voting ?? null,
+    },
+    bondMint: {
+      index: 3,
+      isWritable: false as boolean,
       value: input.bondMint ?? null,
     },
     bondDestination: {
-      index: 3,
+      index: 4,
       isWritable: true as boolean,
       value: input.bondDestination ?? null,
     },
     bondEscrow: {
-      index: 4,
+      index: 5,
       isWritable: true as boolean,
       value: input.bondEscrow ?? null,
     },
     rewardMint: {
-      index: 5,
+      index: 6,
       isWritable: false as boolean,
       value: input.rewardMint ?? null,
     },
     rewardDestination: {
-      index: 6,
+      index: 7,
       isWritable: true as boolean,
       value: input.rewardDestination ?? null,
     },
     rewardEscrow: {
-      index: 7,
+      index: 8,
       isWritable: true as boolean,
       value: input.rewardEscrow ?? null,
     },
     disputer: {
-      index: 8,
-      isWritable: true as boolean,
+      index: 9,
+      isWritable: false as boolean,
       value: input.disputer ?? null,
     },
+    requestRentPayer: {
+      index: 10,
+      isWritable: true as boolean,
+      value: input.requestRentPayer ?? null,
+    },
+    votingRentPayer: {
+      index: 11,
+      isWritable: true as boolean,
+      value: input.votingRentPayer ?? null,
+    },
     tokenProgram: {
-      index: 9,
+      index: 12,
       isWritable: false as boolean,
       value: input.tokenProgram ?? null,
     },
     systemProgram: {
-      index: 10,
+      index: 13,
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
@@ -137,6 +158,11 @@ export function claimDisputeV1(
       request: expectPublicKey(resolvedAccounts.request.value),
     });
   }
+  if (!resolvedAccounts.voting.value) {
+    resolvedAccounts.voting.value = findVotingV1Pda(context, {
+      request: expectPublicKey(resolvedAccounts.request.value),
+    });
+  }
   if (!resolvedAccounts.disputer.value) {
     resolvedAccounts.disputer.value = context.identity;
   }
@@ -0,0 +1,158 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import type { ResolvedAccount, ResolvedAccountsWithIndices } from "../shared";
+import type { Context, Pda, PublicKey, Signer, TransactionBuilder } from "@metaplex-foundation/umi";
+import type { Serializer } from "@metaplex-foundation/umi/serializers";
+
+import { findAssociatedTokenPda } from "@metaplex-foundation/mpl-toolbox";
+import { transactionBuilder } from "@metaplex-foundation/umi";
+import { mapSerializer, struct, u8 } from "@metaplex-foundation/umi/serializers";
+
+import { findRewardPda } from "../../hooked";
+import { findAssertionV1Pda } from "../accounts";
+import { expectPublicKey, getAccountMetasAndSigners } from "../shared";
+
+// Accounts.
+export type ClaimRefundV1InstructionAccounts = {
+  /** Request */
+  request: PublicKey | Pda;
+  /** Assertion */
+  assertion?: PublicKey | Pda;
+  /** Reward mint */
+  rewardMint: PublicKey | Pda;
+  /** Reward destination token account */
+  rewardDestination?: PublicKey | Pda;
+  /** Reward escrow token account */
+  rewardEscrow?: PublicKey | Pda;
+  /** Creator */
+  creator?: Signer;
+  /** Request rent payer */
+  requestRentPayer: PublicKey | Pda;
+  /** SPL token program */
+  tokenProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type ClaimRefundV1InstructionData = { discriminator: number };
+
+export type ClaimRefundV1InstructionDataArgs = {};
+
+export function getClaimRefundV1InstructionDataSerializer(): Serializer<
+  ClaimRefundV1InstructionDataArgs,
+  ClaimRefundV1InstructionData
+> {
+  return mapSerializer<ClaimRefundV1InstructionDataArgs, any, ClaimRefundV1InstructionData>(
+    struct<ClaimRefundV1InstructionData>([["discriminator", u8()]], {
+      description: "ClaimRefundV1InstructionData",
+    }),
+    (value) => ({ ...value, discriminator: 29 }),
+  );
+}
+
+// Instruction.
+export function claimRefundV1(
+  context: Pick<Context, "eddsa" | "identity" | "programs">,
+  input: ClaimRefundV1InstructionAccounts,
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    "optimisticOracle",
+    "DVM2j1a1AJ9hZuEXyMxA5vusKgMR2FcKJyCf3QE5R2ge",
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    request: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.request ?? null,
+    },
+    assertion: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.assertion ?? null,
+    },
+    rewardMint: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.rewardMint ?? null,
+    },
+    rewardDestination: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.rewardDestination ?? null,
+    },
+    rewardEscrow: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.rewardEscrow ?? null,
+    },
+    creator: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.creator ?? null,
+    },
+    requestRentPayer: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.requestRentPayer ?? null,
+    },
+    tokenProgram: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.assertion.value) {
+    resolvedAccounts.assertion.value = findAssertionV1Pda(context, {
+      request: expectPublicKey(resolvedAccounts.request.value),
+    });
+  }
+  if (!resolvedAccounts.creator.value) {
+    resolvedAccounts.creator.value = context.identity;
+  }
+  if (!resolvedAccounts.rewardDestination.value) {
+    resolvedAccounts.rewardDestination.value = findAssociatedTokenPda(context, {
+      mint: expectPublicKey(resolvedAccounts.rewardMint.value),
+      owner: expectPublicKey(resolvedAccounts.creator.value),
+    });
+  }
+  if (!resolvedAccounts.rewardEscrow.value) {
+    resolvedAccounts.rewardEscrow.value = findRewardPda(context, {
+      request: expectPublicKey(resolvedAccounts.request.value),
+    });
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      "splToken",
+      "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: Array<ResolvedAccount> = Object.values(resolvedAccounts).sort(
+    (a, b) => a.index - b.index,
+  );
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(orderedAccounts, "programId", programId);
+
+  // Data.
+  const data = getClaimRefundV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -14,7 +14,7 @@ import { findAssociatedTokenPda } from "@metaplex-foundation/mpl-toolbox";
 import { transactionBuilder } from "@metaplex-foundation/umi";
 import { mapSerializer, struct, u8 } from "@metaplex-foundation/umi/serializers";
 
-import { findAssertionV1Pda, findVoteV1Pda, findVotingV1Pda } from "../accounts";
+import { findAssertionV1Pda, findOracleV1Pda, findVoteV1Pda, findVotingV1Pda } from "../accounts";
 import { expectPublicKey, getAccountMetasAndSigners } from "../shared";
 
 // Accounts.
@@ -29,6 +29,8 @@ export type ClaimVoteV1InstructionAccounts = {
   vote?: PublicKey | Pda;
   /** Stake */
   stake: PublicKey | Pda;
+  /** Oracle */
+  oracle?: PublicKey | Pda;
   /** Bond mint */
   bondMint: PublicKey | Pda;
   /** Bond destination token account */
@@ -37,6 +39,8 @@ export type ClaimVoteV1InstructionAccounts = {
   bondEscrow: PublicKey | Pda;
   /** Voter */
   voter?: Signer;
+  /** Vote rent payer */
+  voteRentPayer: PublicKey | Pda;
   /** SPL token program */
   tokenProgram?: PublicKey | Pda;
   /** System program */
@@ -85,42 +89,52 @@ export function claimVoteV1(
     },
     voting: {
       index: 2,
-      isWritable: false as boolean,
+      isWritable: true as boolean,
       value: input.voting ?? null,
     },
     vote: { index: 3, isWritable: true as boolean, value: input.vote ?? null },
     stake: {
       index: 4,
-      isWritable: false as boolean,
+      isWritable: true as boolean,
       value: input.stake ?? null,
     },
-    bondMint: {
+    oracle: {
       index: 5,
+      isWritable: true as boolean,
+      value: input.oracle ?? null,
+    },
+    bondMint: {
+      index: 6,
       isWritable: false as boolean,
       value: input.bondMint ?? null,
     },
     bondDestination: {
-      index: 6,
+      index: 7,
       isWritable: true as boolean,
       value: input.bondDestination ?? null,
     },
     bondEscrow: {
-      index: 7,
+      index: 8,
       isWritable: true as boolean,
       value: input.bondEscrow ?? null,
     },
     voter: {
-      index: 8,
-      isWritable: true as boolean,
+      index: 9,
+      isWritable: false as boolean,
       value: input.voter ?? null,
     },
+    voteRentPayer: {
+      index: 10,
+      isWritable: true as boolean,
+      value: input.voteRentPayer ?? null,
+    },
     tokenProgram: {
-      index: 9,
+      index: 11,
       isWritable: false as boolean,
       value: input.tokenProgram ?? null,
     },
     systemProgram: {
-      index: 10,
+      index: 12,
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
@@ -143,6 +157,9 @@ export function claimVoteV1(
       stake: expectPublicKey(resolvedAccounts.stake.value),
     });
   }
+  if (!resolvedAccounts.oracle.value) {
+    resolvedAccounts.oracle.value = findOracleV1Pda(context);
+  }
   if (!resolvedAccounts.voter.value) {
     resolvedAccounts.voter.value = context.identity;
   }
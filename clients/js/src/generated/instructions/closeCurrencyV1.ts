@@ -0,0 +1,107 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import type { ResolvedAccount, ResolvedAccountsWithIndices } from "../shared";
+import type { Context, Pda, PublicKey, Signer, TransactionBuilder } from "@metaplex-foundation/umi";
+import type { Serializer } from "@metaplex-foundation/umi/serializers";
+
+import { transactionBuilder } from "@metaplex-foundation/umi";
+import { mapSerializer, struct, u8 } from "@metaplex-foundation/umi/serializers";
+
+import { getAccountMetasAndSigners } from "../shared";
+
+// Accounts.
+export type CloseCurrencyV1InstructionAccounts = {
+  /** Config */
+  config: PublicKey | Pda;
+  /** Currency */
+  currency: PublicKey | Pda;
+  /** Config authority */
+  authority?: Signer;
+  /** Currency rent destination */
+  destination?: PublicKey | Pda;
+};
+
+// Data.
+export type CloseCurrencyV1InstructionData = { discriminator: number };
+
+export type CloseCurrencyV1InstructionDataArgs = {};
+
+export function getCloseCurrencyV1InstructionDataSerializer(): Serializer<
+  CloseCurrencyV1InstructionDataArgs,
+  CloseCurrencyV1InstructionData
+> {
+  return mapSerializer<CloseCurrencyV1InstructionDataArgs, any, CloseCurrencyV1InstructionData>(
+    struct<CloseCurrencyV1InstructionData>([["discriminator", u8()]], {
+      description: "CloseCurrencyV1InstructionData",
+    }),
+    (value) => ({ ...value, discriminator: 30 }),
+  );
+}
+
+// Instruction.
+export function closeCurrencyV1(
+  context: Pick<Context, "identity" | "programs">,
+  input: CloseCurrencyV1InstructionAccounts,
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    "optimisticOracle",
+    "DVM2j1a1AJ9hZuEXyMxA5vusKgMR2FcKJyCf3QE5R2ge",
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    config: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.config ?? null,
+    },
+    currency: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.currency ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    destination: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.destination ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.destination.value) {
+    resolvedAccounts.destination.value = context.identity.publicKey;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: Array<ResolvedAccount> = Object.values(resolvedAccounts).sort(
+    (a, b) => a.index - b.index,
+  );
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(orderedAccounts, "programId", programId);
+
+  // Data.
+  const data = getCloseCurrencyV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,290 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import type { ResolvedAccount, ResolvedAccountsWithIndices } from "../shared";
+import type { Context, Pda, PublicKey, Signer, TransactionBuilder } from "@metaplex-foundation/umi";
+import type { Serializer } from "@metaplex-foundation/umi/serializers";
+
+import { transactionBuilder } from "@metaplex-foundation/umi";
+import { mapSerializer, struct, u8 } from "@metaplex-foundation/umi/serializers";
+
+import {
+  findAssertBondPda,
+  findDisputeBondPda,
+  findRewardPda,
+  findStakePoolPda,
+  findTreasuryPda,
+} from "../../hooked";
+import { findAssertionV1Pda, findOracleV1Pda, findVotingV1Pda } from "../accounts";
+import { expectPublicKey, getAccountMetasAndSigners } from "../shared";
+
+// Accounts.
+export type CloseRequestV1InstructionAccounts = {
+  /** Config */
+  config: PublicKey | Pda;
+  /** Request */
+  request: PublicKey | Pda;
+  /** Assertion */
+  assertion?: PublicKey | Pda;
+  /** Voting */
+  voting?: PublicKey | Pda;
+  /** Bond mint */
+  bondMint: PublicKey | Pda;
+  /** Reward mint */
+  rewardMint: PublicKey | Pda;
+  /** Reward escrow token account */
+  rewardEscrow?: PublicKey | Pda;
+  /** Creator reward token account */
+  rewardDestination: PublicKey | Pda;
+  /** Asserter bond escrow token account */
+  assertBondEscrow?: PublicKey | Pda;
+  /** Disputer bond escrow token account */
+  disputeBondEscrow?: PublicKey | Pda;
+  /** Fee treasury token account */
+  treasury?: PublicKey | Pda;
+  /** Request rent payer */
+  requestRentPayer: PublicKey | Pda;
+  /** Assertion rent payer */
+  assertionRentPayer: PublicKey | Pda;
+  /** Voting rent payer */
+  votingRentPayer: PublicKey | Pda;
+  /** Oracle */
+  oracle?: PublicKey | Pda;
+  /** Governance token mint */
+  governanceMint: PublicKey | Pda;
+  /** Stake pool token account */
+  stakePool?: PublicKey | Pda;
+  /** Fee treasury token account for the governance mint */
+  stakeTreasury?: PublicKey | Pda;
+  /** Payer */
+  payer?: Signer;
+  /** SPL token program */
+  tokenProgram?: PublicKey | Pda;
+  /** System program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type CloseRequestV1InstructionData = { discriminator: number };
+
+export type CloseRequestV1InstructionDataArgs = {};
+
+export function getCloseRequestV1InstructionDataSerializer(): Serializer<
+  CloseRequestV1InstructionDataArgs,
+  CloseRequestV1InstructionData
+> {
+  return mapSerializer<CloseRequestV1InstructionDataArgs, any, CloseRequestV1InstructionData>(
+    struct<CloseRequestV1InstructionData>([["discriminator", u8()]], {
+      description: "CloseRequestV1InstructionData",
+    }),
+    (value) => ({ ...value, discriminator: 23 }),
+  );
+}
+
+// Instruction.
+export function closeRequestV1(
+  context: Pick<Context, "eddsa" | "payer" | "programs">,
+  input: CloseRequestV1InstructionAccounts,
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    "optimisticOracle",
+    "DVM2j1a1AJ9hZuEXyMxA5vusKgMR2FcKJyCf3QE5R2ge",
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    config: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.config ?? null,
+    },
+    request: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.request ?? null,
+    },
+    assertion: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.assertion ?? null,
+    },
+    voting: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.voting ?? null,
+    },
+    bondMint: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.bondMint ?? null,
+    },
+    rewardMint: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.rewardMint ?? null,
+    },
+    rewardEscrow: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.rewardEscrow ?? null,
+    },
+    rewardDestination: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.rewardDestination ?? null,
+    },
+    assertBondEscrow: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.assertBondEscrow ?? null,
+    },
+    disputeBondEscrow: {
+      index: 9,
+      isWritable: true as boolean,
+      value: input.disputeBondEscrow ?? null,
+    },
+    treasury: {
+      index: 10,
+      isWritable: true as boolean,
+      value: input.treasury ?? null,
+    },
+    requestRentPayer: {
+      index: 11,
+      isWritable: true as boolean,
+      value: input.requestRentPayer ?? null,
+    },
+    assertionRentPayer: {
+      index: 12,
+      isWritable: true as boolean,
+      value: input.assertionRentPayer ?? null,
+    },
+    votingRentPayer: {
+      index: 13,
+      isWritable: true as boolean,
+      value: input.votingRentPayer ?? null,
+    },
+    oracle: {
+      index: 14,
+      isWritable: false as boolean,
+      value: input.oracle ?? null,
+    },
+    governanceMint: {
+      index: 15,
+      isWritable: false as boolean,
+      value: input.governanceMint ?? null,
+    },
+    stakePool: {
+      index: 16,
+      isWritable: true as boolean,
+      value: input.stakePool ?? null,
+    },
+    stakeTreasury: {
+      index: 17,
+      isWritable: true as boolean,
+      value: input.stakeTreasury ?? null,
+    },
+    payer: {
+      index: 18,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    tokenProgram: {
+      index: 19,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    systemProgram: {
+      index: 20,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.assertion.value) {
+    resolvedAccounts.assertion.value = findAssertionV1Pda(context, {
+      request: expectPublicKey(resolvedAccounts.request.value),
+    });
+  }
+  if (!resolvedAccounts.voting.value) {
+    resolvedAccounts.voting.value = findVotingV1Pda(context, {
+      request: expectPublicKey(resolvedAccounts.request.value),
+    });
+  }
+  if (!resolvedAccounts.rewardEscrow.value) {
+    resolvedAccounts.rewardEscrow.value = findRewardPda(context, {
+      request: expectPublicKey(resolvedAccounts.request.value),
+    });
+  }
+  if (!resolvedAccounts.assertBondEscrow.value) {
+    resolvedAccounts.assertBondEscrow.value = findAssertBondPda(context, {
+      request: expectPublicKey(resolvedAccounts.request.value),
+    });
+  }
+  if (!resolvedAccounts.disputeBondEscrow.value) {
+    resolvedAccounts.disputeBondEscrow.value = findDisputeBondPda(context, {
+      request: expectPublicKey(resolvedAccounts.request.value),
+    });
+  }
+  if (!resolvedAccounts.treasury.value) {
+    resolvedAccounts.treasury.value = findTreasuryPda(context, {
+      config: expectPublicKey(resolvedAccounts.config.value),
+      mint: expectPublicKey(resolvedAccounts.bondMint.value),
+    });
+  }
+  if (!resolvedAccounts.oracle.value) {
+    resolvedAccounts.oracle.value = findOracleV1Pda(context);
+  }
+  if (!resolvedAccounts.stakePool.value) {
+    resolvedAccounts.stakePool.value = findStakePoolPda(context, {
+      mint: expectPublicKey(resolvedAccounts.governanceMint.value),
+    });
+  }
+  if (!resolvedAccounts.stakeTreasury.value) {
+    resolvedAccounts.stakeTreasury.value = findTreasuryPda(context, {
+      config: expectPublicKey(resolvedAccounts.config.value),
+      mint: expectPublicKey(resolvedAccounts.governanceMint.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      "splToken",
+      "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      "splSystem",
+      "11111111111111111111111111111111",
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: Array<ResolvedAccount> = Object.values(resolvedAccounts).sort(
+    (a, b) => a.index - b.index,
+  );
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(orderedAccounts, "programId", programId);
+
+  // Data.
+  const data = getCloseRequestV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
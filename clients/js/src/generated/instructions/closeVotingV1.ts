@@ -7,13 +7,14 @@
  */
 
 import type { ResolvedAccount, ResolvedAccountsWithIndices } from "../shared";
-import type { Context, Pda, PublicKey, TransactionBuilder } from "@metaplex-foundation/umi";
+import type { Context, Pda, PublicKey, Signer, TransactionBuilder } from "@metaplex-foundation/umi";
 import type { Serializer } from "@metaplex-foundation/umi/serializers";
 
 import { transactionBuilder } from "@metaplex-foundation/umi";
 import { mapSerializer, struct, u8 } from "@metaplex-foundation/umi/serializers";
 
-import { findVotingV1Pda } from "../accounts";
+import { findTreasuryPda } from "../../hooked";
+import { findAssertionV1Pda, findOracleV1Pda, findVotingV1Pda } from "../accounts";
 import { expectPublicKey, getAccountMetasAndSigners } from "../shared";
 
 // Accounts.
@@ -24,6 +25,22 @@ export type CloseVotingV1InstructionAccounts = {
   request: PublicKey | Pda;
   /** Voting */
   voting?: PublicKey | Pda;
+  /** Oracle */
+  oracle?: PublicKey | Pda;
+  /** Assertion */
+  assertion?: PublicKey | Pda;
+  /** Bond mint */
+  bondMint: PublicKey | Pda;
+  /** Bond escrow token account of incorrect asserter/disputer */
+  bondEscrow: PublicKey | Pda;
+  /** Fee treasury token account */
+  treasury?: PublicKey | Pda;
+  /** Payer */
+  payer?: Signer;
+  /** SPL token program */
+  tokenProgram?: PublicKey | Pda;
+  /** System program */
+  systemProgram?: PublicKey | Pda;
 };
 
 // Data.
@@ -45,7 +62,7 @@ export function getCloseVotingV1InstructionDataSerializer(): Serializer<
 
 // Instruction.
 export function closeVotingV1(
-  context: Pick<Context, "eddsa" | "programs">,
+  context: Pick<Context, "eddsa" | "payer" | "programs">,
   input: CloseVotingV1InstructionAccounts,
 ): TransactionBuilder {
   // Program ID.
@@ -71,6 +88,46 @@ export function closeVotingV1(
       isWritable: true as boolean,
       value: input.voting ?? null,
     },
+    oracle: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.oracle ?? null,
+    },
+    assertion: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.assertion ?? null,
+    },
+    bondMint: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.bondMint ?? null,
+    },
+    bondEscrow: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.bondEscrow ?? null,
+    },
+    treasury: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.treasury ?? null,
+    },
+    payer: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    tokenProgram: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    systemProgram: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Default values.
@@ -79,6 +136,37 @@ export function closeVotingV1(
       request: expectPublicKey(resolvedAccounts.request.value),
     });
   }
+  if (!resolvedAccounts.oracle.value) {
+    resolvedAccounts.oracle.value = findOracleV1Pda(context);
+  }
+  if (!resolvedAccounts.assertion.value) {
+    resolvedAccounts.assertion.value = findAssertionV1Pda(context, {
+      request: expectPublicKey(resolvedAccounts.request.value),
+    });
+  }
+  if (!resolvedAccounts.treasury.value) {
+    resolvedAccounts.treasury.value = findTreasuryPda(context, {
+      config: expectPublicKey(resolvedAccounts.config.value),
+      mint: expectPublicKey(resolvedAccounts.bondMint.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      "splToken",
+      "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      "splSystem",
+      "11111111111111111111111111111111",
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
 
   // Accounts in order.
   const orderedAccounts: Array<ResolvedAccount> = Object.values(resolvedAccounts).sort(
@@ -7,6 +7,14 @@
  */
 
 import type { ResolvedAccount, ResolvedAccountsWithIndices } from "../shared";
+import type {
+  Bounds,
+  BoundsArgs,
+  TiePolicy,
+  TiePolicyArgs,
+  TransferFeePolicy,
+  TransferFeePolicyArgs,
+} from "../types";
 import type { Context, Pda, PublicKey, Signer, TransactionBuilder } from "@metaplex-foundation/umi";
 import type { Serializer } from "@metaplex-foundation/umi/serializers";
 
@@ -17,10 +25,16 @@ import {
   struct,
   u16,
   u32,
+  u64,
   u8,
 } from "@metaplex-foundation/umi/serializers";
 
 import { getAccountMetasAndSigners } from "../shared";
+import {
+  getBoundsSerializer,
+  getTiePolicySerializer,
+  getTransferFeePolicySerializer,
+} from "../types";
 
 // Accounts.
 export type CreateConfigV1InstructionAccounts = {
@@ -39,7 +53,18 @@ export type CreateConfigV1InstructionData = {
   bondFeeBps: number;
   disputeWindow: number;
   votingWindow: number;
+  revealWindow: number;
   arbitrationWindow: number;
+  claimWindow: number;
+  disputeWindowRange: Bounds;
+  votingWindowRange: Bounds;
+  arbitrationWindowRange: Bounds;
+  quorumAmount: bigint;
+  quorumBps: number;
+  slashBps: number;
+  slashWindow: number;
+  tiePolicy: TiePolicy;
+  transferFeePolicy: TransferFeePolicy;
 };
 
 export type CreateConfigV1InstructionDataArgs = {
@@ -47,7 +72,18 @@ export type CreateConfigV1InstructionDataArgs = {
   bondFeeBps: number;
   disputeWindow: number;
   votingWindow: number;
+  revealWindow: number;
   arbitrationWindow: number;
+  claimWindow: number;
+  disputeWindowRange: BoundsArgs;
+  votingWindowRange: BoundsArgs;
+  arbitrationWindowRange: BoundsArgs;
+  quorumAmount: number | bigint;
+  quorumBps: number;
+  slashBps: number;
+  slashWindow: number;
+  tiePolicy: TiePolicyArgs;
+  transferFeePolicy: TransferFeePolicyArgs;
 };
 
 export function getCreateConfigV1InstructionDataSerializer(): Serializer<
@@ -62,7 +98,18 @@ export function getCreateConfigV1InstructionDataSerializer(): Serializer<
         ["bondFeeBps", u16()],
         ["disputeWindow", u32()],
         ["votingWindow", u32()],
+        ["revealWindow", u32()],
         ["arbitrationWindow", u32()],
+        ["claimWindow", u32()],
+        ["disputeWindowRange", getBoundsSerializer()],
+        ["votingWindowRange", getBoundsSerializer()],
+        ["arbitrationWindowRange", getBoundsSerializer()],
+        ["quorumAmount", u64()],
+        ["quorumBps", u16()],
+        ["slashBps", u16()],
+        ["slashWindow", u32()],
+        ["tiePolicy", getTiePolicySerializer()],
+        ["transferFeePolicy", getTransferFeePolicySerializer()],
       ],
       { description: "CreateConfigV1InstructionData" },
     ),
@@ -12,6 +12,8 @@ import type {
   Context,
   DateTime,
   DateTimeInput,
+  Option,
+  OptionOrNullable,
   Pda,
   PublicKey,
   Signer,
@@ -22,22 +24,25 @@ import type { Serializer } from "@metaplex-foundation/umi/serializers";
 import { findAssociatedTokenPda } from "@metaplex-foundation/mpl-toolbox";
 import { mapDateTimeSerializer, transactionBuilder } from "@metaplex-foundation/umi";
 import {
+  bool,
   i64,
   mapSerializer,
+  option,
   publicKey as publicKeySerializer,
   struct,
+  u32,
   u64,
   u8,
 } from "@metaplex-foundation/umi/serializers";
 
 import { findRewardPda } from "../../hooked";
-import { findCurrencyV1Pda, findOracleV1Pda } from "../accounts";
+import { findCurrencyV1Pda } from "../accounts";
 import { expectPublicKey, expectSome, getAccountMetasAndSigners } from "../shared";
 import { getRequestDataSerializer } from "../types";
 
 // Accounts.
 export type CreateRequestV1InstructionAccounts = {
-  /** Oracle */
+  /** Oracle (required without a nonce) */
   oracle?: PublicKey | Pda;
   /** Config */
   config: PublicKey | Pda;
@@ -70,6 +75,11 @@ export type CreateRequestV1InstructionData = {
   bond: bigint;
   timestamp: DateTime;
   arbitrator: PublicKey;
+  disputeWindow: Option<number>;
+  votingWindow: Option<number>;
+  arbitrationWindow: Option<number>;
+  nonce: Option<bigint>;
+  allowTooEarly: boolean;
   data: RequestData;
 };
 
@@ -78,6 +88,11 @@ export type CreateRequestV1InstructionDataArgs = {
   bond: number | bigint;
   timestamp: DateTimeInput;
   arbitrator: PublicKey;
+  disputeWindow: OptionOrNullable<number>;
+  votingWindow: OptionOrNullable<number>;
+  arbitrationWindow: OptionOrNullable<number>;
+  nonce: OptionOrNullable<number | bigint>;
+  allowTooEarly: boolean;
   data: RequestDataArgs;
 };
 
@@ -93,6 +108,11 @@ export function getCreateRequestV1InstructionDataSerializer(): Serializer<
         ["bond", u64()],
         ["timestamp", mapDateTimeSerializer(i64())],
         ["arbitrator", publicKeySerializer()],
+        ["disputeWindow", option(u32())],
+        ["votingWindow", option(u32())],
+        ["arbitrationWindow", option(u32())],
+        ["nonce", option(u64())],
+        ["allowTooEarly", bool()],
         ["data", getRequestDataSerializer()],
       ],
       { description: "CreateRequestV1InstructionData" },
@@ -190,9 +210,6 @@ export function createRequestV1(
   const resolvedArgs: CreateRequestV1InstructionArgs = { ...input };
 
   // Default values.
-  if (!resolvedAccounts.oracle.value) {
-    resolvedAccounts.oracle.value = findOracleV1Pda(context);
-  }
   if (!resolvedAccounts.rewardCurrency.value) {
     resolvedAccounts.rewardCurrency.value = findCurrencyV1Pda(context, {
       config: expectPublicKey(resolvedAccounts.config.value),
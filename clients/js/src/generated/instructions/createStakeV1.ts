@@ -82,7 +82,7 @@ export function createStakeV1(
   const resolvedAccounts = {
     oracle: {
       index: 0,
-      isWritable: false as boolean,
+      isWritable: true as boolean,
       value: input.oracle ?? null,
     },
     stake: {
@@ -0,0 +1,158 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import type { ResolvedAccount, ResolvedAccountsWithIndices } from "../shared";
+import type { Context, Pda, PublicKey, Signer, TransactionBuilder } from "@metaplex-foundation/umi";
+import type { Serializer } from "@metaplex-foundation/umi/serializers";
+
+import { findAssociatedTokenPda } from "@metaplex-foundation/mpl-toolbox";
+import { transactionBuilder } from "@metaplex-foundation/umi";
+import { mapSerializer, struct, u64, u8 } from "@metaplex-foundation/umi/serializers";
+
+import { findStakePoolPda } from "../../hooked";
+import { findOracleV1Pda } from "../accounts";
+import { expectPublicKey, getAccountMetasAndSigners } from "../shared";
+
+// Accounts.
+export type DepositStakeV1InstructionAccounts = {
+  /** Oracle */
+  oracle?: PublicKey | Pda;
+  /** Stake */
+  stake: PublicKey | Pda;
+  /** Stake mint */
+  mint: PublicKey | Pda;
+  /** Stake source token account */
+  stakeSource?: PublicKey | Pda;
+  /** Stake pool token account */
+  stakePool?: PublicKey | Pda;
+  /** Stake source token account authority */
+  wallet?: Signer;
+  /** SPL token program */
+  tokenProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type DepositStakeV1InstructionData = {
+  discriminator: number;
+  amount: bigint;
+};
+
+export type DepositStakeV1InstructionDataArgs = { amount: number | bigint };
+
+export function getDepositStakeV1InstructionDataSerializer(): Serializer<
+  DepositStakeV1InstructionDataArgs,
+  DepositStakeV1InstructionData
+> {
+  return mapSerializer<DepositStakeV1InstructionDataArgs, any, DepositStakeV1InstructionData>(
+    struct<DepositStakeV1InstructionData>(
+      [
+        ["discriminator", u8()],
+        ["amount", u64()],
+      ],
+      { description: "DepositStakeV1InstructionData" },
+    ),
+    (value) => ({ ...value, discriminator: 17 }),
+  );
+}
+
+// Args.
+export type DepositStakeV1InstructionArgs = DepositStakeV1InstructionDataArgs;
+
+// Instruction.
+export function depositStakeV1(
+  context: Pick<Context, "eddsa" | "identity" | "programs">,
+  input: DepositStakeV1InstructionAccounts & DepositStakeV1InstructionArgs,
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    "optimisticOracle",
+    "DVM2j1a1AJ9hZuEXyMxA5vusKgMR2FcKJyCf3QE5R2ge",
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    oracle: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.oracle ?? null,
+    },
+    stake: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.stake ?? null,
+    },
+    mint: { index: 2, isWritable: false as boolean, value: input.mint ?? null },
+    stakeSource: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.stakeSource ?? null,
+    },
+    stakePool: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.stakePool ?? null,
+    },
+    wallet: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.wallet ?? null,
+    },
+    tokenProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: DepositStakeV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.oracle.value) {
+    resolvedAccounts.oracle.value = findOracleV1Pda(context);
+  }
+  if (!resolvedAccounts.wallet.value) {
+    resolvedAccounts.wallet.value = context.identity;
+  }
+  if (!resolvedAccounts.stakeSource.value) {
+    resolvedAccounts.stakeSource.value = findAssociatedTokenPda(context, {
+      mint: expectPublicKey(resolvedAccounts.mint.value),
+      owner: expectPublicKey(resolvedAccounts.wallet.value),
+    });
+  }
+  if (!resolvedAccounts.stakePool.value) {
+    resolvedAccounts.stakePool.value = findStakePoolPda(context, {
+      mint: expectPublicKey(resolvedAccounts.mint.value),
+    });
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      "splToken",
+      "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: Array<ResolvedAccount> = Object.values(resolvedAccounts).sort(
+    (a, b) => a.index - b.index,
+  );
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(orderedAccounts, "programId", programId);
+
+  // Data.
+  const data = getDepositStakeV1InstructionDataSerializer().serialize(resolvedArgs);
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -6,19 +6,35 @@
  * @see https://github.com/kinobi-so/kinobi
  */
 
+export * from "./arbitrateV1";
+export * from "./cancelRequestV1";
+export * from "./changeVoteV1";
 export * from "./claimAssertionV1";
 export * from "./claimDisputeV1";
+export * from "./claimRefundV1";
 export * from "./claimVoteV1";
+export * from "./closeCurrencyV1";
+export * from "./closeRequestV1";
+export * from "./closeVoteV1";
 export * from "./closeVotingV1";
+export * from "./commitVoteV1";
 export * from "./createAssertionV1";
 export * from "./createConfigV1";
 export * from "./createCurrencyV1";
 export * from "./createOracleV1";
 export * from "./createRequestV1";
 export * from "./createStakeV1";
+export * from "./depositStakeV1";
 export * from "./disputeAssertionV1";
+export * from "./mergeStakeV1";
 export * from "./resolveAssertionV1";
+export * from "./revealVoteV1";
+export * from "./setStakeDelegateV1";
+export * from "./slashVoteV1";
 export * from "./submitVoteV1";
 export * from "./updateConfigV1";
 export * from "./updateCurrencyV1";
 export * from "./updateOracleV1";
+export * from "./withdrawFeesV1";
+export * from "./withdrawStakeV1";
+export * from "./withdrawVoteV1";
@@ -0,0 +1,97 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import type { ResolvedAccount, ResolvedAccountsWithIndices } from "../shared";
+import type { Context, Pda, PublicKey, Signer, TransactionBuilder } from "@metaplex-foundation/umi";
+import type { Serializer } from "@metaplex-foundation/umi/serializers";
+
+import { transactionBuilder } from "@metaplex-foundation/umi";
+import { mapSerializer, struct, u8 } from "@metaplex-foundation/umi/serializers";
+
+import { getAccountMetasAndSigners } from "../shared";
+
+// Accounts.
+export type MergeStakeV1InstructionAccounts = {
+  /** Stake merged into */
+  stake: PublicKey | Pda;
+  /** Stake merged from */
+  sourceStake: PublicKey | Pda;
+  /** Stake owner */
+  wallet?: Signer;
+};
+
+// Data.
+export type MergeStakeV1InstructionData = { discriminator: number };
+
+export type MergeStakeV1InstructionDataArgs = {};
+
+export function getMergeStakeV1InstructionDataSerializer(): Serializer<
+  MergeStakeV1InstructionDataArgs,
+  MergeStakeV1InstructionData
+> {
+  return mapSerializer<MergeStakeV1InstructionDataArgs, any, MergeStakeV1InstructionData>(
+    struct<MergeStakeV1InstructionData>([["discriminator", u8()]], {
+      description: "MergeStakeV1InstructionData",
+    }),
+    (value) => ({ ...value, discriminator: 18 }),
+  );
+}
+
+// Instruction.
+export function mergeStakeV1(
+  context: Pick<Context, "identity" | "programs">,
+  input: MergeStakeV1InstructionAccounts,
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    "optimisticOracle",
+    "DVM2j1a1AJ9hZuEXyMxA5vusKgMR2FcKJyCf3QE5R2ge",
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    stake: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.stake ?? null,
+    },
+    sourceStake: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.sourceStake ?? null,
+    },
+    wallet: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.wallet ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.wallet.value) {
+    resolvedAccounts.wallet.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: Array<ResolvedAccount> = Object.values(resolvedAccounts).sort(
+    (a, b) => a.index - b.index,
+  );
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(orderedAccounts, "programId", programId);
+
+  // Data.
+  const data = getMergeStakeV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,161 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import type { ResolvedAccount, ResolvedAccountsWithIndices } from "../shared";
+import type { Context, Pda, PublicKey, Signer, TransactionBuilder } from "@metaplex-foundation/umi";
+import type { Serializer } from "@metaplex-foundation/umi/serializers";
+
+import { transactionBuilder } from "@metaplex-foundation/umi";
+import { array, mapSerializer, struct, u64, u8 } from "@metaplex-foundation/umi/serializers";
+
+import { findVoteV1Pda, findVotingV1Pda } from "../accounts";
+import { expectPublicKey, getAccountMetasAndSigners } from "../shared";
+
+// Accounts.
+export type RevealVoteV1InstructionAccounts = {
+  /** Request */
+  request: PublicKey | Pda;
+  /** Voting */
+  voting?: PublicKey | Pda;
+  /** Vote */
+  vote?: PublicKey | Pda;
+  /** Stake */
+  stake: PublicKey | Pda;
+  /** Voter */
+  voter?: Signer;
+  /** Payer */
+  payer?: Signer;
+  /** System program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type RevealVoteV1InstructionData = {
+  discriminator: number;
+  value: bigint;
+  salt: Array<number>;
+};
+
+export type RevealVoteV1InstructionDataArgs = {
+  value: number | bigint;
+  salt: Array<number>;
+};
+
+export function getRevealVoteV1InstructionDataSerializer(): Serializer<
+  RevealVoteV1InstructionDataArgs,
+  RevealVoteV1InstructionData
+> {
+  return mapSerializer<RevealVoteV1InstructionDataArgs, any, RevealVoteV1InstructionData>(
+    struct<RevealVoteV1InstructionData>(
+      [
+        ["discriminator", u8()],
+        ["value", u64()],
+        ["salt", array(u8(), { size: 32 })],
+      ],
+      { description: "RevealVoteV1InstructionData" },
+    ),
+    (value) => ({ ...value, discriminator: 26 }),
+  );
+}
+
+// Args.
+export type RevealVoteV1InstructionArgs = RevealVoteV1InstructionDataArgs;
+
+// Instruction.
+export function revealVoteV1(
+  context: Pick<Context, "eddsa" | "identity" | "payer" | "programs">,
+  input: RevealVoteV1InstructionAccounts & RevealVoteV1InstructionArgs,
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    "optimisticOracle",
+    "DVM2j1a1AJ9hZuEXyMxA5vusKgMR2FcKJyCf3QE5R2ge",
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    request: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.request ?? null,
+    },
+    voting: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.voting ?? null,
+    },
+    vote: { index: 2, isWritable: true as boolean, value: input.vote ?? null },
+    stake: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.stake ?? null,
+    },
+    voter: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.voter ?? null,
+    },
+    payer: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: RevealVoteV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.voting.value) {
+    resolvedAccounts.voting.value = findVotingV1Pda(context, {
+      request: expectPublicKey(resolvedAccounts.request.value),
+    });
+  }
+  if (!resolvedAccounts.vote.value) {
+    resolvedAccounts.vote.value = findVoteV1Pda(context, {
+      voting: expectPublicKey(resolvedAccounts.voting.value),
+      stake: expectPublicKey(resolvedAccounts.stake.value),
+    });
+  }
+  if (!resolvedAccounts.voter.value) {
+    resolvedAccounts.voter.value = context.identity;
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      "splSystem",
+      "11111111111111111111111111111111",
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: Array<ResolvedAccount> = Object.values(resolvedAccounts).sort(
+    (a, b) => a.index - b.index,
+  );
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(orderedAccounts, "programId", programId);
+
+  // Data.
+  const data = getRevealVoteV1InstructionDataSerializer().serialize(resolvedArgs);
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,112 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import type { ResolvedAccount, ResolvedAccountsWithIndices } from "../shared";
+import type { Context, Pda, PublicKey, Signer, TransactionBuilder } from "@metaplex-foundation/umi";
+import type { Serializer } from "@metaplex-foundation/umi/serializers";
+
+import { transactionBuilder } from "@metaplex-foundation/umi";
+import {
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u8,
+} from "@metaplex-foundation/umi/serializers";
+
+import { getAccountMetasAndSigners } from "../shared";
+
+// Accounts.
+export type SetStakeDelegateV1InstructionAccounts = {
+  /** Stake */
+  stake: PublicKey | Pda;
+  /** Stake owner */
+  wallet?: Signer;
+};
+
+// Data.
+export type SetStakeDelegateV1InstructionData = {
+  discriminator: number;
+  newDelegate: PublicKey;
+};
+
+export type SetStakeDelegateV1InstructionDataArgs = { newDelegate: PublicKey };
+
+export function getSetStakeDelegateV1InstructionDataSerializer(): Serializer<
+  SetStakeDelegateV1InstructionDataArgs,
+  SetStakeDelegateV1InstructionData
+> {
+  return mapSerializer<
+    SetStakeDelegateV1InstructionDataArgs,
+    any,
+    SetStakeDelegateV1InstructionData
+  >(
+    struct<SetStakeDelegateV1InstructionData>(
+      [
+        ["discriminator", u8()],
+        ["newDelegate", publicKeySerializer()],
+      ],
+      { description: "SetStakeDelegateV1InstructionData" },
+    ),
+    (value) => ({ ...value, discriminator: 19 }),
+  );
+}
+
+// Args.
+export type SetStakeDelegateV1InstructionArgs = SetStakeDelegateV1InstructionDataArgs;
+
+// Instruction.
+export function setStakeDelegateV1(
+  context: Pick<Context, "identity" | "programs">,
+  input: SetStakeDelegateV1InstructionAccounts & SetStakeDelegateV1InstructionArgs,
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    "optimisticOracle",
+    "DVM2j1a1AJ9hZuEXyMxA5vusKgMR2FcKJyCf3QE5R2ge",
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    stake: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.stake ?? null,
+    },
+    wallet: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.wallet ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: SetStakeDelegateV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.wallet.value) {
+    resolvedAccounts.wallet.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: Array<ResolvedAccount> = Object.values(resolvedAccounts).sort(
+    (a, b) => a.index - b.index,
+  );
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(orderedAccounts, "programId", programId);
+
+  // Data.
+  const data = getSetStakeDelegateV1InstructionDataSerializer().serialize(resolvedArgs);
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,133 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import type { ResolvedAccount, ResolvedAccountsWithIndices } from "../shared";
+import type { Context, Pda, PublicKey, TransactionBuilder } from "@metaplex-foundation/umi";
+import type { Serializer } from "@metaplex-foundation/umi/serializers";
+
+import { transactionBuilder } from "@metaplex-foundation/umi";
+import { mapSerializer, struct, u8 } from "@metaplex-foundation/umi/serializers";
+
+import { findOracleV1Pda, findVoteV1Pda, findVotingV1Pda } from "../accounts";
+import { expectPublicKey, getAccountMetasAndSigners } from "../shared";
+
+// Accounts.
+export type SlashVoteV1InstructionAccounts = {
+  /** Config */
+  config: PublicKey | Pda;
+  /** Request */
+  request: PublicKey | Pda;
+  /** Voting */
+  voting?: PublicKey | Pda;
+  /** Vote */
+  vote?: PublicKey | Pda;
+  /** Stake */
+  stake: PublicKey | Pda;
+  /** Oracle */
+  oracle?: PublicKey | Pda;
+  /** Vote rent payer */
+  voteRentPayer: PublicKey | Pda;
+};
+
+// Data.
+export type SlashVoteV1InstructionData = { discriminator: number };
+
+export type SlashVoteV1InstructionDataArgs = {};
+
+export function getSlashVoteV1InstructionDataSerializer(): Serializer<
+  SlashVoteV1InstructionDataArgs,
+  SlashVoteV1InstructionData
+> {
+  return mapSerializer<SlashVoteV1InstructionDataArgs, any, SlashVoteV1InstructionData>(
+    struct<SlashVoteV1InstructionData>([["discriminator", u8()]], {
+      description: "SlashVoteV1InstructionData",
+    }),
+    (value) => ({ ...value, discriminator: 24 }),
+  );
+}
+
+// Instruction.
+export function slashVoteV1(
+  context: Pick<Context, "eddsa" | "programs">,
+  input: SlashVoteV1InstructionAccounts,
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    "optimisticOracle",
+    "DVM2j1a1AJ9hZuEXyMxA5vusKgMR2FcKJyCf3QE5R2ge",
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    config: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.config ?? null,
+    },
+    request: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.request ?? null,
+    },
+    voting: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.voting ?? null,
+    },
+    vote: { index: 3, isWritable: true as boolean, value: input.vote ?? null },
+    stake: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.stake ?? null,
+    },
+    oracle: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.oracle ?? null,
+    },
+    voteRentPayer: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.voteRentPayer ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.voting.value) {
+    resolvedAccounts.voting.value = findVotingV1Pda(context, {
+      request: expectPublicKey(resolvedAccounts.request.value),
+    });
+  }
+  if (!resolvedAccounts.vote.value) {
+    resolvedAccounts.vote.value = findVoteV1Pda(context, {
+      voting: expectPublicKey(resolvedAccounts.voting.value),
+      stake: expectPublicKey(resolvedAccounts.stake.value),
+    });
+  }
+  if (!resolvedAccounts.oracle.value) {
+    resolvedAccounts.oracle.value = findOracleV1Pda(context);
+  }
+
+  // Accounts in order.
+  const orderedAccounts: Array<ResolvedAccount> = Object.values(resolvedAccounts).sort(
+    (a, b) => a.index - b.index,
+  );
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(orderedAccounts, "programId", programId);
+
+  // Data.
+  const data = getSlashVoteV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
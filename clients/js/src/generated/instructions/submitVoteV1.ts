@@ -94,7 +94,7 @@ export function submitVoteV1(
     vote: { index: 3, isWritable: true as boolean, value: input.vote ?? null },
     stake: {
       index: 4,
-      isWritable: false as boolean,
+      isWritable: true as boolean,
       value: input.stake ?? null,
     },
     voter: {
@@ -7,7 +7,7 @@
  */
 
 import type { ResolvedAccount, ResolvedAccountsWithIndices } from "../shared";
-import type { Bounds, BoundsArgs } from "../types";
+import type { Bounds, BoundsArgs, CurrencyStatus, CurrencyStatusArgs } from "../types";
 import type { Context, Pda, PublicKey, TransactionBuilder } from "@metaplex-foundation/umi";
 import type { Serializer } from "@metaplex-foundation/umi/serializers";
 
@@ -15,7 +15,7 @@ import { transactionBuilder } from "@metaplex-foundation/umi";
 import { mapSerializer, struct, u8 } from "@metaplex-foundation/umi/serializers";
 
 import { getAccountMetasAndSigners } from "../shared";
-import { getBoundsSerializer } from "../types";
+import { getBoundsSerializer, getCurrencyStatusSerializer } from "../types";
 
 // Accounts.
 export type UpdateCurrencyV1InstructionAccounts = {
@@ -32,11 +32,13 @@ export type UpdateCurrencyV1InstructionData = {
   discriminator: number;
   newRewardRange: Bounds;
   newBondRange: Bounds;
+  newStatus: CurrencyStatus;
 };
 
 export type UpdateCurrencyV1InstructionDataArgs = {
   newRewardRange: BoundsArgs;
   newBondRange: BoundsArgs;
+  newStatus: CurrencyStatusArgs;
 };
 
 export function getUpdateCurrencyV1InstructionDataSerializer(): Serializer<
@@ -49,6 +51,7 @@ export function getUpdateCurrencyV1InstructionDataSerializer(): Serializer<
         ["discriminator", u8()],
         ["newRewardRange", getBoundsSerializer()],
         ["newBondRange", getBoundsSerializer()],
+        ["newStatus", getCurrencyStatusSerializer()],
       ],
       { description: "UpdateCurrencyV1InstructionData" },
     ),
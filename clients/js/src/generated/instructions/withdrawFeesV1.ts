@@ -0,0 +1,148 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import type { ResolvedAccount, ResolvedAccountsWithIndices } from "../shared";
+import type { Context, Pda, PublicKey, Signer, TransactionBuilder } from "@metaplex-foundation/umi";
+import type { Serializer } from "@metaplex-foundation/umi/serializers";
+
+import { findAssociatedTokenPda } from "@metaplex-foundation/mpl-toolbox";
+import { transactionBuilder } from "@metaplex-foundation/umi";
+import { mapSerializer, struct, u64, u8 } from "@metaplex-foundation/umi/serializers";
+
+import { findTreasuryPda } from "../../hooked";
+import { expectPublicKey, getAccountMetasAndSigners } from "../shared";
+
+// Accounts.
+export type WithdrawFeesV1InstructionAccounts = {
+  /** Config */
+  config: PublicKey | Pda;
+  /** Fee treasury token account */
+  treasury?: PublicKey | Pda;
+  /** Fee mint */
+  mint: PublicKey | Pda;
+  /** Fee destination token account */
+  destination?: PublicKey | Pda;
+  /** Config authority */
+  authority?: Signer;
+  /** SPL token program */
+  tokenProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type WithdrawFeesV1InstructionData = {
+  discriminator: number;
+  amount: bigint;
+};
+
+export type WithdrawFeesV1InstructionDataArgs = { amount: number | bigint };
+
+export function getWithdrawFeesV1InstructionDataSerializer(): Serializer<
+  WithdrawFeesV1InstructionDataArgs,
+  WithdrawFeesV1InstructionData
+> {
+  return mapSerializer<WithdrawFeesV1InstructionDataArgs, any, WithdrawFeesV1InstructionData>(
+    struct<WithdrawFeesV1InstructionData>(
+      [
+        ["discriminator", u8()],
+        ["amount", u64()],
+      ],
+      { description: "WithdrawFeesV1InstructionData" },
+    ),
+    (value) => ({ ...value, discriminator: 21 }),
+  );
+}
+
+// Args.
+export type WithdrawFeesV1InstructionArgs = WithdrawFeesV1InstructionDataArgs;
+
+// Instruction.
+export function withdrawFeesV1(
+  context: Pick<Context, "eddsa" | "identity" | "programs">,
+  input: WithdrawFeesV1InstructionAccounts & WithdrawFeesV1InstructionArgs,
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    "optimisticOracle",
+    "DVM2j1a1AJ9hZuEXyMxA5vusKgMR2FcKJyCf3QE5R2ge",
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    config: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.config ?? null,
+    },
+    treasury: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.treasury ?? null,
+    },
+    mint: { index: 2, isWritable: false as boolean, value: input.mint ?? null },
+    destination: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.destination ?? null,
+    },
+    authority: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    tokenProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: WithdrawFeesV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.treasury.value) {
+    resolvedAccounts.treasury.value = findTreasuryPda(context, {
+      config: expectPublicKey(resolvedAccounts.config.value),
+      mint: expectPublicKey(resolvedAccounts.mint.value),
+    });
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.destination.value) {
+    resolvedAccounts.destination.value = findAssociatedTokenPda(context, {
+      mint: expectPublicKey(resolvedAccounts.mint.value),
+      owner: expectPublicKey(resolvedAccounts.authority.value),
+    });
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      "splToken",
+      "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: Array<ResolvedAccount> = Object.values(resolvedAccounts).sort(
+    (a, b) => a.index - b.index,
+  );
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(orderedAccounts, "programId", programId);
+
+  // Data.
+  const data = getWithdrawFeesV1InstructionDataSerializer().serialize(resolvedArgs);
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,126 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import type { ResolvedAccount, ResolvedAccountsWithIndices } from "../shared";
+import type { Context, Pda, PublicKey, Signer, TransactionBuilder } from "@metaplex-foundation/umi";
+import type { Serializer } from "@metaplex-foundation/umi/serializers";
+
+import { transactionBuilder } from "@metaplex-foundation/umi";
+import { mapSerializer, struct, u8 } from "@metaplex-foundation/umi/serializers";
+
+import { findVoteV1Pda, findVotingV1Pda } from "../accounts";
+import { expectPublicKey, getAccountMetasAndSigners } from "../shared";
+
+// Accounts.
+export type WithdrawVoteV1InstructionAccounts = {
+  /** Request */
+  request: PublicKey | Pda;
+  /** Voting */
+  voting?: PublicKey | Pda;
+  /** Vote */
+  vote?: PublicKey | Pda;
+  /** Stake */
+  stake: PublicKey | Pda;
+  /** Voter */
+  voter?: Signer;
+  /** Vote rent payer */
+  voteRentPayer: PublicKey | Pda;
+};
+
+// Data.
+export type WithdrawVoteV1InstructionData = { discriminator: number };
+
+export type WithdrawVoteV1InstructionDataArgs = {};
+
+export function getWithdrawVoteV1InstructionDataSerializer(): Serializer<
+  WithdrawVoteV1InstructionDataArgs,
+  WithdrawVoteV1InstructionData
+> {
+  return mapSerializer<WithdrawVoteV1InstructionDataArgs, any, WithdrawVoteV1InstructionData>(
+    struct<WithdrawVoteV1InstructionData>([["discriminator", u8()]], {
+      description: "WithdrawVoteV1InstructionData",
+    }),
+    (value) => ({ ...value, discriminator: 28 }),
+  );
+}
+
+// Instruction.
+export function withdrawVoteV1(
+  context: Pick<Context, "eddsa" | "identity" | "programs">,
+  input: WithdrawVoteV1InstructionAccounts,
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    "optimisticOracle",
+    "DVM2j1a1AJ9hZuEXyMxA5vusKgMR2FcKJyCf3QE5R2ge",
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    request: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.request ?? null,
+    },
+    voting: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.voting ?? null,
+    },
+    vote: { index: 2, isWritable: true as boolean, value: input.vote ?? null },
+    stake: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.stake ?? null,
+    },
+    voter: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.voter ?? null,
+    },
+    voteRentPayer: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.voteRentPayer ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.voting.value) {
+    resolvedAccounts.voting.value = findVotingV1Pda(context, {
+      request: expectPublicKey(resolvedAccounts.request.value),
+    });
+  }
+  if (!resolvedAccounts.vote.value) {
+    resolvedAccounts.vote.value = findVoteV1Pda(context, {
+      voting: expectPublicKey(resolvedAccounts.voting.value),
+      stake: expectPublicKey(resolvedAccounts.stake.value),
+    });
+  }
+  if (!resolvedAccounts.voter.value) {
+    resolvedAccounts.voter.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: Array<ResolvedAccount> = Object.values(resolvedAccounts).sort(
+    (a, b) => a.index - b.index,
+  );
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(orderedAccounts, "programId", programId);
+
+  // Data.
+  const data = getWithdrawVoteV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
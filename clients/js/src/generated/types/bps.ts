@@ -0,0 +1,21 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import type { Serializer } from "@metaplex-foundation/umi/serializers";
+
+import { struct, u16 } from "@metaplex-foundation/umi/serializers";
+
+export type Bps = { bps: number };
+
+export type BpsArgs = Bps;
+
+export function getBpsSerializer(): Serializer<BpsArgs, Bps> {
+  return struct<Bps>([["bps", u16()]], {
+    description: "Bps",
+  }) as Serializer<BpsArgs, Bps>;
+}
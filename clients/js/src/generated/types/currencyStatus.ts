@@ -0,0 +1,24 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import type { Serializer } from "@metaplex-foundation/umi/serializers";
+
+import { scalarEnum } from "@metaplex-foundation/umi/serializers";
+
+export enum CurrencyStatus {
+  Active,
+  Disabled,
+}
+
+export type CurrencyStatusArgs = CurrencyStatus;
+
+export function getCurrencyStatusSerializer(): Serializer<CurrencyStatusArgs, CurrencyStatus> {
+  return scalarEnum<CurrencyStatus>(CurrencyStatus, {
+    description: "CurrencyStatus",
+  }) as Serializer<CurrencyStatusArgs, CurrencyStatus>;
+}
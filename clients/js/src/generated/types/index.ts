@@ -8,7 +8,14 @@
 
 export * from "./accountType";
 export * from "./bounds";
+export * from "./bps";
+export * from "./currencyStatus";
+export * from "./oracleEvent";
 export * from "./requestData";
+export * from "./requestDerivation";
 export * from "./requestState";
+export * from "./rewardPool";
+export * from "./tiePolicy";
+export * from "./transferFeePolicy";
 export * from "./updateConfigV1Args";
 export * from "./updateOracleV1Args";
@@ -0,0 +1,311 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import type { Option, OptionOrNullable, PublicKey } from "@metaplex-foundation/umi";
+import type {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  Serializer,
+} from "@metaplex-foundation/umi/serializers";
+
+import {
+  dataEnum,
+  option,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from "@metaplex-foundation/umi/serializers";
+
+export type OracleEvent =
+  | {
+      __kind: "RequestCreated";
+      request: PublicKey;
+      index: bigint;
+      config: PublicKey;
+      creator: PublicKey;
+      nonce: Option<bigint>;
+    }
+  | {
+      __kind: "Asserted";
+      request: PublicKey;
+      asserter: PublicKey;
+      value: bigint;
+    }
+  | { __kind: "Disputed"; request: PublicKey; disputer: PublicKey }
+  | {
+      __kind: "VoteCast";
+      request: PublicKey;
+      stake: PublicKey;
+      value: bigint;
+      votes: bigint;
+    }
+  | { __kind: "Resolved"; request: PublicKey; value: bigint }
+  | {
+      __kind: "Claimed";
+      request: PublicKey;
+      claimer: PublicKey;
+      bond: bigint;
+      reward: bigint;
+    }
+  | { __kind: "Cancelled"; request: PublicKey }
+  | { __kind: "Closed"; request: PublicKey }
+  | { __kind: "Slashed"; request: PublicKey; stake: PublicKey; amount: bigint }
+  | {
+      __kind: "VoteCommitted";
+      request: PublicKey;
+      stake: PublicKey;
+      votes: bigint;
+    }
+  | {
+      __kind: "VoteChanged";
+      request: PublicKey;
+      stake: PublicKey;
+      oldValue: bigint;
+      value: bigint;
+      votes: bigint;
+    }
+  | {
+      __kind: "VoteWithdrawn";
+      request: PublicKey;
+      stake: PublicKey;
+      votes: bigint;
+    }
+  | { __kind: "Reset"; request: PublicKey };
+
+export type OracleEventArgs =
+  | {
+      __kind: "RequestCreated";
+      request: PublicKey;
+      index: number | bigint;
+      config: PublicKey;
+      creator: PublicKey;
+      nonce: OptionOrNullable<number | bigint>;
+    }
+  | {
+      __kind: "Asserted";
+      request: PublicKey;
+      asserter: PublicKey;
+      value: number | bigint;
+    }
+  | { __kind: "Disputed"; request: PublicKey; disputer: PublicKey }
+  | {
+      __kind: "VoteCast";
+      request: PublicKey;
+      stake: PublicKey;
+      value: number | bigint;
+      votes: number | bigint;
+    }
+  | { __kind: "Resolved"; request: PublicKey; value: number | bigint }
+  | {
+      __kind: "Claimed";
+      request: PublicKey;
+      claimer: PublicKey;
+      bond: number | bigint;
+      reward: number | bigint;
+    }
+  | { __kind: "Cancelled"; request: PublicKey }
+  | { __kind: "Closed"; request: PublicKey }
+  | {
+      __kind: "Slashed";
+      request: PublicKey;
+      stake: PublicKey;
+      amount: number | bigint;
+    }
+  | {
+      __kind: "VoteCommitted";
+      request: PublicKey;
+      stake: PublicKey;
+      votes: number | bigint;
+    }
+  | {
+      __kind: "VoteChanged";
+      request: PublicKey;
+      stake: PublicKey;
+      oldValue: number | bigint;
+      value: number | bigint;
+      votes: number | bigint;
+    }
+  | {
+      __kind: "VoteWithdrawn";
+      request: PublicKey;
+      stake: PublicKey;
+      votes: number | bigint;
+    }
+  | { __kind: "Reset"; request: PublicKey };
+
+export function getOracleEventSerializer(): Serializer<OracleEventArgs, OracleEvent> {
+  return dataEnum<OracleEvent>(
+    [
+      [
+        "RequestCreated",
+        struct<GetDataEnumKindContent<OracleEvent, "RequestCreated">>([
+          ["request", publicKeySerializer()],
+          ["index", u64()],
+          ["config", publicKeySerializer()],
+          ["creator", publicKeySerializer()],
+          ["nonce", option(u64())],
+        ]),
+      ],
+      [
+        "Asserted",
+        struct<GetDataEnumKindContent<OracleEvent, "Asserted">>([
+          ["request", publicKeySerializer()],
+          ["asserter", publicKeySerializer()],
+          ["value", u64()],
+        ]),
+      ],
+      [
+        "Disputed",
+        struct<GetDataEnumKindContent<OracleEvent, "Disputed">>([
+          ["request", publicKeySerializer()],
+          ["disputer", publicKeySerializer()],
+        ]),
+      ],
+      [
+        "VoteCast",
+        struct<GetDataEnumKindContent<OracleEvent, "VoteCast">>([
+          ["request", publicKeySerializer()],
+          ["stake", publicKeySerializer()],
+          ["value", u64()],
+          ["votes", u64()],
+        ]),
+      ],
+      [
+        "Resolved",
+        struct<GetDataEnumKindContent<OracleEvent, "Resolved">>([
+          ["request", publicKeySerializer()],
+          ["value", u64()],
+        ]),
+      ],
+      [
+        "Claimed",
+        struct<GetDataEnumKindContent<OracleEvent, "Claimed">>([
+          ["request", publicKeySerializer()],
+          ["claimer", publicKeySerializer()],
+          ["bond", u64()],
+          ["reward", u64()],
+        ]),
+      ],
+      [
+        "Cancelled",
+        struct<GetDataEnumKindContent<OracleEvent, "Cancelled">>([
+          ["request", publicKeySerializer()],
+        ]),
+      ],
+      [
+        "Closed",
+        struct<GetDataEnumKindContent<OracleEvent, "Closed">>([["request", publicKeySerializer()]]),
+      ],
+      [
+        "Slashed",
+        struct<GetDataEnumKindContent<OracleEvent, "Slashed">>([
+          ["request", publicKeySerializer()],
+          ["stake", publicKeySerializer()],
+          ["amount", u64()],
+        ]),
+      ],
+      [
+        "VoteCommitted",
+        struct<GetDataEnumKindContent<OracleEvent, "VoteCommitted">>([
+          ["request", publicKeySerializer()],
+          ["stake", publicKeySerializer()],
+          ["votes", u64()],
+        ]),
+      ],
+      [
+        "VoteChanged",
+        struct<GetDataEnumKindContent<OracleEvent, "VoteChanged">>([
+          ["request", publicKeySerializer()],
+          ["stake", publicKeySerializer()],
+          ["oldValue", u64()],
+          ["value", u64()],
+          ["votes", u64()],
+        ]),
+      ],
+      [
+        "VoteWithdrawn",
+        struct<GetDataEnumKindContent<OracleEvent, "VoteWithdrawn">>([
+          ["request", publicKeySerializer()],
+          ["stake", publicKeySerializer()],
+          ["votes", u64()],
+        ]),
+      ],
+      [
+        "Reset",
+        struct<GetDataEnumKindContent<OracleEvent, "Reset">>([["request", publicKeySerializer()]]),
+      ],
+    ],
+    { description: "OracleEvent" },
+  );
+}
+
+// Data Enum Helpers.
+export function oracleEvent(
+  kind: "RequestCreated",
+  data: GetDataEnumKindContent<OracleEventArgs, "RequestCreated">,
+): GetDataEnumKind<OracleEventArgs, "RequestCreated">;
+export function oracleEvent(
+  kind: "Asserted",
+  data: GetDataEnumKindContent<OracleEventArgs, "Asserted">,
+): GetDataEnumKind<OracleEventArgs, "Asserted">;
+export function oracleEvent(
+  kind: "Disputed",
+  data: GetDataEnumKindContent<OracleEventArgs, "Disputed">,
+): GetDataEnumKind<OracleEventArgs, "Disputed">;
+export function oracleEvent(
+  kind: "VoteCast",
+  data: GetDataEnumKindContent<OracleEventArgs, "VoteCast">,
+): GetDataEnumKind<OracleEventArgs, "VoteCast">;
+export function oracleEvent(
+  kind: "Resolved",
+  data: GetDataEnumKindContent<OracleEventArgs, "Resolved">,
+): GetDataEnumKind<OracleEventArgs, "Resolved">;
+export function oracleEvent(
+  kind: "Claimed",
+  data: GetDataEnumKindContent<OracleEventArgs, "Claimed">,
+): GetDataEnumKind<OracleEventArgs, "Claimed">;
+export function oracleEvent(
+  kind: "Cancelled",
+  data: GetDataEnumKindContent<OracleEventArgs, "Cancelled">,
+): GetDataEnumKind<OracleEventArgs, "Cancelled">;
+export function oracleEvent(
+  kind: "Closed",
+  data: GetDataEnumKindContent<OracleEventArgs, "Closed">,
+): GetDataEnumKind<OracleEventArgs, "Closed">;
+export function oracleEvent(
+  kind: "Slashed",
+  data: GetDataEnumKindContent<OracleEventArgs, "Slashed">,
+): GetDataEnumKind<OracleEventArgs, "Slashed">;
+export function oracleEvent(
+  kind: "VoteCommitted",
+  data: GetDataEnumKindContent<OracleEventArgs, "VoteCommitted">,
+): GetDataEnumKind<OracleEventArgs, "VoteCommitted">;
+export function oracleEvent(
+  kind: "VoteChanged",
+  data: GetDataEnumKindContent<OracleEventArgs, "VoteChanged">,
+): GetDataEnumKind<OracleEventArgs, "VoteChanged">;
+export function oracleEvent(
+  kind: "VoteWithdrawn",
+  data: GetDataEnumKindContent<OracleEventArgs, "VoteWithdrawn">,
+): GetDataEnumKind<OracleEventArgs, "VoteWithdrawn">;
+export function oracleEvent(
+  kind: "Reset",
+  data: GetDataEnumKindContent<OracleEventArgs, "Reset">,
+): GetDataEnumKind<OracleEventArgs, "Reset">;
+export function oracleEvent<K extends OracleEventArgs["__kind"]>(
+  kind: K,
+  data?: any,
+): Extract<OracleEventArgs, { __kind: K }> {
+  return Array.isArray(data) ? { __kind: kind, fields: data } : { __kind: kind, ...(data ?? {}) };
+}
+export function isOracleEvent<K extends OracleEvent["__kind"]>(
+  kind: K,
+  value: OracleEvent,
+): value is OracleEvent & { __kind: K } {
+  return value.__kind === kind;
+}
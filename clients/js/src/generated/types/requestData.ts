@@ -12,15 +12,51 @@ import type {
   Serializer,
 } from "@metaplex-foundation/umi/serializers";
 
-import { dataEnum, string, struct } from "@metaplex-foundation/umi/serializers";
+import { array, dataEnum, string, struct, u64, u8 } from "@metaplex-foundation/umi/serializers";
 
-export type RequestData = { __kind: "YesNo"; question: string };
+export type RequestData =
+  | { __kind: "YesNo"; question: string }
+  | { __kind: "MultipleChoice"; question: string; options: Array<string> }
+  | {
+      __kind: "Numeric";
+      question: string;
+      min: bigint;
+      max: bigint;
+      decimals: number;
+    };
 
-export type RequestDataArgs = RequestData;
+export type RequestDataArgs =
+  | { __kind: "YesNo"; question: string }
+  | { __kind: "MultipleChoice"; question: string; options: Array<string> }
+  | {
+      __kind: "Numeric";
+      question: string;
+      min: number | bigint;
+      max: number | bigint;
+      decimals: number;
+    };
 
 export function getRequestDataSerializer(): Serializer<RequestDataArgs, RequestData> {
   return dataEnum<RequestData>(
-    [["YesNo", struct<GetDataEnumKindContent<RequestData, "YesNo">>([["question", string()]])]],
+    [
+      ["YesNo", struct<GetDataEnumKindContent<RequestData, "YesNo">>([["question", string()]])],
+      [
+        "MultipleChoice",
+        struct<GetDataEnumKindContent<RequestData, "MultipleChoice">>([
+          ["question", string()],
+          ["options", array(string())],
+        ]),
+      ],
+      [
+        "Numeric",
+        struct<GetDataEnumKindContent<RequestData, "Numeric">>([
+          ["question", string()],
+          ["min", u64()],
+          ["max", u64()],
+          ["decimals", u8()],
+        ]),
+      ],
+    ],
     { description: "RequestData" },
   );
 }
@@ -30,6 +66,14 @@ export function requestData(
   kind: "YesNo",
   data: GetDataEnumKindContent<RequestDataArgs, "YesNo">,
 ): GetDataEnumKind<RequestDataArgs, "YesNo">;
+export function requestData(
+  kind: "MultipleChoice",
+  data: GetDataEnumKindContent<RequestDataArgs, "MultipleChoice">,
+): GetDataEnumKind<RequestDataArgs, "MultipleChoice">;
+export function requestData(
+  kind: "Numeric",
+  data: GetDataEnumKindContent<RequestDataArgs, "Numeric">,
+): GetDataEnumKind<RequestDataArgs, "Numeric">;
 export function requestData<K extends RequestDataArgs["__kind"]>(
   kind: K,
   data?: any,
@@ -0,0 +1,27 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import type { Serializer } from "@metaplex-foundation/umi/serializers";
+
+import { scalarEnum } from "@metaplex-foundation/umi/serializers";
+
+export enum RequestDerivation {
+  Index,
+  Creator,
+}
+
+export type RequestDerivationArgs = RequestDerivation;
+
+export function getRequestDerivationSerializer(): Serializer<
+  RequestDerivationArgs,
+  RequestDerivation
+> {
+  return scalarEnum<RequestDerivation>(RequestDerivation, {
+    description: "RequestDerivation",
+  }) as Serializer<RequestDerivationArgs, RequestDerivation>;
+}
@@ -15,6 +15,7 @@ export enum RequestState {
   Asserted,
   Disputed,
   Resolved,
+  Cancelled,
 }
 
 export type RequestStateArgs = RequestState;
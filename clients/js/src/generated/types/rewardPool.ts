@@ -0,0 +1,43 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import type { Serializer } from "@metaplex-foundation/umi/serializers";
+
+import { struct, u64 } from "@metaplex-foundation/umi/serializers";
+
+export type RewardPool = {
+  amount: bigint;
+  votes: bigint;
+  claimed: bigint;
+  claimedVotes: bigint;
+  slashed: bigint;
+  slashedClaimed: bigint;
+};
+
+export type RewardPoolArgs = {
+  amount: number | bigint;
+  votes: number | bigint;
+  claimed: number | bigint;
+  claimedVotes: number | bigint;
+  slashed: number | bigint;
+  slashedClaimed: number | bigint;
+};
+
+export function getRewardPoolSerializer(): Serializer<RewardPoolArgs, RewardPool> {
+  return struct<RewardPool>(
+    [
+      ["amount", u64()],
+      ["votes", u64()],
+      ["claimed", u64()],
+      ["claimedVotes", u64()],
+      ["slashed", u64()],
+      ["slashedClaimed", u64()],
+    ],
+    { description: "RewardPool" },
+  ) as Serializer<RewardPoolArgs, RewardPool>;
+}
@@ -0,0 +1,25 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import type { Serializer } from "@metaplex-foundation/umi/serializers";
+
+import { scalarEnum } from "@metaplex-foundation/umi/serializers";
+
+export enum TiePolicy {
+  ExtendVoting,
+  AssertedValue,
+  Unresolvable,
+}
+
+export type TiePolicyArgs = TiePolicy;
+
+export function getTiePolicySerializer(): Serializer<TiePolicyArgs, TiePolicy> {
+  return scalarEnum<TiePolicy>(TiePolicy, {
+    description: "TiePolicy",
+  }) as Serializer<TiePolicyArgs, TiePolicy>;
+}
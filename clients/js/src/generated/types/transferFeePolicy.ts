@@ -0,0 +1,27 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import type { Serializer } from "@metaplex-foundation/umi/serializers";
+
+import { scalarEnum } from "@metaplex-foundation/umi/serializers";
+
+export enum TransferFeePolicy {
+  Reject,
+  AllowZeroFee,
+}
+
+export type TransferFeePolicyArgs = TransferFeePolicy;
+
+export function getTransferFeePolicySerializer(): Serializer<
+  TransferFeePolicyArgs,
+  TransferFeePolicy
+> {
+  return scalarEnum<TransferFeePolicy>(TransferFeePolicy, {
+    description: "TransferFeePolicy",
+  }) as Serializer<TransferFeePolicyArgs, TransferFeePolicy>;
+}
@@ -6,6 +6,9 @@
  * @see https://github.com/kinobi-so/kinobi
  */
 
+import type { Bounds, BoundsArgs } from "./bounds";
+import type { TiePolicy, TiePolicyArgs } from "./tiePolicy";
+import type { TransferFeePolicy, TransferFeePolicyArgs } from "./transferFeePolicy";
 import type { PublicKey } from "@metaplex-foundation/umi";
 import type {
   GetDataEnumKind,
@@ -19,8 +22,13 @@ import {
   struct,
   u16,
   u32,
+  u64,
 } from "@metaplex-foundation/umi/serializers";
 
+import { getBoundsSerializer } from "./bounds";
+import { getTiePolicySerializer } from "./tiePolicy";
+import { getTransferFeePolicySerializer } from "./transferFeePolicy";
+
 export type UpdateConfigV1Args =
   | { __kind: "Authority"; newAuthority: PublicKey }
   | {
@@ -28,10 +36,45 @@ export type UpdateConfigV1Args =
       newBondFeeBps: number;
       newDisputeWindow: number;
       newVotingWindow: number;
+      newRevealWindow: number;
       newArbitrationWindow: number;
-    };
+      newClaimWindow: number;
+    }
+  | { __kind: "Quorum"; newQuorumAmount: bigint; newQuorumBps: number }
+  | { __kind: "Slash"; newSlashBps: number; newSlashWindow: number }
+  | { __kind: "TiePolicy"; newTiePolicy: TiePolicy }
+  | {
+      __kind: "WindowRanges";
+      newDisputeWindowRange: Bounds;
+      newVotingWindowRange: Bounds;
+      newArbitrationWindowRange: Bounds;
+    }
+  | { __kind: "TransferFeePolicy"; newTransferFeePolicy: TransferFeePolicy };
 
-export type UpdateConfigV1ArgsArgs = UpdateConfigV1Args;
+export type UpdateConfigV1ArgsArgs =
+  | { __kind: "Authority"; newAuthority: PublicKey }
+  | {
+      __kind: "Config";
+      newBondFeeBps: number;
+      newDisputeWindow: number;
+      newVotingWindow: number;
+      newRevealWindow: number;
+      newArbitrationWindow: number;
+      newClaimWindow: number;
+    }
+  | { __kind: "Quorum"; newQuorumAmount: number | bigint; newQuorumBps: number }
+  | { __kind: "Slash"; newSlashBps: number; newSlashWindow: number }
+  | { __kind: "TiePolicy"; newTiePolicy: TiePolicyArgs }
+  | {
+      __kind: "WindowRanges";
+      newDisputeWindowRange: BoundsArgs;
+      newVotingWindowRange: BoundsArgs;
+      newArbitrationWindowRange: BoundsArgs;
+    }
+  | {
+      __kind: "TransferFeePolicy";
+      newTransferFeePolicy: TransferFeePolicyArgs;
+    };
 
 export function getUpdateConfigV1ArgsSerializer(): Serializer<
   UpdateConfigV1ArgsArgs,
@@ -51,7 +94,43 @@ export function getUpdateConfigV1ArgsSerializer(): Serializer<
           ["newBondFeeBps", u16()],
           ["newDisputeWindow", u32()],
           ["newVotingWindow", u32()],
+          ["newRevealWindow", u32()],
           ["newArbitrationWindow", u32()],
+          ["newClaimWindow", u32()],
+        ]),
+      ],
+      [
+        "Quorum",
+        struct<GetDataEnumKindContent<UpdateConfigV1Args, "Quorum">>([
+          ["newQuorumAmount", u64()],
+          ["newQuorumBps", u16()],
+        ]),
+      ],
+      [
+        "Slash",
+        struct<GetDataEnumKindContent<UpdateConfigV1Args, "Slash">>([
+          ["newSlashBps", u16()],
+          ["newSlashWindow", u32()],
+        ]),
+      ],
+      [
+        "TiePolicy",
+        struct<GetDataEnumKindContent<UpdateConfigV1Args, "TiePolicy">>([
+          ["newTiePolicy", getTiePolicySerializer()],
+        ]),
+      ],
+      [
+        "WindowRanges",
+        struct<GetDataEnumKindContent<UpdateConfigV1Args, "WindowRanges">>([
+          ["newDisputeWindowRange", getBoundsSerializer()],
+          ["newVotingWindowRange", getBoundsSerializer()],
+          ["newArbitrationWindowRange", getBoundsSerializer()],
+        ]),
+      ],
+      [
+        "TransferFeePolicy",
+        struct<GetDataEnumKindContent<UpdateConfigV1Args, "TransferFeePolicy">>([
+          ["newTransferFeePolicy", getTransferFeePolicySerializer()],
         ]),
       ],
     ],
@@ -68,6 +147,26 @@ export function updateConfigV1Args(
   kind: "Config",
   data: GetDataEnumKindContent<UpdateConfigV1ArgsArgs, "Config">,
 ): GetDataEnumKind<UpdateConfigV1ArgsArgs, "Config">;
+export function updateConfigV1Args(
+  kind: "Quorum",
+  data: GetDataEnumKindContent<UpdateConfigV1ArgsArgs, "Quorum">,
+): GetDataEnumKind<UpdateConfigV1ArgsArgs, "Quorum">;
+export function updateConfigV1Args(
+  kind: "Slash",
+  data: GetDataEnumKindContent<UpdateConfigV1ArgsArgs, "Slash">,
+): GetDataEnumKind<UpdateConfigV1ArgsArgs, "Slash">;
+export function updateConfigV1Args(
+  kind: "TiePolicy",
+  data: GetDataEnumKindContent<UpdateConfigV1ArgsArgs, "TiePolicy">,
+): GetDataEnumKind<UpdateConfigV1ArgsArgs, "TiePolicy">;
+export function updateConfigV1Args(
+  kind: "WindowRanges",
+  data: GetDataEnumKindContent<UpdateConfigV1ArgsArgs, "WindowRanges">,
+): GetDataEnumKind<UpdateConfigV1ArgsArgs, "WindowRanges">;
+export function updateConfigV1Args(
+  kind: "TransferFeePolicy",
+  data: GetDataEnumKindContent<UpdateConfigV1ArgsArgs, "TransferFeePolicy">,
+): GetDataEnumKind<UpdateConfigV1ArgsArgs, "TransferFeePolicy">;
 export function updateConfigV1Args<K extends UpdateConfigV1ArgsArgs["__kind"]>(
   kind: K,
   data?: any,
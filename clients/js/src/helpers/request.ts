@@ -0,0 +1,14 @@
+/**
+ * Reserved value for a request which is invalid, e.g. if the question is ambiguous.
+ *
+ * A request resolved as invalid refunds the reward to the creator, and both bonds to the asserter
+ * and disputer.
+ */
+export const INVALID_VALUE = 0xffffffff_ffffffffn;
+
+/**
+ * Reserved value for a request which cannot be answered yet.
+ *
+ * A request resolved as too early is reset to accept a new assertion once it is closed.
+ */
+export const TOO_EARLY_VALUE = 0xffffffff_fffffffen;
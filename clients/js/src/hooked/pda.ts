@@ -1,6 +1,6 @@
 import type { Context, Pda, PublicKey } from "@metaplex-foundation/umi";
 
-import { publicKey, string } from "@metaplex-foundation/umi/serializers";
+import { publicKey, string, u64 } from "@metaplex-foundation/umi/serializers";
 
 import { getOptimisticOracleProgramId } from "../generated/programs/optimisticOracle";
 
@@ -18,6 +18,40 @@ export function findStakePoolPda(
   ]);
 }
 
+export function findTreasuryPda(
+  context: Pick<Context, "eddsa" | "programs">,
+  seeds: {
+    /** The address of the config. */
+    config: PublicKey;
+    /** The address of the fee mint. */
+    mint: PublicKey;
+  },
+): Pda {
+  const programId = getOptimisticOracleProgramId(context);
+  return context.eddsa.findPda(programId, [
+    string({ size: "variable" }).serialize("treasury"),
+    publicKey().serialize(seeds.config),
+    publicKey().serialize(seeds.mint),
+  ]);
+}
+
+export function findCreatorRequestPda(
+  context: Pick<Context, "eddsa" | "programs">,
+  seeds: {
+    /** The address of the request creator. */
+    creator: PublicKey;
+    /** The nonce chosen by the creator. */
+    nonce: number | bigint;
+  },
+): Pda {
+  const programId = getOptimisticOracleProgramId(context);
+  return context.eddsa.findPda(programId, [
+    string({ size: "variable" }).serialize("creator_request"),
+    publicKey().serialize(seeds.creator),
+    u64().serialize(seeds.nonce),
+  ]);
+}
+
 export function findRewardPda(
   context: Pick<Context, "eddsa" | "programs">,
   seeds: {
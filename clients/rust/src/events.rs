@@ -0,0 +1,125 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use borsh::BorshDeserialize;
+
+use crate::types::OracleEvent;
+
+const PROGRAM: &str = "Program ";
+const PROGRAM_DATA: &str = "Program data: ";
+
+impl OracleEvent {
+    /// Decodes an event from the data logged by the program.
+    pub fn from_bytes(data: &[u8]) -> std::io::Result<Self> {
+        Self::try_from_slice(data)
+    }
+
+    /// Decodes an event from a `Program data: <base64>` transaction log message.
+    ///
+    /// Returns `None` if the message is not a program data log, or cannot be decoded as an event.
+    pub fn from_log(log: &str) -> Option<Self> {
+        let data = log.strip_prefix(PROGRAM_DATA)?;
+
+        // Events are logged as a single base64 encoded field.
+        let data = STANDARD.decode(data.trim()).ok()?;
+
+        Self::from_bytes(&data).ok()
+    }
+}
+
+/// Decodes the events logged by the program from the log messages of a transaction.
+///
+/// Other programs, including those invoked by the oracle program, can also log `Program data:`
+/// messages, so the invoked programs are tracked from the `invoke` and result messages, and only
+/// messages logged while the oracle program is executing are decoded.
+pub fn parse_logs<'a, I>(logs: I) -> Vec<OracleEvent>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let program_id = crate::ID.to_string();
+
+    // The programs currently executing, innermost last.
+    let mut invoked: Vec<&str> = Vec::new();
+    let mut events = Vec::new();
+
+    for log in logs {
+        if log.starts_with(PROGRAM_DATA) {
+            if invoked.last() == Some(&program_id.as_str()) {
+                events.extend(OracleEvent::from_log(log));
+            }
+            continue;
+        }
+
+        // Invocations are logged as `Program <id> invoke [<depth>]`, and their results as
+        // `Program <id> success` or `Program <id> failed: <error>`.
+        let Some((program, message)) =
+            log.strip_prefix(PROGRAM).and_then(|log| log.split_once(' '))
+        else {
+            continue;
+        };
+
+        if message.starts_with("invoke [") {
+            invoked.push(program);
+        } else if message == "success" || message.starts_with("failed") {
+            invoked.pop();
+        }
+    }
+
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshSerialize;
+    use solana_program::pubkey::Pubkey;
+
+    use super::*;
+
+    fn event_log(event: &OracleEvent) -> String {
+        format!("{PROGRAM_DATA}{}", STANDARD.encode(event.try_to_vec().unwrap()))
+    }
+
+    #[test]
+    fn decode_log() {
+        let event = OracleEvent::Resolved { request: Pubkey::new_unique(), value: 7 };
+        let log = event_log(&event);
+
+        let invoke = format!("Program {} invoke [1]", crate::ID);
+        let success = format!("Program {} success", crate::ID);
+
+        assert_eq!(OracleEvent::from_log(&log), Some(event.clone()));
+        assert_eq!(
+            parse_logs([&invoke, "Program log: Instruction: ResolveAssertionV1", &log, &success,]),
+            [event],
+        );
+    }
+
+    #[test]
+    fn ignore_other_program_logs() {
+        let event = OracleEvent::Resolved { request: Pubkey::new_unique(), value: 7 };
+        let log = event_log(&event);
+
+        let other = Pubkey::new_unique();
+
+        let logs = [
+            format!("Program {other} invoke [1]"),
+            log.clone(),
+            format!("Program {other} success"),
+            format!("Program {} invoke [1]", crate::ID),
+            format!("Program {other} invoke [2]"),
+            log.clone(),
+            format!("Program {other} failed: custom program error: 0x0"),
+            log.clone(),
+            format!("Program {} success", crate::ID),
+            log,
+        ];
+
+        // Only the event logged by the oracle program itself is decoded.
+        assert_eq!(parse_logs(logs.iter().map(String::as_str)), [event]);
+    }
+
+    #[test]
+    fn ignore_invalid_log() {
+        assert_eq!(OracleEvent::from_log("Program data: AAAA"), None);
+        assert_eq!(OracleEvent::from_log("Program data: not base64"), None);
+    }
+}
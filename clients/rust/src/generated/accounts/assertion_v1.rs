@@ -21,11 +21,13 @@ pub struct AssertionV1 {
     pub asserter: Pubkey,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
     pub disputer: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+    pub rent_payer: Pubkey,
     pub asserted_value: u64,
 }
 
 impl AssertionV1 {
-    pub const LEN: usize = 121;
+    pub const LEN: usize = 153;
 
     /// Prefix values used to generate a PDA for this account.
     ///
@@ -5,7 +5,7 @@
 //! <https://github.com/kinobi-so/kinobi>
 //!
 
-use crate::generated::types::AccountType;
+use crate::generated::types::{AccountType, Bounds, TiePolicy, TransferFeePolicy};
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 
@@ -19,10 +19,21 @@ pub struct ConfigV1 {
     pub dispute_window: u32,
     pub voting_window: u32,
     pub arbitration_window: u32,
+    pub reveal_window: u32,
+    pub claim_window: u32,
+    pub dispute_window_range: Bounds,
+    pub voting_window_range: Bounds,
+    pub arbitration_window_range: Bounds,
+    pub quorum_amount: u64,
+    pub quorum_bps: u16,
+    pub slash_bps: u16,
+    pub tie_policy: TiePolicy,
+    pub transfer_fee_policy: TransferFeePolicy,
+    pub slash_window: u32,
 }
 
 impl ConfigV1 {
-    pub const LEN: usize = 47;
+    pub const LEN: usize = 121;
 
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
@@ -5,7 +5,7 @@
 //! <https://github.com/kinobi-so/kinobi>
 //!
 
-use crate::generated::types::{AccountType, Bounds};
+use crate::generated::types::{AccountType, Bounds, CurrencyStatus};
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 
@@ -19,10 +19,11 @@ pub struct CurrencyV1 {
     pub mint: Pubkey,
     pub reward_range: Bounds,
     pub bond_range: Bounds,
+    pub status: CurrencyStatus,
 }
 
 impl CurrencyV1 {
-    pub const LEN: usize = 97;
+    pub const LEN: usize = 98;
 
     /// Prefix values used to generate a PDA for this account.
     ///
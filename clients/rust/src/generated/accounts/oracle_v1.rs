@@ -18,10 +18,11 @@ pub struct OracleV1 {
     pub authority: Pubkey,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
     pub governance_mint: Pubkey,
+    pub total_staked: u64,
 }
 
 impl OracleV1 {
-    pub const LEN: usize = 73;
+    pub const LEN: usize = 81;
 
     /// Prefix values used to generate a PDA for this account.
     ///
@@ -5,7 +5,9 @@
 //! <https://github.com/kinobi-so/kinobi>
 //!
 
-use crate::generated::types::{AccountType, RequestData, RequestState};
+use crate::generated::types::{
+    AccountType, RequestData, RequestDerivation, RequestState, TiePolicy,
+};
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 
@@ -30,6 +32,24 @@ pub struct RequestV1 {
     pub value: u64,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
     pub arbitrator: Pubkey,
+    pub round: u32,
+    pub dispute_window: u32,
+    pub voting_window: u32,
+    pub reveal_window: u32,
+    pub arbitration_window: u32,
+    pub claim_window: u32,
+    pub bond_fee_bps: u16,
+    pub quorum_amount: u64,
+    pub quorum_bps: u16,
+    pub slash_bps: u16,
+    pub tie_policy: TiePolicy,
+    pub bond_fee: u64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+    pub rent_payer: Pubkey,
+    pub slash_window: u32,
+    pub derivation: RequestDerivation,
+    pub allow_too_early: bool,
+    pub nonce: u64,
     pub data: RequestData,
 }
 
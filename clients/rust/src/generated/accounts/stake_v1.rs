@@ -21,10 +21,11 @@ pub struct StakeV1 {
     pub delegate: Pubkey,
     pub amount: u64,
     pub lock_timestamp: i64,
+    pub open_votes: u32,
 }
 
 impl StakeV1 {
-    pub const LEN: usize = 113;
+    pub const LEN: usize = 117;
 
     /// Prefix values used to generate a PDA for this account.
     ///
@@ -17,12 +17,18 @@ pub struct VoteV1 {
     pub voting: Pubkey,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
     pub stake: Pubkey,
+    pub round: u32,
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+    pub rent_payer: Pubkey,
     pub value: u64,
     pub votes: u64,
+    pub commitment: [u8; 32],
+    pub revealed: bool,
+    pub voting_rent: u64,
 }
 
 impl VoteV1 {
-    pub const LEN: usize = 81;
+    pub const LEN: usize = 158;
 
     /// Prefix values used to generate a PDA for this account.
     ///
@@ -5,7 +5,7 @@
 //! <https://github.com/kinobi-so/kinobi>
 //!
 
-use crate::generated::types::AccountType;
+use crate::generated::types::{AccountType, RewardPool};
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 use std::collections::HashMap;
@@ -23,6 +23,12 @@ pub struct VotingV1 {
     pub vote_count: u64,
     pub mode_value: u64,
     pub votes: HashMap<u64, u64>,
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+    pub rent_payer: Pubkey,
+    pub reveal_window: u32,
+    pub unrevealed_count: u64,
+    pub reward_pool: RewardPool,
+    pub open_votes: u32,
 }
 
 impl VotingV1 {
@@ -103,6 +103,114 @@ pub enum OptimisticOracleError {
     /// 30 - Value voted for is not the resolved value
     #[error("Value voted for is not the resolved value")]
     IncorrectVote = 0x1E,
+    /// 31 - Stake owner address does not match
+    #[error("Stake owner address does not match")]
+    StakeOwnerMismatch = 0x1F,
+    /// 32 - Stake is locked
+    #[error("Stake is locked")]
+    StakeLocked = 0x20,
+    /// 33 - Insufficient stake
+    #[error("Insufficient stake")]
+    InsufficientStake = 0x21,
+    /// 34 - Cannot merge a stake account into itself
+    #[error("Cannot merge a stake account into itself")]
+    StakeMergeSelf = 0x22,
+    /// 35 - Arbitrator address does not match
+    #[error("Arbitrator address does not match")]
+    ArbitratorMismatch = 0x23,
+    /// 36 - Arbitration window has expired
+    #[error("Arbitration window has expired")]
+    ArbitrationWindowExpired = 0x24,
+    /// 37 - Request data is not valid
+    #[error("Request data is not valid")]
+    InvalidRequestData = 0x25,
+    /// 38 - Creator address does not match
+    #[error("Creator address does not match")]
+    CreatorMismatch = 0x26,
+    /// 39 - Request has unclaimed bond or reward
+    #[error("Request has unclaimed bond or reward")]
+    RequestNotSettled = 0x27,
+    /// 40 - Claim window has not expired
+    #[error("Claim window has not expired")]
+    ClaimWindowNotExpired = 0x28,
+    /// 41 - Rent payer address does not match
+    #[error("Rent payer address does not match")]
+    RentPayerMismatch = 0x29,
+    /// 42 - Reward pool has no unclaimed votes remaining
+    #[error("Reward pool has no unclaimed votes remaining")]
+    RewardPoolExhausted = 0x2A,
+    /// 43 - Slashing is not enabled for the config
+    #[error("Slashing is not enabled for the config")]
+    SlashingDisabled = 0x2B,
+    /// 44 - Value voted for is the resolved value
+    #[error("Value voted for is the resolved value")]
+    CorrectVote = 0x2C,
+    /// 45 - Votes must be committed and then revealed
+    #[error("Votes must be committed and then revealed")]
+    CommitRevealRequired = 0x2D,
+    /// 46 - Commit-reveal voting is not enabled
+    #[error("Commit-reveal voting is not enabled")]
+    CommitRevealDisabled = 0x2E,
+    /// 47 - Reveal window is not open
+    #[error("Reveal window is not open")]
+    RevealWindowNotOpen = 0x2F,
+    /// 48 - Vote has already been revealed
+    #[error("Vote has already been revealed")]
+    VoteAlreadyRevealed = 0x30,
+    /// 49 - Revealed value and salt do not match commitment
+    #[error("Revealed value and salt do not match commitment")]
+    CommitmentMismatch = 0x31,
+    /// 50 - Vote has not been revealed
+    #[error("Vote has not been revealed")]
+    VoteNotRevealed = 0x32,
+    /// 51 - Stake has already voted
+    #[error("Stake has already voted")]
+    AlreadyVoted = 0x33,
+    /// 52 - Vote is from a previous round of the request
+    #[error("Vote is from a previous round of the request")]
+    VoteRoundMismatch = 0x34,
+    /// 53 - Window must be within valid bounds
+    #[error("Window must be within valid bounds")]
+    WindowBounds = 0x35,
+    /// 54 - Mint transfer fee is not supported
+    #[error("Mint transfer fee is not supported")]
+    TransferFeeNotSupported = 0x36,
+    /// 55 - Escrow did not receive the full amount
+    #[error("Escrow did not receive the full amount")]
+    EscrowAmountMismatch = 0x37,
+    /// 56 - Bounds must not be empty
+    #[error("Bounds must not be empty")]
+    EmptyBounds = 0x38,
+    /// 57 - Mint extension is not supported
+    #[error("Mint extension is not supported")]
+    MintExtensionNotSupported = 0x39,
+    /// 58 - Currency is disabled
+    #[error("Currency is disabled")]
+    CurrencyDisabled = 0x3A,
+    /// 59 - Stake has open votes
+    #[error("Stake has open votes")]
+    StakeHasOpenVotes = 0x3B,
+    /// 60 - Insufficient fees
+    #[error("Insufficient fees")]
+    InsufficientFees = 0x3C,
+    /// 61 - Voting has open votes
+    #[error("Voting has open votes")]
+    VotingHasOpenVotes = 0x3D,
+    /// 62 - Slash window expired
+    #[error("Slash window expired")]
+    SlashWindowExpired = 0x3E,
+    /// 63 - Slash window not expired
+    #[error("Slash window not expired")]
+    SlashWindowNotExpired = 0x3F,
+    /// 64 - Request cannot be resolved as too early
+    #[error("Request cannot be resolved as too early")]
+    TooEarlyNotAllowed = 0x40,
+    /// 65 - Token account owner does not match
+    #[error("Token account owner does not match")]
+    TokenOwnerMismatch = 0x41,
+    /// 66 - Request has been cancelled
+    #[error("Request has been cancelled")]
+    RequestCancelled = 0x42,
 }
 
 impl solana_program::program_error::PrintProgramError for OptimisticOracleError {
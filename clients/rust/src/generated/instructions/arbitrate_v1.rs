@@ -0,0 +1,658 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+//!
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct ArbitrateV1 {
+    /// Config
+    pub config: solana_program::pubkey::Pubkey,
+    /// Request
+    pub request: solana_program::pubkey::Pubkey,
+    /// Voting
+    pub voting: solana_program::pubkey::Pubkey,
+    /// Assertion
+    pub assertion: solana_program::pubkey::Pubkey,
+    /// Bond mint
+    pub bond_mint: solana_program::pubkey::Pubkey,
+    /// Bond escrow token account of incorrect asserter/disputer
+    pub bond_escrow: solana_program::pubkey::Pubkey,
+    /// Fee treasury token account
+    pub treasury: solana_program::pubkey::Pubkey,
+    /// Arbitrator
+    pub arbitrator: solana_program::pubkey::Pubkey,
+    /// Payer
+    pub payer: solana_program::pubkey::Pubkey,
+    /// SPL token program
+    pub token_program: solana_program::pubkey::Pubkey,
+    /// System program
+    pub system_program: solana_program::pubkey::Pubkey,
+}
+
+impl ArbitrateV1 {
+    pub fn instruction(
+        &self,
+        args: ArbitrateV1InstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: ArbitrateV1InstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(self.config, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.request, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.voting, false));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new_readonly(self.assertion, false));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new_readonly(self.bond_mint, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.bond_escrow, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.treasury, false));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new_readonly(self.arbitrator, true));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.payer, true));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = ArbitrateV1InstructionData::new().try_to_vec().unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::OPTIMISTIC_ORACLE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct ArbitrateV1InstructionData {
+    discriminator: u8,
+}
+
+impl ArbitrateV1InstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 20 }
+    }
+}
+
+impl Default for ArbitrateV1InstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArbitrateV1InstructionArgs {
+    pub value: u64,
+}
+
+/// Instruction builder for `ArbitrateV1`.
+///
+/// ### Accounts:
+///
+///   0. `[]` config
+///   1. `[writable]` request
+///   2. `[writable]` voting
+///   3. `[]` assertion
+///   4. `[]` bond_mint
+///   5. `[writable]` bond_escrow
+///   6. `[writable]` treasury
+///   7. `[signer]` arbitrator
+///   8. `[writable, signer]` payer
+///   9. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   10. `[optional]` system_program (default to `11111111111111111111111111111111`)
+#[derive(Clone, Debug, Default)]
+pub struct ArbitrateV1Builder {
+    config: Option<solana_program::pubkey::Pubkey>,
+    request: Option<solana_program::pubkey::Pubkey>,
+    voting: Option<solana_program::pubkey::Pubkey>,
+    assertion: Option<solana_program::pubkey::Pubkey>,
+    bond_mint: Option<solana_program::pubkey::Pubkey>,
+    bond_escrow: Option<solana_program::pubkey::Pubkey>,
+    treasury: Option<solana_program::pubkey::Pubkey>,
+    arbitrator: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    value: Option<u64>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl ArbitrateV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Config
+    #[inline(always)]
+    pub fn config(&mut self, config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.config = Some(config);
+        self
+    }
+    /// Request
+    #[inline(always)]
+    pub fn request(&mut self, request: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.request = Some(request);
+        self
+    }
+    /// Voting
+    #[inline(always)]
+    pub fn voting(&mut self, voting: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.voting = Some(voting);
+        self
+    }
+    /// Assertion
+    #[inline(always)]
+    pub fn assertion(&mut self, assertion: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.assertion = Some(assertion);
+        self
+    }
+    /// Bond mint
+    #[inline(always)]
+    pub fn bond_mint(&mut self, bond_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.bond_mint = Some(bond_mint);
+        self
+    }
+    /// Bond escrow token account of incorrect asserter/disputer
+    #[inline(always)]
+    pub fn bond_escrow(&mut self, bond_escrow: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.bond_escrow = Some(bond_escrow);
+        self
+    }
+    /// Fee treasury token account
+    #[inline(always)]
+    pub fn treasury(&mut self, treasury: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.treasury = Some(treasury);
+        self
+    }
+    /// Arbitrator
+    #[inline(always)]
+    pub fn arbitrator(&mut self, arbitrator: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.arbitrator = Some(arbitrator);
+        self
+    }
+    /// Payer
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    /// SPL token program
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn value(&mut self, value: u64) -> &mut Self {
+        self.value = Some(value);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = ArbitrateV1 {
+            config: self.config.expect("config is not set"),
+            request: self.request.expect("request is not set"),
+            voting: self.voting.expect("voting is not set"),
+            assertion: self.assertion.expect("assertion is not set"),
+            bond_mint: self.bond_mint.expect("bond_mint is not set"),
+            bond_escrow: self.bond_escrow.expect("bond_escrow is not set"),
+            treasury: self.treasury.expect("treasury is not set"),
+            arbitrator: self.arbitrator.expect("arbitrator is not set"),
+            payer: self.payer.expect("payer is not set"),
+            token_program: self
+                .token_program
+                .unwrap_or(solana_program::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+        };
+        let args =
+            ArbitrateV1InstructionArgs { value: self.value.clone().expect("value is not set") };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `arbitrate_v1` CPI accounts.
+pub struct ArbitrateV1CpiAccounts<'a, 'b> {
+    /// Config
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Request
+    pub request: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Voting
+    pub voting: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Assertion
+    pub assertion: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Bond mint
+    pub bond_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Bond escrow token account of incorrect asserter/disputer
+    pub bond_escrow: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Fee treasury token account
+    pub treasury: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Arbitrator
+    pub arbitrator: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Payer
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// SPL token program
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// System program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `arbitrate_v1` CPI instruction.
+pub struct ArbitrateV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Config
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Request
+    pub request: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Voting
+    pub voting: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Assertion
+    pub assertion: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Bond mint
+    pub bond_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Bond escrow token account of incorrect asserter/disputer
+    pub bond_escrow: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Fee treasury token account
+    pub treasury: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Arbitrator
+    pub arbitrator: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Payer
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// SPL token program
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// System program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: ArbitrateV1InstructionArgs,
+}
+
+impl<'a, 'b> ArbitrateV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: ArbitrateV1CpiAccounts<'a, 'b>,
+        args: ArbitrateV1InstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            config: accounts.config,
+            request: accounts.request,
+            voting: accounts.voting,
+            assertion: accounts.assertion,
+            bond_mint: accounts.bond_mint,
+            bond_escrow: accounts.bond_escrow,
+            treasury: accounts.treasury,
+            arbitrator: accounts.arbitrator,
+            payer: accounts.payer,
+            token_program: accounts.token_program,
+            system_program: accounts.system_program,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
+        accounts
+            .push(solana_program::instruction::AccountMeta::new_readonly(*self.config.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.request.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.voting.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.assertion.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.bond_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.bond_escrow.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.treasury.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.arbitrator.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.payer.key, true));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = ArbitrateV1InstructionData::new().try_to_vec().unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::OPTIMISTIC_ORACLE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(11 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.config.clone());
+        account_infos.push(self.request.clone());
+        account_infos.push(self.voting.clone());
+        account_infos.push(self.assertion.clone());
+        account_infos.push(self.bond_mint.clone());
+        account_infos.push(self.bond_escrow.clone());
+        account_infos.push(self.treasury.clone());
+        account_infos.push(self.arbitrator.clone());
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.token_program.clone());
+        account_infos.push(self.system_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `ArbitrateV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[]` config
+///   1. `[writable]` request
+///   2. `[writable]` voting
+///   3. `[]` assertion
+///   4. `[]` bond_mint
+///   5. `[writable]` bond_escrow
+///   6. `[writable]` treasury
+///   7. `[signer]` arbitrator
+///   8. `[writable, signer]` payer
+///   9. `[]` token_program
+///   10. `[]` system_program
+#[derive(Clone, Debug)]
+pub struct ArbitrateV1CpiBuilder<'a, 'b> {
+    instruction: Box<ArbitrateV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> ArbitrateV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(ArbitrateV1CpiBuilderInstruction {
+            __program: program,
+            config: None,
+            request: None,
+            voting: None,
+            assertion: None,
+            bond_mint: None,
+            bond_escrow: None,
+            treasury: None,
+            arbitrator: None,
+            payer: None,
+            token_program: None,
+            system_program: None,
+            value: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Config
+    #[inline(always)]
+    pub fn config(
+        &mut self,
+        config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.config = Some(config);
+        self
+    }
+    /// Request
+    #[inline(always)]
+    pub fn request(
+        &mut self,
+        request: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.request = Some(request);
+        self
+    }
+    /// Voting
+    #[inline(always)]
+    pub fn voting(
+        &mut self,
+        voting: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.voting = Some(voting);
+        self
+    }
+    /// Assertion
+    #[inline(always)]
+    pub fn assertion(
+        &mut self,
+        assertion: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.assertion = Some(assertion);
+        self
+    }
+    /// Bond mint
+    #[inline(always)]
+    pub fn bond_mint(
+        &mut self,
+        bond_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.bond_mint = Some(bond_mint);
+        self
+    }
+    /// Bond escrow token account of incorrect asserter/disputer
+    #[inline(always)]
+    pub fn bond_escrow(
+        &mut self,
+        bond_escrow: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.bond_escrow = Some(bond_escrow);
+        self
+    }
+    /// Fee treasury token account
+    #[inline(always)]
+    pub fn treasury(
+        &mut self,
+        treasury: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.treasury = Some(treasury);
+        self
+    }
+    /// Arbitrator
+    #[inline(always)]
+    pub fn arbitrator(
+        &mut self,
+        arbitrator: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.arbitrator = Some(arbitrator);
+        self
+    }
+    /// Payer
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// SPL token program
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    /// System program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn value(&mut self, value: u64) -> &mut Self {
+        self.instruction.value = Some(value);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction.__remaining_accounts.push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)],
+    ) -> &mut Self {
+        self.instruction.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = ArbitrateV1InstructionArgs {
+            value: self.instruction.value.clone().expect("value is not set"),
+        };
+        let instruction = ArbitrateV1Cpi {
+            __program: self.instruction.__program,
+
+            config: self.instruction.config.expect("config is not set"),
+
+            request: self.instruction.request.expect("request is not set"),
+
+            voting: self.instruction.voting.expect("voting is not set"),
+
+            assertion: self.instruction.assertion.expect("assertion is not set"),
+
+            bond_mint: self.instruction.bond_mint.expect("bond_mint is not set"),
+
+            bond_escrow: self.instruction.bond_escrow.expect("bond_escrow is not set"),
+
+            treasury: self.instruction.treasury.expect("treasury is not set"),
+
+            arbitrator: self.instruction.arbitrator.expect("arbitrator is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            token_program: self.instruction.token_program.expect("token_program is not set"),
+
+            system_program: self.instruction.system_program.expect("system_program is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ArbitrateV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    request: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    voting: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    assertion: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    bond_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    bond_escrow: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    treasury: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    arbitrator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    value: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)>,
+}
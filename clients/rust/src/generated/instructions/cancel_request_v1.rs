@@ -0,0 +1,496 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+//!
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct CancelRequestV1 {
+    /// Request
+    pub request: solana_program::pubkey::Pubkey,
+    /// Reward mint
+    pub reward_mint: solana_program::pubkey::Pubkey,
+    /// Reward destination token account
+    pub reward_destination: solana_program::pubkey::Pubkey,
+    /// Reward escrow token account
+    pub reward_escrow: solana_program::pubkey::Pubkey,
+    /// Creator
+    pub creator: solana_program::pubkey::Pubkey,
+    /// Request rent payer
+    pub request_rent_payer: solana_program::pubkey::Pubkey,
+    /// SPL token program
+    pub token_program: solana_program::pubkey::Pubkey,
+}
+
+impl CancelRequestV1 {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(self.request, false));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new_readonly(self.reward_mint, false));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new(self.reward_destination, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.reward_escrow, false));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(self.creator, true));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new(self.request_rent_payer, false));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = CancelRequestV1InstructionData::new().try_to_vec().unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::OPTIMISTIC_ORACLE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct CancelRequestV1InstructionData {
+    discriminator: u8,
+}
+
+impl CancelRequestV1InstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 22 }
+    }
+}
+
+impl Default for CancelRequestV1InstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Instruction builder for `CancelRequestV1`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` request
+///   1. `[]` reward_mint
+///   2. `[writable]` reward_destination
+///   3. `[writable]` reward_escrow
+///   4. `[signer]` creator
+///   5. `[writable]` request_rent_payer
+///   6. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+#[derive(Clone, Debug, Default)]
+pub struct CancelRequestV1Builder {
+    request: Option<solana_program::pubkey::Pubkey>,
+    reward_mint: Option<solana_program::pubkey::Pubkey>,
+    reward_destination: Option<solana_program::pubkey::Pubkey>,
+    reward_escrow: Option<solana_program::pubkey::Pubkey>,
+    creator: Option<solana_program::pubkey::Pubkey>,
+    request_rent_payer: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl CancelRequestV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Request
+    #[inline(always)]
+    pub fn request(&mut self, request: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.request = Some(request);
+        self
+    }
+    /// Reward mint
+    #[inline(always)]
+    pub fn reward_mint(&mut self, reward_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.reward_mint = Some(reward_mint);
+        self
+    }
+    /// Reward destination token account
+    #[inline(always)]
+    pub fn reward_destination(
+        &mut self,
+        reward_destination: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.reward_destination = Some(reward_destination);
+        self
+    }
+    /// Reward escrow token account
+    #[inline(always)]
+    pub fn reward_escrow(&mut self, reward_escrow: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.reward_escrow = Some(reward_escrow);
+        self
+    }
+    /// Creator
+    #[inline(always)]
+    pub fn creator(&mut self, creator: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.creator = Some(creator);
+        self
+    }
+    /// Request rent payer
+    #[inline(always)]
+    pub fn request_rent_payer(
+        &mut self,
+        request_rent_payer: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.request_rent_payer = Some(request_rent_payer);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    /// SPL token program
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = CancelRequestV1 {
+            request: self.request.expect("request is not set"),
+            reward_mint: self.reward_mint.expect("reward_mint is not set"),
+            reward_destination: self.reward_destination.expect("reward_destination is not set"),
+            reward_escrow: self.reward_escrow.expect("reward_escrow is not set"),
+            creator: self.creator.expect("creator is not set"),
+            request_rent_payer: self.request_rent_payer.expect("request_rent_payer is not set"),
+            token_program: self
+                .token_program
+                .unwrap_or(solana_program::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `cancel_request_v1` CPI accounts.
+pub struct CancelRequestV1CpiAccounts<'a, 'b> {
+    /// Request
+    pub request: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Reward mint
+    pub reward_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Reward destination token account
+    pub reward_destination: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Reward escrow token account
+    pub reward_escrow: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Creator
+    pub creator: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Request rent payer
+    pub request_rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// SPL token program
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `cancel_request_v1` CPI instruction.
+pub struct CancelRequestV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Request
+    pub request: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Reward mint
+    pub reward_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Reward destination token account
+    pub reward_destination: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Reward escrow token account
+    pub reward_escrow: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Creator
+    pub creator: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Request rent payer
+    pub request_rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// SPL token program
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> CancelRequestV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: CancelRequestV1CpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            request: accounts.request,
+            reward_mint: accounts.reward_mint,
+            reward_destination: accounts.reward_destination,
+            reward_escrow: accounts.reward_escrow,
+            creator: accounts.creator,
+            request_rent_payer: accounts.request_rent_payer,
+            token_program: accounts.token_program,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.request.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.reward_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.reward_destination.key,
+            false,
+        ));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new(*self.reward_escrow.key, false));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new_readonly(*self.creator.key, true));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.request_rent_payer.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = CancelRequestV1InstructionData::new().try_to_vec().unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::OPTIMISTIC_ORACLE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.request.clone());
+        account_infos.push(self.reward_mint.clone());
+        account_infos.push(self.reward_destination.clone());
+        account_infos.push(self.reward_escrow.clone());
+        account_infos.push(self.creator.clone());
+        account_infos.push(self.request_rent_payer.clone());
+        account_infos.push(self.token_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `CancelRequestV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` request
+///   1. `[]` reward_mint
+///   2. `[writable]` reward_destination
+///   3. `[writable]` reward_escrow
+///   4. `[signer]` creator
+///   5. `[writable]` request_rent_payer
+///   6. `[]` token_program
+#[derive(Clone, Debug)]
+pub struct CancelRequestV1CpiBuilder<'a, 'b> {
+    instruction: Box<CancelRequestV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> CancelRequestV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(CancelRequestV1CpiBuilderInstruction {
+            __program: program,
+            request: None,
+            reward_mint: None,
+            reward_destination: None,
+            reward_escrow: None,
+            creator: None,
+            request_rent_payer: None,
+            token_program: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Request
+    #[inline(always)]
+    pub fn request(
+        &mut self,
+        request: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.request = Some(request);
+        self
+    }
+    /// Reward mint
+    #[inline(always)]
+    pub fn reward_mint(
+        &mut self,
+        reward_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.reward_mint = Some(reward_mint);
+        self
+    }
+    /// Reward destination token account
+    #[inline(always)]
+    pub fn reward_destination(
+        &mut self,
+        reward_destination: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.reward_destination = Some(reward_destination);
+        self
+    }
+    /// Reward escrow token account
+    #[inline(always)]
+    pub fn reward_escrow(
+        &mut self,
+        reward_escrow: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.reward_escrow = Some(reward_escrow);
+        self
+    }
+    /// Creator
+    #[inline(always)]
+    pub fn creator(
+        &mut self,
+        creator: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.creator = Some(creator);
+        self
+    }
+    /// Request rent payer
+    #[inline(always)]
+    pub fn request_rent_payer(
+        &mut self,
+        request_rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.request_rent_payer = Some(request_rent_payer);
+        self
+    }
+    /// SPL token program
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction.__remaining_accounts.push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)],
+    ) -> &mut Self {
+        self.instruction.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = CancelRequestV1Cpi {
+            __program: self.instruction.__program,
+
+            request: self.instruction.request.expect("request is not set"),
+
+            reward_mint: self.instruction.reward_mint.expect("reward_mint is not set"),
+
+            reward_destination: self
+                .instruction
+                .reward_destination
+                .expect("reward_destination is not set"),
+
+            reward_escrow: self.instruction.reward_escrow.expect("reward_escrow is not set"),
+
+            creator: self.instruction.creator.expect("creator is not set"),
+
+            request_rent_payer: self
+                .instruction
+                .request_rent_payer
+                .expect("request_rent_payer is not set"),
+
+            token_program: self.instruction.token_program.expect("token_program is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct CancelRequestV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    request: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    reward_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    reward_destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    reward_escrow: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    creator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    request_rent_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)>,
+}
@@ -0,0 +1,498 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+//!
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct ChangeVoteV1 {
+    /// Request
+    pub request: solana_program::pubkey::Pubkey,
+    /// Voting
+    pub voting: solana_program::pubkey::Pubkey,
+    /// Vote
+    pub vote: solana_program::pubkey::Pubkey,
+    /// Stake
+    pub stake: solana_program::pubkey::Pubkey,
+    /// Voter
+    pub voter: solana_program::pubkey::Pubkey,
+    /// Payer
+    pub payer: solana_program::pubkey::Pubkey,
+    /// System program
+    pub system_program: solana_program::pubkey::Pubkey,
+}
+
+impl ChangeVoteV1 {
+    pub fn instruction(
+        &self,
+        args: ChangeVoteV1InstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: ChangeVoteV1InstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(self.request, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.voting, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.vote, false));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(self.stake, false));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(self.voter, true));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.payer, true));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = ChangeVoteV1InstructionData::new().try_to_vec().unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::OPTIMISTIC_ORACLE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct ChangeVoteV1InstructionData {
+    discriminator: u8,
+}
+
+impl ChangeVoteV1InstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 27 }
+    }
+}
+
+impl Default for ChangeVoteV1InstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChangeVoteV1InstructionArgs {
+    pub value: u64,
+}
+
+/// Instruction builder for `ChangeVoteV1`.
+///
+/// ### Accounts:
+///
+///   0. `[]` request
+///   1. `[writable]` voting
+///   2. `[writable]` vote
+///   3. `[]` stake
+///   4. `[signer]` voter
+///   5. `[writable, signer]` payer
+///   6. `[optional]` system_program (default to `11111111111111111111111111111111`)
+#[derive(Clone, Debug, Default)]
+pub struct ChangeVoteV1Builder {
+    request: Option<solana_program::pubkey::Pubkey>,
+    voting: Option<solana_program::pubkey::Pubkey>,
+    vote: Option<solana_program::pubkey::Pubkey>,
+    stake: Option<solana_program::pubkey::Pubkey>,
+    voter: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    value: Option<u64>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl ChangeVoteV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Request
+    #[inline(always)]
+    pub fn request(&mut self, request: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.request = Some(request);
+        self
+    }
+    /// Voting
+    #[inline(always)]
+    pub fn voting(&mut self, voting: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.voting = Some(voting);
+        self
+    }
+    /// Vote
+    #[inline(always)]
+    pub fn vote(&mut self, vote: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.vote = Some(vote);
+        self
+    }
+    /// Stake
+    #[inline(always)]
+    pub fn stake(&mut self, stake: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.stake = Some(stake);
+        self
+    }
+    /// Voter
+    #[inline(always)]
+    pub fn voter(&mut self, voter: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.voter = Some(voter);
+        self
+    }
+    /// Payer
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn value(&mut self, value: u64) -> &mut Self {
+        self.value = Some(value);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = ChangeVoteV1 {
+            request: self.request.expect("request is not set"),
+            voting: self.voting.expect("voting is not set"),
+            vote: self.vote.expect("vote is not set"),
+            stake: self.stake.expect("stake is not set"),
+            voter: self.voter.expect("voter is not set"),
+            payer: self.payer.expect("payer is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+        };
+        let args =
+            ChangeVoteV1InstructionArgs { value: self.value.clone().expect("value is not set") };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `change_vote_v1` CPI accounts.
+pub struct ChangeVoteV1CpiAccounts<'a, 'b> {
+    /// Request
+    pub request: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Voting
+    pub voting: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Vote
+    pub vote: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake
+    pub stake: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Voter
+    pub voter: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Payer
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// System program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `change_vote_v1` CPI instruction.
+pub struct ChangeVoteV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Request
+    pub request: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Voting
+    pub voting: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Vote
+    pub vote: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake
+    pub stake: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Voter
+    pub voter: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Payer
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// System program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: ChangeVoteV1InstructionArgs,
+}
+
+impl<'a, 'b> ChangeVoteV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: ChangeVoteV1CpiAccounts<'a, 'b>,
+        args: ChangeVoteV1InstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            request: accounts.request,
+            voting: accounts.voting,
+            vote: accounts.vote,
+            stake: accounts.stake,
+            voter: accounts.voter,
+            payer: accounts.payer,
+            system_program: accounts.system_program,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts
+            .push(solana_program::instruction::AccountMeta::new_readonly(*self.request.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.voting.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.vote.key, false));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new_readonly(*self.stake.key, false));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new_readonly(*self.voter.key, true));
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.payer.key, true));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = ChangeVoteV1InstructionData::new().try_to_vec().unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::OPTIMISTIC_ORACLE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.request.clone());
+        account_infos.push(self.voting.clone());
+        account_infos.push(self.vote.clone());
+        account_infos.push(self.stake.clone());
+        account_infos.push(self.voter.clone());
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.system_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `ChangeVoteV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[]` request
+///   1. `[writable]` voting
+///   2. `[writable]` vote
+///   3. `[]` stake
+///   4. `[signer]` voter
+///   5. `[writable, signer]` payer
+///   6. `[]` system_program
+#[derive(Clone, Debug)]
+pub struct ChangeVoteV1CpiBuilder<'a, 'b> {
+    instruction: Box<ChangeVoteV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> ChangeVoteV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(ChangeVoteV1CpiBuilderInstruction {
+            __program: program,
+            request: None,
+            voting: None,
+            vote: None,
+            stake: None,
+            voter: None,
+            payer: None,
+            system_program: None,
+            value: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Request
+    #[inline(always)]
+    pub fn request(
+        &mut self,
+        request: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.request = Some(request);
+        self
+    }
+    /// Voting
+    #[inline(always)]
+    pub fn voting(
+        &mut self,
+        voting: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.voting = Some(voting);
+        self
+    }
+    /// Vote
+    #[inline(always)]
+    pub fn vote(&mut self, vote: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.vote = Some(vote);
+        self
+    }
+    /// Stake
+    #[inline(always)]
+    pub fn stake(&mut self, stake: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.stake = Some(stake);
+        self
+    }
+    /// Voter
+    #[inline(always)]
+    pub fn voter(&mut self, voter: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.voter = Some(voter);
+        self
+    }
+    /// Payer
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// System program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn value(&mut self, value: u64) -> &mut Self {
+        self.instruction.value = Some(value);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction.__remaining_accounts.push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)],
+    ) -> &mut Self {
+        self.instruction.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = ChangeVoteV1InstructionArgs {
+            value: self.instruction.value.clone().expect("value is not set"),
+        };
+        let instruction = ChangeVoteV1Cpi {
+            __program: self.instruction.__program,
+
+            request: self.instruction.request.expect("request is not set"),
+
+            voting: self.instruction.voting.expect("voting is not set"),
+
+            vote: self.instruction.vote.expect("vote is not set"),
+
+            stake: self.instruction.stake.expect("stake is not set"),
+
+            voter: self.instruction.voter.expect("voter is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            system_program: self.instruction.system_program.expect("system_program is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ChangeVoteV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    request: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    voting: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vote: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    stake: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    voter: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    value: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)>,
+}
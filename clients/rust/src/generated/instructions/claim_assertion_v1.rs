@@ -27,6 +27,10 @@ pub struct ClaimAssertionV1 {
     pub reward_escrow: solana_program::pubkey::Pubkey,
     /// Asserter
     pub asserter: solana_program::pubkey::Pubkey,
+    /// Request rent payer
+    pub request_rent_payer: solana_program::pubkey::Pubkey,
+    /// Assertion rent payer
+    pub assertion_rent_payer: solana_program::pubkey::Pubkey,
     /// SPL token program
     pub token_program: solana_program::pubkey::Pubkey,
     /// System program
@@ -42,7 +46,7 @@ impl ClaimAssertionV1 {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(self.request, false));
         accounts.push(solana_program::instruction::AccountMeta::new(self.assertion, false));
         accounts
@@ -54,7 +58,11 @@ impl ClaimAssertionV1 {
         accounts
             .push(solana_program::instruction::AccountMeta::new(self.reward_destination, false));
         accounts.push(solana_program::instruction::AccountMeta::new(self.reward_escrow, false));
-        accounts.push(solana_program::instruction::AccountMeta::new(self.asserter, true));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(self.asserter, true));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new(self.request_rent_payer, false));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new(self.assertion_rent_payer, false));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.token_program,
             false,
@@ -103,9 +111,11 @@ impl Default for ClaimAssertionV1InstructionData {
 ///   5. `[]` reward_mint
 ///   6. `[writable]` reward_destination
 ///   7. `[writable]` reward_escrow
-///   8. `[writable, signer]` asserter
-///   9. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-///   10. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   8. `[signer]` asserter
+///   9. `[writable]` request_rent_payer
+///   10. `[writable]` assertion_rent_payer
+///   11. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   12. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct ClaimAssertionV1Builder {
     request: Option<solana_program::pubkey::Pubkey>,
@@ -117,6 +127,8 @@ pub struct ClaimAssertionV1Builder {
     reward_destination: Option<solana_program::pubkey::Pubkey>,
     reward_escrow: Option<solana_program::pubkey::Pubkey>,
     asserter: Option<solana_program::pubkey::Pubkey>,
+    request_rent_payer: Option<solana_program::pubkey::Pubkey>,
+    assertion_rent_payer: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
@@ -186,6 +198,24 @@ impl ClaimAssertionV1Builder {
         self.asserter = Some(asserter);
         self
     }
+    /// Request rent payer
+    #[inline(always)]
+    pub fn request_rent_payer(
+        &mut self,
+        request_rent_payer: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.request_rent_payer = Some(request_rent_payer);
+        self
+    }
+    /// Assertion rent payer
+    #[inline(always)]
+    pub fn assertion_rent_payer(
+        &mut self,
+        assertion_rent_payer: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.assertion_rent_payer = Some(assertion_rent_payer);
+        self
+    }
     /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
     /// SPL token program
     #[inline(always)]
@@ -230,6 +260,10 @@ impl ClaimAssertionV1Builder {
             reward_destination: self.reward_destination.expect("reward_destination is not set"),
             reward_escrow: self.reward_escrow.expect("reward_escrow is not set"),
             asserter: self.asserter.expect("asserter is not set"),
+            request_rent_payer: self.request_rent_payer.expect("request_rent_payer is not set"),
+            assertion_rent_payer: self
+                .assertion_rent_payer
+                .expect("assertion_rent_payer is not set"),
             token_program: self
                 .token_program
                 .unwrap_or(solana_program::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")),
@@ -262,6 +296,10 @@ pub struct ClaimAssertionV1CpiAccounts<'a, 'b> {
     pub reward_escrow: &'b solana_program::account_info::AccountInfo<'a>,
     /// Asserter
     pub asserter: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Request rent payer
+    pub request_rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Assertion rent payer
+    pub assertion_rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
     /// SPL token program
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
     /// System program
@@ -290,6 +328,10 @@ pub struct ClaimAssertionV1Cpi<'a, 'b> {
     pub reward_escrow: &'b solana_program::account_info::AccountInfo<'a>,
     /// Asserter
     pub asserter: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Request rent payer
+    pub request_rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Assertion rent payer
+    pub assertion_rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
     /// SPL token program
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
     /// System program
@@ -312,6 +354,8 @@ impl<'a, 'b> ClaimAssertionV1Cpi<'a, 'b> {
             reward_destination: accounts.reward_destination,
             reward_escrow: accounts.reward_escrow,
             asserter: accounts.asserter,
+            request_rent_payer: accounts.request_rent_payer,
+            assertion_rent_payer: accounts.assertion_rent_payer,
             token_program: accounts.token_program,
             system_program: accounts.system_program,
         }
@@ -341,7 +385,7 @@ impl<'a, 'b> ClaimAssertionV1Cpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
         accounts
             .push(solana_program::instruction::AccountMeta::new_readonly(*self.request.key, false));
         accounts.push(solana_program::instruction::AccountMeta::new(*self.assertion.key, false));
@@ -362,7 +406,16 @@ impl<'a, 'b> ClaimAssertionV1Cpi<'a, 'b> {
         ));
         accounts
             .push(solana_program::instruction::AccountMeta::new(*self.reward_escrow.key, false));
-        accounts.push(solana_program::instruction::AccountMeta::new(*self.asserter.key, true));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new_readonly(*self.asserter.key, true));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.request_rent_payer.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.assertion_rent_payer.key,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.token_program.key,
             false,
@@ -385,7 +438,7 @@ impl<'a, 'b> ClaimAssertionV1Cpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(11 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(13 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.request.clone());
         account_infos.push(self.assertion.clone());
@@ -396,6 +449,8 @@ impl<'a, 'b> ClaimAssertionV1Cpi<'a, 'b> {
         account_infos.push(self.reward_destination.clone());
         account_infos.push(self.reward_escrow.clone());
         account_infos.push(self.asserter.clone());
+        account_infos.push(self.request_rent_payer.clone());
+        account_infos.push(self.assertion_rent_payer.clone());
         account_infos.push(self.token_program.clone());
         account_infos.push(self.system_program.clone());
         remaining_accounts
@@ -422,9 +477,11 @@ impl<'a, 'b> ClaimAssertionV1Cpi<'a, 'b> {
 ///   5. `[]` reward_mint
 ///   6. `[writable]` reward_destination
 ///   7. `[writable]` reward_escrow
-///   8. `[writable, signer]` asserter
-///   9. `[]` token_program
-///   10. `[]` system_program
+///   8. `[signer]` asserter
+///   9. `[writable]` request_rent_payer
+///   10. `[writable]` assertion_rent_payer
+///   11. `[]` token_program
+///   12. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct ClaimAssertionV1CpiBuilder<'a, 'b> {
     instruction: Box<ClaimAssertionV1CpiBuilderInstruction<'a, 'b>>,
@@ -443,6 +500,8 @@ impl<'a, 'b> ClaimAssertionV1CpiBuilder<'a, 'b> {
             reward_destination: None,
             reward_escrow: None,
             asserter: None,
+            request_rent_payer: None,
+            assertion_rent_payer: None,
             token_program: None,
             system_program: None,
             __remaining_accounts: Vec::new(),
@@ -530,6 +589,24 @@ impl<'a, 'b> ClaimAssertionV1CpiBuilder<'a, 'b> {
         self.instruction.asserter = Some(asserter);
         self
     }
+    /// Request rent payer
+    #[inline(always)]
+    pub fn request_rent_payer(
+        &mut self,
+        request_rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.request_rent_payer = Some(request_rent_payer);
+        self
+    }
+    /// Assertion rent payer
+    #[inline(always)]
+    pub fn assertion_rent_payer(
+        &mut self,
+        assertion_rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.assertion_rent_payer = Some(assertion_rent_payer);
+        self
+    }
     /// SPL token program
     #[inline(always)]
     pub fn token_program(
@@ -608,6 +685,16 @@ impl<'a, 'b> ClaimAssertionV1CpiBuilder<'a, 'b> {
 
             asserter: self.instruction.asserter.expect("asserter is not set"),
 
+            request_rent_payer: self
+                .instruction
+                .request_rent_payer
+                .expect("request_rent_payer is not set"),
+
+            assertion_rent_payer: self
+                .instruction
+                .assertion_rent_payer
+                .expect("assertion_rent_payer is not set"),
+
             token_program: self.instruction.token_program.expect("token_program is not set"),
 
             system_program: self.instruction.system_program.expect("system_program is not set"),
@@ -631,6 +718,8 @@ struct ClaimAssertionV1CpiBuilderInstruction<'a, 'b> {
     reward_destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     reward_escrow: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     asserter: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    request_rent_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    assertion_rent_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
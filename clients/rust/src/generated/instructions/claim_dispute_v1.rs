@@ -13,6 +13,8 @@ pub struct ClaimDisputeV1 {
     pub request: solana_program::pubkey::Pubkey,
     /// Assertion
     pub assertion: solana_program::pubkey::Pubkey,
+    /// Voting
+    pub voting: solana_program::pubkey::Pubkey,
     /// Bond mint
     pub bond_mint: solana_program::pubkey::Pubkey,
     /// Reclaimed bond destination token account
@@ -27,6 +29,10 @@ pub struct ClaimDisputeV1 {
     pub reward_escrow: solana_program::pubkey::Pubkey,
     /// Disputer
     pub disputer: solana_program::pubkey::Pubkey,
+    /// Request rent payer
+    pub request_rent_payer: solana_program::pubkey::Pubkey,
+    /// Voting rent payer
+    pub voting_rent_payer: solana_program::pubkey::Pubkey,
     /// SPL token program
     pub token_program: solana_program::pubkey::Pubkey,
     /// System program
@@ -42,9 +48,10 @@ impl ClaimDisputeV1 {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(self.request, false));
         accounts.push(solana_program::instruction::AccountMeta::new(self.assertion, false));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(self.voting, false));
         accounts
             .push(solana_program::instruction::AccountMeta::new_readonly(self.bond_mint, false));
         accounts.push(solana_program::instruction::AccountMeta::new(self.bond_destination, false));
@@ -54,7 +61,10 @@ impl ClaimDisputeV1 {
         accounts
             .push(solana_program::instruction::AccountMeta::new(self.reward_destination, false));
         accounts.push(solana_program::instruction::AccountMeta::new(self.reward_escrow, false));
-        accounts.push(solana_program::instruction::AccountMeta::new(self.disputer, true));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(self.disputer, true));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new(self.request_rent_payer, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.voting_rent_payer, false));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.token_program,
             false,
@@ -97,19 +107,23 @@ impl Default for ClaimDisputeV1InstructionData {
 ///
 ///   0. `[]` request
 ///   1. `[writable]` assertion
-///   2. `[]` bond_mint
-///   3. `[writable]` bond_destination
-///   4. `[writable]` bond_escrow
-///   5. `[]` reward_mint
-///   6. `[writable]` reward_destination
-///   7. `[writable]` reward_escrow
-///   8. `[writable, signer]` disputer
-///   9. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-///   10. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   2. `[]` voting
+///   3. `[]` bond_mint
+///   4. `[writable]` bond_destination
+///   5. `[writable]` bond_escrow
+///   6. `[]` reward_mint
+///   7. `[writable]` reward_destination
+///   8. `[writable]` reward_escrow
+///   9. `[signer]` disputer
+///   10. `[writable]` request_rent_payer
+///   11. `[writable]` voting_rent_payer
+///   12. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   13. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct ClaimDisputeV1Builder {
     request: Option<solana_program::pubkey::Pubkey>,
     assertion: Option<solana_program::pubkey::Pubkey>,
+    voting: Option<solana_program::pubkey::Pubkey>,
     bond_mint: Option<solana_program::pubkey::Pubkey>,
     bond_destination: Option<solana_program::pubkey::Pubkey>,
     bond_escrow: Option<solana_program::pubkey::Pubkey>,
@@ -117,6 +131,8 @@ pub struct ClaimDisputeV1Builder {
     reward_destination: Option<solana_program::pubkey::Pubkey>,
     reward_escrow: Option<solana_program::pubkey::Pubkey>,
     disputer: Option<solana_program::pubkey::Pubkey>,
+    request_rent_payer: Option<solana_program::pubkey::Pubkey>,
+    voting_rent_payer: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
@@ -138,6 +154,12 @@ impl ClaimDisputeV1Builder {
         self.assertion = Some(assertion);
         self
     }
+    /// Voting
+    #[inline(always)]
+    pub fn voting(&mut self, voting: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.voting = Some(voting);
+        self
+    }
     /// Bond mint
     #[inline(always)]
     pub fn bond_mint(&mut self, bond_mint: solana_program::pubkey::Pubkey) -> &mut Self {
@@ -186,6 +208,24 @@ impl ClaimDisputeV1Builder {
         self.disputer = Some(disputer);
         self
     }
+    /// Request rent payer
+    #[inline(always)]
+    pub fn request_rent_payer(
+        &mut self,
+        request_rent_payer: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.request_rent_payer = Some(request_rent_payer);
+        self
+    }
+    /// Voting rent payer
+    #[inline(always)]
+    pub fn voting_rent_payer(
+        &mut self,
+        voting_rent_payer: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.voting_rent_payer = Some(voting_rent_payer);
+        self
+    }
     /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
     /// SPL token program
     #[inline(always)]
@@ -223,6 +263,7 @@ impl ClaimDisputeV1Builder {
         let accounts = ClaimDisputeV1 {
             request: self.request.expect("request is not set"),
             assertion: self.assertion.expect("assertion is not set"),
+            voting: self.voting.expect("voting is not set"),
             bond_mint: self.bond_mint.expect("bond_mint is not set"),
             bond_destination: self.bond_destination.expect("bond_destination is not set"),
             bond_escrow: self.bond_escrow.expect("bond_escrow is not set"),
@@ -230,6 +271,8 @@ impl ClaimDisputeV1Builder {
             reward_destination: self.reward_destination.expect("reward_destination is not set"),
             reward_escrow: self.reward_escrow.expect("reward_escrow is not set"),
             disputer: self.disputer.expect("disputer is not set"),
+            request_rent_payer: self.request_rent_payer.expect("request_rent_payer is not set"),
+            voting_rent_payer: self.voting_rent_payer.expect("voting_rent_payer is not set"),
             token_program: self
                 .token_program
                 .unwrap_or(solana_program::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")),
@@ -248,6 +291,8 @@ pub struct ClaimDisputeV1CpiAccounts<'a, 'b> {
     pub request: &'b solana_program::account_info::AccountInfo<'a>,
     /// Assertion
     pub assertion: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Voting
+    pub voting: &'b solana_program::account_info::AccountInfo<'a>,
     /// Bond mint
     pub bond_mint: &'b solana_program::account_info::AccountInfo<'a>,
     /// Reclaimed bond destination token account
@@ -262,6 +307,10 @@ pub struct ClaimDisputeV1CpiAccounts<'a, 'b> {
     pub reward_escrow: &'b solana_program::account_info::AccountInfo<'a>,
     /// Disputer
     pub disputer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Request rent payer
+    pub request_rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Voting rent payer
+    pub voting_rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
     /// SPL token program
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
     /// System program
@@ -276,6 +325,8 @@ pub struct ClaimDisputeV1Cpi<'a, 'b> {
     pub request: &'b solana_program::account_info::AccountInfo<'a>,
     /// Assertion
     pub assertion: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Voting
+    pub voting: &'b solana_program::account_info::AccountInfo<'a>,
     /// Bond mint
     pub bond_mint: &'b solana_program::account_info::AccountInfo<'a>,
     /// Reclaimed bond destination token account
@@ -290,6 +341,10 @@ pub struct ClaimDisputeV1Cpi<'a, 'b> {
     pub reward_escrow: &'b solana_program::account_info::AccountInfo<'a>,
     /// Disputer
     pub disputer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Request rent payer
+    pub request_rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Voting rent payer
+    pub voting_rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
     /// SPL token program
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
     /// System program
@@ -305,6 +360,7 @@ impl<'a, 'b> ClaimDisputeV1Cpi<'a, 'b> {
             __program: program,
             request: accounts.request,
             assertion: accounts.assertion,
+            voting: accounts.voting,
             bond_mint: accounts.bond_mint,
             bond_destination: accounts.bond_destination,
             bond_escrow: accounts.bond_escrow,
@@ -312,6 +368,8 @@ impl<'a, 'b> ClaimDisputeV1Cpi<'a, 'b> {
             reward_destination: accounts.reward_destination,
             reward_escrow: accounts.reward_escrow,
             disputer: accounts.disputer,
+            request_rent_payer: accounts.request_rent_payer,
+            voting_rent_payer: accounts.voting_rent_payer,
             token_program: accounts.token_program,
             system_program: accounts.system_program,
         }
@@ -341,10 +399,12 @@ impl<'a, 'b> ClaimDisputeV1Cpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
         accounts
             .push(solana_program::instruction::AccountMeta::new_readonly(*self.request.key, false));
         accounts.push(solana_program::instruction::AccountMeta::new(*self.assertion.key, false));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new_readonly(*self.voting.key, false));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.bond_mint.key,
             false,
@@ -362,7 +422,16 @@ impl<'a, 'b> ClaimDisputeV1Cpi<'a, 'b> {
         ));
         accounts
             .push(solana_program::instruction::AccountMeta::new(*self.reward_escrow.key, false));
-        accounts.push(solana_program::instruction::AccountMeta::new(*self.disputer.key, true));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new_readonly(*self.disputer.key, true));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.request_rent_payer.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.voting_rent_payer.key,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.token_program.key,
             false,
@@ -385,10 +454,11 @@ impl<'a, 'b> ClaimDisputeV1Cpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(11 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(14 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.request.clone());
         account_infos.push(self.assertion.clone());
+        account_infos.push(self.voting.clone());
         account_infos.push(self.bond_mint.clone());
         account_infos.push(self.bond_destination.clone());
         account_infos.push(self.bond_escrow.clone());
@@ -396,6 +466,8 @@ impl<'a, 'b> ClaimDisputeV1Cpi<'a, 'b> {
         account_infos.push(self.reward_destination.clone());
         account_infos.push(self.reward_escrow.clone());
         account_infos.push(self.disputer.clone());
+        account_infos.push(self.request_rent_payer.clone());
+        account_infos.push(self.voting_rent_payer.clone());
         account_infos.push(self.token_program.clone());
         account_infos.push(self.system_program.clone());
         remaining_accounts
@@ -416,15 +488,18 @@ impl<'a, 'b> ClaimDisputeV1Cpi<'a, 'b> {
 ///
 ///   0. `[]` request
 ///   1. `[writable]` assertion
-///   2. `[]` bond_mint
-///   3. `[writable]` bond_destination
-///   4. `[writable]` bond_escrow
-///   5. `[]` reward_mint
-///   6. `[writable]` reward_destination
-///   7. `[writable]` reward_escrow
-///   8. `[writable, signer]` disputer
-///   9. `[]` token_program
-///   10. `[]` system_program
+///   2. `[]` voting
+///   3. `[]` bond_mint
+///   4. `[writable]` bond_destination
+///   5. `[writable]` bond_escrow
+///   6. `[]` reward_mint
+///   7. `[writable]` reward_destination
+///   8. `[writable]` reward_escrow
+///   9. `[signer]` disputer
+///   10. `[writable]` request_rent_payer
+///   11. `[writable]` voting_rent_payer
+///   12. `[]` token_program
+///   13. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct ClaimDisputeV1CpiBuilder<'a, 'b> {
     instruction: Box<ClaimDisputeV1CpiBuilderInstruction<'a, 'b>>,
@@ -436,6 +511,7 @@ impl<'a, 'b> ClaimDisputeV1CpiBuilder<'a, 'b> {
             __program: program,
             request: None,
             assertion: None,
+            voting: None,
             bond_mint: None,
             bond_destination: None,
             bond_escrow: None,
@@ -443,6 +519,8 @@ impl<'a, 'b> ClaimDisputeV1CpiBuilder<'a, 'b> {
             reward_destination: None,
             reward_escrow: None,
             disputer: None,
+            request_rent_payer: None,
+            voting_rent_payer: None,
             token_program: None,
             system_program: None,
             __remaining_accounts: Vec::new(),
@@ -467,6 +545,15 @@ impl<'a, 'b> ClaimDisputeV1CpiBuilder<'a, 'b> {
         self.instruction.assertion = Some(assertion);
         self
     }
+    /// Voting
+    #[inline(always)]
+    pub fn voting(
+        &mut self,
+        voting: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.voting = Some(voting);
+        self
+    }
     /// Bond mint
     #[inline(always)]
     pub fn bond_mint(
@@ -530,6 +617,24 @@ impl<'a, 'b> ClaimDisputeV1CpiBuilder<'a, 'b> {
         self.instruction.disputer = Some(disputer);
         self
     }
+    /// Request rent payer
+    #[inline(always)]
+    pub fn request_rent_payer(
+        &mut self,
+        request_rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.request_rent_payer = Some(request_rent_payer);
+        self
+    }
+    /// Voting rent payer
+    #[inline(always)]
+    pub fn voting_rent_payer(
+        &mut self,
+        voting_rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.voting_rent_payer = Some(voting_rent_payer);
+        self
+    }
     /// SPL token program
     #[inline(always)]
     pub fn token_program(
@@ -588,6 +693,8 @@ impl<'a, 'b> ClaimDisputeV1CpiBuilder<'a, 'b> {
 
             assertion: self.instruction.assertion.expect("assertion is not set"),
 
+            voting: self.instruction.voting.expect("voting is not set"),
+
             bond_mint: self.instruction.bond_mint.expect("bond_mint is not set"),
 
             bond_destination: self
@@ -608,6 +715,16 @@ impl<'a, 'b> ClaimDisputeV1CpiBuilder<'a, 'b> {
 
             disputer: self.instruction.disputer.expect("disputer is not set"),
 
+            request_rent_payer: self
+                .instruction
+                .request_rent_payer
+                .expect("request_rent_payer is not set"),
+
+            voting_rent_payer: self
+                .instruction
+                .voting_rent_payer
+                .expect("voting_rent_payer is not set"),
+
             token_program: self.instruction.token_program.expect("token_program is not set"),
 
             system_program: self.instruction.system_program.expect("system_program is not set"),
@@ -624,6 +741,7 @@ struct ClaimDisputeV1CpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_program::account_info::AccountInfo<'a>,
     request: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     assertion: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    voting: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     bond_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     bond_destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     bond_escrow: Option<&'b solana_program::account_info::AccountInfo<'a>>,
@@ -631,6 +749,8 @@ struct ClaimDisputeV1CpiBuilderInstruction<'a, 'b> {
     reward_destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     reward_escrow: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     disputer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    request_rent_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    voting_rent_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -19,6 +19,8 @@ pub struct ClaimVoteV1 {
     pub vote: solana_program::pubkey::Pubkey,
     /// Stake
     pub stake: solana_program::pubkey::Pubkey,
+    /// Oracle
+    pub oracle: solana_program::pubkey::Pubkey,
     /// Bond mint
     pub bond_mint: solana_program::pubkey::Pubkey,
     /// Bond destination token account
@@ -27,6 +29,8 @@ pub struct ClaimVoteV1 {
     pub bond_escrow: solana_program::pubkey::Pubkey,
     /// Voter
     pub voter: solana_program::pubkey::Pubkey,
+    /// Vote rent payer
+    pub vote_rent_payer: solana_program::pubkey::Pubkey,
     /// SPL token program
     pub token_program: solana_program::pubkey::Pubkey,
     /// System program
@@ -42,18 +46,20 @@ impl ClaimVoteV1 {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(self.request, false));
         accounts
             .push(solana_program::instruction::AccountMeta::new_readonly(self.assertion, false));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(self.voting, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.voting, false));
         accounts.push(solana_program::instruction::AccountMeta::new(self.vote, false));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(self.stake, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.stake, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.oracle, false));
         accounts
             .push(solana_program::instruction::AccountMeta::new_readonly(self.bond_mint, false));
         accounts.push(solana_program::instruction::AccountMeta::new(self.bond_destination, false));
         accounts.push(solana_program::instruction::AccountMeta::new(self.bond_escrow, false));
-        accounts.push(solana_program::instruction::AccountMeta::new(self.voter, true));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(self.voter, true));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.vote_rent_payer, false));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.token_program,
             false,
@@ -96,15 +102,17 @@ impl Default for ClaimVoteV1InstructionData {
 ///
 ///   0. `[]` request
 ///   1. `[]` assertion
-///   2. `[]` voting
+///   2. `[writable]` voting
 ///   3. `[writable]` vote
-///   4. `[]` stake
-///   5. `[]` bond_mint
-///   6. `[writable]` bond_destination
-///   7. `[writable]` bond_escrow
-///   8. `[writable, signer]` voter
-///   9. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-///   10. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   4. `[writable]` stake
+///   5. `[writable]` oracle
+///   6. `[]` bond_mint
+///   7. `[writable]` bond_destination
+///   8. `[writable]` bond_escrow
+///   9. `[signer]` voter
+///   10. `[writable]` vote_rent_payer
+///   11. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   12. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct ClaimVoteV1Builder {
     request: Option<solana_program::pubkey::Pubkey>,
@@ -112,10 +120,12 @@ pub struct ClaimVoteV1Builder {
     voting: Option<solana_program::pubkey::Pubkey>,
     vote: Option<solana_program::pubkey::Pubkey>,
     stake: Option<solana_program::pubkey::Pubkey>,
+    oracle: Option<solana_program::pubkey::Pubkey>,
     bond_mint: Option<solana_program::pubkey::Pubkey>,
     bond_destination: Option<solana_program::pubkey::Pubkey>,
     bond_escrow: Option<solana_program::pubkey::Pubkey>,
     voter: Option<solana_program::pubkey::Pubkey>,
+    vote_rent_payer: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
@@ -155,6 +165,12 @@ impl ClaimVoteV1Builder {
         self.stake = Some(stake);
         self
     }
+    /// Oracle
+    #[inline(always)]
+    pub fn oracle(&mut self, oracle: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.oracle = Some(oracle);
+        self
+    }
     /// Bond mint
     #[inline(always)]
     pub fn bond_mint(&mut self, bond_mint: solana_program::pubkey::Pubkey) -> &mut Self {
@@ -182,6 +198,15 @@ impl ClaimVoteV1Builder {
         self.voter = Some(voter);
         self
     }
+    /// Vote rent payer
+    #[inline(always)]
+    pub fn vote_rent_payer(
+        &mut self,
+        vote_rent_payer: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.vote_rent_payer = Some(vote_rent_payer);
+        self
+    }
     /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
     /// SPL token program
     #[inline(always)]
@@ -222,10 +247,12 @@ impl ClaimVoteV1Builder {
             voting: self.voting.expect("voting is not set"),
             vote: self.vote.expect("vote is not set"),
             stake: self.stake.expect("stake is not set"),
+            oracle: self.oracle.expect("oracle is not set"),
             bond_mint: self.bond_mint.expect("bond_mint is not set"),
             bond_destination: self.bond_destination.expect("bond_destination is not set"),
             bond_escrow: self.bond_escrow.expect("bond_escrow is not set"),
             voter: self.voter.expect("voter is not set"),
+            vote_rent_payer: self.vote_rent_payer.expect("vote_rent_payer is not set"),
             token_program: self
                 .token_program
                 .unwrap_or(solana_program::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")),
@@ -250,6 +277,8 @@ pub struct ClaimVoteV1CpiAccounts<'a, 'b> {
     pub vote: &'b solana_program::account_info::AccountInfo<'a>,
     /// Stake
     pub stake: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Oracle
+    pub oracle: &'b solana_program::account_info::AccountInfo<'a>,
     /// Bond mint
     pub bond_mint: &'b solana_program::account_info::AccountInfo<'a>,
     /// Bond destination token account
@@ -258,6 +287,8 @@ pub struct ClaimVoteV1CpiAccounts<'a, 'b> {
     pub bond_escrow: &'b solana_program::account_info::AccountInfo<'a>,
     /// Voter
     pub voter: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Vote rent payer
+    pub vote_rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
     /// SPL token program
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
     /// System program
@@ -278,6 +309,8 @@ pub struct ClaimVoteV1Cpi<'a, 'b> {
     pub vote: &'b solana_program::account_info::AccountInfo<'a>,
     /// Stake
     pub stake: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Oracle
+    pub oracle: &'b solana_program::account_info::AccountInfo<'a>,
     /// Bond mint
     pub bond_mint: &'b solana_program::account_info::AccountInfo<'a>,
     /// Bond destination token account
@@ -286,6 +319,8 @@ pub struct ClaimVoteV1Cpi<'a, 'b> {
     pub bond_escrow: &'b solana_program::account_info::AccountInfo<'a>,
     /// Voter
     pub voter: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Vote rent payer
+    pub vote_rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
     /// SPL token program
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
     /// System program
@@ -304,10 +339,12 @@ impl<'a, 'b> ClaimVoteV1Cpi<'a, 'b> {
             voting: accounts.voting,
             vote: accounts.vote,
             stake: accounts.stake,
+            oracle: accounts.oracle,
             bond_mint: accounts.bond_mint,
             bond_destination: accounts.bond_destination,
             bond_escrow: accounts.bond_escrow,
             voter: accounts.voter,
+            vote_rent_payer: accounts.vote_rent_payer,
             token_program: accounts.token_program,
             system_program: accounts.system_program,
         }
@@ -337,18 +374,17 @@ impl<'a, 'b> ClaimVoteV1Cpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
         accounts
             .push(solana_program::instruction::AccountMeta::new_readonly(*self.request.key, false));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.assertion.key,
             false,
         ));
-        accounts
-            .push(solana_program::instruction::AccountMeta::new_readonly(*self.voting.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.voting.key, false));
         accounts.push(solana_program::instruction::AccountMeta::new(*self.vote.key, false));
-        accounts
-            .push(solana_program::instruction::AccountMeta::new_readonly(*self.stake.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.stake.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.oracle.key, false));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.bond_mint.key,
             false,
@@ -356,7 +392,10 @@ impl<'a, 'b> ClaimVoteV1Cpi<'a, 'b> {
         accounts
             .push(solana_program::instruction::AccountMeta::new(*self.bond_destination.key, false));
         accounts.push(solana_program::instruction::AccountMeta::new(*self.bond_escrow.key, false));
-        accounts.push(solana_program::instruction::AccountMeta::new(*self.voter.key, true));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new_readonly(*self.voter.key, true));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new(*self.vote_rent_payer.key, false));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.token_program.key,
             false,
@@ -379,17 +418,19 @@ impl<'a, 'b> ClaimVoteV1Cpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(11 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(13 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.request.clone());
         account_infos.push(self.assertion.clone());
         account_infos.push(self.voting.clone());
         account_infos.push(self.vote.clone());
         account_infos.push(self.stake.clone());
+        account_infos.push(self.oracle.clone());
         account_infos.push(self.bond_mint.clone());
         account_infos.push(self.bond_destination.clone());
         account_infos.push(self.bond_escrow.clone());
         account_infos.push(self.voter.clone());
+        account_infos.push(self.vote_rent_payer.clone());
         account_infos.push(self.token_program.clone());
         account_infos.push(self.system_program.clone());
         remaining_accounts
@@ -410,15 +451,17 @@ impl<'a, 'b> ClaimVoteV1Cpi<'a, 'b> {
 ///
 ///   0. `[]` request
 ///   1. `[]` assertion
-///   2. `[]` voting
+///   2. `[writable]` voting
 ///   3. `[writable]` vote
-///   4. `[]` stake
-///   5. `[]` bond_mint
-///   6. `[writable]` bond_destination
-///   7. `[writable]` bond_escrow
-///   8. `[writable, signer]` voter
-///   9. `[]` token_program
-///   10. `[]` system_program
+///   4. `[writable]` stake
+///   5. `[writable]` oracle
+///   6. `[]` bond_mint
+///   7. `[writable]` bond_destination
+///   8. `[writable]` bond_escrow
+///   9. `[signer]` voter
+///   10. `[writable]` vote_rent_payer
+///   11. `[]` token_program
+///   12. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct ClaimVoteV1CpiBuilder<'a, 'b> {
     instruction: Box<ClaimVoteV1CpiBuilderInstruction<'a, 'b>>,
@@ -433,10 +476,12 @@ impl<'a, 'b> ClaimVoteV1CpiBuilder<'a, 'b> {
             voting: None,
             vote: None,
             stake: None,
+            oracle: None,
             bond_mint: None,
             bond_destination: None,
             bond_escrow: None,
             voter: None,
+            vote_rent_payer: None,
             token_program: None,
             system_program: None,
             __remaining_accounts: Vec::new(),
@@ -482,6 +527,15 @@ impl<'a, 'b> ClaimVoteV1CpiBuilder<'a, 'b> {
         self.instruction.stake = Some(stake);
         self
     }
+    /// Oracle
+    #[inline(always)]
+    pub fn oracle(
+        &mut self,
+        oracle: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.oracle = Some(oracle);
+        self
+    }
     /// Bond mint
     #[inline(always)]
     pub fn bond_mint(
@@ -515,6 +569,15 @@ impl<'a, 'b> ClaimVoteV1CpiBuilder<'a, 'b> {
         self.instruction.voter = Some(voter);
         self
     }
+    /// Vote rent payer
+    #[inline(always)]
+    pub fn vote_rent_payer(
+        &mut self,
+        vote_rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.vote_rent_payer = Some(vote_rent_payer);
+        self
+    }
     /// SPL token program
     #[inline(always)]
     pub fn token_program(
@@ -579,6 +642,8 @@ impl<'a, 'b> ClaimVoteV1CpiBuilder<'a, 'b> {
 
             stake: self.instruction.stake.expect("stake is not set"),
 
+            oracle: self.instruction.oracle.expect("oracle is not set"),
+
             bond_mint: self.instruction.bond_mint.expect("bond_mint is not set"),
 
             bond_destination: self
@@ -590,6 +655,8 @@ impl<'a, 'b> ClaimVoteV1CpiBuilder<'a, 'b> {
 
             voter: self.instruction.voter.expect("voter is not set"),
 
+            vote_rent_payer: self.instruction.vote_rent_payer.expect("vote_rent_payer is not set"),
+
             token_program: self.instruction.token_program.expect("token_program is not set"),
 
             system_program: self.instruction.system_program.expect("system_program is not set"),
@@ -609,10 +676,12 @@ struct ClaimVoteV1CpiBuilderInstruction<'a, 'b> {
     voting: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     vote: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     stake: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    oracle: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     bond_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     bond_destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     bond_escrow: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     voter: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vote_rent_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -0,0 +1,1020 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+//!
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct CloseRequestV1 {
+    /// Config
+    pub config: solana_program::pubkey::Pubkey,
+    /// Request
+    pub request: solana_program::pubkey::Pubkey,
+    /// Assertion
+    pub assertion: solana_program::pubkey::Pubkey,
+    /// Voting
+    pub voting: solana_program::pubkey::Pubkey,
+    /// Bond mint
+    pub bond_mint: solana_program::pubkey::Pubkey,
+    /// Reward mint
+    pub reward_mint: solana_program::pubkey::Pubkey,
+    /// Reward escrow token account
+    pub reward_escrow: solana_program::pubkey::Pubkey,
+    /// Creator reward token account
+    pub reward_destination: solana_program::pubkey::Pubkey,
+    /// Asserter bond escrow token account
+    pub assert_bond_escrow: solana_program::pubkey::Pubkey,
+    /// Disputer bond escrow token account
+    pub dispute_bond_escrow: solana_program::pubkey::Pubkey,
+    /// Fee treasury token account
+    pub treasury: solana_program::pubkey::Pubkey,
+    /// Request rent payer
+    pub request_rent_payer: solana_program::pubkey::Pubkey,
+    /// Assertion rent payer
+    pub assertion_rent_payer: solana_program::pubkey::Pubkey,
+    /// Voting rent payer
+    pub voting_rent_payer: solana_program::pubkey::Pubkey,
+    /// Oracle
+    pub oracle: solana_program::pubkey::Pubkey,
+    /// Governance token mint
+    pub governance_mint: solana_program::pubkey::Pubkey,
+    /// Stake pool token account
+    pub stake_pool: solana_program::pubkey::Pubkey,
+    /// Fee treasury token account for the governance mint
+    pub stake_treasury: solana_program::pubkey::Pubkey,
+    /// Payer
+    pub payer: solana_program::pubkey::Pubkey,
+    /// SPL token program
+    pub token_program: solana_program::pubkey::Pubkey,
+    /// System program
+    pub system_program: solana_program::pubkey::Pubkey,
+}
+
+impl CloseRequestV1 {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(21 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(self.config, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.request, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.assertion, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.voting, false));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new_readonly(self.bond_mint, false));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new_readonly(self.reward_mint, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.reward_escrow, false));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new(self.reward_destination, false));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new(self.assert_bond_escrow, false));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new(self.dispute_bond_escrow, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.treasury, false));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new(self.request_rent_payer, false));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new(self.assertion_rent_payer, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.voting_rent_payer, false));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(self.oracle, false));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.governance_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.stake_pool, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.stake_treasury, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.payer, true));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = CloseRequestV1InstructionData::new().try_to_vec().unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::OPTIMISTIC_ORACLE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct CloseRequestV1InstructionData {
+    discriminator: u8,
+}
+
+impl CloseRequestV1InstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 23 }
+    }
+}
+
+impl Default for CloseRequestV1InstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Instruction builder for `CloseRequestV1`.
+///
+/// ### Accounts:
+///
+///   0. `[]` config
+///   1. `[writable]` request
+///   2. `[writable]` assertion
+///   3. `[writable]` voting
+///   4. `[]` bond_mint
+///   5. `[]` reward_mint
+///   6. `[writable]` reward_escrow
+///   7. `[writable]` reward_destination
+///   8. `[writable]` assert_bond_escrow
+///   9. `[writable]` dispute_bond_escrow
+///   10. `[writable]` treasury
+///   11. `[writable]` request_rent_payer
+///   12. `[writable]` assertion_rent_payer
+///   13. `[writable]` voting_rent_payer
+///   14. `[]` oracle
+///   15. `[]` governance_mint
+///   16. `[writable]` stake_pool
+///   17. `[writable]` stake_treasury
+///   18. `[writable, signer]` payer
+///   19. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   20. `[optional]` system_program (default to `11111111111111111111111111111111`)
+#[derive(Clone, Debug, Default)]
+pub struct CloseRequestV1Builder {
+    config: Option<solana_program::pubkey::Pubkey>,
+    request: Option<solana_program::pubkey::Pubkey>,
+    assertion: Option<solana_program::pubkey::Pubkey>,
+    voting: Option<solana_program::pubkey::Pubkey>,
+    bond_mint: Option<solana_program::pubkey::Pubkey>,
+    reward_mint: Option<solana_program::pubkey::Pubkey>,
+    reward_escrow: Option<solana_program::pubkey::Pubkey>,
+    reward_destination: Option<solana_program::pubkey::Pubkey>,
+    assert_bond_escrow: Option<solana_program::pubkey::Pubkey>,
+    dispute_bond_escrow: Option<solana_program::pubkey::Pubkey>,
+    treasury: Option<solana_program::pubkey::Pubkey>,
+    request_rent_payer: Option<solana_program::pubkey::Pubkey>,
+    assertion_rent_payer: Option<solana_program::pubkey::Pubkey>,
+    voting_rent_payer: Option<solana_program::pubkey::Pubkey>,
+    oracle: Option<solana_program::pubkey::Pubkey>,
+    governance_mint: Option<solana_program::pubkey::Pubkey>,
+    stake_pool: Option<solana_program::pubkey::Pubkey>,
+    stake_treasury: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl CloseRequestV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Config
+    #[inline(always)]
+    pub fn config(&mut self, config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.config = Some(config);
+        self
+    }
+    /// Request
+    #[inline(always)]
+    pub fn request(&mut self, request: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.request = Some(request);
+        self
+    }
+    /// Assertion
+    #[inline(always)]
+    pub fn assertion(&mut self, assertion: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.assertion = Some(assertion);
+        self
+    }
+    /// Voting
+    #[inline(always)]
+    pub fn voting(&mut self, voting: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.voting = Some(voting);
+        self
+    }
+    /// Bond mint
+    #[inline(always)]
+    pub fn bond_mint(&mut self, bond_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.bond_mint = Some(bond_mint);
+        self
+    }
+    /// Reward mint
+    #[inline(always)]
+    pub fn reward_mint(&mut self, reward_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.reward_mint = Some(reward_mint);
+        self
+    }
+    /// Reward escrow token account
+    #[inline(always)]
+    pub fn reward_escrow(&mut self, reward_escrow: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.reward_escrow = Some(reward_escrow);
+        self
+    }
+    /// Creator reward token account
+    #[inline(always)]
+    pub fn reward_destination(
+        &mut self,
+        reward_destination: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.reward_destination = Some(reward_destination);
+        self
+    }
+    /// Asserter bond escrow token account
+    #[inline(always)]
+    pub fn assert_bond_escrow(
+        &mut self,
+        assert_bond_escrow: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.assert_bond_escrow = Some(assert_bond_escrow);
+        self
+    }
+    /// Disputer bond escrow token account
+    #[inline(always)]
+    pub fn dispute_bond_escrow(
+        &mut self,
+        dispute_bond_escrow: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.dispute_bond_escrow = Some(dispute_bond_escrow);
+        self
+    }
+    /// Fee treasury token account
+    #[inline(always)]
+    pub fn treasury(&mut self, treasury: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.treasury = Some(treasury);
+        self
+    }
+    /// Request rent payer
+    #[inline(always)]
+    pub fn request_rent_payer(
+        &mut self,
+        request_rent_payer: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.request_rent_payer = Some(request_rent_payer);
+        self
+    }
+    /// Assertion rent payer
+    #[inline(always)]
+    pub fn assertion_rent_payer(
+        &mut self,
+        assertion_rent_payer: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.assertion_rent_payer = Some(assertion_rent_payer);
+        self
+    }
+    /// Voting rent payer
+    #[inline(always)]
+    pub fn voting_rent_payer(
+        &mut self,
+        voting_rent_payer: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.voting_rent_payer = Some(voting_rent_payer);
+        self
+    }
+    /// Oracle
+    #[inline(always)]
+    pub fn oracle(&mut self, oracle: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.oracle = Some(oracle);
+        self
+    }
+    /// Governance token mint
+    #[inline(always)]
+    pub fn governance_mint(
+        &mut self,
+        governance_mint: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.governance_mint = Some(governance_mint);
+        self
+    }
+    /// Stake pool token account
+    #[inline(always)]
+    pub fn stake_pool(&mut self, stake_pool: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.stake_pool = Some(stake_pool);
+        self
+    }
+    /// Fee treasury token account for the governance mint
+    #[inline(always)]
+    pub fn stake_treasury(&mut self, stake_treasury: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.stake_treasury = Some(stake_treasury);
+        self
+    }
+    /// Payer
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    /// SPL token program
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = CloseRequestV1 {
+            config: self.config.expect("config is not set"),
+            request: self.request.expect("request is not set"),
+            assertion: self.assertion.expect("assertion is not set"),
+            voting: self.voting.expect("voting is not set"),
+            bond_mint: self.bond_mint.expect("bond_mint is not set"),
+            reward_mint: self.reward_mint.expect("reward_mint is not set"),
+            reward_escrow: self.reward_escrow.expect("reward_escrow is not set"),
+            reward_destination: self.reward_destination.expect("reward_destination is not set"),
+            assert_bond_escrow: self.assert_bond_escrow.expect("assert_bond_escrow is not set"),
+            dispute_bond_escrow: self.dispute_bond_escrow.expect("dispute_bond_escrow is not set"),
+            treasury: self.treasury.expect("treasury is not set"),
+            request_rent_payer: self.request_rent_payer.expect("request_rent_payer is not set"),
+            assertion_rent_payer: self
+                .assertion_rent_payer
+                .expect("assertion_rent_payer is not set"),
+            voting_rent_payer: self.voting_rent_payer.expect("voting_rent_payer is not set"),
+            oracle: self.oracle.expect("oracle is not set"),
+            governance_mint: self.governance_mint.expect("governance_mint is not set"),
+            stake_pool: self.stake_pool.expect("stake_pool is not set"),
+            stake_treasury: self.stake_treasury.expect("stake_treasury is not set"),
+            payer: self.payer.expect("payer is not set"),
+            token_program: self
+                .token_program
+                .unwrap_or(solana_program::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `close_request_v1` CPI accounts.
+pub struct CloseRequestV1CpiAccounts<'a, 'b> {
+    /// Config
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Request
+    pub request: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Assertion
+    pub assertion: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Voting
+    pub voting: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Bond mint
+    pub bond_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Reward mint
+    pub reward_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Reward escrow token account
+    pub reward_escrow: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Creator reward token account
+    pub reward_destination: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Asserter bond escrow token account
+    pub assert_bond_escrow: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Disputer bond escrow token account
+    pub dispute_bond_escrow: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Fee treasury token account
+    pub treasury: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Request rent payer
+    pub request_rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Assertion rent payer
+    pub assertion_rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Voting rent payer
+    pub voting_rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Oracle
+    pub oracle: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Governance token mint
+    pub governance_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake pool token account
+    pub stake_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Fee treasury token account for the governance mint
+    pub stake_treasury: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Payer
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// SPL token program
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// System program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `close_request_v1` CPI instruction.
+pub struct CloseRequestV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Config
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Request
+    pub request: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Assertion
+    pub assertion: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Voting
+    pub voting: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Bond mint
+    pub bond_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Reward mint
+    pub reward_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Reward escrow token account
+    pub reward_escrow: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Creator reward token account
+    pub reward_destination: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Asserter bond escrow token account
+    pub assert_bond_escrow: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Disputer bond escrow token account
+    pub dispute_bond_escrow: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Fee treasury token account
+    pub treasury: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Request rent payer
+    pub request_rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Assertion rent payer
+    pub assertion_rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Voting rent payer
+    pub voting_rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Oracle
+    pub oracle: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Governance token mint
+    pub governance_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake pool token account
+    pub stake_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Fee treasury token account for the governance mint
+    pub stake_treasury: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Payer
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// SPL token program
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// System program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> CloseRequestV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: CloseRequestV1CpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            config: accounts.config,
+            request: accounts.request,
+            assertion: accounts.assertion,
+            voting: accounts.voting,
+            bond_mint: accounts.bond_mint,
+            reward_mint: accounts.reward_mint,
+            reward_escrow: accounts.reward_escrow,
+            reward_destination: accounts.reward_destination,
+            assert_bond_escrow: accounts.assert_bond_escrow,
+            dispute_bond_escrow: accounts.dispute_bond_escrow,
+            treasury: accounts.treasury,
+            request_rent_payer: accounts.request_rent_payer,
+            assertion_rent_payer: accounts.assertion_rent_payer,
+            voting_rent_payer: accounts.voting_rent_payer,
+            oracle: accounts.oracle,
+            governance_mint: accounts.governance_mint,
+            stake_pool: accounts.stake_pool,
+            stake_treasury: accounts.stake_treasury,
+            payer: accounts.payer,
+            token_program: accounts.token_program,
+            system_program: accounts.system_program,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(21 + remaining_accounts.len());
+        accounts
+            .push(solana_program::instruction::AccountMeta::new_readonly(*self.config.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.request.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.assertion.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.voting.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.bond_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.reward_mint.key,
+            false,
+        ));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new(*self.reward_escrow.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.reward_destination.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.assert_bond_escrow.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.dispute_bond_escrow.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.treasury.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.request_rent_payer.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.assertion_rent_payer.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.voting_rent_payer.key,
+            false,
+        ));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new_readonly(*self.oracle.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.governance_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.stake_pool.key, false));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new(*self.stake_treasury.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.payer.key, true));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = CloseRequestV1InstructionData::new().try_to_vec().unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::OPTIMISTIC_ORACLE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(21 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.config.clone());
+        account_infos.push(self.request.clone());
+        account_infos.push(self.assertion.clone());
+        account_infos.push(self.voting.clone());
+        account_infos.push(self.bond_mint.clone());
+        account_infos.push(self.reward_mint.clone());
+        account_infos.push(self.reward_escrow.clone());
+        account_infos.push(self.reward_destination.clone());
+        account_infos.push(self.assert_bond_escrow.clone());
+        account_infos.push(self.dispute_bond_escrow.clone());
+        account_infos.push(self.treasury.clone());
+        account_infos.push(self.request_rent_payer.clone());
+        account_infos.push(self.assertion_rent_payer.clone());
+        account_infos.push(self.voting_rent_payer.clone());
+        account_infos.push(self.oracle.clone());
+        account_infos.push(self.governance_mint.clone());
+        account_infos.push(self.stake_pool.clone());
+        account_infos.push(self.stake_treasury.clone());
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.token_program.clone());
+        account_infos.push(self.system_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `CloseRequestV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[]` config
+///   1. `[writable]` request
+///   2. `[writable]` assertion
+///   3. `[writable]` voting
+///   4. `[]` bond_mint
+///   5. `[]` reward_mint
+///   6. `[writable]` reward_escrow
+///   7. `[writable]` reward_destination
+///   8. `[writable]` assert_bond_escrow
+///   9. `[writable]` dispute_bond_escrow
+///   10. `[writable]` treasury
+///   11. `[writable]` request_rent_payer
+///   12. `[writable]` assertion_rent_payer
+///   13. `[writable]` voting_rent_payer
+///   14. `[]` oracle
+///   15. `[]` governance_mint
+///   16. `[writable]` stake_pool
+///   17. `[writable]` stake_treasury
+///   18. `[writable, signer]` payer
+///   19. `[]` token_program
+///   20. `[]` system_program
+#[derive(Clone, Debug)]
+pub struct CloseRequestV1CpiBuilder<'a, 'b> {
+    instruction: Box<CloseRequestV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> CloseRequestV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(CloseRequestV1CpiBuilderInstruction {
+            __program: program,
+            config: None,
+            request: None,
+            assertion: None,
+            voting: None,
+            bond_mint: None,
+            reward_mint: None,
+            reward_escrow: None,
+            reward_destination: None,
+            assert_bond_escrow: None,
+            dispute_bond_escrow: None,
+            treasury: None,
+            request_rent_payer: None,
+            assertion_rent_payer: None,
+            voting_rent_payer: None,
+            oracle: None,
+            governance_mint: None,
+            stake_pool: None,
+            stake_treasury: None,
+            payer: None,
+            token_program: None,
+            system_program: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Config
+    #[inline(always)]
+    pub fn config(
+        &mut self,
+        config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.config = Some(config);
+        self
+    }
+    /// Request
+    #[inline(always)]
+    pub fn request(
+        &mut self,
+        request: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.request = Some(request);
+        self
+    }
+    /// Assertion
+    #[inline(always)]
+    pub fn assertion(
+        &mut self,
+        assertion: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.assertion = Some(assertion);
+        self
+    }
+    /// Voting
+    #[inline(always)]
+    pub fn voting(
+        &mut self,
+        voting: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.voting = Some(voting);
+        self
+    }
+    /// Bond mint
+    #[inline(always)]
+    pub fn bond_mint(
+        &mut self,
+        bond_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.bond_mint = Some(bond_mint);
+        self
+    }
+    /// Reward mint
+    #[inline(always)]
+    pub fn reward_mint(
+        &mut self,
+        reward_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.reward_mint = Some(reward_mint);
+        self
+    }
+    /// Reward escrow token account
+    #[inline(always)]
+    pub fn reward_escrow(
+        &mut self,
+        reward_escrow: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.reward_escrow = Some(reward_escrow);
+        self
+    }
+    /// Creator reward token account
+    #[inline(always)]
+    pub fn reward_destination(
+        &mut self,
+        reward_destination: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.reward_destination = Some(reward_destination);
+        self
+    }
+    /// Asserter bond escrow token account
+    #[inline(always)]
+    pub fn assert_bond_escrow(
+        &mut self,
+        assert_bond_escrow: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.assert_bond_escrow = Some(assert_bond_escrow);
+        self
+    }
+    /// Disputer bond escrow token account
+    #[inline(always)]
+    pub fn dispute_bond_escrow(
+        &mut self,
+        dispute_bond_escrow: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.dispute_bond_escrow = Some(dispute_bond_escrow);
+        self
+    }
+    /// Fee treasury token account
+    #[inline(always)]
+    pub fn treasury(
+        &mut self,
+        treasury: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.treasury = Some(treasury);
+        self
+    }
+    /// Request rent payer
+    #[inline(always)]
+    pub fn request_rent_payer(
+        &mut self,
+        request_rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.request_rent_payer = Some(request_rent_payer);
+        self
+    }
+    /// Assertion rent payer
+    #[inline(always)]
+    pub fn assertion_rent_payer(
+        &mut self,
+        assertion_rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.assertion_rent_payer = Some(assertion_rent_payer);
+        self
+    }
+    /// Voting rent payer
+    #[inline(always)]
+    pub fn voting_rent_payer(
+        &mut self,
+        voting_rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.voting_rent_payer = Some(voting_rent_payer);
+        self
+    }
+    /// Oracle
+    #[inline(always)]
+    pub fn oracle(
+        &mut self,
+        oracle: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.oracle = Some(oracle);
+        self
+    }
+    /// Governance token mint
+    #[inline(always)]
+    pub fn governance_mint(
+        &mut self,
+        governance_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.governance_mint = Some(governance_mint);
+        self
+    }
+    /// Stake pool token account
+    #[inline(always)]
+    pub fn stake_pool(
+        &mut self,
+        stake_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.stake_pool = Some(stake_pool);
+        self
+    }
+    /// Fee treasury token account for the governance mint
+    #[inline(always)]
+    pub fn stake_treasury(
+        &mut self,
+        stake_treasury: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.stake_treasury = Some(stake_treasury);
+        self
+    }
+    /// Payer
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// SPL token program
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    /// System program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction.__remaining_accounts.push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)],
+    ) -> &mut Self {
+        self.instruction.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = CloseRequestV1Cpi {
+            __program: self.instruction.__program,
+
+            config: self.instruction.config.expect("config is not set"),
+
+            request: self.instruction.request.expect("request is not set"),
+
+            assertion: self.instruction.assertion.expect("assertion is not set"),
+
+            voting: self.instruction.voting.expect("voting is not set"),
+
+            bond_mint: self.instruction.bond_mint.expect("bond_mint is not set"),
+
+            reward_mint: self.instruction.reward_mint.expect("reward_mint is not set"),
+
+            reward_escrow: self.instruction.reward_escrow.expect("reward_escrow is not set"),
+
+            reward_destination: self
+                .instruction
+                .reward_destination
+                .expect("reward_destination is not set"),
+
+            assert_bond_escrow: self
+                .instruction
+                .assert_bond_escrow
+                .expect("assert_bond_escrow is not set"),
+
+            dispute_bond_escrow: self
+                .instruction
+                .dispute_bond_escrow
+                .expect("dispute_bond_escrow is not set"),
+
+            treasury: self.instruction.treasury.expect("treasury is not set"),
+
+            request_rent_payer: self
+                .instruction
+                .request_rent_payer
+                .expect("request_rent_payer is not set"),
+
+            assertion_rent_payer: self
+                .instruction
+                .assertion_rent_payer
+                .expect("assertion_rent_payer is not set"),
+
+            voting_rent_payer: self
+                .instruction
+                .voting_rent_payer
+                .expect("voting_rent_payer is not set"),
+
+            oracle: self.instruction.oracle.expect("oracle is not set"),
+
+            governance_mint: self.instruction.governance_mint.expect("governance_mint is not set"),
+
+            stake_pool: self.instruction.stake_pool.expect("stake_pool is not set"),
+
+            stake_treasury: self.instruction.stake_treasury.expect("stake_treasury is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            token_program: self.instruction.token_program.expect("token_program is not set"),
+
+            system_program: self.instruction.system_program.expect("system_program is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct CloseRequestV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    request: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    assertion: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    voting: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    bond_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    reward_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    reward_escrow: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    reward_destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    assert_bond_escrow: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    dispute_bond_escrow: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    treasury: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    request_rent_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    assertion_rent_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    voting_rent_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    oracle: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    governance_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    stake_pool: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    stake_treasury: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)>,
+}
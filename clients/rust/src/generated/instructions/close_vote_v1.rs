@@ -0,0 +1,394 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+//!
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct CloseVoteV1 {
+    /// Request
+    pub request: solana_program::pubkey::Pubkey,
+    /// Voting
+    pub voting: solana_program::pubkey::Pubkey,
+    /// Vote
+    pub vote: solana_program::pubkey::Pubkey,
+    /// Stake
+    pub stake: solana_program::pubkey::Pubkey,
+    /// Vote rent payer
+    pub vote_rent_payer: solana_program::pubkey::Pubkey,
+}
+
+impl CloseVoteV1 {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(self.request, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.voting, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.vote, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.stake, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.vote_rent_payer, false));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = CloseVoteV1InstructionData::new().try_to_vec().unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::OPTIMISTIC_ORACLE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct CloseVoteV1InstructionData {
+    discriminator: u8,
+}
+
+impl CloseVoteV1InstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 31 }
+    }
+}
+
+impl Default for CloseVoteV1InstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Instruction builder for `CloseVoteV1`.
+///
+/// ### Accounts:
+///
+///   0. `[]` request
+///   1. `[writable]` voting
+///   2. `[writable]` vote
+///   3. `[writable]` stake
+///   4. `[writable]` vote_rent_payer
+#[derive(Clone, Debug, Default)]
+pub struct CloseVoteV1Builder {
+    request: Option<solana_program::pubkey::Pubkey>,
+    voting: Option<solana_program::pubkey::Pubkey>,
+    vote: Option<solana_program::pubkey::Pubkey>,
+    stake: Option<solana_program::pubkey::Pubkey>,
+    vote_rent_payer: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl CloseVoteV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Request
+    #[inline(always)]
+    pub fn request(&mut self, request: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.request = Some(request);
+        self
+    }
+    /// Voting
+    #[inline(always)]
+    pub fn voting(&mut self, voting: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.voting = Some(voting);
+        self
+    }
+    /// Vote
+    #[inline(always)]
+    pub fn vote(&mut self, vote: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.vote = Some(vote);
+        self
+    }
+    /// Stake
+    #[inline(always)]
+    pub fn stake(&mut self, stake: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.stake = Some(stake);
+        self
+    }
+    /// Vote rent payer
+    #[inline(always)]
+    pub fn vote_rent_payer(
+        &mut self,
+        vote_rent_payer: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.vote_rent_payer = Some(vote_rent_payer);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = CloseVoteV1 {
+            request: self.request.expect("request is not set"),
+            voting: self.voting.expect("voting is not set"),
+            vote: self.vote.expect("vote is not set"),
+            stake: self.stake.expect("stake is not set"),
+            vote_rent_payer: self.vote_rent_payer.expect("vote_rent_payer is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `close_vote_v1` CPI accounts.
+pub struct CloseVoteV1CpiAccounts<'a, 'b> {
+    /// Request
+    pub request: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Voting
+    pub voting: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Vote
+    pub vote: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake
+    pub stake: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Vote rent payer
+    pub vote_rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `close_vote_v1` CPI instruction.
+pub struct CloseVoteV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Request
+    pub request: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Voting
+    pub voting: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Vote
+    pub vote: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake
+    pub stake: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Vote rent payer
+    pub vote_rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> CloseVoteV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: CloseVoteV1CpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            request: accounts.request,
+            voting: accounts.voting,
+            vote: accounts.vote,
+            stake: accounts.stake,
+            vote_rent_payer: accounts.vote_rent_payer,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts
+            .push(solana_program::instruction::AccountMeta::new_readonly(*self.request.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.voting.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.vote.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.stake.key, false));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new(*self.vote_rent_payer.key, false));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = CloseVoteV1InstructionData::new().try_to_vec().unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::OPTIMISTIC_ORACLE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.request.clone());
+        account_infos.push(self.voting.clone());
+        account_infos.push(self.vote.clone());
+        account_infos.push(self.stake.clone());
+        account_infos.push(self.vote_rent_payer.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `CloseVoteV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[]` request
+///   1. `[writable]` voting
+///   2. `[writable]` vote
+///   3. `[writable]` stake
+///   4. `[writable]` vote_rent_payer
+#[derive(Clone, Debug)]
+pub struct CloseVoteV1CpiBuilder<'a, 'b> {
+    instruction: Box<CloseVoteV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> CloseVoteV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(CloseVoteV1CpiBuilderInstruction {
+            __program: program,
+            request: None,
+            voting: None,
+            vote: None,
+            stake: None,
+            vote_rent_payer: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Request
+    #[inline(always)]
+    pub fn request(
+        &mut self,
+        request: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.request = Some(request);
+        self
+    }
+    /// Voting
+    #[inline(always)]
+    pub fn voting(
+        &mut self,
+        voting: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.voting = Some(voting);
+        self
+    }
+    /// Vote
+    #[inline(always)]
+    pub fn vote(&mut self, vote: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.vote = Some(vote);
+        self
+    }
+    /// Stake
+    #[inline(always)]
+    pub fn stake(&mut self, stake: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.stake = Some(stake);
+        self
+    }
+    /// Vote rent payer
+    #[inline(always)]
+    pub fn vote_rent_payer(
+        &mut self,
+        vote_rent_payer: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.vote_rent_payer = Some(vote_rent_payer);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction.__remaining_accounts.push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)],
+    ) -> &mut Self {
+        self.instruction.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = CloseVoteV1Cpi {
+            __program: self.instruction.__program,
+
+            request: self.instruction.request.expect("request is not set"),
+
+            voting: self.instruction.voting.expect("voting is not set"),
+
+            vote: self.instruction.vote.expect("vote is not set"),
+
+            stake: self.instruction.stake.expect("stake is not set"),
+
+            vote_rent_payer: self.instruction.vote_rent_payer.expect("vote_rent_payer is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct CloseVoteV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    request: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    voting: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vote: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    stake: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vote_rent_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)>,
+}
@@ -15,6 +15,22 @@ pub struct CloseVotingV1 {
     pub request: solana_program::pubkey::Pubkey,
     /// Voting
     pub voting: solana_program::pubkey::Pubkey,
+    /// Oracle
+    pub oracle: solana_program::pubkey::Pubkey,
+    /// Assertion
+    pub assertion: solana_program::pubkey::Pubkey,
+    /// Bond mint
+    pub bond_mint: solana_program::pubkey::Pubkey,
+    /// Bond escrow token account of incorrect asserter/disputer
+    pub bond_escrow: solana_program::pubkey::Pubkey,
+    /// Fee treasury token account
+    pub treasury: solana_program::pubkey::Pubkey,
+    /// Payer
+    pub payer: solana_program::pubkey::Pubkey,
+    /// SPL token program
+    pub token_program: solana_program::pubkey::Pubkey,
+    /// System program
+    pub system_program: solana_program::pubkey::Pubkey,
 }
 
 impl CloseVotingV1 {
@@ -26,10 +42,26 @@ impl CloseVotingV1 {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(self.config, false));
         accounts.push(solana_program::instruction::AccountMeta::new(self.request, false));
         accounts.push(solana_program::instruction::AccountMeta::new(self.voting, false));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(self.oracle, false));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new_readonly(self.assertion, false));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new_readonly(self.bond_mint, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.bond_escrow, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.treasury, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.payer, true));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let data = CloseVotingV1InstructionData::new().try_to_vec().unwrap();
 
@@ -65,11 +97,27 @@ impl Default for CloseVotingV1InstructionData {
 ///   0. `[]` config
 ///   1. `[writable]` request
 ///   2. `[writable]` voting
+///   3. `[]` oracle
+///   4. `[]` assertion
+///   5. `[]` bond_mint
+///   6. `[writable]` bond_escrow
+///   7. `[writable]` treasury
+///   8. `[writable, signer]` payer
+///   9. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   10. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct CloseVotingV1Builder {
     config: Option<solana_program::pubkey::Pubkey>,
     request: Option<solana_program::pubkey::Pubkey>,
     voting: Option<solana_program::pubkey::Pubkey>,
+    oracle: Option<solana_program::pubkey::Pubkey>,
+    assertion: Option<solana_program::pubkey::Pubkey>,
+    bond_mint: Option<solana_program::pubkey::Pubkey>,
+    bond_escrow: Option<solana_program::pubkey::Pubkey>,
+    treasury: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -95,6 +143,56 @@ impl CloseVotingV1Builder {
         self.voting = Some(voting);
         self
     }
+    /// Oracle
+    #[inline(always)]
+    pub fn oracle(&mut self, oracle: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.oracle = Some(oracle);
+        self
+    }
+    /// Assertion
+    #[inline(always)]
+    pub fn assertion(&mut self, assertion: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.assertion = Some(assertion);
+        self
+    }
+    /// Bond mint
+    #[inline(always)]
+    pub fn bond_mint(&mut self, bond_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.bond_mint = Some(bond_mint);
+        self
+    }
+    /// Bond escrow token account of incorrect asserter/disputer
+    #[inline(always)]
+    pub fn bond_escrow(&mut self, bond_escrow: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.bond_escrow = Some(bond_escrow);
+        self
+    }
+    /// Fee treasury token account
+    #[inline(always)]
+    pub fn treasury(&mut self, treasury: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.treasury = Some(treasury);
+        self
+    }
+    /// Payer
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    /// SPL token program
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -119,6 +217,18 @@ impl CloseVotingV1Builder {
             config: self.config.expect("config is not set"),
             request: self.request.expect("request is not set"),
             voting: self.voting.expect("voting is not set"),
+            oracle: self.oracle.expect("oracle is not set"),
+            assertion: self.assertion.expect("assertion is not set"),
+            bond_mint: self.bond_mint.expect("bond_mint is not set"),
+            bond_escrow: self.bond_escrow.expect("bond_escrow is not set"),
+            treasury: self.treasury.expect("treasury is not set"),
+            payer: self.payer.expect("payer is not set"),
+            token_program: self
+                .token_program
+                .unwrap_or(solana_program::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -133,6 +243,22 @@ pub struct CloseVotingV1CpiAccounts<'a, 'b> {
     pub request: &'b solana_program::account_info::AccountInfo<'a>,
     /// Voting
     pub voting: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Oracle
+    pub oracle: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Assertion
+    pub assertion: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Bond mint
+    pub bond_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Bond escrow token account of incorrect asserter/disputer
+    pub bond_escrow: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Fee treasury token account
+    pub treasury: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Payer
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// SPL token program
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// System program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `close_voting_v1` CPI instruction.
@@ -145,6 +271,22 @@ pub struct CloseVotingV1Cpi<'a, 'b> {
     pub request: &'b solana_program::account_info::AccountInfo<'a>,
     /// Voting
     pub voting: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Oracle
+    pub oracle: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Assertion
+    pub assertion: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Bond mint
+    pub bond_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Bond escrow token account of incorrect asserter/disputer
+    pub bond_escrow: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Fee treasury token account
+    pub treasury: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Payer
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// SPL token program
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// System program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 impl<'a, 'b> CloseVotingV1Cpi<'a, 'b> {
@@ -157,6 +299,14 @@ impl<'a, 'b> CloseVotingV1Cpi<'a, 'b> {
             config: accounts.config,
             request: accounts.request,
             voting: accounts.voting,
+            oracle: accounts.oracle,
+            assertion: accounts.assertion,
+            bond_mint: accounts.bond_mint,
+            bond_escrow: accounts.bond_escrow,
+            treasury: accounts.treasury,
+            payer: accounts.payer,
+            token_program: accounts.token_program,
+            system_program: accounts.system_program,
         }
     }
     #[inline(always)]
@@ -184,11 +334,32 @@ impl<'a, 'b> CloseVotingV1Cpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
         accounts
             .push(solana_program::instruction::AccountMeta::new_readonly(*self.config.key, false));
         accounts.push(solana_program::instruction::AccountMeta::new(*self.request.key, false));
         accounts.push(solana_program::instruction::AccountMeta::new(*self.voting.key, false));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new_readonly(*self.oracle.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.assertion.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.bond_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.bond_escrow.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.treasury.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.payer.key, true));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -203,11 +374,19 @@ impl<'a, 'b> CloseVotingV1Cpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(3 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(11 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.config.clone());
         account_infos.push(self.request.clone());
         account_infos.push(self.voting.clone());
+        account_infos.push(self.oracle.clone());
+        account_infos.push(self.assertion.clone());
+        account_infos.push(self.bond_mint.clone());
+        account_infos.push(self.bond_escrow.clone());
+        account_infos.push(self.treasury.clone());
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.token_program.clone());
+        account_infos.push(self.system_program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -227,6 +406,14 @@ impl<'a, 'b> CloseVotingV1Cpi<'a, 'b> {
 ///   0. `[]` config
 ///   1. `[writable]` request
 ///   2. `[writable]` voting
+///   3. `[]` oracle
+///   4. `[]` assertion
+///   5. `[]` bond_mint
+///   6. `[writable]` bond_escrow
+///   7. `[writable]` treasury
+///   8. `[writable, signer]` payer
+///   9. `[]` token_program
+///   10. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct CloseVotingV1CpiBuilder<'a, 'b> {
     instruction: Box<CloseVotingV1CpiBuilderInstruction<'a, 'b>>,
@@ -239,6 +426,14 @@ impl<'a, 'b> CloseVotingV1CpiBuilder<'a, 'b> {
             config: None,
             request: None,
             voting: None,
+            oracle: None,
+            assertion: None,
+            bond_mint: None,
+            bond_escrow: None,
+            treasury: None,
+            payer: None,
+            token_program: None,
+            system_program: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -270,6 +465,75 @@ impl<'a, 'b> CloseVotingV1CpiBuilder<'a, 'b> {
         self.instruction.voting = Some(voting);
         self
     }
+    /// Oracle
+    #[inline(always)]
+    pub fn oracle(
+        &mut self,
+        oracle: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.oracle = Some(oracle);
+        self
+    }
+    /// Assertion
+    #[inline(always)]
+    pub fn assertion(
+        &mut self,
+        assertion: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.assertion = Some(assertion);
+        self
+    }
+    /// Bond mint
+    #[inline(always)]
+    pub fn bond_mint(
+        &mut self,
+        bond_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.bond_mint = Some(bond_mint);
+        self
+    }
+    /// Bond escrow token account of incorrect asserter/disputer
+    #[inline(always)]
+    pub fn bond_escrow(
+        &mut self,
+        bond_escrow: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.bond_escrow = Some(bond_escrow);
+        self
+    }
+    /// Fee treasury token account
+    #[inline(always)]
+    pub fn treasury(
+        &mut self,
+        treasury: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.treasury = Some(treasury);
+        self
+    }
+    /// Payer
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// SPL token program
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    /// System program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -311,6 +575,22 @@ impl<'a, 'b> CloseVotingV1CpiBuilder<'a, 'b> {
             request: self.instruction.request.expect("request is not set"),
 
             voting: self.instruction.voting.expect("voting is not set"),
+
+            oracle: self.instruction.oracle.expect("oracle is not set"),
+
+            assertion: self.instruction.assertion.expect("assertion is not set"),
+
+            bond_mint: self.instruction.bond_mint.expect("bond_mint is not set"),
+
+            bond_escrow: self.instruction.bond_escrow.expect("bond_escrow is not set"),
+
+            treasury: self.instruction.treasury.expect("treasury is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            token_program: self.instruction.token_program.expect("token_program is not set"),
+
+            system_program: self.instruction.system_program.expect("system_program is not set"),
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -325,6 +605,14 @@ struct CloseVotingV1CpiBuilderInstruction<'a, 'b> {
     config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     request: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     voting: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    oracle: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    assertion: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    bond_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    bond_escrow: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    treasury: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)>,
 }
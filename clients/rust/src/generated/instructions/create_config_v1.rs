@@ -5,6 +5,7 @@
 //! <https://github.com/kinobi-so/kinobi>
 //!
 
+use crate::generated::types::{Bounds, TiePolicy, TransferFeePolicy};
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 
@@ -75,7 +76,18 @@ pub struct CreateConfigV1InstructionArgs {
     pub bond_fee_bps: u16,
     pub dispute_window: u32,
     pub voting_window: u32,
+    pub reveal_window: u32,
     pub arbitration_window: u32,
+    pub claim_window: u32,
+    pub dispute_window_range: Bounds,
+    pub voting_window_range: Bounds,
+    pub arbitration_window_range: Bounds,
+    pub quorum_amount: u64,
+    pub quorum_bps: u16,
+    pub slash_bps: u16,
+    pub slash_window: u32,
+    pub tie_policy: TiePolicy,
+    pub transfer_fee_policy: TransferFeePolicy,
 }
 
 /// Instruction builder for `CreateConfigV1`.
@@ -94,7 +106,18 @@ pub struct CreateConfigV1Builder {
     bond_fee_bps: Option<u16>,
     dispute_window: Option<u32>,
     voting_window: Option<u32>,
+    reveal_window: Option<u32>,
     arbitration_window: Option<u32>,
+    claim_window: Option<u32>,
+    dispute_window_range: Option<Bounds>,
+    voting_window_range: Option<Bounds>,
+    arbitration_window_range: Option<Bounds>,
+    quorum_amount: Option<u64>,
+    quorum_bps: Option<u16>,
+    slash_bps: Option<u16>,
+    slash_window: Option<u32>,
+    tie_policy: Option<TiePolicy>,
+    transfer_fee_policy: Option<TransferFeePolicy>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -142,10 +165,65 @@ impl CreateConfigV1Builder {
         self
     }
     #[inline(always)]
+    pub fn reveal_window(&mut self, reveal_window: u32) -> &mut Self {
+        self.reveal_window = Some(reveal_window);
+        self
+    }
+    #[inline(always)]
     pub fn arbitration_window(&mut self, arbitration_window: u32) -> &mut Self {
         self.arbitration_window = Some(arbitration_window);
         self
     }
+    #[inline(always)]
+    pub fn claim_window(&mut self, claim_window: u32) -> &mut Self {
+        self.claim_window = Some(claim_window);
+        self
+    }
+    #[inline(always)]
+    pub fn dispute_window_range(&mut self, dispute_window_range: Bounds) -> &mut Self {
+        self.dispute_window_range = Some(dispute_window_range);
+        self
+    }
+    #[inline(always)]
+    pub fn voting_window_range(&mut self, voting_window_range: Bounds) -> &mut Self {
+        self.voting_window_range = Some(voting_window_range);
+        self
+    }
+    #[inline(always)]
+    pub fn arbitration_window_range(&mut self, arbitration_window_range: Bounds) -> &mut Self {
+        self.arbitration_window_range = Some(arbitration_window_range);
+        self
+    }
+    #[inline(always)]
+    pub fn quorum_amount(&mut self, quorum_amount: u64) -> &mut Self {
+        self.quorum_amount = Some(quorum_amount);
+        self
+    }
+    #[inline(always)]
+    pub fn quorum_bps(&mut self, quorum_bps: u16) -> &mut Self {
+        self.quorum_bps = Some(quorum_bps);
+        self
+    }
+    #[inline(always)]
+    pub fn slash_bps(&mut self, slash_bps: u16) -> &mut Self {
+        self.slash_bps = Some(slash_bps);
+        self
+    }
+    #[inline(always)]
+    pub fn slash_window(&mut self, slash_window: u32) -> &mut Self {
+        self.slash_window = Some(slash_window);
+        self
+    }
+    #[inline(always)]
+    pub fn tie_policy(&mut self, tie_policy: TiePolicy) -> &mut Self {
+        self.tie_policy = Some(tie_policy);
+        self
+    }
+    #[inline(always)]
+    pub fn transfer_fee_policy(&mut self, transfer_fee_policy: TransferFeePolicy) -> &mut Self {
+        self.transfer_fee_policy = Some(transfer_fee_policy);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -178,10 +256,33 @@ impl CreateConfigV1Builder {
             bond_fee_bps: self.bond_fee_bps.clone().expect("bond_fee_bps is not set"),
             dispute_window: self.dispute_window.clone().expect("dispute_window is not set"),
             voting_window: self.voting_window.clone().expect("voting_window is not set"),
+            reveal_window: self.reveal_window.clone().expect("reveal_window is not set"),
             arbitration_window: self
                 .arbitration_window
                 .clone()
                 .expect("arbitration_window is not set"),
+            claim_window: self.claim_window.clone().expect("claim_window is not set"),
+            dispute_window_range: self
+                .dispute_window_range
+                .clone()
+                .expect("dispute_window_range is not set"),
+            voting_window_range: self
+                .voting_window_range
+                .clone()
+                .expect("voting_window_range is not set"),
+            arbitration_window_range: self
+                .arbitration_window_range
+                .clone()
+                .expect("arbitration_window_range is not set"),
+            quorum_amount: self.quorum_amount.clone().expect("quorum_amount is not set"),
+            quorum_bps: self.quorum_bps.clone().expect("quorum_bps is not set"),
+            slash_bps: self.slash_bps.clone().expect("slash_bps is not set"),
+            slash_window: self.slash_window.clone().expect("slash_window is not set"),
+            tie_policy: self.tie_policy.clone().expect("tie_policy is not set"),
+            transfer_fee_policy: self
+                .transfer_fee_policy
+                .clone()
+                .expect("transfer_fee_policy is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -314,7 +415,18 @@ impl<'a, 'b> CreateConfigV1CpiBuilder<'a, 'b> {
             bond_fee_bps: None,
             dispute_window: None,
             voting_window: None,
+            reveal_window: None,
             arbitration_window: None,
+            claim_window: None,
+            dispute_window_range: None,
+            voting_window_range: None,
+            arbitration_window_range: None,
+            quorum_amount: None,
+            quorum_bps: None,
+            slash_bps: None,
+            slash_window: None,
+            tie_policy: None,
+            transfer_fee_policy: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -364,10 +476,65 @@ impl<'a, 'b> CreateConfigV1CpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn reveal_window(&mut self, reveal_window: u32) -> &mut Self {
+        self.instruction.reveal_window = Some(reveal_window);
+        self
+    }
+    #[inline(always)]
     pub fn arbitration_window(&mut self, arbitration_window: u32) -> &mut Self {
         self.instruction.arbitration_window = Some(arbitration_window);
         self
     }
+    #[inline(always)]
+    pub fn claim_window(&mut self, claim_window: u32) -> &mut Self {
+        self.instruction.claim_window = Some(claim_window);
+        self
+    }
+    #[inline(always)]
+    pub fn dispute_window_range(&mut self, dispute_window_range: Bounds) -> &mut Self {
+        self.instruction.dispute_window_range = Some(dispute_window_range);
+        self
+    }
+    #[inline(always)]
+    pub fn voting_window_range(&mut self, voting_window_range: Bounds) -> &mut Self {
+        self.instruction.voting_window_range = Some(voting_window_range);
+        self
+    }
+    #[inline(always)]
+    pub fn arbitration_window_range(&mut self, arbitration_window_range: Bounds) -> &mut Self {
+        self.instruction.arbitration_window_range = Some(arbitration_window_range);
+        self
+    }
+    #[inline(always)]
+    pub fn quorum_amount(&mut self, quorum_amount: u64) -> &mut Self {
+        self.instruction.quorum_amount = Some(quorum_amount);
+        self
+    }
+    #[inline(always)]
+    pub fn quorum_bps(&mut self, quorum_bps: u16) -> &mut Self {
+        self.instruction.quorum_bps = Some(quorum_bps);
+        self
+    }
+    #[inline(always)]
+    pub fn slash_bps(&mut self, slash_bps: u16) -> &mut Self {
+        self.instruction.slash_bps = Some(slash_bps);
+        self
+    }
+    #[inline(always)]
+    pub fn slash_window(&mut self, slash_window: u32) -> &mut Self {
+        self.instruction.slash_window = Some(slash_window);
+        self
+    }
+    #[inline(always)]
+    pub fn tie_policy(&mut self, tie_policy: TiePolicy) -> &mut Self {
+        self.instruction.tie_policy = Some(tie_policy);
+        self
+    }
+    #[inline(always)]
+    pub fn transfer_fee_policy(&mut self, transfer_fee_policy: TransferFeePolicy) -> &mut Self {
+        self.instruction.transfer_fee_policy = Some(transfer_fee_policy);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -414,11 +581,46 @@ impl<'a, 'b> CreateConfigV1CpiBuilder<'a, 'b> {
                 .voting_window
                 .clone()
                 .expect("voting_window is not set"),
+            reveal_window: self
+                .instruction
+                .reveal_window
+                .clone()
+                .expect("reveal_window is not set"),
             arbitration_window: self
                 .instruction
                 .arbitration_window
                 .clone()
                 .expect("arbitration_window is not set"),
+            claim_window: self.instruction.claim_window.clone().expect("claim_window is not set"),
+            dispute_window_range: self
+                .instruction
+                .dispute_window_range
+                .clone()
+                .expect("dispute_window_range is not set"),
+            voting_window_range: self
+                .instruction
+                .voting_window_range
+                .clone()
+                .expect("voting_window_range is not set"),
+            arbitration_window_range: self
+                .instruction
+                .arbitration_window_range
+                .clone()
+                .expect("arbitration_window_range is not set"),
+            quorum_amount: self
+                .instruction
+                .quorum_amount
+                .clone()
+                .expect("quorum_amount is not set"),
+            quorum_bps: self.instruction.quorum_bps.clone().expect("quorum_bps is not set"),
+            slash_bps: self.instruction.slash_bps.clone().expect("slash_bps is not set"),
+            slash_window: self.instruction.slash_window.clone().expect("slash_window is not set"),
+            tie_policy: self.instruction.tie_policy.clone().expect("tie_policy is not set"),
+            transfer_fee_policy: self
+                .instruction
+                .transfer_fee_policy
+                .clone()
+                .expect("transfer_fee_policy is not set"),
         };
         let instruction = CreateConfigV1Cpi {
             __program: self.instruction.__program,
@@ -447,7 +649,18 @@ struct CreateConfigV1CpiBuilderInstruction<'a, 'b> {
     bond_fee_bps: Option<u16>,
     dispute_window: Option<u32>,
     voting_window: Option<u32>,
+    reveal_window: Option<u32>,
     arbitration_window: Option<u32>,
+    claim_window: Option<u32>,
+    dispute_window_range: Option<Bounds>,
+    voting_window_range: Option<Bounds>,
+    arbitration_window_range: Option<Bounds>,
+    quorum_amount: Option<u64>,
+    quorum_bps: Option<u16>,
+    slash_bps: Option<u16>,
+    slash_window: Option<u32>,
+    tie_policy: Option<TiePolicy>,
+    transfer_fee_policy: Option<TransferFeePolicy>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)>,
 }
@@ -11,8 +11,8 @@ use solana_program::pubkey::Pubkey;
 
 /// Accounts.
 pub struct CreateRequestV1 {
-    /// Oracle
-    pub oracle: solana_program::pubkey::Pubkey,
+    /// Oracle (required without a nonce)
+    pub oracle: Option<solana_program::pubkey::Pubkey>,
     /// Config
     pub config: solana_program::pubkey::Pubkey,
     /// Request
@@ -51,7 +51,14 @@ impl CreateRequestV1 {
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
         let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
-        accounts.push(solana_program::instruction::AccountMeta::new(self.oracle, false));
+        if let Some(oracle) = self.oracle {
+            accounts.push(solana_program::instruction::AccountMeta::new(oracle, false));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::OPTIMISTIC_ORACLE_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(self.config, false));
         accounts.push(solana_program::instruction::AccountMeta::new(self.request, false));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
@@ -113,6 +120,11 @@ pub struct CreateRequestV1InstructionArgs {
     pub bond: u64,
     pub timestamp: i64,
     pub arbitrator: Pubkey,
+    pub dispute_window: Option<u32>,
+    pub voting_window: Option<u32>,
+    pub arbitration_window: Option<u32>,
+    pub nonce: Option<u64>,
+    pub allow_too_early: bool,
     pub data: RequestData,
 }
 
@@ -120,7 +132,7 @@ pub struct CreateRequestV1InstructionArgs {
 ///
 /// ### Accounts:
 ///
-///   0. `[writable]` oracle
+///   0. `[writable, optional]` oracle
 ///   1. `[]` config
 ///   2. `[writable]` request
 ///   3. `[]` reward_currency
@@ -150,6 +162,11 @@ pub struct CreateRequestV1Builder {
     bond: Option<u64>,
     timestamp: Option<i64>,
     arbitrator: Option<Pubkey>,
+    dispute_window: Option<Option<u32>>,
+    voting_window: Option<Option<u32>>,
+    arbitration_window: Option<Option<u32>>,
+    nonce: Option<Option<u64>>,
+    allow_too_early: Option<bool>,
     data: Option<RequestData>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -158,10 +175,11 @@ impl CreateRequestV1Builder {
     pub fn new() -> Self {
         Self::default()
     }
-    /// Oracle
+    /// `[optional account]`
+    /// Oracle (required without a nonce)
     #[inline(always)]
-    pub fn oracle(&mut self, oracle: solana_program::pubkey::Pubkey) -> &mut Self {
-        self.oracle = Some(oracle);
+    pub fn oracle(&mut self, oracle: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.oracle = oracle;
         self
     }
     /// Config
@@ -256,6 +274,31 @@ impl CreateRequestV1Builder {
         self
     }
     #[inline(always)]
+    pub fn dispute_window(&mut self, dispute_window: Option<u32>) -> &mut Self {
+        self.dispute_window = Some(dispute_window);
+        self
+    }
+    #[inline(always)]
+    pub fn voting_window(&mut self, voting_window: Option<u32>) -> &mut Self {
+        self.voting_window = Some(voting_window);
+        self
+    }
+    #[inline(always)]
+    pub fn arbitration_window(&mut self, arbitration_window: Option<u32>) -> &mut Self {
+        self.arbitration_window = Some(arbitration_window);
+        self
+    }
+    #[inline(always)]
+    pub fn nonce(&mut self, nonce: Option<u64>) -> &mut Self {
+        self.nonce = Some(nonce);
+        self
+    }
+    #[inline(always)]
+    pub fn allow_too_early(&mut self, allow_too_early: bool) -> &mut Self {
+        self.allow_too_early = Some(allow_too_early);
+        self
+    }
+    #[inline(always)]
     pub fn data(&mut self, data: RequestData) -> &mut Self {
         self.data = Some(data);
         self
@@ -281,7 +324,7 @@ impl CreateRequestV1Builder {
     #[allow(clippy::clone_on_copy)]
     pub fn instruction(&self) -> solana_program::instruction::Instruction {
         let accounts = CreateRequestV1 {
-            oracle: self.oracle.expect("oracle is not set"),
+            oracle: self.oracle,
             config: self.config.expect("config is not set"),
             request: self.request.expect("request is not set"),
             reward_currency: self.reward_currency.expect("reward_currency is not set"),
@@ -303,6 +346,14 @@ impl CreateRequestV1Builder {
             bond: self.bond.clone().expect("bond is not set"),
             timestamp: self.timestamp.clone().expect("timestamp is not set"),
             arbitrator: self.arbitrator.clone().expect("arbitrator is not set"),
+            dispute_window: self.dispute_window.clone().expect("dispute_window is not set"),
+            voting_window: self.voting_window.clone().expect("voting_window is not set"),
+            arbitration_window: self
+                .arbitration_window
+                .clone()
+                .expect("arbitration_window is not set"),
+            nonce: self.nonce.clone().expect("nonce is not set"),
+            allow_too_early: self.allow_too_early.clone().expect("allow_too_early is not set"),
             data: self.data.clone().expect("data is not set"),
         };
 
@@ -312,8 +363,8 @@ impl CreateRequestV1Builder {
 
 /// `create_request_v1` CPI accounts.
 pub struct CreateRequestV1CpiAccounts<'a, 'b> {
-    /// Oracle
-    pub oracle: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Oracle (required without a nonce)
+    pub oracle: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Config
     pub config: &'b solana_program::account_info::AccountInfo<'a>,
     /// Request
@@ -342,8 +393,8 @@ pub struct CreateRequestV1CpiAccounts<'a, 'b> {
 pub struct CreateRequestV1Cpi<'a, 'b> {
     /// The program to invoke.
     pub __program: &'b solana_program::account_info::AccountInfo<'a>,
-    /// Oracle
-    pub oracle: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Oracle (required without a nonce)
+    pub oracle: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Config
     pub config: &'b solana_program::account_info::AccountInfo<'a>,
     /// Request
@@ -419,7 +470,14 @@ impl<'a, 'b> CreateRequestV1Cpi<'a, 'b> {
         remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program::entrypoint::ProgramResult {
         let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
-        accounts.push(solana_program::instruction::AccountMeta::new(*self.oracle.key, false));
+        if let Some(oracle) = self.oracle {
+            accounts.push(solana_program::instruction::AccountMeta::new(*oracle.key, false));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::OPTIMISTIC_ORACLE_ID,
+                false,
+            ));
+        }
         accounts
             .push(solana_program::instruction::AccountMeta::new_readonly(*self.config.key, false));
         accounts.push(solana_program::instruction::AccountMeta::new(*self.request.key, false));
@@ -468,7 +526,9 @@ impl<'a, 'b> CreateRequestV1Cpi<'a, 'b> {
         };
         let mut account_infos = Vec::with_capacity(12 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
-        account_infos.push(self.oracle.clone());
+        if let Some(oracle) = self.oracle {
+            account_infos.push(oracle.clone());
+        }
         account_infos.push(self.config.clone());
         account_infos.push(self.request.clone());
         account_infos.push(self.reward_currency.clone());
@@ -496,7 +556,7 @@ impl<'a, 'b> CreateRequestV1Cpi<'a, 'b> {
 ///
 /// ### Accounts:
 ///
-///   0. `[writable]` oracle
+///   0. `[writable, optional]` oracle
 ///   1. `[]` config
 ///   2. `[writable]` request
 ///   3. `[]` reward_currency
@@ -533,18 +593,24 @@ impl<'a, 'b> CreateRequestV1CpiBuilder<'a, 'b> {
             bond: None,
             timestamp: None,
             arbitrator: None,
+            dispute_window: None,
+            voting_window: None,
+            arbitration_window: None,
+            nonce: None,
+            allow_too_early: None,
             data: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
     }
-    /// Oracle
+    /// `[optional account]`
+    /// Oracle (required without a nonce)
     #[inline(always)]
     pub fn oracle(
         &mut self,
-        oracle: &'b solana_program::account_info::AccountInfo<'a>,
+        oracle: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ) -> &mut Self {
-        self.instruction.oracle = Some(oracle);
+        self.instruction.oracle = oracle;
         self
     }
     /// Config
@@ -664,6 +730,31 @@ impl<'a, 'b> CreateRequestV1CpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn dispute_window(&mut self, dispute_window: Option<u32>) -> &mut Self {
+        self.instruction.dispute_window = Some(dispute_window);
+        self
+    }
+    #[inline(always)]
+    pub fn voting_window(&mut self, voting_window: Option<u32>) -> &mut Self {
+        self.instruction.voting_window = Some(voting_window);
+        self
+    }
+    #[inline(always)]
+    pub fn arbitration_window(&mut self, arbitration_window: Option<u32>) -> &mut Self {
+        self.instruction.arbitration_window = Some(arbitration_window);
+        self
+    }
+    #[inline(always)]
+    pub fn nonce(&mut self, nonce: Option<u64>) -> &mut Self {
+        self.instruction.nonce = Some(nonce);
+        self
+    }
+    #[inline(always)]
+    pub fn allow_too_early(&mut self, allow_too_early: bool) -> &mut Self {
+        self.instruction.allow_too_early = Some(allow_too_early);
+        self
+    }
+    #[inline(always)]
     pub fn data(&mut self, data: RequestData) -> &mut Self {
         self.instruction.data = Some(data);
         self
@@ -706,12 +797,33 @@ impl<'a, 'b> CreateRequestV1CpiBuilder<'a, 'b> {
             bond: self.instruction.bond.clone().expect("bond is not set"),
             timestamp: self.instruction.timestamp.clone().expect("timestamp is not set"),
             arbitrator: self.instruction.arbitrator.clone().expect("arbitrator is not set"),
+            dispute_window: self
+                .instruction
+                .dispute_window
+                .clone()
+                .expect("dispute_window is not set"),
+            voting_window: self
+                .instruction
+                .voting_window
+                .clone()
+                .expect("voting_window is not set"),
+            arbitration_window: self
+                .instruction
+                .arbitration_window
+                .clone()
+                .expect("arbitration_window is not set"),
+            nonce: self.instruction.nonce.clone().expect("nonce is not set"),
+            allow_too_early: self
+                .instruction
+                .allow_too_early
+                .clone()
+                .expect("allow_too_early is not set"),
             data: self.instruction.data.clone().expect("data is not set"),
         };
         let instruction = CreateRequestV1Cpi {
             __program: self.instruction.__program,
 
-            oracle: self.instruction.oracle.expect("oracle is not set"),
+            oracle: self.instruction.oracle,
 
             config: self.instruction.config.expect("config is not set"),
 
@@ -762,6 +874,11 @@ struct CreateRequestV1CpiBuilderInstruction<'a, 'b> {
     bond: Option<u64>,
     timestamp: Option<i64>,
     arbitrator: Option<Pubkey>,
+    dispute_window: Option<Option<u32>>,
+    voting_window: Option<Option<u32>>,
+    arbitration_window: Option<Option<u32>>,
+    nonce: Option<Option<u64>>,
+    allow_too_early: Option<bool>,
     data: Option<RequestData>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)>,
@@ -43,7 +43,7 @@ impl CreateStakeV1 {
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
         let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(self.oracle, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.oracle, false));
         accounts.push(solana_program::instruction::AccountMeta::new(self.stake, true));
         accounts.push(solana_program::instruction::AccountMeta::new(self.mint, false));
         accounts.push(solana_program::instruction::AccountMeta::new(self.stake_source, false));
@@ -98,7 +98,7 @@ pub struct CreateStakeV1InstructionArgs {
 ///
 /// ### Accounts:
 ///
-///   0. `[]` oracle
+///   0. `[writable]` oracle
 ///   1. `[writable, signer]` stake
 ///   2. `[writable]` mint
 ///   3. `[writable]` stake_source
@@ -324,8 +324,7 @@ impl<'a, 'b> CreateStakeV1Cpi<'a, 'b> {
         remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program::entrypoint::ProgramResult {
         let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
-        accounts
-            .push(solana_program::instruction::AccountMeta::new_readonly(*self.oracle.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.oracle.key, false));
         accounts.push(solana_program::instruction::AccountMeta::new(*self.stake.key, true));
         accounts.push(solana_program::instruction::AccountMeta::new(*self.mint.key, false));
         accounts.push(solana_program::instruction::AccountMeta::new(*self.stake_source.key, false));
@@ -383,7 +382,7 @@ impl<'a, 'b> CreateStakeV1Cpi<'a, 'b> {
 ///
 /// ### Accounts:
 ///
-///   0. `[]` oracle
+///   0. `[writable]` oracle
 ///   1. `[writable, signer]` stake
 ///   2. `[writable]` mint
 ///   3. `[writable]` stake_source
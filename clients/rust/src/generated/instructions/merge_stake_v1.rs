@@ -0,0 +1,326 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+//!
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct MergeStakeV1 {
+    /// Stake merged into
+    pub stake: solana_program::pubkey::Pubkey,
+    /// Stake merged from
+    pub source_stake: solana_program::pubkey::Pubkey,
+    /// Stake owner
+    pub wallet: solana_program::pubkey::Pubkey,
+}
+
+impl MergeStakeV1 {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(self.stake, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.source_stake, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.wallet, true));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = MergeStakeV1InstructionData::new().try_to_vec().unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::OPTIMISTIC_ORACLE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct MergeStakeV1InstructionData {
+    discriminator: u8,
+}
+
+impl MergeStakeV1InstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 18 }
+    }
+}
+
+impl Default for MergeStakeV1InstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Instruction builder for `MergeStakeV1`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` stake
+///   1. `[writable]` source_stake
+///   2. `[writable, signer]` wallet
+#[derive(Clone, Debug, Default)]
+pub struct MergeStakeV1Builder {
+    stake: Option<solana_program::pubkey::Pubkey>,
+    source_stake: Option<solana_program::pubkey::Pubkey>,
+    wallet: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl MergeStakeV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Stake merged into
+    #[inline(always)]
+    pub fn stake(&mut self, stake: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.stake = Some(stake);
+        self
+    }
+    /// Stake merged from
+    #[inline(always)]
+    pub fn source_stake(&mut self, source_stake: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.source_stake = Some(source_stake);
+        self
+    }
+    /// Stake owner
+    #[inline(always)]
+    pub fn wallet(&mut self, wallet: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.wallet = Some(wallet);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = MergeStakeV1 {
+            stake: self.stake.expect("stake is not set"),
+            source_stake: self.source_stake.expect("source_stake is not set"),
+            wallet: self.wallet.expect("wallet is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `merge_stake_v1` CPI accounts.
+pub struct MergeStakeV1CpiAccounts<'a, 'b> {
+    /// Stake merged into
+    pub stake: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake merged from
+    pub source_stake: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake owner
+    pub wallet: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `merge_stake_v1` CPI instruction.
+pub struct MergeStakeV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake merged into
+    pub stake: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake merged from
+    pub source_stake: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake owner
+    pub wallet: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> MergeStakeV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: MergeStakeV1CpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            stake: accounts.stake,
+            source_stake: accounts.source_stake,
+            wallet: accounts.wallet,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.stake.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.source_stake.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.wallet.key, true));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = MergeStakeV1InstructionData::new().try_to_vec().unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::OPTIMISTIC_ORACLE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(3 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.stake.clone());
+        account_infos.push(self.source_stake.clone());
+        account_infos.push(self.wallet.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `MergeStakeV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` stake
+///   1. `[writable]` source_stake
+///   2. `[writable, signer]` wallet
+#[derive(Clone, Debug)]
+pub struct MergeStakeV1CpiBuilder<'a, 'b> {
+    instruction: Box<MergeStakeV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> MergeStakeV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(MergeStakeV1CpiBuilderInstruction {
+            __program: program,
+            stake: None,
+            source_stake: None,
+            wallet: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Stake merged into
+    #[inline(always)]
+    pub fn stake(&mut self, stake: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.stake = Some(stake);
+        self
+    }
+    /// Stake merged from
+    #[inline(always)]
+    pub fn source_stake(
+        &mut self,
+        source_stake: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.source_stake = Some(source_stake);
+        self
+    }
+    /// Stake owner
+    #[inline(always)]
+    pub fn wallet(
+        &mut self,
+        wallet: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.wallet = Some(wallet);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction.__remaining_accounts.push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)],
+    ) -> &mut Self {
+        self.instruction.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = MergeStakeV1Cpi {
+            __program: self.instruction.__program,
+
+            stake: self.instruction.stake.expect("stake is not set"),
+
+            source_stake: self.instruction.source_stake.expect("source_stake is not set"),
+
+            wallet: self.instruction.wallet.expect("wallet is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct MergeStakeV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    stake: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    source_stake: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    wallet: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)>,
+}
@@ -5,36 +5,68 @@
 //! <https://github.com/kinobi-so/kinobi>
 //!
 
+pub(crate) mod r#arbitrate_v1;
+pub(crate) mod r#cancel_request_v1;
+pub(crate) mod r#change_vote_v1;
 pub(crate) mod r#claim_assertion_v1;
 pub(crate) mod r#claim_dispute_v1;
+pub(crate) mod r#claim_refund_v1;
 pub(crate) mod r#claim_vote_v1;
+pub(crate) mod r#close_currency_v1;
+pub(crate) mod r#close_request_v1;
+pub(crate) mod r#close_vote_v1;
 pub(crate) mod r#close_voting_v1;
+pub(crate) mod r#commit_vote_v1;
 pub(crate) mod r#create_assertion_v1;
 pub(crate) mod r#create_config_v1;
 pub(crate) mod r#create_currency_v1;
 pub(crate) mod r#create_oracle_v1;
 pub(crate) mod r#create_request_v1;
 pub(crate) mod r#create_stake_v1;
+pub(crate) mod r#deposit_stake_v1;
 pub(crate) mod r#dispute_assertion_v1;
+pub(crate) mod r#merge_stake_v1;
 pub(crate) mod r#resolve_assertion_v1;
+pub(crate) mod r#reveal_vote_v1;
+pub(crate) mod r#set_stake_delegate_v1;
+pub(crate) mod r#slash_vote_v1;
 pub(crate) mod r#submit_vote_v1;
 pub(crate) mod r#update_config_v1;
 pub(crate) mod r#update_currency_v1;
 pub(crate) mod r#update_oracle_v1;
+pub(crate) mod r#withdraw_fees_v1;
+pub(crate) mod r#withdraw_stake_v1;
+pub(crate) mod r#withdraw_vote_v1;
 
+pub use self::r#arbitrate_v1::*;
+pub use self::r#cancel_request_v1::*;
+pub use self::r#change_vote_v1::*;
 pub use self::r#claim_assertion_v1::*;
 pub use self::r#claim_dispute_v1::*;
+pub use self::r#claim_refund_v1::*;
 pub use self::r#claim_vote_v1::*;
+pub use self::r#close_currency_v1::*;
+pub use self::r#close_request_v1::*;
+pub use self::r#close_vote_v1::*;
 pub use self::r#close_voting_v1::*;
+pub use self::r#commit_vote_v1::*;
 pub use self::r#create_assertion_v1::*;
 pub use self::r#create_config_v1::*;
 pub use self::r#create_currency_v1::*;
 pub use self::r#create_oracle_v1::*;
 pub use self::r#create_request_v1::*;
 pub use self::r#create_stake_v1::*;
+pub use self::r#deposit_stake_v1::*;
 pub use self::r#dispute_assertion_v1::*;
+pub use self::r#merge_stake_v1::*;
 pub use self::r#resolve_assertion_v1::*;
+pub use self::r#reveal_vote_v1::*;
+pub use self::r#set_stake_delegate_v1::*;
+pub use self::r#slash_vote_v1::*;
 pub use self::r#submit_vote_v1::*;
 pub use self::r#update_config_v1::*;
 pub use self::r#update_currency_v1::*;
 pub use self::r#update_oracle_v1::*;
+pub use self::r#withdraw_fees_v1::*;
+pub use self::r#withdraw_stake_v1::*;
+pub use self::r#withdraw_vote_v1::*;
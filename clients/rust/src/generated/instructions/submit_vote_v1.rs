@@ -45,7 +45,7 @@ impl SubmitVoteV1 {
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(self.request, false));
         accounts.push(solana_program::instruction::AccountMeta::new(self.voting, false));
         accounts.push(solana_program::instruction::AccountMeta::new(self.vote, false));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(self.stake, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.stake, false));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(self.voter, true));
         accounts.push(solana_program::instruction::AccountMeta::new(self.payer, true));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
@@ -96,7 +96,7 @@ pub struct SubmitVoteV1InstructionArgs {
 ///   1. `[]` request
 ///   2. `[writable]` voting
 ///   3. `[writable]` vote
-///   4. `[]` stake
+///   4. `[writable]` stake
 ///   5. `[signer]` voter
 ///   6. `[writable, signer]` payer
 ///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
@@ -306,8 +306,7 @@ impl<'a, 'b> SubmitVoteV1Cpi<'a, 'b> {
             .push(solana_program::instruction::AccountMeta::new_readonly(*self.request.key, false));
         accounts.push(solana_program::instruction::AccountMeta::new(*self.voting.key, false));
         accounts.push(solana_program::instruction::AccountMeta::new(*self.vote.key, false));
-        accounts
-            .push(solana_program::instruction::AccountMeta::new_readonly(*self.stake.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.stake.key, false));
         accounts
             .push(solana_program::instruction::AccountMeta::new_readonly(*self.voter.key, true));
         accounts.push(solana_program::instruction::AccountMeta::new(*self.payer.key, true));
@@ -361,7 +360,7 @@ impl<'a, 'b> SubmitVoteV1Cpi<'a, 'b> {
 ///   1. `[]` request
 ///   2. `[writable]` voting
 ///   3. `[writable]` vote
-///   4. `[]` stake
+///   4. `[writable]` stake
 ///   5. `[signer]` voter
 ///   6. `[writable, signer]` payer
 ///   7. `[]` system_program
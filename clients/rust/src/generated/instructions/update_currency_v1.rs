@@ -5,7 +5,7 @@
 //! <https://github.com/kinobi-so/kinobi>
 //!
 
-use crate::generated::types::Bounds;
+use crate::generated::types::{Bounds, CurrencyStatus};
 use borsh::{BorshDeserialize, BorshSerialize};
 
 /// Accounts.
@@ -71,6 +71,7 @@ impl Default for UpdateCurrencyV1InstructionData {
 pub struct UpdateCurrencyV1InstructionArgs {
     pub new_reward_range: Bounds,
     pub new_bond_range: Bounds,
+    pub new_status: CurrencyStatus,
 }
 
 /// Instruction builder for `UpdateCurrencyV1`.
@@ -87,6 +88,7 @@ pub struct UpdateCurrencyV1Builder {
     authority: Option<solana_program::pubkey::Pubkey>,
     new_reward_range: Option<Bounds>,
     new_bond_range: Option<Bounds>,
+    new_status: Option<CurrencyStatus>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -122,6 +124,11 @@ impl UpdateCurrencyV1Builder {
         self.new_bond_range = Some(new_bond_range);
         self
     }
+    #[inline(always)]
+    pub fn new_status(&mut self, new_status: CurrencyStatus) -> &mut Self {
+        self.new_status = Some(new_status);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -150,6 +157,7 @@ impl UpdateCurrencyV1Builder {
         let args = UpdateCurrencyV1InstructionArgs {
             new_reward_range: self.new_reward_range.clone().expect("new_reward_range is not set"),
             new_bond_range: self.new_bond_range.clone().expect("new_bond_range is not set"),
+            new_status: self.new_status.clone().expect("new_status is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -281,6 +289,7 @@ impl<'a, 'b> UpdateCurrencyV1CpiBuilder<'a, 'b> {
             authority: None,
             new_reward_range: None,
             new_bond_range: None,
+            new_status: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -322,6 +331,11 @@ impl<'a, 'b> UpdateCurrencyV1CpiBuilder<'a, 'b> {
         self.instruction.new_bond_range = Some(new_bond_range);
         self
     }
+    #[inline(always)]
+    pub fn new_status(&mut self, new_status: CurrencyStatus) -> &mut Self {
+        self.instruction.new_status = Some(new_status);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -366,6 +380,7 @@ impl<'a, 'b> UpdateCurrencyV1CpiBuilder<'a, 'b> {
                 .new_bond_range
                 .clone()
                 .expect("new_bond_range is not set"),
+            new_status: self.instruction.new_status.clone().expect("new_status is not set"),
         };
         let instruction = UpdateCurrencyV1Cpi {
             __program: self.instruction.__program,
@@ -392,6 +407,7 @@ struct UpdateCurrencyV1CpiBuilderInstruction<'a, 'b> {
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     new_reward_range: Option<Bounds>,
     new_bond_range: Option<Bounds>,
+    new_status: Option<CurrencyStatus>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)>,
 }
@@ -0,0 +1,512 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+//!
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct WithdrawStakeV1 {
+    /// Oracle
+    pub oracle: solana_program::pubkey::Pubkey,
+    /// Stake
+    pub stake: solana_program::pubkey::Pubkey,
+    /// Stake mint
+    pub mint: solana_program::pubkey::Pubkey,
+    /// Stake destination token account
+    pub stake_destination: solana_program::pubkey::Pubkey,
+    /// Stake pool token account
+    pub stake_pool: solana_program::pubkey::Pubkey,
+    /// Stake owner
+    pub wallet: solana_program::pubkey::Pubkey,
+    /// SPL token program
+    pub token_program: solana_program::pubkey::Pubkey,
+}
+
+impl WithdrawStakeV1 {
+    pub fn instruction(
+        &self,
+        args: WithdrawStakeV1InstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: WithdrawStakeV1InstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(self.oracle, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.stake, false));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(self.mint, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.stake_destination, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.stake_pool, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(self.wallet, true));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = WithdrawStakeV1InstructionData::new().try_to_vec().unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::OPTIMISTIC_ORACLE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct WithdrawStakeV1InstructionData {
+    discriminator: u8,
+}
+
+impl WithdrawStakeV1InstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 16 }
+    }
+}
+
+impl Default for WithdrawStakeV1InstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WithdrawStakeV1InstructionArgs {
+    pub amount: u64,
+}
+
+/// Instruction builder for `WithdrawStakeV1`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` oracle
+///   1. `[writable]` stake
+///   2. `[]` mint
+///   3. `[writable]` stake_destination
+///   4. `[writable]` stake_pool
+///   5. `[writable, signer]` wallet
+///   6. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+#[derive(Clone, Debug, Default)]
+pub struct WithdrawStakeV1Builder {
+    oracle: Option<solana_program::pubkey::Pubkey>,
+    stake: Option<solana_program::pubkey::Pubkey>,
+    mint: Option<solana_program::pubkey::Pubkey>,
+    stake_destination: Option<solana_program::pubkey::Pubkey>,
+    stake_pool: Option<solana_program::pubkey::Pubkey>,
+    wallet: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    amount: Option<u64>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl WithdrawStakeV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Oracle
+    #[inline(always)]
+    pub fn oracle(&mut self, oracle: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.oracle = Some(oracle);
+        self
+    }
+    /// Stake
+    #[inline(always)]
+    pub fn stake(&mut self, stake: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.stake = Some(stake);
+        self
+    }
+    /// Stake mint
+    #[inline(always)]
+    pub fn mint(&mut self, mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    /// Stake destination token account
+    #[inline(always)]
+    pub fn stake_destination(
+        &mut self,
+        stake_destination: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.stake_destination = Some(stake_destination);
+        self
+    }
+    /// Stake pool token account
+    #[inline(always)]
+    pub fn stake_pool(&mut self, stake_pool: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.stake_pool = Some(stake_pool);
+        self
+    }
+    /// Stake owner
+    #[inline(always)]
+    pub fn wallet(&mut self, wallet: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.wallet = Some(wallet);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    /// SPL token program
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn amount(&mut self, amount: u64) -> &mut Self {
+        self.amount = Some(amount);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = WithdrawStakeV1 {
+            oracle: self.oracle.expect("oracle is not set"),
+            stake: self.stake.expect("stake is not set"),
+            mint: self.mint.expect("mint is not set"),
+            stake_destination: self.stake_destination.expect("stake_destination is not set"),
+            stake_pool: self.stake_pool.expect("stake_pool is not set"),
+            wallet: self.wallet.expect("wallet is not set"),
+            token_program: self
+                .token_program
+                .unwrap_or(solana_program::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")),
+        };
+        let args = WithdrawStakeV1InstructionArgs {
+            amount: self.amount.clone().expect("amount is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `withdraw_stake_v1` CPI accounts.
+pub struct WithdrawStakeV1CpiAccounts<'a, 'b> {
+    /// Oracle
+    pub oracle: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake
+    pub stake: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake mint
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake destination token account
+    pub stake_destination: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake pool token account
+    pub stake_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake owner
+    pub wallet: &'b solana_program::account_info::AccountInfo<'a>,
+    /// SPL token program
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `withdraw_stake_v1` CPI instruction.
+pub struct WithdrawStakeV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Oracle
+    pub oracle: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake
+    pub stake: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake mint
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake destination token account
+    pub stake_destination: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake pool token account
+    pub stake_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake owner
+    pub wallet: &'b solana_program::account_info::AccountInfo<'a>,
+    /// SPL token program
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: WithdrawStakeV1InstructionArgs,
+}
+
+impl<'a, 'b> WithdrawStakeV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: WithdrawStakeV1CpiAccounts<'a, 'b>,
+        args: WithdrawStakeV1InstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            oracle: accounts.oracle,
+            stake: accounts.stake,
+            mint: accounts.mint,
+            stake_destination: accounts.stake_destination,
+            stake_pool: accounts.stake_pool,
+            wallet: accounts.wallet,
+            token_program: accounts.token_program,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.oracle.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.stake.key, false));
+        accounts
+            .push(solana_program::instruction::AccountMeta::new_readonly(*self.mint.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.stake_destination.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.stake_pool.key, false));
+        accounts.push(solana_program::instruction::AccountMeta::new(*self.wallet.key, true));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = WithdrawStakeV1InstructionData::new().try_to_vec().unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::OPTIMISTIC_ORACLE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.oracle.clone());
+        account_infos.push(self.stake.clone());
+        account_infos.push(self.mint.clone());
+        account_infos.push(self.stake_destination.clone());
+        account_infos.push(self.stake_pool.clone());
+        account_infos.push(self.wallet.clone());
+        account_infos.push(self.token_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `WithdrawStakeV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` oracle
+///   1. `[writable]` stake
+///   2. `[]` mint
+///   3. `[writable]` stake_destination
+///   4. `[writable]` stake_pool
+///   5. `[writable, signer]` wallet
+///   6. `[]` token_program
+#[derive(Clone, Debug)]
+pub struct WithdrawStakeV1CpiBuilder<'a, 'b> {
+    instruction: Box<WithdrawStakeV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> WithdrawStakeV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(WithdrawStakeV1CpiBuilderInstruction {
+            __program: program,
+            oracle: None,
+            stake: None,
+            mint: None,
+            stake_destination: None,
+            stake_pool: None,
+            wallet: None,
+            token_program: None,
+            amount: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Oracle
+    #[inline(always)]
+    pub fn oracle(
+        &mut self,
+        oracle: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.oracle = Some(oracle);
+        self
+    }
+    /// Stake
+    #[inline(always)]
+    pub fn stake(&mut self, stake: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.stake = Some(stake);
+        self
+    }
+    /// Stake mint
+    #[inline(always)]
+    pub fn mint(&mut self, mint: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.mint = Some(mint);
+        self
+    }
+    /// Stake destination token account
+    #[inline(always)]
+    pub fn stake_destination(
+        &mut self,
+        stake_destination: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.stake_destination = Some(stake_destination);
+        self
+    }
+    /// Stake pool token account
+    #[inline(always)]
+    pub fn stake_pool(
+        &mut self,
+        stake_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.stake_pool = Some(stake_pool);
+        self
+    }
+    /// Stake owner
+    #[inline(always)]
+    pub fn wallet(
+        &mut self,
+        wallet: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.wallet = Some(wallet);
+        self
+    }
+    /// SPL token program
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn amount(&mut self, amount: u64) -> &mut Self {
+        self.instruction.amount = Some(amount);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction.__remaining_accounts.push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)],
+    ) -> &mut Self {
+        self.instruction.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = WithdrawStakeV1InstructionArgs {
+            amount: self.instruction.amount.clone().expect("amount is not set"),
+        };
+        let instruction = WithdrawStakeV1Cpi {
+            __program: self.instruction.__program,
+
+            oracle: self.instruction.oracle.expect("oracle is not set"),
+
+            stake: self.instruction.stake.expect("stake is not set"),
+
+            mint: self.instruction.mint.expect("mint is not set"),
+
+            stake_destination: self
+                .instruction
+                .stake_destination
+                .expect("stake_destination is not set"),
+
+            stake_pool: self.instruction.stake_pool.expect("stake_pool is not set"),
+
+            wallet: self.instruction.wallet.expect("wallet is not set"),
+
+            token_program: self.instruction.token_program.expect("token_program is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct WithdrawStakeV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    oracle: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    stake: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    stake_destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    stake_pool: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    wallet: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    amount: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)>,
+}
@@ -7,14 +7,28 @@
 
 pub(crate) mod r#account_type;
 pub(crate) mod r#bounds;
+pub(crate) mod r#bps;
+pub(crate) mod r#currency_status;
+pub(crate) mod r#oracle_event;
 pub(crate) mod r#request_data;
+pub(crate) mod r#request_derivation;
 pub(crate) mod r#request_state;
+pub(crate) mod r#reward_pool;
+pub(crate) mod r#tie_policy;
+pub(crate) mod r#transfer_fee_policy;
 pub(crate) mod r#update_config_v1_args;
 pub(crate) mod r#update_oracle_v1_args;
 
 pub use self::r#account_type::*;
 pub use self::r#bounds::*;
+pub use self::r#bps::*;
+pub use self::r#currency_status::*;
+pub use self::r#oracle_event::*;
 pub use self::r#request_data::*;
+pub use self::r#request_derivation::*;
 pub use self::r#request_state::*;
+pub use self::r#reward_pool::*;
+pub use self::r#tie_policy::*;
+pub use self::r#transfer_fee_policy::*;
 pub use self::r#update_config_v1_args::*;
 pub use self::r#update_oracle_v1_args::*;
@@ -11,4 +11,6 @@ use borsh::{BorshDeserialize, BorshSerialize};
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RequestData {
     YesNo { question: String },
+    MultipleChoice { question: String, options: Vec<String> },
+    Numeric { question: String, min: u64, max: u64, decimals: u8 },
 }
@@ -26,4 +26,5 @@ pub enum RequestState {
     Asserted,
     Disputed,
     Resolved,
+    Cancelled,
 }
@@ -5,6 +5,7 @@
 //! <https://github.com/kinobi-so/kinobi>
 //!
 
+use crate::generated::types::{Bounds, TiePolicy, TransferFeePolicy};
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 
@@ -22,6 +23,27 @@ pub enum UpdateConfigV1Args {
         new_bond_fee_bps: u16,
         new_dispute_window: u32,
         new_voting_window: u32,
+        new_reveal_window: u32,
         new_arbitration_window: u32,
+        new_claim_window: u32,
+    },
+    Quorum {
+        new_quorum_amount: u64,
+        new_quorum_bps: u16,
+    },
+    Slash {
+        new_slash_bps: u16,
+        new_slash_window: u32,
+    },
+    TiePolicy {
+        new_tie_policy: TiePolicy,
+    },
+    WindowRanges {
+        new_dispute_window_range: Bounds,
+        new_voting_window_range: Bounds,
+        new_arbitration_window_range: Bounds,
+    },
+    TransferFeePolicy {
+        new_transfer_fee_policy: TransferFeePolicy,
     },
 }
@@ -6,5 +6,11 @@
 )]
 mod generated;
 
+pub mod events;
+pub mod transfer_hook;
+
+mod request;
+mod vote;
+
 pub use crate::generated::programs::OPTIMISTIC_ORACLE_ID as ID;
 pub use crate::generated::*;
@@ -0,0 +1,67 @@
+use solana_program::pubkey::{Pubkey, PubkeyError};
+
+use crate::accounts::RequestV1;
+use crate::types::RequestData;
+
+impl RequestData {
+    /// Reserved value for a request which is invalid, e.g. if the question is ambiguous.
+    ///
+    /// A request resolved as invalid refunds the reward to the creator, and both bonds to the
+    /// asserter and disputer.
+    pub const INVALID_VALUE: u64 = u64::MAX;
+    /// Reserved value for a request which cannot be answered yet.
+    ///
+    /// A request resolved as too early is reset to accept a new assertion once it is closed.
+    pub const TOO_EARLY_VALUE: u64 = u64::MAX - 1;
+}
+
+impl RequestV1 {
+    /// Prefix values used to generate a PDA for a request derived from its creator.
+    ///
+    /// Values are positional and appear in the following order:
+    ///
+    ///   0. `RequestV1::CREATOR_PREFIX`
+    ///   1. creator (`Pubkey`)
+    ///   2. nonce (`u64`)
+    pub const CREATOR_PREFIX: &'static [u8] = "creator_request".as_bytes();
+
+    /// Creates the address of a request created with `nonce` by `creator`.
+    pub fn create_creator_pda(
+        creator: &Pubkey,
+        nonce: u64,
+        bump: u8,
+    ) -> Result<Pubkey, PubkeyError> {
+        Pubkey::create_program_address(
+            &[Self::CREATOR_PREFIX, creator.as_ref(), &nonce.to_le_bytes(), &[bump]],
+            &crate::ID,
+        )
+    }
+
+    /// Finds the address of a request created with `nonce` by `creator`.
+    ///
+    /// Unlike addresses derived from the oracle request index, the address is known before the
+    /// request is created, so retrying a transaction cannot create a duplicate request while the
+    /// request exists. The nonce can be reused once the request is cancelled or closed.
+    pub fn find_creator_pda(creator: &Pubkey, nonce: u64) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[Self::CREATOR_PREFIX, creator.as_ref(), &nonce.to_le_bytes()],
+            &crate::ID,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn creator_pda() {
+        let creator = Pubkey::new_unique();
+
+        let (address, bump) = RequestV1::find_creator_pda(&creator, 1);
+
+        assert_eq!(RequestV1::create_creator_pda(&creator, 1, bump), Ok(address));
+        assert_ne!(RequestV1::find_creator_pda(&creator, 2).0, address);
+        assert_ne!(RequestV1::find_creator_pda(&Pubkey::new_unique(), 1).0, address);
+    }
+}
@@ -0,0 +1,532 @@
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+use spl_token_2022::extension::{transfer_hook, StateWithExtensions};
+use spl_token_2022::state::{Account, Mint};
+use spl_transfer_hook_interface::get_extra_account_metas_address;
+use spl_transfer_hook_interface::instruction::{execute, ExecuteInstruction};
+use spl_type_length_value::state::TlvStateBorrowed;
+use thiserror::Error;
+
+use crate::accounts::RequestV1;
+use crate::instructions::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum TransferHookError {
+    #[error("Account {0} not found")]
+    AccountNotFound(Pubkey),
+    #[error("Invalid account data for {0}")]
+    InvalidAccount(Pubkey),
+    #[error("Invalid extra account metas: {0}")]
+    InvalidExtraAccountMetas(ProgramError),
+}
+
+/// Appends the accounts required by the transfer hooks of the mints transferred by an
+/// instruction to its remaining accounts.
+///
+/// For each mint with a transfer hook, this is the hook program, its extra account metas account,
+/// and the extra accounts listed there. The program resolves these from the remaining accounts of
+/// the instruction, so all other accounts and arguments of the instruction must be set first.
+pub trait AddTransferHookAccounts {
+    /// Resolves the transfer hook accounts of the token transfers made by the instruction.
+    ///
+    /// `get_account_data` returns the data of an account, or `None` if the account does not exist.
+    ///
+    /// Extra accounts derived from the amount transferred are resolved with the amount argument of
+    /// the instruction, or the bond of the request, where the program transfers that amount. The
+    /// program otherwise computes the amount itself, and the balance of the source account is used.
+    fn add_transfer_hook_accounts<F>(
+        &mut self,
+        get_account_data: F,
+    ) -> Result<&mut Self, TransferHookError>
+    where
+        F: FnMut(&Pubkey) -> Option<Vec<u8>>;
+}
+
+/// A token transfer made by an instruction, given by the indices of its accounts.
+struct Transfer {
+    source: usize,
+    mint: usize,
+    destination: usize,
+    authority: usize,
+    amount: Amount,
+}
+
+/// The amount of a token transfer made by an instruction.
+enum Amount {
+    /// The first argument of the instruction.
+    Argument,
+    /// The bond of the request at the given account index.
+    Bond(usize),
+    /// The balance of the source account, which is not transferred from if it does not exist.
+    Balance,
+}
+
+macro_rules! impl_add_transfer_hook_accounts {
+    ($(
+        $builder:ty => [$((
+            $source:expr, $mint:expr, $destination:expr, $authority:expr, $amount:expr
+        )),* $(,)?],
+    )*) => {
+        $(
+            impl AddTransferHookAccounts for $builder {
+                fn add_transfer_hook_accounts<F>(
+                    &mut self,
+                    get_account_data: F,
+                ) -> Result<&mut Self, TransferHookError>
+                where
+                    F: FnMut(&Pubkey) -> Option<Vec<u8>>,
+                {
+                    let transfers = [$(
+                        Transfer {
+                            source: $source,
+                            mint: $mint,
+                            destination: $destination,
+                            authority: $authority,
+                            amount: $amount,
+                        },
+                    )*];
+
+                    let accounts =
+                        transfer_hook_accounts(&self.instruction(), &transfers, get_account_data)?;
+
+                    Ok(self.add_remaining_accounts(&accounts))
+                }
+            }
+        )*
+    };
+}
+
+// Transfers are listed as (source, mint, destination, authority, amount), by the index of each
+// account in the instruction.
+impl_add_transfer_hook_accounts! {
+    // Bond fee from the bond escrow to the treasury.
+    ArbitrateV1Builder => [(5, 4, 6, 1, Amount::Balance)],
+    // Reward from the reward escrow to the creator.
+    CancelRequestV1Builder => [(3, 1, 2, 0, Amount::Balance)],
+    // Bond and reward from the escrows to the asserter.
+    ClaimAssertionV1Builder => [(4, 2, 3, 0, Amount::Balance), (7, 5, 6, 0, Amount::Balance)],
+    // Bond and reward from the escrows to the disputer.
+    ClaimDisputeV1Builder => [(5, 3, 4, 0, Amount::Balance), (8, 6, 7, 0, Amount::Balance)],
+    // Reward from the reward escrow to the creator.
+    ClaimRefundV1Builder => [(4, 2, 3, 0, Amount::Balance)],
+    // Voter reward from the bond escrow to the voter.
+    ClaimVoteV1Builder => [(8, 6, 7, 0, Amount::Balance)],
+    // Reward refund to the creator, and unclaimed rewards from the bond escrows and stake pool to
+    // the treasuries.
+    CloseRequestV1Builder => [
+        (6, 5, 7, 1, Amount::Balance),
+        (8, 4, 10, 1, Amount::Balance),
+        (9, 4, 10, 1, Amount::Balance),
+        (16, 15, 17, 14, Amount::Balance),
+    ],
+    // Bond fee from the bond escrow to the treasury.
+    CloseVotingV1Builder => [(6, 5, 7, 1, Amount::Balance)],
+    // Bond from the asserter to the bond escrow.
+    CreateAssertionV1Builder => [(4, 3, 5, 6, Amount::Bond(1))],
+    // Reward from the creator to the reward escrow.
+    CreateRequestV1Builder => [(6, 5, 7, 8, Amount::Argument)],
+    // Stake from the wallet to the stake pool.
+    CreateStakeV1Builder => [(3, 2, 4, 5, Amount::Argument)],
+    // Stake from the wallet to the stake pool.
+    DepositStakeV1Builder => [(3, 2, 4, 5, Amount::Argument)],
+    // Bond from the disputer to the bond escrow.
+    DisputeAssertionV1Builder => [(6, 5, 7, 8, Amount::Bond(2))],
+    // Fees from the treasury to the destination.
+    WithdrawFeesV1Builder => [(1, 2, 3, 1, Amount::Argument)],
+    // Stake from the stake pool to the wallet.
+    WithdrawStakeV1Builder => [(4, 2, 3, 0, Amount::Argument)],
+}
+
+/// Resolves the accounts required by the transfer hooks of the mints of the given transfers.
+fn transfer_hook_accounts<F>(
+    instruction: &Instruction,
+    transfers: &[Transfer],
+    mut get_account_data: F,
+) -> Result<Vec<AccountMeta>, TransferHookError>
+where
+    F: FnMut(&Pubkey) -> Option<Vec<u8>>,
+{
+    let key = |index: usize| instruction.accounts[index].pubkey;
+
+    let mut accounts = Vec::new();
+
+    for transfer in transfers {
+        let Some(amount) = transfer_amount(instruction, transfer, &mut get_account_data)? else {
+            continue;
+        };
+
+        let mint = key(transfer.mint);
+        let mint_data = get_account_data(&mint).ok_or(TransferHookError::AccountNotFound(mint))?;
+
+        let Some(program_id) = transfer_hook_program_id(&mint_data)
+            .map_err(|_| TransferHookError::InvalidAccount(mint))?
+        else {
+            continue;
+        };
+
+        add_account(&mut accounts, AccountMeta::new_readonly(program_id, false));
+
+        let metas_address = get_extra_account_metas_address(&mint, &program_id);
+
+        // The hook is executed without extra accounts if the hook program has not initialized
+        // the extra account metas for the mint.
+        let Some(metas_data) = get_account_data(&metas_address) else {
+            continue;
+        };
+
+        add_account(&mut accounts, AccountMeta::new_readonly(metas_address, false));
+
+        let execute = execute(
+            &program_id,
+            &key(transfer.source),
+            &mint,
+            &key(transfer.destination),
+            &key(transfer.authority),
+            &metas_address,
+            amount,
+        );
+
+        for meta in resolve_extra_accounts(execute, &metas_data, &mut get_account_data)
+            .map_err(TransferHookError::InvalidExtraAccountMetas)?
+        {
+            add_account(&mut accounts, meta);
+        }
+    }
+
+    Ok(accounts)
+}
+
+/// Returns the amount of a transfer, or `None` if the transfer is not made.
+fn transfer_amount<F>(
+    instruction: &Instruction,
+    transfer: &Transfer,
+    get_account_data: &mut F,
+) -> Result<Option<u64>, TransferHookError>
+where
+    F: FnMut(&Pubkey) -> Option<Vec<u8>>,
+{
+    let amount = match transfer.amount {
+        // The instruction data is the discriminator followed by the arguments.
+        Amount::Argument => u64::from_le_bytes(instruction.data[1..9].try_into().unwrap()),
+        Amount::Bond(index) => {
+            let request = instruction.accounts[index].pubkey;
+            let data =
+                get_account_data(&request).ok_or(TransferHookError::AccountNotFound(request))?;
+
+            RequestV1::from_bytes(&data)
+                .map_err(|_| TransferHookError::InvalidAccount(request))?
+                .bond
+        }
+        Amount::Balance => {
+            let source = instruction.accounts[transfer.source].pubkey;
+            let Some(data) = get_account_data(&source) else {
+                return Ok(None);
+            };
+
+            StateWithExtensions::<Account>::unpack(&data)
+                .map_err(|_| TransferHookError::InvalidAccount(source))?
+                .base
+                .amount
+        }
+    };
+
+    Ok(Some(amount))
+}
+
+/// Returns the transfer hook program of a mint, if the mint has the transfer hook extension.
+fn transfer_hook_program_id(mint_data: &[u8]) -> Result<Option<Pubkey>, ProgramError> {
+    let mint = StateWithExtensions::<Mint>::unpack(mint_data)?;
+
+    Ok(transfer_hook::get_program_id(&mint))
+}
+
+/// Resolves the extra accounts listed for the `Execute` instruction of a transfer hook.
+fn resolve_extra_accounts<F>(
+    mut execute: Instruction,
+    metas_data: &[u8],
+    get_account_data: &mut F,
+) -> Result<Vec<AccountMeta>, ProgramError>
+where
+    F: FnMut(&Pubkey) -> Option<Vec<u8>>,
+{
+    let state = TlvStateBorrowed::unpack(metas_data)?;
+    let metas = ExtraAccountMetaList::unpack_with_tlv_state::<ExecuteInstruction>(&state)?;
+
+    // Extra accounts may be derived from the data of any preceding account of the instruction.
+    let mut account_data: Vec<_> =
+        execute.accounts.iter().map(|meta| (meta.pubkey, get_account_data(&meta.pubkey))).collect();
+    let base_accounts = account_data.len();
+
+    for extra_meta in metas.data() {
+        let meta = extra_meta.resolve(&execute.data, &execute.program_id, |index| {
+            account_data.get(index).map(|(pubkey, data)| (pubkey, data.as_deref()))
+        })?;
+
+        account_data.push((meta.pubkey, get_account_data(&meta.pubkey)));
+        execute.accounts.push(meta);
+    }
+
+    Ok(execute.accounts.split_off(base_accounts))
+}
+
+/// Adds an account, merging the privileges of duplicate accounts.
+fn add_account(accounts: &mut Vec<AccountMeta>, meta: AccountMeta) {
+    match accounts.iter_mut().find(|account| account.pubkey == meta.pubkey) {
+        Some(account) => {
+            account.is_signer |= meta.is_signer;
+            account.is_writable |= meta.is_writable;
+        }
+        None => accounts.push(meta),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use solana_program::program_option::COption;
+    use solana_program::program_pack::Pack;
+    use spl_tlv_account_resolution::account::ExtraAccountMeta;
+    use spl_tlv_account_resolution::pubkey_data::PubkeyData;
+    use spl_tlv_account_resolution::seeds::Seed;
+    use spl_token_2022::extension::transfer_hook::TransferHook;
+    use spl_token_2022::extension::{
+        BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut,
+    };
+    use spl_token_2022::state::AccountState;
+
+    use super::*;
+
+    fn mint_data(program_id: Option<Pubkey>) -> Vec<u8> {
+        let Some(program_id) = program_id else {
+            let mut data = vec![0; Mint::LEN];
+            Mint { is_initialized: true, ..Mint::default() }.pack_into_slice(&mut data);
+            return data;
+        };
+
+        let len = ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::TransferHook])
+            .unwrap();
+        let mut data = vec![0; len];
+
+        let mut mint = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+        let extension = mint.init_extension::<TransferHook>(true).unwrap();
+        extension.program_id = Some(program_id).try_into().unwrap();
+
+        mint.base = Mint { is_initialized: true, ..Mint::default() };
+        mint.pack_base();
+        mint.init_account_type().unwrap();
+
+        data
+    }
+
+    fn token_account_data(mint: Pubkey, amount: u64) -> Vec<u8> {
+        let mut data = vec![0; Account::LEN];
+        Account {
+            mint,
+            owner: Pubkey::new_unique(),
+            amount,
+            delegate: COption::None,
+            state: AccountState::Initialized,
+            is_native: COption::None,
+            delegated_amount: 0,
+            close_authority: COption::None,
+        }
+        .pack_into_slice(&mut data);
+        data
+    }
+
+    fn metas_data(metas: &[ExtraAccountMeta]) -> Vec<u8> {
+        let mut data = vec![0; ExtraAccountMetaList::size_of(metas.len()).unwrap()];
+        ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, metas).unwrap();
+        data
+    }
+
+    fn create_request(mint: Pubkey, reward_source: Pubkey, reward: u64) -> CreateRequestV1Builder {
+        let mut builder = CreateRequestV1Builder::new();
+        builder
+            .config(Pubkey::new_unique())
+            .request(Pubkey::new_unique())
+            .reward_currency(Pubkey::new_unique())
+            .bond_currency(Pubkey::new_unique())
+            .reward_mint(mint)
+            .reward_source(reward_source)
+            .reward_escrow(Pubkey::new_unique())
+            .creator(Pubkey::new_unique())
+            .payer(Pubkey::new_unique())
+            .reward(reward)
+            .bond(1)
+            .timestamp(0)
+            .arbitrator(Pubkey::new_unique())
+            .dispute_window(None)
+            .voting_window(None)
+            .arbitration_window(None)
+            .nonce(None)
+            .allow_too_early(false)
+            .data(crate::types::RequestData::YesNo { question: String::new() });
+        builder
+    }
+
+    #[test]
+    fn mint_without_hook() {
+        let mint = Pubkey::new_unique();
+
+        assert_eq!(transfer_hook_program_id(&mint_data(None)), Ok(None));
+
+        let accounts = HashMap::from([(mint, mint_data(None))]);
+
+        let mut builder = create_request(mint, Pubkey::new_unique(), 1);
+        builder.add_transfer_hook_accounts(|key| accounts.get(key).cloned()).unwrap();
+        assert_eq!(builder.instruction().accounts.split_off(12), vec![]);
+
+        let resolved = create_request(mint, Pubkey::new_unique(), 1)
+            .add_transfer_hook_accounts(|_| None)
+            .map(|_| ());
+        assert_eq!(resolved, Err(TransferHookError::AccountNotFound(mint)));
+    }
+
+    #[test]
+    fn mint_without_extra_accounts() {
+        let mint = Pubkey::new_unique();
+        let program_id = Pubkey::new_unique();
+
+        assert_eq!(transfer_hook_program_id(&mint_data(Some(program_id))), Ok(Some(program_id)));
+
+        let accounts = HashMap::from([(mint, mint_data(Some(program_id)))]);
+
+        let mut builder = create_request(mint, Pubkey::new_unique(), 1);
+        builder.add_transfer_hook_accounts(|key| accounts.get(key).cloned()).unwrap();
+        assert_eq!(
+            builder.instruction().accounts.split_off(12),
+            vec![AccountMeta::new_readonly(program_id, false)],
+        );
+    }
+
+    #[test]
+    fn resolve_extra_accounts() {
+        let mint = Pubkey::new_unique();
+        let program_id = Pubkey::new_unique();
+        let metas_address = get_extra_account_metas_address(&mint, &program_id);
+
+        let reward_source = Pubkey::new_unique();
+        let fixed = Pubkey::new_unique();
+        let stored = Pubkey::new_unique();
+
+        let metas = [
+            // Fixed address.
+            ExtraAccountMeta::new_with_pubkey(&fixed, false, true).unwrap(),
+            // Derived from a literal, the source, and the amount.
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal { bytes: b"seed".to_vec() },
+                    Seed::AccountKey { index: 0 },
+                    Seed::InstructionData { index: 8, length: 8 },
+                ],
+                false,
+                false,
+            )
+            .unwrap(),
+            // Read from the data of the fixed account.
+            ExtraAccountMeta::new_with_pubkey_data(
+                &PubkeyData::AccountData { account_index: 5, data_index: 0 },
+                false,
+                false,
+            )
+            .unwrap(),
+            // Derived by the fixed account from the data of the source.
+            ExtraAccountMeta::new_external_pda_with_seeds(
+                5,
+                &[Seed::AccountData { account_index: 0, data_index: 0, length: 4 }],
+                false,
+                true,
+            )
+            .unwrap(),
+        ];
+
+        let source_data = token_account_data(mint, 1000);
+
+        let accounts = HashMap::from([
+            (mint, mint_data(Some(program_id))),
+            (metas_address, metas_data(&metas)),
+            (fixed, stored.to_bytes().to_vec()),
+            (reward_source, source_data.clone()),
+        ]);
+
+        let mut builder = create_request(mint, reward_source, 500);
+        builder.add_transfer_hook_accounts(|key| accounts.get(key).cloned()).unwrap();
+
+        let seeded = Pubkey::find_program_address(
+            &[b"seed", reward_source.as_ref(), &500u64.to_le_bytes()],
+            &program_id,
+        )
+        .0;
+        let external = Pubkey::find_program_address(&[&source_data[..4]], &fixed).0;
+
+        assert_eq!(
+            builder.instruction().accounts.split_off(12),
+            vec![
+                AccountMeta::new_readonly(program_id, false),
+                AccountMeta::new_readonly(metas_address, false),
+                AccountMeta::new(fixed, false),
+                AccountMeta::new_readonly(seeded, false),
+                AccountMeta::new_readonly(stored, false),
+                AccountMeta::new(external, false),
+            ],
+        );
+    }
+
+    #[test]
+    fn merge_duplicate_accounts() {
+        let mint = Pubkey::new_unique();
+        let program_id = Pubkey::new_unique();
+        let metas_address = get_extra_account_metas_address(&mint, &program_id);
+
+        let bond_escrow = Pubkey::new_unique();
+        let reward_escrow = Pubkey::new_unique();
+
+        // Derived from the source, so differs between the transfers.
+        let metas =
+            [ExtraAccountMeta::new_with_seeds(&[Seed::AccountKey { index: 0 }], false, false)
+                .unwrap()];
+
+        let accounts = HashMap::from([
+            (mint, mint_data(Some(program_id))),
+            (metas_address, metas_data(&metas)),
+            (bond_escrow, token_account_data(mint, 1)),
+            (reward_escrow, token_account_data(mint, 2)),
+        ]);
+
+        // The same mint is used for the bond and reward of the request.
+        let mut builder = ClaimAssertionV1Builder::new();
+        builder
+            .request(Pubkey::new_unique())
+            .assertion(Pubkey::new_unique())
+            .bond_mint(mint)
+            .bond_destination(Pubkey::new_unique())
+            .bond_escrow(bond_escrow)
+            .reward_mint(mint)
+            .reward_destination(Pubkey::new_unique())
+            .reward_escrow(reward_escrow)
+            .asserter(Pubkey::new_unique())
+            .request_rent_payer(Pubkey::new_unique())
+            .assertion_rent_payer(Pubkey::new_unique());
+        builder.add_transfer_hook_accounts(|key| accounts.get(key).cloned()).unwrap();
+
+        assert_eq!(
+            builder.instruction().accounts.split_off(13),
+            vec![
+                AccountMeta::new_readonly(program_id, false),
+                AccountMeta::new_readonly(metas_address, false),
+                AccountMeta::new_readonly(
+                    Pubkey::find_program_address(&[bond_escrow.as_ref()], &program_id).0,
+                    false,
+                ),
+                AccountMeta::new_readonly(
+                    Pubkey::find_program_address(&[reward_escrow.as_ref()], &program_id).0,
+                    false,
+                ),
+            ],
+        );
+    }
+}
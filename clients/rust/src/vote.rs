@@ -0,0 +1,15 @@
+use solana_program::hash::hashv;
+use solana_program::pubkey::Pubkey;
+
+use crate::accounts::VoteV1;
+
+impl VoteV1 {
+    /// Returns the commitment for a vote for `value` hidden by `salt`, cast with `stake` in
+    /// `voting`.
+    ///
+    /// The commitment is submitted with `CommitVoteV1`, and the same value and salt must then be
+    /// submitted with `RevealVoteV1` during the reveal window for the vote to be counted.
+    pub fn commitment(voting: &Pubkey, stake: &Pubkey, value: u64, salt: &[u8; 32]) -> [u8; 32] {
+        hashv(&[voting.as_ref(), stake.as_ref(), &value.to_le_bytes(), salt]).to_bytes()
+    }
+}
@@ -138,6 +138,41 @@ const ataPdaValueNode = (mint = "mint", owner = "owner") =>
 // Update instructions.
 kinobi.update(
   k.updateInstructionsVisitor({
+    arbitrateV1: {
+      accounts: {
+        treasury: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("treasury", "hooked"), [
+            k.pdaSeedValueNode("config", k.accountValueNode("config")),
+            k.pdaSeedValueNode("mint", k.accountValueNode("bondMint")),
+          ]),
+        },
+        arbitrator: {
+          defaultValue: k.identityValueNode(),
+        },
+      },
+    },
+    cancelRequestV1: {
+      accounts: {
+        rewardDestination: {
+          defaultValue: ataPdaValueNode("rewardMint", "creator"),
+        },
+        rewardEscrow: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("reward", "hooked"), [
+            k.pdaSeedValueNode("request", k.accountValueNode("request")),
+          ]),
+        },
+        creator: {
+          defaultValue: k.identityValueNode(),
+        },
+      },
+    },
+    changeVoteV1: {
+      accounts: {
+        voter: {
+          defaultValue: k.identityValueNode(),
+        },
+      },
+    },
     claimAssertionV1: {
       accounts: {
         bondDestination: {
@@ -184,6 +219,21 @@ kinobi.update(
         },
       },
     },
+    claimRefundV1: {
+      accounts: {
+        rewardDestination: {
+          defaultValue: ataPdaValueNode("rewardMint", "creator"),
+        },
+        rewardEscrow: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("reward", "hooked"), [
+            k.pdaSeedValueNode("request", k.accountValueNode("request")),
+          ]),
+        },
+        creator: {
+          defaultValue: k.identityValueNode(),
+        },
+      },
+    },
     claimVoteV1: {
       // TODO: Conditional bondEscrow based on whether asserter/disputer correct.
       accounts: {
@@ -195,6 +245,66 @@ kinobi.update(
         },
       },
     },
+    closeCurrencyV1: {
+      accounts: {
+        destination: {
+          defaultValue: k.identityValueNode(),
+        },
+      },
+    },
+    closeRequestV1: {
+      accounts: {
+        rewardEscrow: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("reward", "hooked"), [
+            k.pdaSeedValueNode("request", k.accountValueNode("request")),
+          ]),
+        },
+        assertBondEscrow: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("assertBond", "hooked"), [
+            k.pdaSeedValueNode("request", k.accountValueNode("request")),
+          ]),
+        },
+        disputeBondEscrow: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("disputeBond", "hooked"), [
+            k.pdaSeedValueNode("request", k.accountValueNode("request")),
+          ]),
+        },
+        treasury: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("treasury", "hooked"), [
+            k.pdaSeedValueNode("config", k.accountValueNode("config")),
+            k.pdaSeedValueNode("mint", k.accountValueNode("bondMint")),
+          ]),
+        },
+        stakePool: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("stakePool", "hooked"), [
+            k.pdaSeedValueNode("mint", k.accountValueNode("governanceMint")),
+          ]),
+        },
+        stakeTreasury: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("treasury", "hooked"), [
+            k.pdaSeedValueNode("config", k.accountValueNode("config")),
+            k.pdaSeedValueNode("mint", k.accountValueNode("governanceMint")),
+          ]),
+        },
+      },
+    },
+    closeVotingV1: {
+      accounts: {
+        treasury: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("treasury", "hooked"), [
+            k.pdaSeedValueNode("config", k.accountValueNode("config")),
+            k.pdaSeedValueNode("mint", k.accountValueNode("bondMint")),
+          ]),
+        },
+      },
+    },
+    commitVoteV1: {
+      accounts: {
+        voter: {
+          defaultValue: k.identityValueNode(),
+        },
+      },
+    },
     createAssertionV1: {
       accounts: {
         bondSource: {
@@ -255,6 +365,18 @@ kinobi.update(
         },
       },
     },
+    depositStakeV1: {
+      accounts: {
+        stakeSource: {
+          defaultValue: ataPdaValueNode("mint", "wallet"),
+        },
+        stakePool: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("stakePool", "hooked"), [
+            k.pdaSeedValueNode("mint", k.accountValueNode("mint")),
+          ]),
+        },
+      },
+    },
     disputeAssertionV1: {
       accounts: {
         bondSource: {
@@ -270,6 +392,13 @@ kinobi.update(
         },
       },
     },
+    revealVoteV1: {
+      accounts: {
+        voter: {
+          defaultValue: k.identityValueNode(),
+        },
+      },
+    },
     submitVoteV1: {
       accounts: {
         voter: {
@@ -277,6 +406,38 @@ kinobi.update(
         },
       },
     },
+    withdrawFeesV1: {
+      accounts: {
+        treasury: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("treasury", "hooked"), [
+            k.pdaSeedValueNode("config", k.accountValueNode("config")),
+            k.pdaSeedValueNode("mint", k.accountValueNode("mint")),
+          ]),
+        },
+        destination: {
+          defaultValue: ataPdaValueNode("mint", "authority"),
+        },
+      },
+    },
+    withdrawStakeV1: {
+      accounts: {
+        stakeDestination: {
+          defaultValue: ataPdaValueNode("mint", "wallet"),
+        },
+        stakePool: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("stakePool", "hooked"), [
+            k.pdaSeedValueNode("mint", k.accountValueNode("mint")),
+          ]),
+        },
+      },
+    },
+    withdrawVoteV1: {
+      accounts: {
+        voter: {
+          defaultValue: k.identityValueNode(),
+        },
+      },
+    },
   }),
 );
 
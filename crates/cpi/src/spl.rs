@@ -7,7 +7,8 @@ use solana_program::program_error::ProgramError;
 use solana_program::pubkey;
 use solana_program::pubkey::Pubkey;
 use solana_utils::invoke::{invoke, invoke_signed};
-use spl_token_2022::extension::PodStateWithExtensions;
+use spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+use spl_token_2022::extension::{BaseStateWithExtensions, ExtensionType, PodStateWithExtensions};
 use spl_token_2022::instruction::AuthorityType;
 use spl_token_2022::pod::{PodAccount, PodMint};
 
@@ -30,6 +31,9 @@ pub struct TransferChecked<'a, 'info> {
     pub mint: &'a AccountInfo<'info>,
     pub authority: &'a AccountInfo<'info>,
     pub token_program: &'a AccountInfo<'info>,
+    /// Accounts from which the extra accounts required by the transfer hook of the mint, if any,
+    /// are resolved.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
 }
 
 pub struct CloseAccount<'a, 'info> {
@@ -86,26 +90,26 @@ pub fn create_token_account(
 }
 
 /// Transfers tokens from the source account to the destination account.
+///
+/// If the mint has the transfer hook extension, the hook program, its extra account metas account
+/// and the extra accounts it requires must be included in the remaining accounts.
 pub fn transfer_checked(
     amount: u64,
     decimals: u8,
     accounts: TransferChecked,
     signers_seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let TransferChecked { source, destination, mint, authority, token_program } = accounts;
+    let TransferChecked { source, destination, mint, authority, token_program, .. } = accounts;
 
-    invoke_signed(
-        &spl_token_2022::instruction::transfer_checked(
-            token_program.key,
-            source.key,
-            mint.key,
-            destination.key,
-            authority.key,
-            &[],
-            amount,
-            decimals,
-        )?,
-        &[source.clone(), mint.clone(), destination.clone(), authority.clone()],
+    spl_token_2022::onchain::invoke_transfer_checked(
+        token_program.key,
+        source.clone(),
+        mint.clone(),
+        destination.clone(),
+        authority.clone(),
+        accounts.remaining_accounts,
+        amount,
+        decimals,
         signers_seeds,
     )?;
 
@@ -205,6 +209,34 @@ pub fn mint_decimals(mint: &AccountInfo<'_>) -> Result<u8, ProgramError> {
     Ok(mint.base.decimals)
 }
 
+/// Gets the extension types of the mint, which is empty for mints of the original token program.
+pub fn mint_extension_types(mint: &AccountInfo<'_>) -> Result<Vec<ExtensionType>, ProgramError> {
+    let data = mint.data.borrow();
+    let mint = PodStateWithExtensions::<PodMint>::unpack(*data)?;
+
+    mint.get_extension_types()
+}
+
+/// Gets the transfer fee of the mint in basis points, if the mint has the transfer fee extension.
+///
+/// The larger of the older and newer transfer fees is returned, so that a fee scheduled for a
+/// future epoch is included.
+pub fn mint_transfer_fee_bps(mint: &AccountInfo<'_>) -> Result<Option<u16>, ProgramError> {
+    let data = mint.data.borrow();
+    let mint = PodStateWithExtensions::<PodMint>::unpack(*data)?;
+
+    if !mint.get_extension_types()?.contains(&ExtensionType::TransferFeeConfig) {
+        return Ok(None);
+    }
+
+    let config = mint.get_extension::<TransferFeeConfig>()?;
+
+    let older_bps = u16::from(config.older_transfer_fee.transfer_fee_basis_points);
+    let newer_bps = u16::from(config.newer_transfer_fee.transfer_fee_basis_points);
+
+    Ok(Some(older_bps.max(newer_bps)))
+}
+
 /// Gets the amount of tokens in the account.
 pub fn account_amount(account: &AccountInfo<'_>) -> Result<u64, ProgramError> {
     let data = account.data.borrow();
@@ -215,3 +247,11 @@ pub fn account_amount(account: &AccountInfo<'_>) -> Result<u64, ProgramError> {
 
     Ok(amount)
 }
+
+/// Gets the owner of the token account.
+pub fn account_owner(account: &AccountInfo<'_>) -> Result<Pubkey, ProgramError> {
+    let data = account.data.borrow();
+    let account = PodStateWithExtensions::<PodAccount>::unpack(*data)?;
+
+    Ok(account.base.owner)
+}
@@ -98,6 +98,114 @@ pub enum OracleError {
 
     #[error("Value voted for is not the resolved value")]
     IncorrectVote,
+
+    #[error("Stake owner address does not match")]
+    StakeOwnerMismatch,
+
+    #[error("Stake is locked")]
+    StakeLocked,
+
+    #[error("Insufficient stake")]
+    InsufficientStake,
+
+    #[error("Cannot merge a stake account into itself")]
+    StakeMergeSelf,
+
+    #[error("Arbitrator address does not match")]
+    ArbitratorMismatch,
+
+    #[error("Arbitration window has expired")]
+    ArbitrationWindowExpired,
+
+    #[error("Request data is not valid")]
+    InvalidRequestData,
+
+    #[error("Creator address does not match")]
+    CreatorMismatch,
+
+    #[error("Request has unclaimed bond or reward")]
+    RequestNotSettled,
+
+    #[error("Claim window has not expired")]
+    ClaimWindowNotExpired,
+
+    #[error("Rent payer address does not match")]
+    RentPayerMismatch,
+
+    #[error("Reward pool has no unclaimed votes remaining")]
+    RewardPoolExhausted,
+
+    #[error("Slashing is not enabled for the config")]
+    SlashingDisabled,
+
+    #[error("Value voted for is the resolved value")]
+    CorrectVote,
+
+    #[error("Votes must be committed and then revealed")]
+    CommitRevealRequired,
+
+    #[error("Commit-reveal voting is not enabled")]
+    CommitRevealDisabled,
+
+    #[error("Reveal window is not open")]
+    RevealWindowNotOpen,
+
+    #[error("Vote has already been revealed")]
+    VoteAlreadyRevealed,
+
+    #[error("Revealed value and salt do not match commitment")]
+    CommitmentMismatch,
+
+    #[error("Vote has not been revealed")]
+    VoteNotRevealed,
+
+    #[error("Stake has already voted")]
+    AlreadyVoted,
+
+    #[error("Vote is from a previous round of the request")]
+    VoteRoundMismatch,
+
+    #[error("Window must be within valid bounds")]
+    WindowBounds,
+
+    #[error("Mint transfer fee is not supported")]
+    TransferFeeNotSupported,
+
+    #[error("Escrow did not receive the full amount")]
+    EscrowAmountMismatch,
+
+    #[error("Bounds must not be empty")]
+    EmptyBounds,
+
+    #[error("Mint extension is not supported")]
+    MintExtensionNotSupported,
+
+    #[error("Currency is disabled")]
+    CurrencyDisabled,
+
+    #[error("Stake has open votes")]
+    StakeHasOpenVotes,
+
+    #[error("Insufficient fees")]
+    InsufficientFees,
+
+    #[error("Voting has open votes")]
+    VotingHasOpenVotes,
+
+    #[error("Slash window expired")]
+    SlashWindowExpired,
+
+    #[error("Slash window not expired")]
+    SlashWindowNotExpired,
+
+    #[error("Request cannot be resolved as too early")]
+    TooEarlyNotAllowed,
+
+    #[error("Token account owner does not match")]
+    TokenOwnerMismatch,
+
+    #[error("Request has been cancelled")]
+    RequestCancelled,
 }
 
 impl PrintProgramError for OracleError {
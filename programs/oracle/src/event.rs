@@ -0,0 +1,64 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::log::sol_log_data;
+use solana_program::pubkey::Pubkey;
+use solana_utils::log;
+
+use crate::error::OracleError;
+
+/// Events emitted by the program on state transitions.
+///
+/// Events are borsh serialized and logged with `sol_log_data`, so they appear in the transaction
+/// logs as `Program data: <base64>` messages.
+#[derive(Clone, BorshDeserialize, BorshSerialize)]
+pub enum OracleEvent {
+    /// A request was created.
+    ///
+    /// The nonce is set if the request address is derived from the creator, in which case the
+    /// request is not indexed by the oracle and the index is zero.
+    RequestCreated {
+        request: Pubkey,
+        index: u64,
+        config: Pubkey,
+        creator: Pubkey,
+        nonce: Option<u64>,
+    },
+    /// A value was asserted for a request.
+    Asserted { request: Pubkey, asserter: Pubkey, value: u64 },
+    /// The assertion for a request was disputed.
+    Disputed { request: Pubkey, disputer: Pubkey },
+    /// A vote was cast on a disputed request.
+    VoteCast { request: Pubkey, stake: Pubkey, value: u64, votes: u64 },
+    /// A request was resolved.
+    Resolved { request: Pubkey, value: u64 },
+    /// Bond and reward tokens were claimed from a resolved request.
+    Claimed { request: Pubkey, claimer: Pubkey, bond: u64, reward: u64 },
+    /// A request was cancelled by its creator.
+    Cancelled { request: Pubkey },
+    /// A settled request was closed.
+    Closed { request: Pubkey },
+    /// The stake of a vote for a value other than the resolved value was slashed.
+    Slashed { request: Pubkey, stake: Pubkey, amount: u64 },
+    /// A hidden vote was committed on a disputed request, to be revealed later.
+    VoteCommitted { request: Pubkey, stake: Pubkey, votes: u64 },
+    /// A vote on a disputed request was changed to another value.
+    VoteChanged { request: Pubkey, stake: Pubkey, old_value: u64, value: u64, votes: u64 },
+    /// A vote on a disputed request was withdrawn.
+    VoteWithdrawn { request: Pubkey, stake: Pubkey, votes: u64 },
+    /// A request resolved as too early was reset to accept a new assertion.
+    Reset { request: Pubkey },
+}
+
+impl OracleEvent {
+    /// Logs the serialized event.
+    pub fn emit(&self) -> ProgramResult {
+        let data = borsh::to_vec(self).map_err(|err| {
+            log!("Error: Event serialization failed: {err}");
+            OracleError::SerializationError
+        })?;
+
+        sol_log_data(&[&data]);
+
+        Ok(())
+    }
+}
@@ -47,7 +47,7 @@ pub enum OracleInstruction {
     UpdateCurrencyV1(UpdateCurrencyV1Args),
 
     /// Creates a new request.
-    #[account(0, writable, name = "oracle", desc = "Oracle")]
+    #[account(0, optional, writable, name = "oracle", desc = "Oracle (required without a nonce)")]
     #[account(1, name = "config", desc = "Config")]
     #[account(2, writable, name = "request", desc = "Request")]
     #[account(3, name = "reward_currency", desc = "Reward currency")]
@@ -100,7 +100,7 @@ pub enum OracleInstruction {
     #[account(1, name = "request", desc = "Request")]
     #[account(2, writable, name = "voting", desc = "Voting")]
     #[account(3, writable, name = "vote", desc = "Vote")]
-    #[account(4, name = "stake", desc = "Stake")]
+    #[account(4, writable, name = "stake", desc = "Stake")]
     #[account(5, signer, name = "voter", desc = "Voter")]
     #[account(6, signer, writable, name = "payer", desc = "Payer")]
     #[account(7, name = "system_program", desc = "System program")]
@@ -110,10 +110,18 @@ pub enum OracleInstruction {
     #[account(0, name = "config", desc = "Config")]
     #[account(1, writable, name = "request", desc = "Request")]
     #[account(2, writable, name = "voting", desc = "Voting")]
+    #[account(3, name = "oracle", desc = "Oracle")]
+    #[account(4, name = "assertion", desc = "Assertion")]
+    #[account(5, name = "bond_mint", desc = "Bond mint")]
+    #[account(6, writable, name = "bond_escrow", desc = "Bond escrow token account of incorrect asserter/disputer")]
+    #[account(7, writable, name = "treasury", desc = "Fee treasury token account")]
+    #[account(8, signer, writable, name = "payer", desc = "Payer")]
+    #[account(9, name = "token_program", desc = "SPL token program")]
+    #[account(10, name = "system_program", desc = "System program")]
     CloseVotingV1,
 
     /// Creates a stake account.
-    #[account(0, name = "oracle", desc = "Oracle")]
+    #[account(0, writable, name = "oracle", desc = "Oracle")]
     #[account(1, signer, writable, name = "stake", desc = "Stake")]
     #[account(2, writable, name = "mint", desc = "Stake")]
     #[account(3, writable, name = "stake_source", desc = "Stake source token account")]
@@ -132,34 +140,215 @@ pub enum OracleInstruction {
     #[account(5, name = "reward_mint", desc = "Reward mint")]
     #[account(6, writable, name = "reward_destination", desc = "Reward destination token account")]
     #[account(7, writable, name = "reward_escrow", desc = "Reward escrow token account")]
-    #[account(8, signer, writable, name = "asserter", desc = "Asserter")]
-    #[account(9, name = "token_program", desc = "SPL token program")]
-    #[account(10, name = "system_program", desc = "System program")]
+    #[account(8, signer, name = "asserter", desc = "Asserter")]
+    #[account(9, writable, name = "request_rent_payer", desc = "Request rent payer")]
+    #[account(10, writable, name = "assertion_rent_payer", desc = "Assertion rent payer")]
+    #[account(11, name = "token_program", desc = "SPL token program")]
+    #[account(12, name = "system_program", desc = "System program")]
     ClaimAssertionV1,
 
     #[account(0, name = "request", desc = "Request")]
     #[account(1, writable, name = "assertion", desc = "Assertion")]
-    #[account(2, name = "bond_mint", desc = "Bond mint")]
-    #[account(3, writable, name = "bond_destination", desc = "Reclaimed bond destination token account")]
-    #[account(4, writable, name = "bond_escrow", desc = "Disputer bond escrow token account")]
-    #[account(5, name = "reward_mint", desc = "Reward mint")]
-    #[account(6, writable, name = "reward_destination", desc = "Reward destination token account")]
-    #[account(7, writable, name = "reward_escrow", desc = "Reward escrow token account")]
-    #[account(8, signer, writable, name = "disputer", desc = "Disputer")]
-    #[account(9, name = "token_program", desc = "SPL token program")]
-    #[account(10, name = "system_program", desc = "System program")]
+    #[account(2, name = "voting", desc = "Voting")]
+    #[account(3, name = "bond_mint", desc = "Bond mint")]
+    #[account(4, writable, name = "bond_destination", desc = "Reclaimed bond destination token account")]
+    #[account(5, writable, name = "bond_escrow", desc = "Disputer bond escrow token account")]
+    #[account(6, name = "reward_mint", desc = "Reward mint")]
+    #[account(7, writable, name = "reward_destination", desc = "Reward destination token account")]
+    #[account(8, writable, name = "reward_escrow", desc = "Reward escrow token account")]
+    #[account(9, signer, name = "disputer", desc = "Disputer")]
+    #[account(10, writable, name = "request_rent_payer", desc = "Request rent payer")]
+    #[account(11, writable, name = "voting_rent_payer", desc = "Voting rent payer")]
+    #[account(12, name = "token_program", desc = "SPL token program")]
+    #[account(13, name = "system_program", desc = "System program")]
     ClaimDisputeV1,
 
     #[account(0, name = "request", desc = "Request")]
     #[account(1, name = "assertion", desc = "Assertion")]
-    #[account(2, name = "voting", desc = "Voting")]
+    #[account(2, writable, name = "voting", desc = "Voting")]
     #[account(3, writable, name = "vote", desc = "Vote")]
-    #[account(4, name = "stake", desc = "Stake")]
-    #[account(5, name = "bond_mint", desc = "Bond mint")]
-    #[account(6, writable, name = "bond_destination", desc = "Bond destination token account")]
-    #[account(7, writable, name = "bond_escrow", desc = "Bond escrow token account of incorrect asserter/disputer")]
-    #[account(8, signer, writable, name = "voter", desc = "Voter")]
+    #[account(4, writable, name = "stake", desc = "Stake")]
+    #[account(5, writable, name = "oracle", desc = "Oracle")]
+    #[account(6, name = "bond_mint", desc = "Bond mint")]
+    #[account(7, writable, name = "bond_destination", desc = "Bond destination token account")]
+    #[account(8, writable, name = "bond_escrow", desc = "Bond escrow token account of incorrect asserter/disputer")]
+    #[account(9, signer, name = "voter", desc = "Voter")]
+    #[account(10, writable, name = "vote_rent_payer", desc = "Vote rent payer")]
+    #[account(11, name = "token_program", desc = "SPL token program")]
+    #[account(12, name = "system_program", desc = "System program")]
+    ClaimVoteV1,
+
+    /// Withdraws unlocked stake, closing the stake account when emptied.
+    #[account(0, writable, name = "oracle", desc = "Oracle")]
+    #[account(1, writable, name = "stake", desc = "Stake")]
+    #[account(2, name = "mint", desc = "Stake mint")]
+    #[account(3, writable, name = "stake_destination", desc = "Stake destination token account")]
+    #[account(4, writable, name = "stake_pool", desc = "Stake pool token account")]
+    #[account(5, signer, writable, name = "wallet", desc = "Stake owner")]
+    #[account(6, name = "token_program", desc = "SPL token program")]
+    WithdrawStakeV1(WithdrawStakeV1Args),
+
+    /// Deposits additional tokens into an existing stake account.
+    #[account(0, writable, name = "oracle", desc = "Oracle")]
+    #[account(1, writable, name = "stake", desc = "Stake")]
+    #[account(2, name = "mint", desc = "Stake mint")]
+    #[account(3, writable, name = "stake_source", desc = "Stake source token account")]
+    #[account(4, writable, name = "stake_pool", desc = "Stake pool token account")]
+    #[account(5, signer, name = "wallet", desc = "Stake source token account authority")]
+    #[account(6, name = "token_program", desc = "SPL token program")]
+    DepositStakeV1(DepositStakeV1Args),
+
+    /// Merges a stake account into another stake account with the same owner.
+    #[account(0, writable, name = "stake", desc = "Stake merged into")]
+    #[account(1, writable, name = "source_stake", desc = "Stake merged from, closed after merging")]
+    #[account(2, signer, writable, name = "wallet", desc = "Stake owner")]
+    MergeStakeV1,
+
+    /// Sets the delegate of a stake account.
+    #[account(0, writable, name = "stake", desc = "Stake")]
+    #[account(1, signer, name = "wallet", desc = "Stake owner")]
+    SetStakeDelegateV1(SetStakeDelegateV1Args),
+
+    /// Resolves a disputed request with the arbitrator's value during the arbitration window.
+    #[account(0, name = "config", desc = "Config")]
+    #[account(1, writable, name = "request", desc = "Request")]
+    #[account(2, writable, name = "voting", desc = "Voting")]
+    #[account(3, name = "assertion", desc = "Assertion")]
+    #[account(4, name = "bond_mint", desc = "Bond mint")]
+    #[account(5, writable, name = "bond_escrow", desc = "Bond escrow token account of incorrect asserter/disputer")]
+    #[account(6, writable, name = "treasury", desc = "Fee treasury token account")]
+    #[account(7, signer, name = "arbitrator", desc = "Arbitrator")]
+    #[account(8, signer, writable, name = "payer", desc = "Payer")]
     #[account(9, name = "token_program", desc = "SPL token program")]
     #[account(10, name = "system_program", desc = "System program")]
-    ClaimVoteV1,
+    ArbitrateV1(ArbitrateV1Args),
+
+    /// Withdraws an amount of the fees collected in a config treasury.
+    #[account(0, name = "config", desc = "Config")]
+    #[account(1, writable, name = "treasury", desc = "Fee treasury token account")]
+    #[account(2, name = "mint", desc = "Fee mint")]
+    #[account(3, writable, name = "destination", desc = "Fee destination token account")]
+    #[account(4, signer, name = "authority", desc = "Config authority")]
+    #[account(5, name = "token_program", desc = "SPL token program")]
+    WithdrawFeesV1(WithdrawFeesV1Args),
+
+    /// Cancels a request which has not been asserted, refunding the reward to the creator.
+    #[account(0, writable, name = "request", desc = "Request")]
+    #[account(1, name = "reward_mint", desc = "Reward mint")]
+    #[account(2, writable, name = "reward_destination", desc = "Reward destination token account")]
+    #[account(3, writable, name = "reward_escrow", desc = "Reward escrow token account")]
+    #[account(4, signer, name = "creator", desc = "Creator")]
+    #[account(5, writable, name = "request_rent_payer", desc = "Request rent payer")]
+    #[account(6, name = "token_program", desc = "SPL token program")]
+    CancelRequestV1,
+
+    /// Closes a settled request with its assertion and voting accounts, refunding rent.
+    ///
+    /// Voter rewards and slashed stake which remain unclaimed after the claim window are swept to
+    /// the treasury. The reward of a request resolved as invalid is refunded to the creator if it
+    /// has not been claimed. A request resolved as too early is reset to accept a new assertion,
+    /// instead of being closed.
+    #[account(0, name = "config", desc = "Config")]
+    #[account(1, writable, name = "request", desc = "Request")]
+    #[account(2, writable, name = "assertion", desc = "Assertion")]
+    #[account(3, writable, name = "voting", desc = "Voting")]
+    #[account(4, name = "bond_mint", desc = "Bond mint")]
+    #[account(5, name = "reward_mint", desc = "Reward mint")]
+    #[account(6, writable, name = "reward_escrow", desc = "Reward escrow token account")]
+    #[account(7, writable, name = "reward_destination", desc = "Creator reward token account")]
+    #[account(8, writable, name = "assert_bond_escrow", desc = "Asserter bond escrow token account")]
+    #[account(9, writable, name = "dispute_bond_escrow", desc = "Disputer bond escrow token account")]
+    #[account(10, writable, name = "treasury", desc = "Fee treasury token account")]
+    #[account(11, writable, name = "request_rent_payer", desc = "Request rent payer")]
+    #[account(12, writable, name = "assertion_rent_payer", desc = "Assertion rent payer")]
+    #[account(13, writable, name = "voting_rent_payer", desc = "Voting rent payer")]
+    #[account(14, name = "oracle", desc = "Oracle")]
+    #[account(15, name = "governance_mint", desc = "Governance token mint")]
+    #[account(16, writable, name = "stake_pool", desc = "Stake pool token account")]
+    #[account(17, writable, name = "stake_treasury", desc = "Fee treasury token account for the governance mint")]
+    #[account(18, signer, writable, name = "payer", desc = "Payer")]
+    #[account(19, name = "token_program", desc = "SPL token program")]
+    #[account(20, name = "system_program", desc = "System program")]
+    CloseRequestV1,
+
+    /// Slashes the stake of a vote for a value other than the resolved value, adding the slashed
+    /// stake to the voter reward pool.
+    #[account(0, name = "config", desc = "Config")]
+    #[account(1, name = "request", desc = "Request")]
+    #[account(2, writable, name = "voting", desc = "Voting")]
+    #[account(3, writable, name = "vote", desc = "Vote")]
+    #[account(4, writable, name = "stake", desc = "Stake")]
+    #[account(5, writable, name = "oracle", desc = "Oracle")]
+    #[account(6, writable, name = "vote_rent_payer", desc = "Vote rent payer")]
+    SlashVoteV1,
+
+    /// Commits a hidden vote for resolving a disputed assertion, if commit-reveal voting is
+    /// enabled.
+    #[account(0, name = "config", desc = "Config")]
+    #[account(1, name = "request", desc = "Request")]
+    #[account(2, writable, name = "voting", desc = "Voting")]
+    #[account(3, writable, name = "vote", desc = "Vote")]
+    #[account(4, writable, name = "stake", desc = "Stake")]
+    #[account(5, signer, name = "voter", desc = "Voter")]
+    #[account(6, signer, writable, name = "payer", desc = "Payer")]
+    #[account(7, name = "system_program", desc = "System program")]
+    CommitVoteV1(CommitVoteV1Args),
+
+    /// Reveals a committed vote during the reveal window, counting the votes.
+    #[account(0, name = "request", desc = "Request")]
+    #[account(1, writable, name = "voting", desc = "Voting")]
+    #[account(2, writable, name = "vote", desc = "Vote")]
+    #[account(3, name = "stake", desc = "Stake")]
+    #[account(4, signer, name = "voter", desc = "Voter")]
+    #[account(5, signer, writable, name = "payer", desc = "Payer")]
+    #[account(6, name = "system_program", desc = "System program")]
+    RevealVoteV1(RevealVoteV1Args),
+
+    /// Changes the value of a vote before the voting window ends.
+    #[account(0, name = "request", desc = "Request")]
+    #[account(1, writable, name = "voting", desc = "Voting")]
+    #[account(2, writable, name = "vote", desc = "Vote")]
+    #[account(3, name = "stake", desc = "Stake")]
+    #[account(4, signer, name = "voter", desc = "Voter")]
+    #[account(5, signer, writable, name = "payer", desc = "Payer")]
+    #[account(6, name = "system_program", desc = "System program")]
+    ChangeVoteV1(ChangeVoteV1Args),
+
+    /// Withdraws a vote before the voting window ends, closing the vote account.
+    #[account(0, name = "request", desc = "Request")]
+    #[account(1, writable, name = "voting", desc = "Voting")]
+    #[account(2, writable, name = "vote", desc = "Vote")]
+    #[account(3, writable, name = "stake", desc = "Stake")]
+    #[account(4, signer, name = "voter", desc = "Voter")]
+    #[account(5, writable, name = "vote_rent_payer", desc = "Vote rent payer")]
+    WithdrawVoteV1,
+
+    /// Refunds the reward to the creator of a request resolved as invalid.
+    #[account(0, name = "request", desc = "Request")]
+    #[account(1, name = "assertion", desc = "Assertion")]
+    #[account(2, name = "reward_mint", desc = "Reward mint")]
+    #[account(3, writable, name = "reward_destination", desc = "Reward destination token account")]
+    #[account(4, writable, name = "reward_escrow", desc = "Reward escrow token account")]
+    #[account(5, signer, name = "creator", desc = "Creator")]
+    #[account(6, writable, name = "request_rent_payer", desc = "Request rent payer")]
+    #[account(7, name = "token_program", desc = "SPL token program")]
+    ClaimRefundV1,
+
+    /// Closes a currency, so that it cannot be used for new requests.
+    #[account(0, name = "config", desc = "Config")]
+    #[account(1, writable, name = "currency", desc = "Currency")]
+    #[account(2, signer, name = "authority", desc = "Config authority")]
+    #[account(3, writable, name = "destination", desc = "Currency rent destination")]
+    CloseCurrencyV1,
+
+    /// Closes a vote once the claim window of the resolved request has expired, refunding rent
+    /// to the vote rent payer. Rewards for the vote can no longer be claimed.
+    ///
+    /// A vote from a previous round of a request reset as too early can be closed at any time.
+    #[account(0, name = "request", desc = "Request")]
+    #[account(1, writable, name = "voting", desc = "Voting")]
+    #[account(2, writable, name = "vote", desc = "Vote")]
+    #[account(3, writable, name = "stake", desc = "Stake")]
+    #[account(4, writable, name = "vote_rent_payer", desc = "Vote rent payer")]
+    CloseVoteV1,
 }
@@ -7,9 +7,11 @@ mod utils;
 #[cfg(not(feature = "no-entrypoint"))]
 pub mod entrypoint;
 pub mod error;
+pub mod event;
 pub mod instruction;
 pub mod pda;
 pub mod processor;
+pub mod settlement;
 pub mod state;
 
 #[cfg(not(feature = "no-entrypoint"))]
@@ -65,8 +65,10 @@ pdas! {
 
     "Currency": currency(config: Pubkey, mint: Pubkey);
     "Stake pool": stake_pool(mint: Pubkey);
+    "Treasury": treasury(config: Pubkey, mint: Pubkey);
 
     "Request": request(index: u64);
+    "Creator request": creator_request(creator: Pubkey, nonce: u64);
     "Reward": reward(request: Pubkey);
 
     "Assertion": assertion(request: Pubkey);
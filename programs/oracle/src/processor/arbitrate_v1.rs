@@ -0,0 +1,185 @@
+use borsh::BorshDeserialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+
+use crate::error::OracleError;
+use crate::event::OracleEvent;
+use crate::instruction::accounts::ArbitrateV1Accounts;
+use crate::state::{
+    Account, AccountSized, AssertionV1, RequestSigner, RequestState, RequestV1, VotingV1,
+};
+use crate::{pda, settlement, utils};
+
+#[derive(Clone, BorshDeserialize)]
+pub struct ArbitrateV1Args {
+    /// Value to resolve the request with.
+    pub value: u64,
+}
+
+pub fn arbitrate_v1<'a>(
+    _program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    args: ArbitrateV1Args,
+) -> ProgramResult {
+    let ctx = ArbitrateV1Accounts::context(accounts)?;
+
+    // Guard signatures.
+    utils::assert_signer(ctx.accounts.arbitrator)?;
+    utils::assert_signer(ctx.accounts.payer)?;
+
+    // Guard programs.
+    utils::assert_token_program(ctx.accounts.token_program.key)?;
+    utils::assert_system_program(ctx.accounts.system_program.key)?;
+
+    let request = RequestV1::from_account_info_mut(ctx.accounts.request)?;
+    let request_signer: RequestSigner;
+
+    // Step 1: Check the request is awaiting resolution by voting.
+    {
+        // Guard request.
+        request_signer = request.signer(request.assert_pda(ctx.accounts.request.key)?);
+        request.assert_config(ctx.accounts.config.key)?;
+        request.assert_bond_mint(ctx.accounts.bond_mint.key)?;
+        request.assert_arbitrator(ctx.accounts.arbitrator.key)?;
+
+        // If the request state is not `Disputed`,
+        // then the voting must have ended and resolved the request.
+        if request.state != RequestState::Disputed {
+            return Err(OracleError::NotDisputed.into());
+        }
+
+        request.validate_value(args.value)?;
+    }
+
+    // Guard voting PDA.
+    pda::voting::assert_pda(ctx.accounts.voting.key, ctx.accounts.request.key)?;
+
+    let now = Clock::get()?.unix_timestamp;
+
+    let voting = VotingV1::from_account_info_mut(ctx.accounts.voting)?;
+
+    // Step 2: Check the arbitration window is open.
+    {
+        // The arbitration window starts once voting has ended, including the reveal window.
+        let voting_end_timestamp = voting.reveal_end_timestamp()?;
+
+        if now < voting_end_timestamp {
+            return Err(OracleError::VotingWindowNotExpired.into());
+        }
+
+        let end_timestamp =
+            checked_add!(voting_end_timestamp, i64::from(request.arbitration_window))?;
+
+        if now >= end_timestamp {
+            return Err(OracleError::ArbitrationWindowExpired.into());
+        }
+    }
+
+    // Guard assertion PDA.
+    pda::assertion::assert_pda(ctx.accounts.assertion.key, ctx.accounts.request.key)?;
+
+    let assertion = AssertionV1::from_account_info(ctx.accounts.assertion)?;
+
+    // Step 3: Resolve the request with the arbitrated value.
+    settlement::resolve_dispute(
+        request,
+        voting,
+        &assertion,
+        args.value,
+        now,
+        &request_signer,
+        utils::TransferBondFee {
+            config: ctx.accounts.config,
+            request: ctx.accounts.request,
+            bond_mint: ctx.accounts.bond_mint,
+            bond_escrow: ctx.accounts.bond_escrow,
+            treasury: ctx.accounts.treasury,
+            payer: ctx.accounts.payer,
+            token_program: ctx.accounts.token_program,
+            system_program: ctx.accounts.system_program,
+            remaining_accounts: ctx.remaining_accounts,
+        },
+    )?;
+
+    OracleEvent::Resolved { request: *ctx.accounts.request.key, value: args.value }.emit()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::instruction::AccountMeta;
+    use solana_program::system_program;
+
+    use super::*;
+    use crate::processor::tests::{request_params, TestContext};
+    use crate::state::{InitAssertion, RequestParams};
+
+    #[test]
+    fn arbitrate_with_request_parameters() {
+        let mut context = TestContext::new();
+        let request = context
+            .create_disputed_request(RequestParams { bond_fee_bps: 1000, ..request_params() });
+
+        let bond_mint = context.create_mint(6);
+        let arbitrator = context.create_wallet();
+
+        let mut state = context.state::<RequestV1>(&request.request);
+        state.bond = 100;
+        state.bond_mint = bond_mint;
+        state.arbitrator = arbitrator;
+        context.set_state(request.request, &state);
+
+        let (assertion, _) = <(AssertionV1, usize)>::try_from(InitAssertion {
+            request: request.request,
+            assertion_timestamp: context.now,
+            asserter: Pubkey::new_unique(),
+            rent_payer: Pubkey::new_unique(),
+            asserted_value: 1,
+            dispute_window: 0,
+        })
+        .unwrap();
+
+        context.set_state(pda::assertion::pda(&request.request).0, &assertion);
+
+        let (bond_escrow, _) = pda::dispute_bond::pda(&request.request);
+        context.set_token_account(bond_escrow, &bond_mint, &request.request, 100);
+
+        let (treasury, _) = pda::treasury::pda(&request.config, &bond_mint);
+
+        // The arbitration window of the request is still open, though the window of the config
+        // has expired.
+        context.now = context.state::<VotingV1>(&request.voting).end_timestamp + 1800;
+
+        let metas = [
+            AccountMeta::new_readonly(request.config, false),
+            AccountMeta::new(request.request, false),
+            AccountMeta::new(request.voting, false),
+            AccountMeta::new_readonly(pda::assertion::pda(&request.request).0, false),
+            AccountMeta::new_readonly(bond_mint, false),
+            AccountMeta::new(bond_escrow, false),
+            AccountMeta::new(treasury, false),
+            AccountMeta::new_readonly(arbitrator, true),
+            AccountMeta::new(arbitrator, true),
+            AccountMeta::new_readonly(spl_token_2022::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+
+        assert_eq!(
+            context.process(&metas, |program_id, accounts| {
+                arbitrate_v1(program_id, accounts, ArbitrateV1Args { value: 1 })
+            }),
+            Ok(()),
+        );
+
+        // The fee is taken at the bond fee rate of the request rather than the config.
+        let state = context.state::<RequestV1>(&request.request);
+        assert!(state.state == RequestState::Resolved);
+        assert_eq!(state.bond_fee, 10);
+        assert_eq!(context.token_amount(&treasury), 10);
+        assert_eq!(context.token_amount(&bond_escrow), 90);
+    }
+}
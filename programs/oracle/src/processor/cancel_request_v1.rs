@@ -0,0 +1,172 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::pubkey::Pubkey;
+
+use crate::error::OracleError;
+use crate::event::OracleEvent;
+use crate::instruction::accounts::CancelRequestV1Accounts;
+use crate::settlement::Settlement;
+use crate::state::{AccountSized, RequestSigner, RequestState, RequestV1};
+use crate::{pda, utils};
+
+pub fn cancel_request_v1<'a>(
+    _program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    let ctx = CancelRequestV1Accounts::context(accounts)?;
+
+    // Guard signatures.
+    utils::assert_signer(ctx.accounts.creator)?;
+
+    // Guard programs.
+    utils::assert_token_program(ctx.accounts.token_program.key)?;
+
+    let request_signer: RequestSigner;
+    let settlement: Settlement;
+
+    // Step 1: Mark the request as cancelled.
+    {
+        let mut request = RequestV1::from_account_info_mut(ctx.accounts.request)?;
+
+        // Guard request.
+        request_signer = request.signer(request.assert_pda(ctx.accounts.request.key)?);
+        request.assert_creator(ctx.accounts.creator.key)?;
+        request.assert_reward_mint(ctx.accounts.reward_mint.key)?;
+        request.assert_rent_payer(ctx.accounts.request_rent_payer.key)?;
+
+        // The request can only be cancelled before a value is asserted.
+        match request.state {
+            RequestState::Requested => {}
+            RequestState::Asserted | RequestState::Disputed => {
+                return Err(OracleError::AlreadyAsserted.into());
+            }
+            RequestState::Resolved => return Err(OracleError::AlreadyResolved.into()),
+            RequestState::Cancelled => return Err(OracleError::RequestCancelled.into()),
+        }
+
+        request.state = RequestState::Cancelled;
+
+        settlement = Settlement::new(&request, None)?;
+
+        request.save()?;
+    }
+
+    let reward = settlement.creator_reward;
+
+    // Step 2: Refund reward.
+    if reward > 0 {
+        pda::reward::assert_pda(ctx.accounts.reward_escrow.key, ctx.accounts.request.key)?;
+
+        let signer_seeds = request_signer.seeds();
+
+        let decimals = cpi::spl::mint_decimals(ctx.accounts.reward_mint)?;
+
+        // Step 2.1: Transfer reward from escrow to creator.
+        //
+        // The whole balance is transferred, so that tokens sent directly to the escrow cannot
+        // prevent the request from being cancelled.
+        let amount = cpi::spl::account_amount(ctx.accounts.reward_escrow)?;
+
+        cpi::spl::transfer_checked(
+            amount,
+            decimals,
+            cpi::spl::TransferChecked {
+                source: ctx.accounts.reward_escrow,
+                destination: ctx.accounts.reward_destination,
+                mint: ctx.accounts.reward_mint,
+                authority: ctx.accounts.request,
+                token_program: ctx.accounts.token_program,
+                remaining_accounts: ctx.remaining_accounts,
+            },
+            &[&signer_seeds],
+        )?;
+
+        // Step 2.2: Close reward escrow account.
+        cpi::spl::close_account(
+            cpi::spl::CloseAccount {
+                account: ctx.accounts.reward_escrow,
+                destination: ctx.accounts.request_rent_payer,
+                authority: ctx.accounts.request,
+                token_program: ctx.accounts.token_program,
+            },
+            &[&signer_seeds],
+        )?;
+    }
+
+    // Step 3: Close request account, as a cancelled request has no further use.
+    solana_utils::close_account(ctx.accounts.request, ctx.accounts.request_rent_payer)?;
+
+    OracleEvent::Cancelled { request: *ctx.accounts.request.key }.emit()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::instruction::AccountMeta;
+
+    use super::*;
+    use crate::processor::tests::{request_params, TestContext};
+
+    #[test]
+    fn cancel_closes_request() {
+        let mut context = TestContext::new();
+        let request = context.create_disputed_request(request_params()).request;
+
+        let reward_mint = context.create_mint(6);
+
+        let mut state = context.state::<RequestV1>(&request);
+        state.state = RequestState::Requested;
+        state.reward = 100;
+        state.reward_mint = reward_mint;
+        context.set_state(request, &state);
+
+        let (reward_escrow, _) = pda::reward::pda(&request);
+        context.set_token_account(reward_escrow, &reward_mint, &request, 100);
+
+        let reward_destination = context.create_token_account(&reward_mint, &state.creator, 0);
+
+        let metas = [
+            AccountMeta::new(request, false),
+            AccountMeta::new_readonly(reward_mint, false),
+            AccountMeta::new(reward_destination, false),
+            AccountMeta::new(reward_escrow, false),
+            AccountMeta::new_readonly(state.creator, true),
+            AccountMeta::new(state.rent_payer, false),
+            AccountMeta::new_readonly(spl_token_2022::ID, false),
+        ];
+
+        assert_eq!(context.process(&metas, cancel_request_v1), Ok(()));
+
+        // The cancelled request is closed along with the escrow.
+        assert!(!context.exists(&request));
+        assert!(!context.exists(&reward_escrow));
+        assert_eq!(context.token_amount(&reward_destination), 100);
+    }
+
+    #[test]
+    fn cancel_asserted_request() {
+        let mut context = TestContext::new();
+        let request = context.create_disputed_request(request_params()).request;
+
+        let mut state = context.state::<RequestV1>(&request);
+        state.state = RequestState::Asserted;
+        context.set_state(request, &state);
+
+        let metas = [
+            AccountMeta::new(request, false),
+            AccountMeta::new_readonly(state.reward_mint, false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(pda::reward::pda(&request).0, false),
+            AccountMeta::new_readonly(state.creator, true),
+            AccountMeta::new(state.rent_payer, false),
+            AccountMeta::new_readonly(spl_token_2022::ID, false),
+        ];
+
+        assert_eq!(
+            context.process(&metas, cancel_request_v1),
+            Err(OracleError::AlreadyAsserted.into()),
+        );
+        assert!(context.exists(&request));
+    }
+}
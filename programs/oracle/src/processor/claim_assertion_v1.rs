@@ -3,8 +3,10 @@ use solana_program::entrypoint::ProgramResult;
 use solana_program::pubkey::Pubkey;
 
 use crate::error::OracleError;
+use crate::event::OracleEvent;
 use crate::instruction::accounts::ClaimAssertionV1Accounts;
-use crate::state::{Account, AssertionV1, RequestState, RequestV1};
+use crate::settlement::Settlement;
+use crate::state::{Account, AssertionV1, RequestData, RequestSigner, RequestState, RequestV1};
 use crate::{pda, utils};
 
 pub fn claim_assertion_v1<'a>(
@@ -20,28 +22,26 @@ pub fn claim_assertion_v1<'a>(
     utils::assert_token_program(ctx.accounts.token_program.key)?;
     utils::assert_system_program(ctx.accounts.system_program.key)?;
 
-    let request_index: u64;
-    let request_bump: u8;
+    let request_signer: RequestSigner;
+    let settlement: Settlement;
 
     {
-        let resolved_value: u64;
+        let request: RequestV1;
 
         // Step 1: Check request state.
         {
-            let request = RequestV1::from_account_info(ctx.accounts.request)?;
+            request = RequestV1::from_account_info(ctx.accounts.request)?;
 
             // Guard request.
-            request_bump = request.assert_pda(ctx.accounts.request.key)?;
+            request_signer = request.signer(request.assert_pda(ctx.accounts.request.key)?);
             request.assert_reward_mint(ctx.accounts.reward_mint.key)?;
             request.assert_bond_mint(ctx.accounts.bond_mint.key)?;
+            request.assert_rent_payer(ctx.accounts.request_rent_payer.key)?;
 
             // The request must be resolved to claim.
             if request.state != RequestState::Resolved {
                 return Err(OracleError::NotResolved.into());
             }
-
-            request_index = request.index;
-            resolved_value = request.value;
         }
 
         // Step 2: Check assertion.
@@ -53,26 +53,39 @@ pub fn claim_assertion_v1<'a>(
 
             // Guard assertion.
             assertion.assert_asserter(ctx.accounts.asserter.key)?;
+            assertion.assert_rent_payer(ctx.accounts.assertion_rent_payer.key)?;
 
-            // The asserter can only claim if the asserted value is correct.
-            if assertion.asserted_value != resolved_value {
+            // The asserter can only claim if the asserted value is correct, or the request is
+            // invalid, in which case the bond is refunded.
+            if assertion.asserted_value != request.value
+                && request.value != RequestData::INVALID_VALUE
+            {
                 return Err(OracleError::IncorrectClaimer.into());
             }
+
+            settlement = Settlement::new(&request, Some(&assertion))?;
         }
     }
 
-    let signer_seeds = pda::request::seeds_with_bump(&request_index, &request_bump);
+    let signer_seeds = request_signer.seeds();
+
+    let bond = settlement.asserter_bond;
+    let reward = settlement.asserter_reward;
 
     // Step 3: Recover asserter bond.
     {
         pda::assert_bond::assert_pda(ctx.accounts.bond_escrow.key, ctx.accounts.request.key)?;
 
-        let bond = cpi::spl::account_amount(ctx.accounts.bond_escrow)?;
         let decimals = cpi::spl::mint_decimals(ctx.accounts.bond_mint)?;
 
         // Step 3.1: Transfer bond from escrow to asserter.
+        //
+        // The whole balance is transferred, as tokens sent directly to the escrow would otherwise
+        // prevent it from being closed.
+        let amount = cpi::spl::account_amount(ctx.accounts.bond_escrow)?;
+
         cpi::spl::transfer_checked(
-            bond,
+            amount,
             decimals,
             cpi::spl::TransferChecked {
                 source: ctx.accounts.bond_escrow,
@@ -80,6 +93,7 @@ pub fn claim_assertion_v1<'a>(
                 mint: ctx.accounts.bond_mint,
                 authority: ctx.accounts.request,
                 token_program: ctx.accounts.token_program,
+                remaining_accounts: ctx.remaining_accounts,
             },
             &[&signer_seeds],
         )?;
@@ -88,7 +102,7 @@ pub fn claim_assertion_v1<'a>(
         cpi::spl::close_account(
             cpi::spl::CloseAccount {
                 account: ctx.accounts.bond_escrow,
-                destination: ctx.accounts.asserter,
+                destination: ctx.accounts.assertion_rent_payer,
                 authority: ctx.accounts.request,
                 token_program: ctx.accounts.token_program,
             },
@@ -97,15 +111,18 @@ pub fn claim_assertion_v1<'a>(
     }
 
     // Step 4: Claim reward.
-    {
+    if reward > 0 {
         pda::reward::assert_pda(ctx.accounts.reward_escrow.key, ctx.accounts.request.key)?;
 
-        let reward = cpi::spl::account_amount(ctx.accounts.reward_escrow)?;
         let decimals = cpi::spl::mint_decimals(ctx.accounts.reward_mint)?;
 
         // Step 4.1: Transfer reward from escrow to asserter.
+        //
+        // As with the bond, the whole balance is transferred so that the escrow can be closed.
+        let amount = cpi::spl::account_amount(ctx.accounts.reward_escrow)?;
+
         cpi::spl::transfer_checked(
-            reward,
+            amount,
             decimals,
             cpi::spl::TransferChecked {
                 source: ctx.accounts.reward_escrow,
@@ -113,6 +130,7 @@ pub fn claim_assertion_v1<'a>(
                 mint: ctx.accounts.reward_mint,
                 authority: ctx.accounts.request,
                 token_program: ctx.accounts.token_program,
+                remaining_accounts: ctx.remaining_accounts,
             },
             &[&signer_seeds],
         )?;
@@ -121,7 +139,7 @@ pub fn claim_assertion_v1<'a>(
         cpi::spl::close_account(
             cpi::spl::CloseAccount {
                 account: ctx.accounts.reward_escrow,
-                destination: ctx.accounts.asserter,
+                destination: ctx.accounts.request_rent_payer,
                 authority: ctx.accounts.request,
                 token_program: ctx.accounts.token_program,
             },
@@ -129,5 +147,91 @@ pub fn claim_assertion_v1<'a>(
         )?;
     }
 
+    OracleEvent::Claimed {
+        request: *ctx.accounts.request.key,
+        claimer: *ctx.accounts.asserter.key,
+        bond,
+        reward,
+    }
+    .emit()?;
+
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use solana_program::instruction::AccountMeta;
+    use solana_program::system_program;
+
+    use super::*;
+    use crate::processor::tests::{request_params, TestContext};
+    use crate::state::InitAssertion;
+
+    #[test]
+    fn claim_donated_balance() {
+        let mut context = TestContext::new();
+        let request = context.create_disputed_request(request_params()).request;
+
+        let bond_mint = context.create_mint(6);
+        let reward_mint = context.create_mint(6);
+
+        let mut state = context.state::<RequestV1>(&request);
+        state.state = RequestState::Resolved;
+        state.value = 1;
+        state.resolve_timestamp = context.now;
+        state.bond = 100;
+        state.bond_mint = bond_mint;
+        state.reward = 50;
+        state.reward_mint = reward_mint;
+        context.set_state(request, &state);
+
+        let asserter = context.create_wallet();
+
+        let (assertion_state, _) = <(AssertionV1, usize)>::try_from(InitAssertion {
+            request,
+            assertion_timestamp: context.now,
+            asserter,
+            rent_payer: asserter,
+            asserted_value: 1,
+            dispute_window: 0,
+        })
+        .unwrap();
+
+        let (assertion, _) = pda::assertion::pda(&request);
+        context.set_state(assertion, &assertion_state);
+
+        // Tokens have been transferred directly to both escrows.
+        let (bond_escrow, _) = pda::assert_bond::pda(&request);
+        context.set_token_account(bond_escrow, &bond_mint, &request, 107);
+
+        let (reward_escrow, _) = pda::reward::pda(&request);
+        context.set_token_account(reward_escrow, &reward_mint, &request, 53);
+
+        let bond_destination = context.create_token_account(&bond_mint, &asserter, 0);
+        let reward_destination = context.create_token_account(&reward_mint, &asserter, 0);
+
+        let metas = [
+            AccountMeta::new_readonly(request, false),
+            AccountMeta::new(assertion, false),
+            AccountMeta::new_readonly(bond_mint, false),
+            AccountMeta::new(bond_destination, false),
+            AccountMeta::new(bond_escrow, false),
+            AccountMeta::new_readonly(reward_mint, false),
+            AccountMeta::new(reward_destination, false),
+            AccountMeta::new(reward_escrow, false),
+            AccountMeta::new_readonly(asserter, true),
+            AccountMeta::new(state.rent_payer, false),
+            AccountMeta::new(asserter, false),
+            AccountMeta::new_readonly(spl_token_2022::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+
+        assert_eq!(context.process(&metas, claim_assertion_v1), Ok(()));
+
+        // The escrows are emptied and closed, so that the request can be closed.
+        assert!(!context.exists(&bond_escrow));
+        assert!(!context.exists(&reward_escrow));
+        assert_eq!(context.token_amount(&bond_destination), 107);
+        assert_eq!(context.token_amount(&reward_destination), 53);
+    }
+}
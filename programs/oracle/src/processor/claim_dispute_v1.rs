@@ -3,8 +3,12 @@ use solana_program::entrypoint::ProgramResult;
 use solana_program::pubkey::Pubkey;
 
 use crate::error::OracleError;
+use crate::event::OracleEvent;
 use crate::instruction::accounts::ClaimDisputeV1Accounts;
-use crate::state::{Account, AssertionV1, RequestState, RequestV1};
+use crate::settlement::Settlement;
+use crate::state::{
+    Account, AssertionV1, RequestData, RequestSigner, RequestState, RequestV1, VotingV1,
+};
 use crate::{pda, utils};
 
 pub fn claim_dispute_v1<'a>(
@@ -20,28 +24,26 @@ pub fn claim_dispute_v1<'a>(
     utils::assert_token_program(ctx.accounts.token_program.key)?;
     utils::assert_system_program(ctx.accounts.system_program.key)?;
 
-    let request_index: u64;
-    let request_bump: u8;
+    let request_signer: RequestSigner;
+    let settlement: Settlement;
 
     {
-        let resolved_value: u64;
+        let request: RequestV1;
 
         // Step 1: Check request state.
         {
-            let request = RequestV1::from_account_info(ctx.accounts.request)?;
+            request = RequestV1::from_account_info(ctx.accounts.request)?;
 
             // Guard request.
-            request_bump = request.assert_pda(ctx.accounts.request.key)?;
+            request_signer = request.signer(request.assert_pda(ctx.accounts.request.key)?);
             request.assert_reward_mint(ctx.accounts.reward_mint.key)?;
             request.assert_bond_mint(ctx.accounts.bond_mint.key)?;
+            request.assert_rent_payer(ctx.accounts.request_rent_payer.key)?;
 
             // The request must be resolved to claim.
             if request.state != RequestState::Resolved {
                 return Err(OracleError::NotResolved.into());
             }
-
-            request_index = request.index;
-            resolved_value = request.value;
         }
 
         // Step 2: Check assertion.
@@ -54,25 +56,48 @@ pub fn claim_dispute_v1<'a>(
             // Guard assertion.
             assertion.assert_disputer(ctx.accounts.disputer.key)?;
 
-            // The disputer can only claim if the asserted value is incorrect.
-            if assertion.asserted_value == resolved_value {
+            // The disputer can only claim if the asserted value is incorrect, or the request is
+            // invalid, in which case the bond is refunded.
+            if assertion.asserted_value == request.value
+                && request.value != RequestData::INVALID_VALUE
+            {
                 return Err(OracleError::IncorrectClaimer.into());
             }
+
+            settlement = Settlement::new(&request, Some(&assertion))?;
+        }
+
+        // Step 3: Check voting rent payer, who also paid for the disputer bond escrow.
+        {
+            // Guard voting PDA.
+            pda::voting::assert_pda(ctx.accounts.voting.key, ctx.accounts.request.key)?;
+
+            let voting = VotingV1::from_account_info(ctx.accounts.voting)?;
+
+            // Guard voting.
+            voting.assert_rent_payer(ctx.accounts.voting_rent_payer.key)?;
         }
     }
 
-    let signer_seeds = pda::request::seeds_with_bump(&request_index, &request_bump);
+    let signer_seeds = request_signer.seeds();
 
-    // Step 3: Recover disputer bond.
+    let bond = settlement.disputer_bond;
+    let reward = settlement.disputer_reward;
+
+    // Step 4: Recover disputer bond.
     {
         pda::dispute_bond::assert_pda(ctx.accounts.bond_escrow.key, ctx.accounts.request.key)?;
 
-        let bond = cpi::spl::account_amount(ctx.accounts.bond_escrow)?;
         let decimals = cpi::spl::mint_decimals(ctx.accounts.bond_mint)?;
 
-        // Step 3.1: Transfer bond from escrow to disputer.
+        // Step 4.1: Transfer bond from escrow to disputer.
+        //
+        // The whole balance is transferred, as tokens sent directly to the escrow would otherwise
+        // prevent it from being closed.
+        let amount = cpi::spl::account_amount(ctx.accounts.bond_escrow)?;
+
         cpi::spl::transfer_checked(
-            bond,
+            amount,
             decimals,
             cpi::spl::TransferChecked {
                 source: ctx.accounts.bond_escrow,
@@ -80,15 +105,16 @@ pub fn claim_dispute_v1<'a>(
                 mint: ctx.accounts.bond_mint,
                 authority: ctx.accounts.request,
                 token_program: ctx.accounts.token_program,
+                remaining_accounts: ctx.remaining_accounts,
             },
             &[&signer_seeds],
         )?;
 
-        // Step 3.2: Close bond escrow account.
+        // Step 4.2: Close bond escrow account.
         cpi::spl::close_account(
             cpi::spl::CloseAccount {
                 account: ctx.accounts.bond_escrow,
-                destination: ctx.accounts.disputer,
+                destination: ctx.accounts.voting_rent_payer,
                 authority: ctx.accounts.request,
                 token_program: ctx.accounts.token_program,
             },
@@ -96,16 +122,19 @@ pub fn claim_dispute_v1<'a>(
         )?;
     }
 
-    // Step 4: Claim reward.
-    {
+    // Step 5: Claim reward.
+    if reward > 0 {
         pda::reward::assert_pda(ctx.accounts.reward_escrow.key, ctx.accounts.request.key)?;
 
-        let reward = cpi::spl::account_amount(ctx.accounts.reward_escrow)?;
         let decimals = cpi::spl::mint_decimals(ctx.accounts.reward_mint)?;
 
-        // Step 4.1: Transfer reward from escrow to disputer.
+        // Step 5.1: Transfer reward from escrow to disputer.
+        //
+        // As with the bond, the whole balance is transferred so that the escrow can be closed.
+        let amount = cpi::spl::account_amount(ctx.accounts.reward_escrow)?;
+
         cpi::spl::transfer_checked(
-            reward,
+            amount,
             decimals,
             cpi::spl::TransferChecked {
                 source: ctx.accounts.reward_escrow,
@@ -113,15 +142,16 @@ pub fn claim_dispute_v1<'a>(
                 mint: ctx.accounts.reward_mint,
                 authority: ctx.accounts.request,
                 token_program: ctx.accounts.token_program,
+                remaining_accounts: ctx.remaining_accounts,
             },
             &[&signer_seeds],
         )?;
 
-        // Step 4.2: Close reward escrow account.
+        // Step 5.2: Close reward escrow account.
         cpi::spl::close_account(
             cpi::spl::CloseAccount {
                 account: ctx.accounts.reward_escrow,
-                destination: ctx.accounts.disputer,
+                destination: ctx.accounts.request_rent_payer,
                 authority: ctx.accounts.request,
                 token_program: ctx.accounts.token_program,
             },
@@ -129,5 +159,13 @@ pub fn claim_dispute_v1<'a>(
         )?;
     }
 
+    OracleEvent::Claimed {
+        request: *ctx.accounts.request.key,
+        claimer: *ctx.accounts.disputer.key,
+        bond,
+        reward,
+    }
+    .emit()?;
+
     Ok(())
 }
@@ -0,0 +1,113 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::pubkey::Pubkey;
+
+use crate::error::OracleError;
+use crate::event::OracleEvent;
+use crate::instruction::accounts::ClaimRefundV1Accounts;
+use crate::settlement::Settlement;
+use crate::state::{Account, AssertionV1, RequestData, RequestSigner, RequestState, RequestV1};
+use crate::{pda, utils};
+
+pub fn claim_refund_v1<'a>(
+    _program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    let ctx = ClaimRefundV1Accounts::context(accounts)?;
+
+    // Guard signatures.
+    utils::assert_signer(ctx.accounts.creator)?;
+
+    // Guard programs.
+    utils::assert_token_program(ctx.accounts.token_program.key)?;
+
+    let request_signer: RequestSigner;
+    let settlement: Settlement;
+
+    {
+        let request: RequestV1;
+
+        // Step 1: Check request state.
+        {
+            request = RequestV1::from_account_info(ctx.accounts.request)?;
+
+            // Guard request.
+            request_signer = request.signer(request.assert_pda(ctx.accounts.request.key)?);
+            request.assert_creator(ctx.accounts.creator.key)?;
+            request.assert_reward_mint(ctx.accounts.reward_mint.key)?;
+            request.assert_rent_payer(ctx.accounts.request_rent_payer.key)?;
+
+            // The request must be resolved to claim.
+            if request.state != RequestState::Resolved {
+                return Err(OracleError::NotResolved.into());
+            }
+
+            // The reward is only refunded if the request is invalid.
+            if request.value != RequestData::INVALID_VALUE {
+                return Err(OracleError::IncorrectClaimer.into());
+            }
+        }
+
+        // Step 2: Check assertion.
+        {
+            // Guard assertion PDA.
+            pda::assertion::assert_pda(ctx.accounts.assertion.key, ctx.accounts.request.key)?;
+
+            let assertion = AssertionV1::from_account_info(ctx.accounts.assertion)?;
+
+            settlement = Settlement::new(&request, Some(&assertion))?;
+        }
+    }
+
+    let reward = settlement.creator_reward;
+
+    // Step 3: Refund reward.
+    if reward > 0 {
+        pda::reward::assert_pda(ctx.accounts.reward_escrow.key, ctx.accounts.request.key)?;
+
+        let signer_seeds = request_signer.seeds();
+
+        let decimals = cpi::spl::mint_decimals(ctx.accounts.reward_mint)?;
+
+        // Step 3.1: Transfer reward from escrow to creator.
+        //
+        // The whole balance is transferred, as tokens sent directly to the escrow would otherwise
+        // prevent it from being closed, and with it the request.
+        let amount = cpi::spl::account_amount(ctx.accounts.reward_escrow)?;
+
+        cpi::spl::transfer_checked(
+            amount,
+            decimals,
+            cpi::spl::TransferChecked {
+                source: ctx.accounts.reward_escrow,
+                destination: ctx.accounts.reward_destination,
+                mint: ctx.accounts.reward_mint,
+                authority: ctx.accounts.request,
+                token_program: ctx.accounts.token_program,
+                remaining_accounts: ctx.remaining_accounts,
+            },
+            &[&signer_seeds],
+        )?;
+
+        // Step 3.2: Close reward escrow account.
+        cpi::spl::close_account(
+            cpi::spl::CloseAccount {
+                account: ctx.accounts.reward_escrow,
+                destination: ctx.accounts.request_rent_payer,
+                authority: ctx.accounts.request,
+                token_program: ctx.accounts.token_program,
+            },
+            &[&signer_seeds],
+        )?;
+    }
+
+    OracleEvent::Claimed {
+        request: *ctx.accounts.request.key,
+        claimer: *ctx.accounts.creator.key,
+        bond: 0,
+        reward,
+    }
+    .emit()?;
+
+    Ok(())
+}
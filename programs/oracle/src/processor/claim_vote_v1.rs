@@ -1,12 +1,18 @@
 use solana_program::account_info::AccountInfo;
+use solana_program::clock::Clock;
 use solana_program::entrypoint::ProgramResult;
 use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
 use solana_utils::log;
 
 use crate::error::OracleError;
+use crate::event::OracleEvent;
 use crate::instruction::accounts::ClaimVoteV1Accounts;
-use crate::state::{Account, AssertionV1, RequestState, RequestV1, StakeV1, VoteV1, VotingV1};
-use crate::{pda, utils};
+use crate::state::{
+    Account, AccountSized, AssertionV1, OracleV1, RequestSigner, RequestState, RequestV1, StakeV1,
+    VoteV1, VoterReward, VotingV1,
+};
+use crate::{pda, settlement, utils};
 
 pub fn claim_vote_v1<'a>(
     _program_id: &'a Pubkey,
@@ -29,18 +35,17 @@ pub fn claim_vote_v1<'a>(
         stake.assert_voter(ctx.accounts.voter.key)?;
     }
 
-    let request_index: u64;
-    let request_bump: u8;
+    let request_signer: RequestSigner;
 
     let resolved_value: u64;
-    let bond: u64;
+    let round: u32;
 
     // Step 2: Check request state.
     {
         let request = RequestV1::from_account_info(ctx.accounts.request)?;
 
         // Guard request.
-        request_bump = request.assert_pda(ctx.accounts.request.key)?;
+        request_signer = request.signer(request.assert_pda(ctx.accounts.request.key)?);
         request.assert_bond_mint(ctx.accounts.bond_mint.key)?;
 
         // The request must be resolved to claim.
@@ -48,75 +53,116 @@ pub fn claim_vote_v1<'a>(
             return Err(OracleError::NotResolved.into());
         }
 
-        request_index = request.index;
+        // Claims begin once incorrect votes can no longer be slashed, so that the slashed stake
+        // shared between voters is known.
+        let now = Clock::get()?.unix_timestamp;
+
+        if now < request.claim_start_timestamp()? {
+            return Err(OracleError::SlashWindowNotExpired.into());
+        }
+
         resolved_value = request.value;
-        bond = request.bond;
+        round = request.round;
     }
 
     // Guard PDAs.
+    pda::oracle::assert_pda(ctx.accounts.oracle.key)?;
     pda::assertion::assert_pda(ctx.accounts.assertion.key, ctx.accounts.request.key)?;
     pda::voting::assert_pda(ctx.accounts.voting.key, ctx.accounts.request.key)?;
     pda::vote::assert_pda(ctx.accounts.vote.key, ctx.accounts.voting.key, ctx.accounts.stake.key)?;
 
     let votes: u64;
+    let voting_rent: u64;
 
     // Step 3: Get voter votes for resolved value.
     {
         let vote = VoteV1::from_account_info(ctx.accounts.vote)?;
 
+        // Guard vote round.
+        vote.assert_round(round)?;
+
+        // Guard vote rent payer.
+        vote.assert_rent_payer(ctx.accounts.vote_rent_payer.key)?;
+
+        // Unrevealed votes were not counted.
+        if !vote.revealed {
+            return Err(OracleError::VoteNotRevealed.into());
+        }
+
         // The vote must be for the resolved value.
         if vote.value != resolved_value {
             return Err(OracleError::IncorrectVote.into());
         }
 
         votes = vote.votes;
+        voting_rent = vote.voting_rent;
     }
 
-    let total_votes: u64;
-
-    // Step 4: Get total votes for resolved value.
+    // Step 4: Check bond escrow for incorrect asserter/disputer. Neither party loses their bond if
+    // the request is invalid, so the bond escrows are not used and may already be closed.
     {
-        let voting = VotingV1::from_account_info(ctx.accounts.request)?;
+        let assertion = AssertionV1::from_account_info(ctx.accounts.assertion)?;
 
-        total_votes = match voting.votes.get(&resolved_value) {
-            // The resolved value matches the voted value; any value voted for should have an entry
-            // with at least one vote in the votes map. Thus this branch should be unreachable.
-            None | Some(0) => unreachable!("total votes should not be zero"),
-            Some(total_votes) => *total_votes,
-        };
+        settlement::assert_incorrect_bond_escrow(
+            ctx.accounts.bond_escrow.key,
+            ctx.accounts.request.key,
+            resolved_value,
+            assertion.asserted_value,
+        )?;
     }
 
-    let voter_reward = (((bond as u128) * (votes as u128)) / (total_votes as u128)) as u64;
-
-    log!("Votes: {votes} / {total_votes}");
-    log!("Reward: {voter_reward}");
+    let voter_reward: VoterReward;
 
-    // Step 5: Check bond escrow for incorrect asserter/disputer.
+    // Step 5: Claim voter share of the reward pool.
     {
-        let assertion = AssertionV1::from_account_info(ctx.accounts.assertion)?;
+        let mut voting = VotingV1::from_account_info_mut(ctx.accounts.voting)?;
 
-        // Check if the asserted value matches the resolved value.
-        if resolved_value == assertion.asserted_value {
-            log!("Assertion is correct");
+        // Voters share the bond of the incorrect asserter/disputer remaining after the fee, and
+        // any stake slashed from voters for other values.
+        voter_reward = voting.reward_pool.claim(votes)?;
 
-            // The resolved value matches the asserted value, so the disputer loses their bond.
-            pda::dispute_bond::assert_pda(ctx.accounts.bond_escrow.key, ctx.accounts.request.key)?;
-        } else {
-            log!("Assertion is incorrect");
+        log!("Votes: {votes} / {}", voting.reward_pool.votes);
+        log!("Reward: {}", voter_reward.bond);
 
-            // The resolved value doesn't match the asserted value, so the asserter loses their bond.
-            pda::assert_bond::assert_pda(ctx.accounts.bond_escrow.key, ctx.accounts.request.key)?;
-        }
+        voting.open_votes = checked_sub!(voting.open_votes, 1)?;
+        voting.refund_excess_rent(voting_rent, ctx.accounts.vote_rent_payer)?;
+
+        voting.save()?;
     }
 
-    // Step 6: Claim voter reward from incorrect bond.
+    // Step 6: Add voter share of slashed stake to stake.
     {
-        let signer_seeds = pda::request::seeds_with_bump(&request_index, &request_bump);
+        let mut stake = StakeV1::from_account_info_mut(ctx.accounts.stake)?;
+
+        if voter_reward.slashed > 0 {
+            log!("Slashed stake reward: {}", voter_reward.slashed);
+
+            // The slashed stake is held in the stake pool, so only the stake amount is updated.
+            stake.amount = checked_add!(stake.amount, voter_reward.slashed)?;
+        }
+
+        stake.open_votes = checked_sub!(stake.open_votes, 1)?;
+
+        stake.save()?;
+    }
+
+    // Step 7: Add voter share of slashed stake to total staked.
+    if voter_reward.slashed > 0 {
+        let mut oracle = OracleV1::from_account_info_mut(ctx.accounts.oracle)?;
+
+        oracle.total_staked = checked_add!(oracle.total_staked, voter_reward.slashed)?;
+
+        oracle.save()?;
+    }
+
+    // Step 8: Claim voter reward from incorrect bond.
+    if voter_reward.bond > 0 {
+        let signer_seeds = request_signer.seeds();
 
         let decimals = cpi::spl::mint_decimals(ctx.accounts.bond_mint)?;
 
         cpi::spl::transfer_checked(
-            voter_reward,
+            voter_reward.bond,
             decimals,
             cpi::spl::TransferChecked {
                 source: ctx.accounts.bond_escrow,
@@ -124,13 +170,308 @@ pub fn claim_vote_v1<'a>(
                 mint: ctx.accounts.bond_mint,
                 authority: ctx.accounts.request,
                 token_program: ctx.accounts.token_program,
+                remaining_accounts: ctx.remaining_accounts,
             },
             &[&signer_seeds],
         )?;
     }
 
-    // Step 7: Close vote account.
-    solana_utils::close_account(ctx.accounts.vote, ctx.accounts.voter)?;
+    // Step 9: Close vote account.
+    solana_utils::close_account(ctx.accounts.vote, ctx.accounts.vote_rent_payer)?;
+
+    // Voter rewards are paid from the incorrect bond.
+    OracleEvent::Claimed {
+        request: *ctx.accounts.request.key,
+        claimer: *ctx.accounts.voter.key,
+        bond: voter_reward.bond,
+        reward: 0,
+    }
+    .emit()?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use solana_program::instruction::AccountMeta;
+    use solana_program::system_program;
+
+    use super::*;
+    use crate::processor::tests::{request_params, TestContext, TestRequest};
+    use crate::processor::{
+        claim_assertion_v1, close_vote_v1, close_voting_v1, submit_vote_v1, SubmitVoteV1Args,
+    };
+    use crate::state::{InitAssertion, RequestData};
+
+    struct Voter {
+        stake: Pubkey,
+        owner: Pubkey,
+        bond_destination: Pubkey,
+    }
+
+    impl Voter {
+        fn vote(&self, request: &TestRequest) -> Pubkey {
+            pda::vote::pda(&request.voting, &self.stake).0
+        }
+    }
+
+    /// Creates a request disputed by a bond of 100 tokens, and voters staking each amount.
+    fn setup(context: &mut TestContext, stakes: &[u64]) -> (TestRequest, Vec<Voter>) {
+        let request = context.create_disputed_request(request_params());
+
+        let bond_mint = context.create_mint(6);
+
+        let mut state = context.state::<RequestV1>(&request.request);
+        state.bond = 100;
+        state.bond_mint = bond_mint;
+        context.set_state(request.request, &state);
+
+        let (mut assertion, _) = <(AssertionV1, usize)>::try_from(InitAssertion {
+            request: request.request,
+            assertion_timestamp: context.now,
+            asserter: Pubkey::new_unique(),
+            rent_payer: Pubkey::new_unique(),
+            asserted_value: 1,
+            dispute_window: 0,
+        })
+        .unwrap();
+        assertion.disputer = Pubkey::new_unique();
+
+        context.set_state(pda::assertion::pda(&request.request).0, &assertion);
+
+        for escrow in [pda::assert_bond::pda, pda::dispute_bond::pda] {
+            context.set_token_account(
+                escrow(&request.request).0,
+                &bond_mint,
+                &request.request,
+                100,
+            );
+        }
+
+        context.create_oracle(&request.governance_mint, stakes.iter().sum());
+
+        let voters = stakes
+            .iter()
+            .map(|&amount| {
+                let (stake, owner) = context.create_stake(&request.governance_mint, amount);
+                let bond_destination = context.create_token_account(&bond_mint, &owner, 0);
+
+                Voter { stake, owner, bond_destination }
+            })
+            .collect();
+
+        (request, voters)
+    }
+
+    fn submit_vote(
+        context: &mut TestContext,
+        request: &TestRequest,
+        voter: &Voter,
+        value: u64,
+    ) -> ProgramResult {
+        let metas = [
+            AccountMeta::new_readonly(request.config, false),
+            AccountMeta::new_readonly(request.request, false),
+            AccountMeta::new(request.voting, false),
+            AccountMeta::new(voter.vote(request), false),
+            AccountMeta::new(voter.stake, false),
+            AccountMeta::new_readonly(voter.owner, true),
+            AccountMeta::new(voter.owner, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+
+        context.process(&metas, |program_id, accounts| {
+            submit_vote_v1(program_id, accounts, SubmitVoteV1Args { value })
+        })
+    }
+
+    fn close_voting(context: &mut TestContext, request: &TestRequest) -> ProgramResult {
+        let bond_mint = context.state::<RequestV1>(&request.request).bond_mint;
+
+        let metas = [
+            AccountMeta::new_readonly(request.config, false),
+            AccountMeta::new(request.request, false),
+            AccountMeta::new(request.voting, false),
+            AccountMeta::new_readonly(pda::oracle::pda().0, false),
+            AccountMeta::new_readonly(pda::assertion::pda(&request.request).0, false),
+            AccountMeta::new_readonly(bond_mint, false),
+            AccountMeta::new(pda::dispute_bond::pda(&request.request).0, false),
+            AccountMeta::new(pda::treasury::pda(&request.config, &bond_mint).0, false),
+            AccountMeta::new(context.create_wallet(), true),
+            AccountMeta::new_readonly(spl_token_2022::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+
+        context.process(&metas, close_voting_v1)
+    }
+
+    fn claim_vote(
+        context: &mut TestContext,
+        request: &TestRequest,
+        voter: &Voter,
+        bond_escrow: Pubkey,
+    ) -> ProgramResult {
+        let bond_mint = context.state::<RequestV1>(&request.request).bond_mint;
+
+        let metas = [
+            AccountMeta::new_readonly(request.request, false),
+            AccountMeta::new_readonly(pda::assertion::pda(&request.request).0, false),
+            AccountMeta::new(request.voting, false),
+            AccountMeta::new(voter.vote(request), false),
+            AccountMeta::new(voter.stake, false),
+            AccountMeta::new(pda::oracle::pda().0, false),
+            AccountMeta::new_readonly(bond_mint, false),
+            AccountMeta::new(voter.bond_destination, false),
+            AccountMeta::new(bond_escrow, false),
+            AccountMeta::new_readonly(voter.owner, true),
+            AccountMeta::new(voter.owner, false),
+            AccountMeta::new_readonly(spl_token_2022::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+
+        context.process(&metas, claim_vote_v1)
+    }
+
+    fn close_vote(
+        context: &mut TestContext,
+        request: &TestRequest,
+        voter: &Voter,
+    ) -> ProgramResult {
+        let metas = [
+            AccountMeta::new_readonly(request.request, false),
+            AccountMeta::new(request.voting, false),
+            AccountMeta::new(voter.vote(request), false),
+            AccountMeta::new(voter.stake, false),
+            AccountMeta::new(voter.owner, false),
+        ];
+
+        context.process(&metas, close_vote_v1)
+    }
+
+    #[test]
+    fn voters_share_incorrect_bond() {
+        let mut context = TestContext::new();
+        let (request, voters) = setup(&mut context, &[100, 200, 50]);
+        let (dispute_bond, _) = pda::dispute_bond::pda(&request.request);
+
+        for (voter, value) in voters.iter().zip([1, 1, 0]) {
+            assert_eq!(submit_vote(&mut context, &request, voter, value), Ok(()));
+        }
+
+        context.now = context.state::<VotingV1>(&request.voting).end_timestamp;
+
+        assert_eq!(close_voting(&mut context, &request), Ok(()));
+
+        let state = context.state::<RequestV1>(&request.request);
+        assert!(state.state == RequestState::Resolved);
+        assert_eq!(state.value, 1);
+
+        // The first claimer receives their share rounded down, and the last claimer receives the
+        // remainder of the bond.
+        assert_eq!(claim_vote(&mut context, &request, &voters[0], dispute_bond), Ok(()));
+        assert_eq!(context.token_amount(&voters[0].bond_destination), 33);
+
+        assert_eq!(claim_vote(&mut context, &request, &voters[1], dispute_bond), Ok(()));
+        assert_eq!(context.token_amount(&voters[1].bond_destination), 67);
+
+        assert_eq!(context.token_amount(&dispute_bond), 0);
+
+        let reward_pool = context.state::<VotingV1>(&request.voting).reward_pool;
+        assert_eq!(reward_pool.claimed, 100);
+        assert_eq!(reward_pool.claimed_votes, 300);
+
+        // Votes cannot be claimed twice, and incorrect votes cannot be claimed.
+        assert!(claim_vote(&mut context, &request, &voters[0], dispute_bond).is_err());
+        assert_eq!(
+            claim_vote(&mut context, &request, &voters[2], dispute_bond),
+            Err(OracleError::IncorrectVote.into()),
+        );
+
+        for voter in &voters[..2] {
+            assert!(!context.exists(&voter.vote(&request)));
+            assert_eq!(context.state::<StakeV1>(&voter.stake).open_votes, 0);
+        }
+
+        // The incorrect vote is closed once the claim window has expired.
+        context.now = state.resolve_timestamp + i64::from(state.claim_window);
+
+        assert_eq!(close_vote(&mut context, &request, &voters[2]), Ok(()));
+        assert_eq!(context.state::<VotingV1>(&request.voting).open_votes, 0);
+    }
+
+    #[test]
+    fn claim_during_slash_window() {
+        let mut context = TestContext::new();
+        let (request, voters) = setup(&mut context, &[100]);
+        let (dispute_bond, _) = pda::dispute_bond::pda(&request.request);
+
+        let mut state = context.state::<RequestV1>(&request.request);
+        state.slash_bps = 1_000;
+        context.set_state(request.request, &state);
+
+        assert_eq!(submit_vote(&mut context, &request, &voters[0], 1), Ok(()));
+
+        context.now = context.state::<VotingV1>(&request.voting).end_timestamp;
+
+        assert_eq!(close_voting(&mut context, &request), Ok(()));
+
+        // Incorrect votes can be slashed before voters claim.
+        assert_eq!(
+            claim_vote(&mut context, &request, &voters[0], dispute_bond),
+            Err(OracleError::SlashWindowNotExpired.into()),
+        );
+
+        context.now += 3600;
+
+        assert_eq!(claim_vote(&mut context, &request, &voters[0], dispute_bond), Ok(()));
+        assert_eq!(context.token_amount(&voters[0].bond_destination), 100);
+    }
+
+    #[test]
+    fn claim_invalid_after_assertion_claimed() {
+        let mut context = TestContext::new();
+        let (request, voters) = setup(&mut context, &[100]);
+
+        assert_eq!(
+            submit_vote(&mut context, &request, &voters[0], RequestData::INVALID_VALUE),
+            Ok(()),
+        );
+
+        context.now = context.state::<VotingV1>(&request.voting).end_timestamp;
+
+        assert_eq!(close_voting(&mut context, &request), Ok(()));
+
+        let state = context.state::<RequestV1>(&request.request);
+        assert_eq!(state.value, RequestData::INVALID_VALUE);
+
+        // The asserter claims the refund of their bond, closing the escrow.
+        let (assertion, _) = pda::assertion::pda(&request.request);
+        let AssertionV1 { asserter, rent_payer, .. } = context.state(&assertion);
+        let (assert_bond, _) = pda::assert_bond::pda(&request.request);
+
+        let metas = [
+            AccountMeta::new_readonly(request.request, false),
+            AccountMeta::new(assertion, false),
+            AccountMeta::new_readonly(state.bond_mint, false),
+            AccountMeta::new(context.create_token_account(&state.bond_mint, &asserter, 0), false),
+            AccountMeta::new(assert_bond, false),
+            AccountMeta::new_readonly(state.reward_mint, false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(pda::reward::pda(&request.request).0, false),
+            AccountMeta::new_readonly(asserter, true),
+            AccountMeta::new(state.rent_payer, false),
+            AccountMeta::new(rent_payer, false),
+            AccountMeta::new_readonly(spl_token_2022::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+
+        assert_eq!(context.process(&metas, claim_assertion_v1), Ok(()));
+        assert!(!context.exists(&assert_bond));
+
+        // Voters for invalid share no bond, so can claim without the escrow.
+        assert_eq!(claim_vote(&mut context, &request, &voters[0], assert_bond), Ok(()));
+        assert_eq!(context.token_amount(&voters[0].bond_destination), 0);
+        assert!(!context.exists(&voters[0].vote(&request)));
+        assert_eq!(context.state::<StakeV1>(&voters[0].stake).open_votes, 0);
+    }
+}
@@ -0,0 +1,36 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::pubkey::Pubkey;
+
+use crate::instruction::accounts::CloseCurrencyV1Accounts;
+use crate::state::{Account, ConfigV1, CurrencyV1};
+use crate::utils;
+
+pub fn close_currency_v1<'a>(
+    _program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    let ctx = CloseCurrencyV1Accounts::context(accounts)?;
+
+    // Guard signatures.
+    utils::assert_signer(ctx.accounts.authority)?;
+
+    // Step 1: Check config authority.
+    ConfigV1::from_account_info(ctx.accounts.config)?
+        .assert_authority(ctx.accounts.authority.key)?;
+
+    // Step 2: Check currency.
+    {
+        let currency = CurrencyV1::from_account_info(ctx.accounts.currency)?;
+
+        // Guard currency.
+        currency.assert_pda(ctx.accounts.currency.key)?;
+        currency.assert_config(ctx.accounts.config.key)?;
+    }
+
+    // Step 3: Close currency account. The currency is only read when creating a request, so
+    // existing requests are not affected.
+    solana_utils::close_account(ctx.accounts.currency, ctx.accounts.destination)?;
+
+    Ok(())
+}
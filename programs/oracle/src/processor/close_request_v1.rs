@@ -0,0 +1,427 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+use solana_utils::log;
+
+use crate::error::OracleError;
+use crate::event::OracleEvent;
+use crate::instruction::accounts::CloseRequestV1Accounts;
+use crate::state::{
+    Account, AccountSized, AssertionV1, RequestData, RequestSigner, RequestState, RequestV1,
+    VotingV1,
+};
+use crate::{pda, utils};
+
+pub fn close_request_v1<'a>(
+    _program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    let ctx = CloseRequestV1Accounts::context(accounts)?;
+
+    // Guard signatures.
+    utils::assert_signer(ctx.accounts.payer)?;
+
+    // Guard programs.
+    utils::assert_token_program(ctx.accounts.token_program.key)?;
+    utils::assert_system_program(ctx.accounts.system_program.key)?;
+
+    // Guard PDAs.
+    pda::assertion::assert_pda(ctx.accounts.assertion.key, ctx.accounts.request.key)?;
+    pda::voting::assert_pda(ctx.accounts.voting.key, ctx.accounts.request.key)?;
+    pda::reward::assert_pda(ctx.accounts.reward_escrow.key, ctx.accounts.request.key)?;
+    pda::assert_bond::assert_pda(ctx.accounts.assert_bond_escrow.key, ctx.accounts.request.key)?;
+    pda::dispute_bond::assert_pda(ctx.accounts.dispute_bond_escrow.key, ctx.accounts.request.key)?;
+    let oracle_bump = pda::oracle::assert_pda(ctx.accounts.oracle.key)?;
+
+    let request_signer: RequestSigner;
+    let creator: Pubkey;
+    let resolved_value: u64;
+    let claim_end_timestamp: i64;
+
+    // Step 1: Check request state.
+    {
+        let request = RequestV1::from_account_info(ctx.accounts.request)?;
+
+        // Guard request.
+        request_signer = request.signer(request.assert_pda(ctx.accounts.request.key)?);
+        request.assert_config(ctx.accounts.config.key)?;
+        request.assert_bond_mint(ctx.accounts.bond_mint.key)?;
+        request.assert_reward_mint(ctx.accounts.reward_mint.key)?;
+        request.assert_rent_payer(ctx.accounts.request_rent_payer.key)?;
+
+        // The request must be resolved to close.
+        if request.state != RequestState::Resolved {
+            return Err(OracleError::NotResolved.into());
+        }
+
+        creator = request.creator;
+        resolved_value = request.value;
+        claim_end_timestamp = request.claim_end_timestamp()?;
+    }
+
+    // The voting account only exists if the assertion was disputed.
+    let disputed = !ctx.accounts.voting.data_is_empty();
+    let asserter_correct: bool;
+    let unclaimed_slashed: u64;
+
+    // Step 2: Check assertion and voting.
+    {
+        let assertion = AssertionV1::from_account_info(ctx.accounts.assertion)?;
+
+        // Guard assertion.
+        assertion.assert_rent_payer(ctx.accounts.assertion_rent_payer.key)?;
+
+        if disputed {
+            let voting = VotingV1::from_account_info(ctx.accounts.voting)?;
+
+            // Guard voting.
+            voting.assert_rent_payer(ctx.accounts.voting_rent_payer.key)?;
+
+            // Guard stake pool.
+            pda::stake_pool::assert_pda(ctx.accounts.stake_pool.key, &voting.governance_mint)?;
+
+            if !solana_utils::pubkeys_eq(&voting.governance_mint, ctx.accounts.governance_mint.key)
+            {
+                return Err(OracleError::StakeMintMismatch.into());
+            }
+
+            // Votes refer to the voting account, which holds the rent paid by voters to
+            // reallocate it, so all votes must be claimed, slashed or closed first.
+            if voting.open_votes > 0 {
+                return Err(OracleError::VotingHasOpenVotes.into());
+            }
+
+            unclaimed_slashed = voting.reward_pool.unclaimed_slashed()?;
+        } else {
+            unclaimed_slashed = 0;
+        }
+
+        asserter_correct = assertion.asserted_value == resolved_value;
+    }
+
+    let invalid = resolved_value == RequestData::INVALID_VALUE;
+    let too_early = resolved_value == RequestData::TOO_EARLY_VALUE;
+
+    // The bond escrows of the parties whose bond is returned, and the bond escrow of the incorrect
+    // party along with the rent payer of the escrow.
+    let (returned_bond_escrows, incorrect_bond_escrow) = if !disputed {
+        ([Some(ctx.accounts.assert_bond_escrow), None], None)
+    } else if invalid {
+        // Both bonds are returned if the request is invalid.
+        ([Some(ctx.accounts.assert_bond_escrow), Some(ctx.accounts.dispute_bond_escrow)], None)
+    } else if asserter_correct {
+        (
+            [Some(ctx.accounts.assert_bond_escrow), None],
+            Some((ctx.accounts.dispute_bond_escrow, ctx.accounts.voting_rent_payer)),
+        )
+    } else {
+        (
+            [Some(ctx.accounts.dispute_bond_escrow), None],
+            Some((ctx.accounts.assert_bond_escrow, ctx.accounts.assertion_rent_payer)),
+        )
+    };
+
+    // Step 3: Check the reward and returned bonds have been claimed. The reward is kept for the
+    // next assertion if the request was too early to resolve, and refunded below if the request
+    // is invalid.
+    let reward_claimed = too_early || invalid || ctx.accounts.reward_escrow.data_is_empty();
+    let bonds_claimed = returned_bond_escrows.iter().flatten().all(|escrow| escrow.data_is_empty());
+
+    if !reward_claimed || !bonds_claimed {
+        return Err(OracleError::RequestNotSettled.into());
+    }
+
+    let signer_seeds = request_signer.seeds();
+
+    let now = Clock::get()?.unix_timestamp;
+    let claim_window_expired = now >= claim_end_timestamp;
+
+    // Step 4: Refund the reward to the creator if the request is invalid and the creator has not
+    // claimed the refund.
+    if invalid && !ctx.accounts.reward_escrow.data_is_empty() {
+        // Guard reward destination.
+        if !solana_utils::pubkeys_eq(
+            &cpi::spl::account_owner(ctx.accounts.reward_destination)?,
+            &creator,
+        ) {
+            return Err(OracleError::TokenOwnerMismatch.into());
+        }
+
+        let decimals = cpi::spl::mint_decimals(ctx.accounts.reward_mint)?;
+        let amount = cpi::spl::account_amount(ctx.accounts.reward_escrow)?;
+
+        log!("Refunded reward: {amount}");
+
+        // Step 4.1: Transfer reward from escrow to creator.
+        cpi::spl::transfer_checked(
+            amount,
+            decimals,
+            cpi::spl::TransferChecked {
+                source: ctx.accounts.reward_escrow,
+                destination: ctx.accounts.reward_destination,
+                mint: ctx.accounts.reward_mint,
+                authority: ctx.accounts.request,
+                token_program: ctx.accounts.token_program,
+                remaining_accounts: ctx.remaining_accounts,
+            },
+            &[&signer_seeds],
+        )?;
+
+        // Step 4.2: Close reward escrow account.
+        cpi::spl::close_account(
+            cpi::spl::CloseAccount {
+                account: ctx.accounts.reward_escrow,
+                destination: ctx.accounts.request_rent_payer,
+                authority: ctx.accounts.request,
+                token_program: ctx.accounts.token_program,
+            },
+            &[&signer_seeds],
+        )?;
+    }
+
+    // Step 5: Close the bond escrow of the incorrect party, from which voters claim rewards.
+    if let Some((bond_escrow, destination)) = incorrect_bond_escrow {
+        if !bond_escrow.data_is_empty() {
+            let unclaimed = cpi::spl::account_amount(bond_escrow)?;
+
+            // Step 5.1: Sweep unclaimed voter rewards to the treasury.
+            if unclaimed > 0 {
+                // Voters must be given the chance to claim rewards.
+                if !claim_window_expired {
+                    return Err(OracleError::ClaimWindowNotExpired.into());
+                }
+
+                log!("Unclaimed voter rewards: {unclaimed}");
+
+                utils::transfer_to_treasury(
+                    unclaimed,
+                    utils::TransferToTreasury {
+                        config: ctx.accounts.config,
+                        mint: ctx.accounts.bond_mint,
+                        source: bond_escrow,
+                        authority: ctx.accounts.request,
+                        treasury: ctx.accounts.treasury,
+                        payer: ctx.accounts.payer,
+                        token_program: ctx.accounts.token_program,
+                        system_program: ctx.accounts.system_program,
+                        remaining_accounts: ctx.remaining_accounts,
+                    },
+                    &signer_seeds,
+                )?;
+            }
+
+            // Step 5.2: Close bond escrow account.
+            cpi::spl::close_account(
+                cpi::spl::CloseAccount {
+                    account: bond_escrow,
+                    destination,
+                    authority: ctx.accounts.request,
+                    token_program: ctx.accounts.token_program,
+                },
+                &[&signer_seeds],
+            )?;
+        }
+    }
+
+    // Step 6: Sweep unclaimed slashed stake from the stake pool to the treasury.
+    if unclaimed_slashed > 0 {
+        // Voters must be given the chance to claim rewards.
+        if !claim_window_expired {
+            return Err(OracleError::ClaimWindowNotExpired.into());
+        }
+
+        log!("Unclaimed slashed stake: {unclaimed_slashed}");
+
+        let signer_seeds = pda::oracle::seeds_with_bump(&oracle_bump);
+
+        utils::transfer_to_treasury(
+            unclaimed_slashed,
+            utils::TransferToTreasury {
+                config: ctx.accounts.config,
+                mint: ctx.accounts.governance_mint,
+                source: ctx.accounts.stake_pool,
+                authority: ctx.accounts.oracle,
+                treasury: ctx.accounts.stake_treasury,
+                payer: ctx.accounts.payer,
+                token_program: ctx.accounts.token_program,
+                system_program: ctx.accounts.system_program,
+                remaining_accounts: ctx.remaining_accounts,
+            },
+            &signer_seeds,
+        )?;
+    }
+
+    // Step 7: Close assertion and voting accounts.
+    {
+        solana_utils::close_account(ctx.accounts.assertion, ctx.accounts.assertion_rent_payer)?;
+
+        if disputed {
+            solana_utils::close_account(ctx.accounts.voting, ctx.accounts.voting_rent_payer)?;
+        }
+    }
+
+    // Step 8: Reset the request if it was too early to resolve, so that a new value can be
+    // asserted. Otherwise close the request account.
+    if too_early {
+        let mut request = RequestV1::from_account_info_mut(ctx.accounts.request)?;
+
+        request.state = RequestState::Requested;
+        request.value = 0;
+        request.bond_fee = 0;
+        request.resolve_timestamp = 0;
+
+        // Votes record the round they were cast in, so that they are not counted in later rounds.
+        request.round = checked_add!(request.round, 1)?;
+
+        request.save()?;
+
+        OracleEvent::Reset { request: *ctx.accounts.request.key }.emit()?;
+    } else {
+        solana_utils::close_account(ctx.accounts.request, ctx.accounts.request_rent_payer)?;
+
+        OracleEvent::Closed { request: *ctx.accounts.request.key }.emit()?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::instruction::AccountMeta;
+    use solana_program::system_program;
+
+    use super::*;
+    use crate::processor::tests::{request_params, TestContext, TestRequest};
+    use crate::state::InitAssertion;
+
+    /// Resolves the request with the value at the current timestamp, returning the asserter of
+    /// the value 1.
+    fn resolve(context: &mut TestContext, request: &TestRequest, value: u64) -> Pubkey {
+        let mut state = context.state::<RequestV1>(&request.request);
+        state.state = RequestState::Resolved;
+        state.value = value;
+        state.resolve_timestamp = context.now;
+        context.set_state(request.request, &state);
+
+        let asserter = context.create_wallet();
+
+        let (assertion, _) = <(AssertionV1, usize)>::try_from(InitAssertion {
+            request: request.request,
+            assertion_timestamp: context.now,
+            asserter,
+            rent_payer: asserter,
+            asserted_value: 1,
+            dispute_window: 0,
+        })
+        .unwrap();
+
+        context.set_state(pda::assertion::pda(&request.request).0, &assertion);
+
+        asserter
+    }
+
+    fn close_request(
+        context: &mut TestContext,
+        request: &TestRequest,
+        asserter: Pubkey,
+        reward_destination: Pubkey,
+    ) -> ProgramResult {
+        let state = context.state::<RequestV1>(&request.request);
+        let voting_rent_payer = context.state::<VotingV1>(&request.voting).rent_payer;
+
+        let metas = [
+            AccountMeta::new_readonly(request.config, false),
+            AccountMeta::new(request.request, false),
+            AccountMeta::new(pda::assertion::pda(&request.request).0, false),
+            AccountMeta::new(request.voting, false),
+            AccountMeta::new_readonly(state.bond_mint, false),
+            AccountMeta::new_readonly(state.reward_mint, false),
+            AccountMeta::new(pda::reward::pda(&request.request).0, false),
+            AccountMeta::new(reward_destination, false),
+            AccountMeta::new(pda::assert_bond::pda(&request.request).0, false),
+            AccountMeta::new(pda::dispute_bond::pda(&request.request).0, false),
+            AccountMeta::new(pda::treasury::pda(&request.config, &state.bond_mint).0, false),
+            AccountMeta::new(state.rent_payer, false),
+            AccountMeta::new(asserter, false),
+            AccountMeta::new(voting_rent_payer, false),
+            AccountMeta::new_readonly(pda::oracle::pda().0, false),
+            AccountMeta::new_readonly(request.governance_mint, false),
+            AccountMeta::new(pda::stake_pool::pda(&request.governance_mint).0, false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(context.create_wallet(), true),
+            AccountMeta::new_readonly(spl_token_2022::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+
+        context.process(&metas, close_request_v1)
+    }
+
+    #[test]
+    fn invalid_refunds_reward() {
+        let mut context = TestContext::new();
+        let request = context.create_disputed_request(request_params());
+        let asserter = resolve(&mut context, &request, RequestData::INVALID_VALUE);
+
+        let reward_mint = context.create_mint(6);
+
+        let mut state = context.state::<RequestV1>(&request.request);
+        state.reward = 50;
+        state.reward_mint = reward_mint;
+        context.set_state(request.request, &state);
+
+        // The creator has not claimed the refund, and both bonds have been claimed.
+        let (reward_escrow, _) = pda::reward::pda(&request.request);
+        context.set_token_account(reward_escrow, &reward_mint, &request.request, 50);
+
+        let creator_destination = context.create_token_account(&reward_mint, &state.creator, 0);
+        let other_destination = context.create_token_account(&reward_mint, &asserter, 0);
+
+        // The reward can only be refunded to the creator.
+        assert_eq!(
+            close_request(&mut context, &request, asserter, other_destination),
+            Err(OracleError::TokenOwnerMismatch.into()),
+        );
+
+        assert_eq!(close_request(&mut context, &request, asserter, creator_destination), Ok(()));
+
+        assert_eq!(context.token_amount(&creator_destination), 50);
+        assert!(!context.exists(&reward_escrow));
+        assert!(!context.exists(&request.request));
+    }
+
+    #[test]
+    fn close_during_claim_window() {
+        let mut context = TestContext::new();
+        let request = context.create_disputed_request(request_params());
+        let asserter = resolve(&mut context, &request, 1);
+
+        let bond_mint = context.create_mint(6);
+
+        let mut state = context.state::<RequestV1>(&request.request);
+        state.bond_mint = bond_mint;
+        context.set_state(request.request, &state);
+
+        // Voters have not claimed their rewards from the bond of the disputer.
+        let (bond_escrow, _) = pda::dispute_bond::pda(&request.request);
+        context.set_token_account(bond_escrow, &bond_mint, &request.request, 10);
+
+        // The claim window of the request is still open, though the window of the config has
+        // expired.
+        context.now += 1800;
+
+        assert_eq!(
+            close_request(&mut context, &request, asserter, Pubkey::new_unique()),
+            Err(OracleError::ClaimWindowNotExpired.into()),
+        );
+
+        context.now += 1800;
+
+        assert_eq!(close_request(&mut context, &request, asserter, Pubkey::new_unique()), Ok(()));
+
+        let (treasury, _) = pda::treasury::pda(&request.config, &bond_mint);
+        assert_eq!(context.token_amount(&treasury), 10);
+        assert!(!context.exists(&request.request));
+    }
+}
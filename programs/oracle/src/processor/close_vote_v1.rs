@@ -0,0 +1,243 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+
+use crate::error::OracleError;
+use crate::instruction::accounts::CloseVoteV1Accounts;
+use crate::pda;
+use crate::state::{Account, AccountSized, RequestState, RequestV1, StakeV1, VoteV1, VotingV1};
+
+pub fn close_vote_v1<'a>(
+    _program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    let ctx = CloseVoteV1Accounts::context(accounts)?;
+
+    // Guard PDAs.
+    pda::voting::assert_pda(ctx.accounts.voting.key, ctx.accounts.request.key)?;
+    pda::vote::assert_pda(ctx.accounts.vote.key, ctx.accounts.voting.key, ctx.accounts.stake.key)?;
+
+    let vote_round: u32;
+    let voting_rent: u64;
+
+    // Step 1: Check vote.
+    {
+        let vote = VoteV1::from_account_info(ctx.accounts.vote)?;
+
+        // Guard vote rent payer.
+        vote.assert_rent_payer(ctx.accounts.vote_rent_payer.key)?;
+
+        vote_round = vote.round;
+        voting_rent = vote.voting_rent;
+    }
+
+    let current_round: bool;
+
+    // Step 2: Check the claim window has expired.
+    {
+        let request = RequestV1::from_account_info(ctx.accounts.request)?;
+
+        // Guard request.
+        request.assert_pda(ctx.accounts.request.key)?;
+
+        // A vote from a previous round can no longer be counted, claimed or slashed, and the
+        // voting account it was cast in was closed when the request was reset.
+        current_round = vote_round == request.round;
+
+        if current_round {
+            // The request must be resolved to know the vote can no longer be counted.
+            if request.state != RequestState::Resolved {
+                return Err(OracleError::NotResolved.into());
+            }
+
+            // Voters must be given the chance to claim rewards, and incorrect votes to be slashed.
+            let now = Clock::get()?.unix_timestamp;
+
+            if now < request.claim_end_timestamp()? {
+                return Err(OracleError::ClaimWindowNotExpired.into());
+            }
+        }
+    }
+
+    // Step 3: Release the stake from the vote.
+    {
+        let mut stake = StakeV1::from_account_info_mut(ctx.accounts.stake)?;
+
+        stake.open_votes = checked_sub!(stake.open_votes, 1)?;
+
+        stake.save()?;
+    }
+
+    // Step 4: Release the voting account from the vote, refunding the rent paid to reallocate it.
+    if current_round {
+        let mut voting = VotingV1::from_account_info_mut(ctx.accounts.voting)?;
+
+        voting.open_votes = checked_sub!(voting.open_votes, 1)?;
+        voting.refund_excess_rent(voting_rent, ctx.accounts.vote_rent_payer)?;
+
+        voting.save()?;
+    }
+
+    // Step 5: Close vote account.
+    solana_utils::close_account(ctx.accounts.vote, ctx.accounts.vote_rent_payer)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::instruction::AccountMeta;
+
+    use super::*;
+    use crate::processor::tests::{request_params, TestContext, TestRequest};
+    use crate::state::{InitVote, VoteValue};
+
+    const VOTING_RENT: u64 = 1_000;
+
+    struct Accounts {
+        request: TestRequest,
+        vote: Pubkey,
+        stake: Pubkey,
+        rent_payer: Pubkey,
+    }
+
+    fn setup(context: &mut TestContext, value: u64) -> Accounts {
+        let request = context.create_disputed_request(request_params());
+
+        let mut state = context.state::<RequestV1>(&request.request);
+        state.state = RequestState::Resolved;
+        state.value = 1;
+        state.resolve_timestamp = context.now;
+        context.set_state(request.request, &state);
+
+        let (stake, _) = context.create_stake(&request.governance_mint, 100);
+
+        let mut state = context.state::<StakeV1>(&stake);
+        state.open_votes = 1;
+        context.set_state(stake, &state);
+
+        // The voting account was reallocated for the vote, and has since been shrunk.
+        let mut state = context.state::<VotingV1>(&request.voting);
+        state.open_votes = 1;
+        context.set_state(request.voting, &state);
+
+        let mut account = context.account(&request.voting).unwrap().clone();
+        account.lamports += VOTING_RENT;
+        context.set_account(request.voting, account);
+
+        let rent_payer = context.create_wallet();
+
+        let (vote_state, _) = <(VoteV1, usize)>::from(InitVote {
+            voting: request.voting,
+            stake,
+            round: 0,
+            rent_payer,
+            value: VoteValue::Revealed(value),
+            votes: 100,
+            voting_rent: VOTING_RENT,
+        });
+
+        let (vote, _) = pda::vote::pda(&request.voting, &stake);
+        context.set_state(vote, &vote_state);
+
+        Accounts { request, vote, stake, rent_payer }
+    }
+
+    fn close_vote(context: &mut TestContext, accounts: &Accounts) -> ProgramResult {
+        let metas = [
+            AccountMeta::new_readonly(accounts.request.request, false),
+            AccountMeta::new(accounts.request.voting, false),
+            AccountMeta::new(accounts.vote, false),
+            AccountMeta::new(accounts.stake, false),
+            AccountMeta::new(accounts.rent_payer, false),
+        ];
+
+        context.process(&metas, close_vote_v1)
+    }
+
+    #[test]
+    fn close_after_claim_window() {
+        let mut context = TestContext::new();
+        let accounts = setup(&mut context, 0);
+
+        let rent = context.account(&accounts.vote).unwrap().lamports;
+        let lamports = context.account(&accounts.rent_payer).unwrap().lamports;
+        let voting_lamports = context.account(&accounts.request.voting).unwrap().lamports;
+
+        context.now += 3600;
+
+        assert_eq!(close_vote(&mut context, &accounts), Ok(()));
+        assert!(!context.exists(&accounts.vote));
+
+        // The rent paid to reallocate the voting account is refunded with the vote rent.
+        assert_eq!(
+            context.account(&accounts.rent_payer).unwrap().lamports,
+            lamports + rent + VOTING_RENT,
+        );
+        assert_eq!(
+            context.account(&accounts.request.voting).unwrap().lamports,
+            voting_lamports - VOTING_RENT,
+        );
+        assert_eq!(context.state::<VotingV1>(&accounts.request.voting).open_votes, 0);
+
+        // The stake is no longer locked by the vote.
+        assert_eq!(context.state::<StakeV1>(&accounts.stake).open_votes, 0);
+    }
+
+    #[test]
+    fn close_during_claim_window() {
+        let mut context = TestContext::new();
+        let accounts = setup(&mut context, 0);
+
+        context.now += 3599;
+
+        assert_eq!(
+            close_vote(&mut context, &accounts),
+            Err(OracleError::ClaimWindowNotExpired.into()),
+        );
+        assert!(context.exists(&accounts.vote));
+    }
+
+    #[test]
+    fn close_unresolved() {
+        let mut context = TestContext::new();
+        let accounts = setup(&mut context, 1);
+
+        let mut state = context.state::<RequestV1>(&accounts.request.request);
+        state.state = RequestState::Disputed;
+        context.set_state(accounts.request.request, &state);
+
+        context.now += 3600;
+
+        assert_eq!(close_vote(&mut context, &accounts), Err(OracleError::NotResolved.into()));
+    }
+
+    #[test]
+    fn close_previous_round() {
+        let mut context = TestContext::new();
+        let accounts = setup(&mut context, 1);
+
+        // The request was reset and disputed again, so the vote is from a previous round.
+        let mut state = context.state::<RequestV1>(&accounts.request.request);
+        state.state = RequestState::Disputed;
+        state.round = 1;
+        context.set_state(accounts.request.request, &state);
+
+        let mut state = context.state::<VotingV1>(&accounts.request.voting);
+        state.open_votes = 0;
+        context.set_state(accounts.request.voting, &state);
+
+        let rent = context.account(&accounts.vote).unwrap().lamports;
+        let lamports = context.account(&accounts.rent_payer).unwrap().lamports;
+
+        assert_eq!(close_vote(&mut context, &accounts), Ok(()));
+        assert!(!context.exists(&accounts.vote));
+        assert_eq!(context.account(&accounts.rent_payer).unwrap().lamports, lamports + rent);
+        assert_eq!(context.state::<StakeV1>(&accounts.stake).open_votes, 0);
+
+        // The voting account of the current round is unchanged.
+        assert_eq!(context.state::<VotingV1>(&accounts.request.voting).open_votes, 0);
+    }
+}
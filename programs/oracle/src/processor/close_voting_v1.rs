@@ -6,9 +6,13 @@ use solana_program::sysvar::Sysvar;
 use solana_utils::log;
 
 use crate::error::OracleError;
+use crate::event::OracleEvent;
 use crate::instruction::accounts::CloseVotingV1Accounts;
-use crate::pda;
-use crate::state::{Account, AccountSized, ConfigV1, RequestState, RequestV1, VotingV1};
+use crate::state::{
+    Account, AccountSized, AssertionV1, OracleV1, RequestData, RequestSigner, RequestState,
+    RequestV1, TiePolicy, VotingV1,
+};
+use crate::{pda, settlement, utils};
 
 pub fn close_voting_v1<'a>(
     _program_id: &'a Pubkey,
@@ -16,24 +20,22 @@ pub fn close_voting_v1<'a>(
 ) -> ProgramResult {
     let ctx = CloseVotingV1Accounts::context(accounts)?;
 
-    let voting_window: u32;
-    let arbitration_window: u32;
+    // Guard signatures.
+    utils::assert_signer(ctx.accounts.payer)?;
 
-    // Step 1: Get config voting and arbitration windows.
-    {
-        let config = ConfigV1::from_account_info(ctx.accounts.config)?;
-
-        voting_window = config.voting_window;
-        arbitration_window = config.arbitration_window;
-    }
+    // Guard programs.
+    utils::assert_token_program(ctx.accounts.token_program.key)?;
+    utils::assert_system_program(ctx.accounts.system_program.key)?;
 
-    let mut request = RequestV1::from_account_info_mut(ctx.accounts.request)?;
+    let request = RequestV1::from_account_info_mut(ctx.accounts.request)?;
+    let request_signer: RequestSigner;
 
-    // Step 2: Check voting has not yet resolved the request.
+    // Step 1: Check voting has not yet resolved the request.
     {
         // Guard request.
-        request.assert_pda(ctx.accounts.request.key)?;
+        request_signer = request.signer(request.assert_pda(ctx.accounts.request.key)?);
         request.assert_config(ctx.accounts.config.key)?;
+        request.assert_bond_mint(ctx.accounts.bond_mint.key)?;
 
         // If the request state is not `Disputed`,
         // then the voting must have ended and resolved the request.
@@ -49,48 +51,255 @@ pub fn close_voting_v1<'a>(
 
     let mut voting = VotingV1::from_account_info_mut(ctx.accounts.voting)?;
 
-    // Step 3: Check the voting window has expired.
-    if now < voting.end_timestamp {
+    // Voting ends after the reveal window, if commit-reveal voting is enabled.
+    let voting_end_timestamp = voting.reveal_end_timestamp()?;
+
+    // Step 2: Check the voting window has expired.
+    if now < voting_end_timestamp {
         return Err(OracleError::VotingWindowNotExpired.into());
     }
 
-    // Step 4: If the request has an arbitrator, check the arbitration window has expired.
-    if request.has_arbitrator() && arbitration_window > 0 {
-        let end_timestamp = checked_add!(voting.end_timestamp, i64::from(arbitration_window))?;
+    // Step 3: If the request has an arbitrator, check the arbitration window has expired.
+    if request.has_arbitrator() && request.arbitration_window > 0 {
+        let end_timestamp =
+            checked_add!(voting_end_timestamp, i64::from(request.arbitration_window))?;
 
         if now < end_timestamp {
             return Err(OracleError::ArbitrationWindowNotExpired.into());
         }
     }
 
-    // Step 5: If no votes were cast then start a new vote window.
-    if voting.vote_count == 0 {
-        log!("Not enough votes cast - starting new vote window");
+    let quorum: u64;
+
+    // Step 4: Get the quorum required to resolve the request.
+    {
+        // Guard oracle PDA.
+        pda::oracle::assert_pda(ctx.accounts.oracle.key)?;
+
+        // The total staked is tracked by the oracle, as the stake pool balance can be inflated by
+        // transferring tokens to it directly.
+        let total_staked = OracleV1::from_account_info(ctx.accounts.oracle)?.total_staked;
+
+        quorum = request.quorum(total_staked)?;
+
+        log!("Votes: {} / {quorum}", voting.vote_count);
+    }
+
+    // Guard assertion PDA.
+    pda::assertion::assert_pda(ctx.accounts.assertion.key, ctx.accounts.request.key)?;
+
+    let assertion = AssertionV1::from_account_info(ctx.accounts.assertion)?;
+
+    // Step 5: Get the value to resolve the request with, if any. Only revealed votes are counted,
+    // so any unrevealed votes may be revealed after a new vote window.
+    let value = if voting.vote_count == 0 || voting.vote_count < quorum {
+        log!("Not enough votes cast");
+
+        None
+    } else {
+        let value = match request.data {
+            // Numeric requests resolve to the lower median, as exact values rarely agree. Votes
+            // must still match the median exactly to be rewarded.
+            RequestData::Numeric { .. } => voting.median_value(),
+            _ => voting.mode(),
+        };
+
+        match value {
+            Some(value) => Some(value),
+            None => {
+                log!("Votes are tied");
+
+                match request.tie_policy {
+                    TiePolicy::ExtendVoting => None,
+                    TiePolicy::AssertedValue => Some(assertion.asserted_value),
+                    TiePolicy::Unresolvable => Some(RequestData::INVALID_VALUE),
+                }
+            }
+        }
+    };
+
+    // Step 6: If a value could not be determined then start a new vote window.
+    let Some(value) = value else {
+        log!("Starting new vote window");
 
         voting.start_timestamp = now;
-        voting.end_timestamp = checked_add!(now, i64::from(voting_window))?;
+        voting.end_timestamp = checked_add!(now, i64::from(request.voting_window))?;
 
         voting.save()?;
 
-        // TODO: Emit an event?
-
         return Ok(());
+    };
+
+    // Step 7: Resolve the request with the voted value.
+    settlement::resolve_dispute(
+        request,
+        voting,
+        &assertion,
+        value,
+        now,
+        &request_signer,
+        utils::TransferBondFee {
+            config: ctx.accounts.config,
+            request: ctx.accounts.request,
+            bond_mint: ctx.accounts.bond_mint,
+            bond_escrow: ctx.accounts.bond_escrow,
+            treasury: ctx.accounts.treasury,
+            payer: ctx.accounts.payer,
+            token_program: ctx.accounts.token_program,
+            system_program: ctx.accounts.system_program,
+            remaining_accounts: ctx.remaining_accounts,
+        },
+    )?;
+
+    OracleEvent::Resolved { request: *ctx.accounts.request.key, value }.emit()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::instruction::AccountMeta;
+
+    use super::*;
+    use crate::processor::tests::{request_params, TestContext, TestRequest};
+    use crate::state::{InitAssertion, RequestParams};
+
+    fn setup(context: &mut TestContext, votes: u64, total_staked: u64) -> TestRequest {
+        let request =
+            context.create_disputed_request(RequestParams { quorum_bps: 5000, ..request_params() });
+
+        context.create_oracle(&request.governance_mint, total_staked);
+
+        let (assertion, _) = <(AssertionV1, usize)>::try_from(InitAssertion {
+            request: request.request,
+            assertion_timestamp: context.now,
+            asserter: Pubkey::new_unique(),
+            rent_payer: Pubkey::new_unique(),
+            asserted_value: 1,
+            dispute_window: 0,
+        })
+        .unwrap();
+
+        context.set_state(pda::assertion::pda(&request.request).0, &assertion);
+
+        let mut voting = context.state::<VotingV1>(&request.voting);
+        voting.add_votes(1, votes).unwrap();
+        context.set_state(request.voting, &voting);
+
+        context.now = voting.end_timestamp;
+
+        request
     }
 
-    // Voting account is not mutated when resolving.
-    let voting = voting.into_inner();
+    fn close_voting(context: &mut TestContext, request: &TestRequest) -> ProgramResult {
+        let bond_mint = context.state::<RequestV1>(&request.request).bond_mint;
 
-    // Step 6: Resolve the request with the modal voted value.
-    {
-        // Update request with resolved value.
-        request.resolve_timestamp = now;
-        request.state = RequestState::Resolved;
-        request.value = voting.mode_value;
+        let metas = [
+            AccountMeta::new_readonly(request.config, false),
+            AccountMeta::new(request.request, false),
+            AccountMeta::new(request.voting, false),
+            AccountMeta::new_readonly(pda::oracle::pda().0, false),
+            AccountMeta::new_readonly(pda::assertion::pda(&request.request).0, false),
+            AccountMeta::new_readonly(bond_mint, false),
+            AccountMeta::new(pda::dispute_bond::pda(&request.request).0, false),
+            AccountMeta::new(pda::treasury::pda(&request.config, &bond_mint).0, false),
+            AccountMeta::new(context.create_wallet(), true),
+            AccountMeta::new_readonly(spl_token_2022::ID, false),
+            AccountMeta::new_readonly(solana_program::system_program::ID, false),
+        ];
 
-        request.save()?;
+        context.process(&metas, close_voting_v1)
     }
 
-    // TODO: Emit an event?
+    #[test]
+    fn quorum_of_total_staked() {
+        let mut context = TestContext::new();
+        let request = setup(&mut context, 500, 1000);
 
-    Ok(())
+        // Tokens transferred directly to the stake pool do not count towards the quorum.
+        let (stake_pool, _) = pda::stake_pool::pda(&request.governance_mint);
+        context.set_token_account(
+            stake_pool,
+            &request.governance_mint,
+            &pda::oracle::pda().0,
+            2000,
+        );
+
+        assert_eq!(close_voting(&mut context, &request), Ok(()));
+
+        let state = context.state::<RequestV1>(&request.request);
+        assert!(state.state == RequestState::Resolved);
+        assert_eq!(state.value, 1);
+    }
+
+    #[test]
+    fn resolution_frees_votes() {
+        let mut context = TestContext::new();
+        let request = setup(&mut context, 500, 1000);
+
+        let account = context.account(&request.voting).unwrap().clone();
+
+        assert_eq!(close_voting(&mut context, &request), Ok(()));
+
+        let voting = context.state::<VotingV1>(&request.voting);
+        assert!(voting.votes.is_empty());
+        assert_eq!(voting.reward_pool.votes, 500);
+
+        // The rent for the freed space is kept to be refunded to voters as their votes close.
+        let resolved = context.account(&request.voting).unwrap();
+        assert!(resolved.data.len() < account.data.len());
+        assert_eq!(resolved.lamports, account.lamports);
+    }
+
+    #[test]
+    fn quorum_not_met() {
+        let mut context = TestContext::new();
+        let request = setup(&mut context, 499, 1000);
+
+        assert_eq!(close_voting(&mut context, &request), Ok(()));
+
+        // A new vote window is started, with the voting window of the request.
+        let voting = context.state::<VotingV1>(&request.voting);
+        assert_eq!(voting.start_timestamp, context.now);
+        assert_eq!(voting.end_timestamp, context.now + 3600);
+        assert!(context.state::<RequestV1>(&request.request).state == RequestState::Disputed);
+    }
+
+    #[test]
+    fn tie_resolved_by_request_policy() {
+        let mut context = TestContext::new();
+        let request = setup(&mut context, 500, 1000);
+
+        let mut state = context.state::<RequestV1>(&request.request);
+        state.tie_policy = TiePolicy::AssertedValue;
+        context.set_state(request.request, &state);
+
+        let mut voting = context.state::<VotingV1>(&request.voting);
+        voting.add_votes(0, 500).unwrap();
+        context.set_state(request.voting, &voting);
+
+        assert_eq!(close_voting(&mut context, &request), Ok(()));
+
+        // The tie is resolved with the asserted value, rather than as invalid by the config.
+        let state = context.state::<RequestV1>(&request.request);
+        assert!(state.state == RequestState::Resolved);
+        assert_eq!(state.value, 1);
+    }
+
+    #[test]
+    fn close_during_arbitration_window() {
+        let mut context = TestContext::new();
+        let request = setup(&mut context, 500, 1000);
+
+        let mut state = context.state::<RequestV1>(&request.request);
+        state.arbitrator = Pubkey::new_unique();
+        context.set_state(request.request, &state);
+
+        context.now += 1800;
+
+        assert_eq!(
+            close_voting(&mut context, &request),
+            Err(OracleError::ArbitrationWindowNotExpired.into()),
+        );
+    }
 }
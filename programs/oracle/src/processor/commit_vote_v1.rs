@@ -0,0 +1,189 @@
+use borsh::BorshDeserialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+use solana_utils::log;
+
+use crate::error::OracleError;
+use crate::event::OracleEvent;
+use crate::instruction::accounts::CommitVoteV1Accounts;
+use crate::state::{
+    Account, AccountSized, InitAccount, InitContext, InitVote, RequestState, RequestV1, StakeV1,
+    VoteV1, VoteValue, VotingV1,
+};
+use crate::{pda, utils};
+
+#[derive(Clone, BorshDeserialize)]
+pub struct CommitVoteV1Args {
+    /// Hash of the voting address, stake address, value to vote for and a secret salt.
+    pub commitment: [u8; 32],
+}
+
+pub fn commit_vote_v1<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    args: CommitVoteV1Args,
+) -> ProgramResult {
+    let ctx = CommitVoteV1Accounts::context(accounts)?;
+
+    // Guard signatures.
+    utils::assert_signer(ctx.accounts.voter)?;
+    utils::assert_signer(ctx.accounts.payer)?;
+
+    // Guard programs.
+    utils::assert_system_program(ctx.accounts.system_program.key)?;
+
+    let round: u32;
+    let voting_window: u32;
+
+    // Step 1: Check voting has not yet resolved the request.
+    {
+        let request = RequestV1::from_account_info(ctx.accounts.request)?;
+
+        // Guard request.
+        request.assert_pda(ctx.accounts.request.key)?;
+        request.assert_config(ctx.accounts.config.key)?;
+
+        // If the request state is not `Disputed`,
+        // then the voting must have ended and resolved the request.
+        if request.state != RequestState::Disputed {
+            return Err(OracleError::NotDisputed.into());
+        }
+
+        round = request.round;
+        voting_window = request.voting_window;
+    }
+
+    // Guard PDAs.
+    pda::voting::assert_pda(ctx.accounts.voting.key, ctx.accounts.request.key)?;
+
+    let now = Clock::get()?.unix_timestamp;
+
+    let mut voting = VotingV1::from_account_info_mut(ctx.accounts.voting)?;
+
+    // If commit-reveal voting is disabled, votes must be submitted with `submit_vote_v1`.
+    if !voting.is_commit_reveal() {
+        return Err(OracleError::CommitRevealDisabled.into());
+    }
+
+    // Step 2: Check the voting window hasn't expired.
+    if voting.end_timestamp <= now {
+        // Voting that ended without reaching quorum is extended by `close_voting_v1`.
+
+        // If any votes were committed, then the voting window is considered expired.
+        if voting.vote_count != 0 || voting.unrevealed_count != 0 {
+            return Err(OracleError::VotingWindowExpired.into());
+        }
+
+        // If no votes were cast then start a new vote window.
+        log!("Not enough votes cast - starting new vote window");
+
+        voting.start_timestamp = now;
+        voting.end_timestamp = checked_add!(now, i64::from(voting_window))?;
+    }
+
+    let votes: u64;
+
+    // Step 3: Get stake votes and update lock.
+    {
+        let mut stake = StakeV1::from_account_info_mut(ctx.accounts.stake)?;
+
+        // Guard stake voter.
+        stake.assert_voter(ctx.accounts.voter.key)?;
+
+        // Lock the stake until the end of the reveal window.
+        votes = stake.lock_for_vote(voting.reveal_end_timestamp()?)?;
+
+        stake.save()?;
+    }
+
+    // Step 4: Initialize `vote` account.
+    {
+        let bump = pda::vote::assert_pda(
+            ctx.accounts.vote.key,
+            ctx.accounts.voting.key,
+            ctx.accounts.stake.key,
+        )?;
+        let signer_seeds =
+            pda::vote::seeds_with_bump(ctx.accounts.voting.key, ctx.accounts.stake.key, &bump);
+
+        VoteV1::init(InitVote {
+            voting: *ctx.accounts.voting.key,
+            stake: *ctx.accounts.stake.key,
+            round,
+            rent_payer: *ctx.accounts.payer.key,
+            value: VoteValue::Committed(args.commitment),
+            votes,
+            voting_rent: 0,
+        })
+        .save(InitContext {
+            account: ctx.accounts.vote,
+            payer: ctx.accounts.payer,
+            system_program: ctx.accounts.system_program,
+            program_id,
+            signers_seeds: &[&signer_seeds],
+        })?;
+
+        voting.open_votes = checked_add!(voting.open_votes, 1)?;
+    }
+
+    // Step 5: Add committed votes, which are counted once revealed.
+    voting.unrevealed_count = checked_add!(voting.unrevealed_count, votes)?;
+
+    voting.save()?;
+
+    OracleEvent::VoteCommitted {
+        request: *ctx.accounts.request.key,
+        stake: *ctx.accounts.stake.key,
+        votes,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::instruction::AccountMeta;
+    use solana_program::system_program;
+
+    use super::*;
+    use crate::processor::tests::{request_params, TestContext};
+    use crate::state::RequestParams;
+
+    #[test]
+    fn commit_after_empty_window_starts_new_window() {
+        let mut context = TestContext::new();
+        let request = context
+            .create_disputed_request(RequestParams { reveal_window: 600, ..request_params() });
+        let (stake, owner) = context.create_stake(&request.governance_mint, 100);
+
+        context.now = context.state::<VotingV1>(&request.voting).end_timestamp;
+
+        let metas = [
+            AccountMeta::new_readonly(request.config, false),
+            AccountMeta::new_readonly(request.request, false),
+            AccountMeta::new(request.voting, false),
+            AccountMeta::new(pda::vote::pda(&request.voting, &stake).0, false),
+            AccountMeta::new(stake, false),
+            AccountMeta::new_readonly(owner, true),
+            AccountMeta::new(owner, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+
+        assert_eq!(
+            context.process(&metas, |program_id, accounts| {
+                commit_vote_v1(program_id, accounts, CommitVoteV1Args { commitment: [1; 32] })
+            }),
+            Ok(()),
+        );
+
+        // The new window has the voting window of the request rather than the config.
+        let voting = context.state::<VotingV1>(&request.voting);
+        assert_eq!(voting.start_timestamp, context.now);
+        assert_eq!(voting.end_timestamp, context.now + 3600);
+        assert_eq!(context.state::<StakeV1>(&stake).lock_timestamp, context.now + 4200);
+    }
+}
@@ -6,10 +6,10 @@ use solana_program::pubkey::Pubkey;
 use solana_program::sysvar::Sysvar;
 
 use crate::error::OracleError;
+use crate::event::OracleEvent;
 use crate::instruction::accounts::CreateAssertionV1Accounts;
 use crate::state::{
-    Account, AccountSized, AssertionV1, ConfigV1, InitAccount, InitAssertion, InitContext,
-    RequestState, RequestV1,
+    AccountSized, AssertionV1, InitAccount, InitAssertion, InitContext, RequestState, RequestV1,
 };
 use crate::{pda, utils};
 
@@ -34,19 +34,11 @@ pub fn create_assertion_v1<'a>(
     utils::assert_token_program(ctx.accounts.token_program.key)?;
     utils::assert_system_program(ctx.accounts.system_program.key)?;
 
-    let dispute_window: u32;
-
-    // Step 1: Get config dispute window.
-    {
-        let config = ConfigV1::from_account_info(ctx.accounts.config)?;
-
-        dispute_window = config.dispute_window;
-    }
-
     let bond: u64;
+    let dispute_window: u32;
     let now = Clock::get()?.unix_timestamp;
 
-    // Step 2: Update request state.
+    // Step 1: Update request state.
     {
         let mut request = RequestV1::from_account_info_mut(ctx.accounts.request)?;
 
@@ -62,16 +54,18 @@ pub fn create_assertion_v1<'a>(
 
         // The assertion timestamp on the request must have been reached.
         request.validate_assertion_timestamp(now)?;
-        // The asserted value must be valid for the request data type.
-        request.data.validate_value(args.value)?;
+        // The asserted value must be valid for the request data type, and may only be too early
+        // if the request allows it.
+        request.validate_value(args.value)?;
 
         bond = request.bond;
+        dispute_window = request.dispute_window;
 
         request.state = RequestState::Asserted;
         request.save()?;
     }
 
-    // Step 3: Initialize `assertion` account.
+    // Step 2: Initialize `assertion` account.
     {
         let bump =
             pda::assertion::assert_pda(ctx.accounts.assertion.key, ctx.accounts.request.key)?;
@@ -81,6 +75,7 @@ pub fn create_assertion_v1<'a>(
             request: *ctx.accounts.request.key,
             assertion_timestamp: now,
             asserter: *ctx.accounts.asserter.key,
+            rent_payer: *ctx.accounts.payer.key,
             asserted_value: args.value,
             dispute_window,
         })?
@@ -129,12 +124,72 @@ pub fn create_assertion_v1<'a>(
                 mint: ctx.accounts.bond_mint,
                 authority: ctx.accounts.asserter,
                 token_program: ctx.accounts.token_program,
+                remaining_accounts: ctx.remaining_accounts,
             },
             &[],
         )?;
+
+        // Step 3.3: Check the full bond was received, as the mint may charge a transfer fee.
+        if cpi::spl::account_amount(ctx.accounts.bond_escrow)? != bond {
+            return Err(OracleError::EscrowAmountMismatch.into());
+        }
     }
 
-    // TODO: Emit an event?
+    OracleEvent::Asserted {
+        request: *ctx.accounts.request.key,
+        asserter: *ctx.accounts.asserter.key,
+        value: args.value,
+    }
+    .emit()?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use solana_program::instruction::AccountMeta;
+    use solana_program::system_program;
+
+    use super::*;
+    use crate::processor::tests::{request_params, TestContext};
+
+    #[test]
+    fn assertion_uses_request_dispute_window() {
+        let mut context = TestContext::new();
+        let request = context.create_request(request_params()).request;
+
+        let bond_mint = context.create_mint(6);
+
+        let mut state = context.state::<RequestV1>(&request);
+        state.bond_mint = bond_mint;
+        context.set_state(request, &state);
+
+        let asserter = context.create_wallet();
+        let bond_source = context.create_token_account(&bond_mint, &asserter, 0);
+        let (assertion, _) = pda::assertion::pda(&request);
+
+        let metas = [
+            AccountMeta::new_readonly(state.config, false),
+            AccountMeta::new(request, false),
+            AccountMeta::new(assertion, false),
+            AccountMeta::new_readonly(bond_mint, false),
+            AccountMeta::new(bond_source, false),
+            AccountMeta::new(pda::assert_bond::pda(&request).0, false),
+            AccountMeta::new_readonly(asserter, true),
+            AccountMeta::new(asserter, true),
+            AccountMeta::new_readonly(spl_token_2022::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+
+        assert_eq!(
+            context.process(&metas, |program_id, accounts| {
+                create_assertion_v1(program_id, accounts, CreateAssertionV1Args { value: 1 })
+            }),
+            Ok(()),
+        );
+
+        // The dispute window is copied from the request rather than the config.
+        let assertion = context.state::<AssertionV1>(&assertion);
+        assert_eq!(assertion.expiration_timestamp, context.now + 3600);
+    }
+}
@@ -4,19 +4,35 @@ use solana_program::entrypoint::ProgramResult;
 use solana_program::pubkey::Pubkey;
 
 use crate::instruction::accounts::CreateConfigV1Accounts;
-use crate::state::{ConfigV1, InitAccount, InitConfig, InitContext};
+use crate::state::{ConfigV1, InitAccount, InitConfig, InitContext, TiePolicy, TransferFeePolicy};
 use crate::utils;
+use crate::utils::{Bounds, Bps};
 
 #[derive(Clone, BorshDeserialize)]
 pub struct CreateConfigV1Args {
     pub authority: Pubkey,
 
-    // TODO: Validate fee bps.
     pub bond_fee_bps: u16,
 
     pub dispute_window: u32,
     pub voting_window: u32,
+    pub reveal_window: u32,
     pub arbitration_window: u32,
+    pub claim_window: u32,
+
+    pub dispute_window_range: Bounds,
+    pub voting_window_range: Bounds,
+    pub arbitration_window_range: Bounds,
+
+    pub quorum_amount: u64,
+    pub quorum_bps: u16,
+
+    pub slash_bps: u16,
+    pub slash_window: u32,
+
+    pub tie_policy: TiePolicy,
+
+    pub transfer_fee_policy: TransferFeePolicy,
 }
 
 pub fn create_config_v1<'a>(
@@ -32,6 +48,18 @@ pub fn create_config_v1<'a>(
     // Guard programs.
     utils::assert_system_program(ctx.accounts.system_program.key)?;
 
+    // Guard fee.
+    Bps::try_from(args.bond_fee_bps)?;
+    Bps::try_from(args.quorum_bps)?;
+    Bps::try_from(args.slash_bps)?;
+
+    // Guard window ranges.
+    ConfigV1::validate_window_ranges(
+        args.dispute_window_range,
+        args.voting_window_range,
+        args.arbitration_window_range,
+    )?;
+
     // Step 1: Initialize `config` account.
     {
         ConfigV1::init(InitConfig {
@@ -39,7 +67,18 @@ pub fn create_config_v1<'a>(
             bond_fee_bps: args.bond_fee_bps,
             dispute_window: args.dispute_window,
             voting_window: args.voting_window,
+            reveal_window: args.reveal_window,
             arbitration_window: args.arbitration_window,
+            claim_window: args.claim_window,
+            dispute_window_range: args.dispute_window_range,
+            voting_window_range: args.voting_window_range,
+            arbitration_window_range: args.arbitration_window_range,
+            quorum_amount: args.quorum_amount,
+            quorum_bps: args.quorum_bps,
+            slash_bps: args.slash_bps,
+            tie_policy: args.tie_policy,
+            transfer_fee_policy: args.transfer_fee_policy,
+            slash_window: args.slash_window,
         })
         .save(InitContext {
             account: ctx.accounts.config,
@@ -8,8 +8,6 @@ use crate::state::{Account, ConfigV1, CurrencyV1, InitAccount, InitContext, Init
 use crate::utils::Bounds;
 use crate::{pda, utils};
 
-// TODO: Validate ranges.
-
 #[derive(Clone, BorshDeserialize)]
 pub struct CreateCurrencyV1Args {
     /// The valid reward range when creating a [`Request`].
@@ -37,11 +35,28 @@ pub fn create_currency_v1<'a>(
     utils::assert_token_program(ctx.accounts.token_program.key)?;
     utils::assert_system_program(ctx.accounts.system_program.key)?;
 
-    // Step 1: Check config authority.
-    ConfigV1::from_account_info(ctx.accounts.config)?
-        .assert_authority(ctx.accounts.authority.key)?;
+    // Guard mint.
+    utils::assert_owner(ctx.accounts.mint, ctx.accounts.token_program.key)?;
+
+    // Step 1: Check reward and bond ranges.
+    CurrencyV1::validate_ranges(args.reward_range, args.bond_range)?;
+
+    // Step 2: Check mint extensions.
+    CurrencyV1::validate_mint_extensions(&cpi::spl::mint_extension_types(ctx.accounts.mint)?)?;
+
+    // Step 3: Check config authority and transfer fee policy.
+    {
+        let config = ConfigV1::from_account_info(ctx.accounts.config)?;
+
+        // Guard config authority.
+        config.assert_authority(ctx.accounts.authority.key)?;
+
+        // Escrows must receive the full reward or bond, so mints which charge a transfer fee
+        // cannot be used.
+        config.validate_transfer_fee(cpi::spl::mint_transfer_fee_bps(ctx.accounts.mint)?)?;
+    }
 
-    // Step 2: Initialize currency account.
+    // Step 4: Initialize currency account.
     {
         let bump = pda::currency::assert_pda(
             ctx.accounts.currency.key,
@@ -1,13 +1,16 @@
 use borsh::BorshDeserialize;
 use solana_program::account_info::AccountInfo;
 use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
+use solana_utils::log;
 
 use crate::error::OracleError;
+use crate::event::OracleEvent;
 use crate::instruction::accounts::CreateRequestV1Accounts;
 use crate::state::{
     Account, AccountSized, ConfigV1, CurrencyV1, InitAccount, InitContext, InitRequest, OracleV1,
-    RequestData, RequestV1,
+    RequestData, RequestDerivation, RequestParams, RequestSigner, RequestV1,
 };
 use crate::{pda, utils};
 
@@ -21,6 +24,18 @@ pub struct CreateRequestV1Args {
     pub timestamp: i64,
     /// Arbitrator address.
     pub arbitrator: Pubkey,
+    /// Dispute window in seconds, in place of the dispute window of the config.
+    pub dispute_window: Option<u32>,
+    /// Voting window in seconds, in place of the voting window of the config.
+    pub voting_window: Option<u32>,
+    /// Arbitration window in seconds, in place of the arbitration window of the config.
+    pub arbitration_window: Option<u32>,
+    /// Nonce chosen by the creator to derive the request address from, in place of the next
+    /// request index in the oracle.
+    pub nonce: Option<u64>,
+    /// Whether the request can be resolved as too early, which resets it to accept a new
+    /// assertion.
+    pub allow_too_early: bool,
     /// Request data.
     pub data: RequestData,
 }
@@ -40,9 +55,6 @@ pub fn create_request_v1<'a>(
     utils::assert_token_program(ctx.accounts.token_program.key)?;
     utils::assert_system_program(ctx.accounts.system_program.key)?;
 
-    // Guard PDAs.
-    pda::oracle::assert_pda(ctx.accounts.oracle.key)?;
-
     // Step 1: Check reward.
     {
         let reward_currency = CurrencyV1::from_account_info(ctx.accounts.reward_currency)?;
@@ -51,6 +63,7 @@ pub fn create_request_v1<'a>(
         reward_currency.assert_pda(ctx.accounts.reward_currency.key)?;
         reward_currency.assert_config(ctx.accounts.config.key)?;
         reward_currency.assert_mint(ctx.accounts.reward_mint.key)?;
+        reward_currency.assert_active()?;
 
         // Check the reward bounds.
         if !reward_currency.reward_range.contains(args.reward) {
@@ -67,6 +80,7 @@ pub fn create_request_v1<'a>(
         // Guard currency.
         bond_currency.assert_pda(ctx.accounts.bond_currency.key)?;
         bond_currency.assert_config(ctx.accounts.config.key)?;
+        bond_currency.assert_active()?;
 
         // Check the bond bounds.
         if !bond_currency.bond_range.contains(args.bond) {
@@ -76,36 +90,80 @@ pub fn create_request_v1<'a>(
         bond_mint = bond_currency.mint;
     }
 
+    let derivation: RequestDerivation;
     let request_index: u64;
-
-    // Step 3: Get and increment next request index.
-    {
-        let mut oracle = OracleV1::from_account_info_mut(ctx.accounts.oracle)?;
-
+    let nonce: u64;
+
+    // Step 3: Get the request nonce, or get and increment next request index.
+    if let Some(request_nonce) = args.nonce {
+        // The oracle is not required, so that requests with a nonce are not serialized by it.
+        derivation = RequestDerivation::Creator;
+        request_index = 0;
+        nonce = request_nonce;
+    } else {
+        let Some(oracle_info) = ctx.accounts.oracle else {
+            log!("Error: Oracle account is required without a nonce");
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Guard oracle PDA.
+        pda::oracle::assert_pda(oracle_info.key)?;
+
+        let mut oracle = OracleV1::from_account_info_mut(oracle_info)?;
+
+        derivation = RequestDerivation::Index;
         request_index = oracle.next_index;
+        nonce = 0;
 
         oracle.next_index = checked_add!(oracle.next_index, 1)?;
         oracle.save()?;
     }
 
-    // Step 4: Check config.
-    ConfigV1::from_account_info(ctx.accounts.config)?;
+    let params: RequestParams;
+
+    // Step 4: Get request parameters from the config, so that config updates do not affect the
+    // request. The request windows must be within the config window ranges if specified.
+    {
+        let config = ConfigV1::from_account_info(ctx.accounts.config)?;
+
+        params = config.request_params(
+            args.dispute_window,
+            args.voting_window,
+            args.arbitration_window,
+        )?;
+    }
 
     // Step 5: Initialize request account.
     {
-        let bump = pda::request::assert_pda(ctx.accounts.request.key, &request_index)?;
-        let signer_seeds = pda::request::seeds_with_bump(&request_index, &bump);
+        let bump = match derivation {
+            RequestDerivation::Index => {
+                pda::request::assert_pda(ctx.accounts.request.key, &request_index)?
+            }
+            RequestDerivation::Creator => pda::creator_request::assert_pda(
+                ctx.accounts.request.key,
+                ctx.accounts.creator.key,
+                &nonce,
+            )?,
+        };
+        let signer =
+            RequestSigner::new(derivation, request_index, nonce, *ctx.accounts.creator.key, bump);
+        let signer_seeds = signer.seeds();
 
         RequestV1::try_init(InitRequest {
             index: request_index,
+            nonce,
             config: *ctx.accounts.config.key,
             creator: *ctx.accounts.creator.key,
+            rent_payer: *ctx.accounts.payer.key,
             reward: args.reward,
             reward_mint: *ctx.accounts.reward_mint.key,
             bond: args.bond,
             bond_mint,
             timestamp: args.timestamp,
+            params,
             arbitrator: args.arbitrator,
+            derivation,
+            allow_too_early: args.allow_too_early,
             data: args.data,
         })?
         .save(InitContext {
@@ -150,12 +208,25 @@ pub fn create_request_v1<'a>(
                 mint: ctx.accounts.reward_mint,
                 authority: ctx.accounts.creator,
                 token_program: ctx.accounts.token_program,
+                remaining_accounts: ctx.remaining_accounts,
             },
             &[],
         )?;
+
+        // Step 6.3: Check the full reward was received, as the mint may charge a transfer fee.
+        if cpi::spl::account_amount(ctx.accounts.reward_escrow)? != args.reward {
+            return Err(OracleError::EscrowAmountMismatch.into());
+        }
     }
 
-    // TODO: Emit an event?
+    OracleEvent::RequestCreated {
+        request: *ctx.accounts.request.key,
+        index: request_index,
+        config: *ctx.accounts.config.key,
+        creator: *ctx.accounts.creator.key,
+        nonce: args.nonce,
+    }
+    .emit()?;
 
     Ok(())
 }
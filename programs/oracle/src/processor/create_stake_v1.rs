@@ -5,7 +5,7 @@ use solana_program::pubkey::Pubkey;
 
 use crate::error::OracleError;
 use crate::instruction::accounts::CreateStakeV1Accounts;
-use crate::state::{Account, InitAccount, InitContext, InitStake, OracleV1, StakeV1};
+use crate::state::{AccountSized, InitAccount, InitContext, InitStake, OracleV1, StakeV1};
 use crate::{pda, utils};
 
 #[derive(Clone, BorshDeserialize)]
@@ -32,13 +32,17 @@ pub fn create_stake_v1<'a>(
     // Guard PDAs.
     pda::oracle::assert_pda(ctx.accounts.oracle.key)?;
 
-    // Step 1: Check governance mint.
+    // Step 1: Check governance mint and add staked amount to total.
     {
-        let oracle = OracleV1::from_account_info(ctx.accounts.oracle)?;
+        let mut oracle = OracleV1::from_account_info_mut(ctx.accounts.oracle)?;
 
         if !solana_utils::pubkeys_eq(&oracle.governance_mint, ctx.accounts.mint.key) {
             return Err(OracleError::StakeMintMismatch.into());
         }
+
+        oracle.total_staked = checked_add!(oracle.total_staked, args.amount)?;
+
+        oracle.save()?;
     }
 
     // Step 2: Create stake account.
@@ -89,6 +93,7 @@ pub fn create_stake_v1<'a>(
                 mint: ctx.accounts.mint,
                 authority: ctx.accounts.wallet,
                 token_program: ctx.accounts.token_program,
+                remaining_accounts: ctx.remaining_accounts,
             },
             &[],
         )?;
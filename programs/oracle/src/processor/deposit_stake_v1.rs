@@ -0,0 +1,74 @@
+use borsh::BorshDeserialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::pubkey::Pubkey;
+
+use crate::instruction::accounts::DepositStakeV1Accounts;
+use crate::state::{AccountSized, OracleV1, StakeV1};
+use crate::{pda, utils};
+
+#[derive(Clone, BorshDeserialize)]
+pub struct DepositStakeV1Args {
+    /// Amount to deposit.
+    pub amount: u64,
+}
+
+pub fn deposit_stake_v1<'a>(
+    _program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    args: DepositStakeV1Args,
+) -> ProgramResult {
+    let ctx = DepositStakeV1Accounts::context(accounts)?;
+
+    // Guard signatures.
+    utils::assert_signer(ctx.accounts.wallet)?;
+
+    // Guard programs.
+    utils::assert_token_program(ctx.accounts.token_program.key)?;
+
+    // Guard PDAs.
+    pda::oracle::assert_pda(ctx.accounts.oracle.key)?;
+    pda::stake_pool::assert_pda(ctx.accounts.stake_pool.key, ctx.accounts.mint.key)?;
+
+    // Step 1: Add deposited amount to stake.
+    {
+        let mut stake = StakeV1::from_account_info_mut(ctx.accounts.stake)?;
+
+        // Guard stake.
+        stake.assert_mint(ctx.accounts.mint.key)?;
+
+        stake.amount = checked_add!(stake.amount, args.amount)?;
+
+        stake.save()?;
+    }
+
+    // Step 2: Add deposited amount to total staked.
+    {
+        let mut oracle = OracleV1::from_account_info_mut(ctx.accounts.oracle)?;
+
+        oracle.total_staked = checked_add!(oracle.total_staked, args.amount)?;
+
+        oracle.save()?;
+    }
+
+    // Step 3: Deposit amount into escrow account.
+    {
+        let mint_decimals = cpi::spl::mint_decimals(ctx.accounts.mint)?;
+
+        cpi::spl::transfer_checked(
+            args.amount,
+            mint_decimals,
+            cpi::spl::TransferChecked {
+                source: ctx.accounts.stake_source,
+                destination: ctx.accounts.stake_pool,
+                mint: ctx.accounts.mint,
+                authority: ctx.accounts.wallet,
+                token_program: ctx.accounts.token_program,
+                remaining_accounts: ctx.remaining_accounts,
+            },
+            &[],
+        )?;
+    }
+
+    Ok(())
+}
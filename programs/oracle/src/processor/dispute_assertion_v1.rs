@@ -5,9 +5,10 @@ use solana_program::pubkey::Pubkey;
 use solana_program::sysvar::Sysvar;
 
 use crate::error::OracleError;
+use crate::event::OracleEvent;
 use crate::instruction::accounts::DisputeAssertionV1Accounts;
 use crate::state::{
-    Account, AccountSized, AssertionV1, ConfigV1, InitAccount, InitContext, InitVoting, OracleV1,
+    Account, AccountSized, AssertionV1, InitAccount, InitContext, InitVoting, OracleV1,
     RequestState, RequestV1, VotingV1,
 };
 use crate::{pda, utils};
@@ -38,19 +39,12 @@ pub fn dispute_assertion_v1<'a>(
         governance_mint = oracle.governance_mint;
     }
 
-    let voting_window: u32;
-
-    // Step 2: Get config voting window.
-    {
-        let config = ConfigV1::from_account_info(ctx.accounts.config)?;
-
-        voting_window = config.voting_window;
-    }
-
     let now = Clock::get()?.unix_timestamp;
     let bond: u64;
+    let voting_window: u32;
+    let reveal_window: u32;
 
-    // Step 3: Update request and assertion states.
+    // Step 2: Update request and assertion states.
     {
         let mut request = RequestV1::from_account_info_mut(ctx.accounts.request)?;
 
@@ -65,11 +59,14 @@ pub fn dispute_assertion_v1<'a>(
             RequestState::Requested => return Err(OracleError::NotAsserted.into()),
             RequestState::Disputed => return Err(OracleError::AlreadyDisputed.into()),
             RequestState::Resolved => return Err(OracleError::AlreadyResolved.into()),
+            RequestState::Cancelled => return Err(OracleError::RequestCancelled.into()),
         }
 
         bond = request.bond;
+        voting_window = request.voting_window;
+        reveal_window = request.reveal_window;
 
-        // Step 3.1: Check and update assertion.
+        // Step 2.1: Check and update assertion.
         {
             // Guard assertion PDA.
             pda::assertion::assert_pda(ctx.accounts.assertion.key, ctx.accounts.request.key)?;
@@ -88,18 +85,18 @@ pub fn dispute_assertion_v1<'a>(
             assertion.save()?;
         }
 
-        // Step 3.2: Update request state.
+        // Step 2.2: Update request state.
         {
             request.state = RequestState::Disputed;
             request.save()?;
         }
     }
 
-    // Step 4: Transfer bond to escrow.
+    // Step 3: Transfer bond to escrow.
     {
         let mint_decimals = cpi::spl::mint_decimals(ctx.accounts.bond_mint)?;
 
-        // Step 4.1: Initialize `bond_escrow` account.
+        // Step 3.1: Initialize `bond_escrow` account.
         {
             let bump = pda::dispute_bond::assert_pda(
                 ctx.accounts.bond_escrow.key,
@@ -120,7 +117,7 @@ pub fn dispute_assertion_v1<'a>(
             )?;
         }
 
-        // Step 4.2: Transfer bond from `bond_source` to `bond_escrow`.
+        // Step 3.2: Transfer bond from `bond_source` to `bond_escrow`.
         cpi::spl::transfer_checked(
             bond,
             mint_decimals,
@@ -130,12 +127,18 @@ pub fn dispute_assertion_v1<'a>(
                 mint: ctx.accounts.bond_mint,
                 authority: ctx.accounts.disputer,
                 token_program: ctx.accounts.token_program,
+                remaining_accounts: ctx.remaining_accounts,
             },
             &[],
         )?;
+
+        // Step 3.3: Check the full bond was received, as the mint may charge a transfer fee.
+        if cpi::spl::account_amount(ctx.accounts.bond_escrow)? != bond {
+            return Err(OracleError::EscrowAmountMismatch.into());
+        }
     }
 
-    // Step 5: Initialize `voting` account.
+    // Step 4: Initialize `voting` account.
     {
         let bump = pda::voting::assert_pda(ctx.accounts.voting.key, ctx.accounts.request.key)?;
         let signer_seeds = pda::voting::seeds_with_bump(ctx.accounts.request.key, &bump);
@@ -143,8 +146,10 @@ pub fn dispute_assertion_v1<'a>(
         VotingV1::try_init(InitVoting {
             request: *ctx.accounts.request.key,
             governance_mint,
+            rent_payer: *ctx.accounts.payer.key,
             start_timestamp: now,
             voting_window,
+            reveal_window,
         })?
         .save(InitContext {
             account: ctx.accounts.voting,
@@ -155,7 +160,73 @@ pub fn dispute_assertion_v1<'a>(
         })?;
     }
 
-    // TODO: Emit an event?
+    OracleEvent::Disputed {
+        request: *ctx.accounts.request.key,
+        disputer: *ctx.accounts.disputer.key,
+    }
+    .emit()?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use solana_program::instruction::AccountMeta;
+    use solana_program::system_program;
+
+    use super::*;
+    use crate::processor::tests::{request_params, TestContext};
+    use crate::state::{InitAssertion, RequestParams};
+
+    #[test]
+    fn dispute_uses_request_voting_windows() {
+        let mut context = TestContext::new();
+        let request =
+            context.create_request(RequestParams { reveal_window: 600, ..request_params() });
+
+        let oracle = context.create_oracle(&request.governance_mint, 0);
+        let bond_mint = context.create_mint(6);
+
+        let mut state = context.state::<RequestV1>(&request.request);
+        state.state = RequestState::Asserted;
+        state.bond_mint = bond_mint;
+        context.set_state(request.request, &state);
+
+        let (assertion, _) = <(AssertionV1, usize)>::try_from(InitAssertion {
+            request: request.request,
+            assertion_timestamp: context.now,
+            asserter: Pubkey::new_unique(),
+            rent_payer: Pubkey::new_unique(),
+            asserted_value: 1,
+            dispute_window: 3600,
+        })
+        .unwrap();
+
+        context.set_state(pda::assertion::pda(&request.request).0, &assertion);
+
+        let disputer = context.create_wallet();
+        let bond_source = context.create_token_account(&bond_mint, &disputer, 0);
+
+        let metas = [
+            AccountMeta::new_readonly(oracle, false),
+            AccountMeta::new_readonly(request.config, false),
+            AccountMeta::new(request.request, false),
+            AccountMeta::new(pda::assertion::pda(&request.request).0, false),
+            AccountMeta::new(request.voting, false),
+            AccountMeta::new_readonly(bond_mint, false),
+            AccountMeta::new(bond_source, false),
+            AccountMeta::new(pda::dispute_bond::pda(&request.request).0, false),
+            AccountMeta::new_readonly(disputer, true),
+            AccountMeta::new(disputer, true),
+            AccountMeta::new_readonly(spl_token_2022::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+
+        assert_eq!(context.process(&metas, dispute_assertion_v1), Ok(()));
+
+        // The voting and reveal windows are copied from the request rather than the config.
+        let voting = context.state::<VotingV1>(&request.voting);
+        assert_eq!(voting.end_timestamp, context.now + 3600);
+        assert_eq!(voting.reveal_window, 600);
+    }
+}
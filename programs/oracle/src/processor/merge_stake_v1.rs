@@ -0,0 +1,166 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+
+use crate::error::OracleError;
+use crate::instruction::accounts::MergeStakeV1Accounts;
+use crate::state::{Account, AccountSized, StakeV1};
+use crate::utils;
+
+pub fn merge_stake_v1<'a>(
+    _program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    let ctx = MergeStakeV1Accounts::context(accounts)?;
+
+    // Guard signatures.
+    utils::assert_signer(ctx.accounts.wallet)?;
+
+    if solana_utils::pubkeys_eq(ctx.accounts.stake.key, ctx.accounts.source_stake.key) {
+        return Err(OracleError::StakeMergeSelf.into());
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+
+    // Step 1: Move source stake amount into destination stake.
+    {
+        let mut stake = StakeV1::from_account_info_mut(ctx.accounts.stake)?;
+        let source_stake = StakeV1::from_account_info(ctx.accounts.source_stake)?;
+
+        // Guard stakes.
+        stake.assert_owner(ctx.accounts.wallet.key)?;
+        source_stake.assert_owner(ctx.accounts.wallet.key)?;
+        source_stake.assert_mint(&stake.mint)?;
+
+        // Neither stake can be merged while it is locked for a vote.
+        stake.validate_unlocked(now)?;
+        source_stake.validate_unlocked(now)?;
+
+        // The source stake is closed, so cannot have votes which refer to it.
+        source_stake.validate_no_open_votes()?;
+
+        stake.amount = checked_add!(stake.amount, source_stake.amount)?;
+
+        stake.save()?;
+    }
+
+    // Step 2: Close source stake account.
+    solana_utils::close_account(ctx.accounts.source_stake, ctx.accounts.wallet)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::instruction::AccountMeta;
+
+    use super::*;
+    use crate::processor::tests::TestContext;
+    use crate::state::InitStake;
+
+    fn create_stake(context: &mut TestContext, mint: Pubkey, owner: Pubkey, amount: u64) -> Pubkey {
+        let (state, _) = <(StakeV1, usize)>::from(InitStake { mint, owner, amount });
+
+        let stake = Pubkey::new_unique();
+        context.set_state(stake, &state);
+        stake
+    }
+
+    fn update_stake(context: &mut TestContext, stake: Pubkey, update: impl FnOnce(&mut StakeV1)) {
+        let mut state = context.state::<StakeV1>(&stake);
+        update(&mut state);
+        context.set_state(stake, &state);
+    }
+
+    fn merge(
+        context: &mut TestContext,
+        stake: Pubkey,
+        source: Pubkey,
+        wallet: Pubkey,
+    ) -> ProgramResult {
+        let metas = [
+            AccountMeta::new(stake, false),
+            AccountMeta::new(source, false),
+            AccountMeta::new(wallet, true),
+        ];
+
+        context.process(&metas, merge_stake_v1)
+    }
+
+    #[test]
+    fn merge_closes_source() {
+        let mut context = TestContext::new();
+        let wallet = context.create_wallet();
+        let mint = Pubkey::new_unique();
+
+        let stake = create_stake(&mut context, mint, wallet, 100);
+        let source = create_stake(&mut context, mint, wallet, 50);
+
+        assert_eq!(merge(&mut context, stake, source, wallet), Ok(()));
+        assert_eq!(context.state::<StakeV1>(&stake).amount, 150);
+        assert!(!context.exists(&source));
+    }
+
+    #[test]
+    fn merge_locked() {
+        let mut context = TestContext::new();
+        let wallet = context.create_wallet();
+        let mint = Pubkey::new_unique();
+        let now = context.now;
+
+        let stake = create_stake(&mut context, mint, wallet, 100);
+        let source = create_stake(&mut context, mint, wallet, 50);
+
+        update_stake(&mut context, source, |state| state.lock_timestamp = now + 1);
+        assert_eq!(
+            merge(&mut context, stake, source, wallet),
+            Err(OracleError::StakeLocked.into())
+        );
+
+        update_stake(&mut context, source, |state| state.lock_timestamp = i64::MIN);
+        update_stake(&mut context, stake, |state| state.lock_timestamp = now + 1);
+        assert_eq!(
+            merge(&mut context, stake, source, wallet),
+            Err(OracleError::StakeLocked.into())
+        );
+    }
+
+    #[test]
+    fn merge_source_with_open_votes() {
+        let mut context = TestContext::new();
+        let wallet = context.create_wallet();
+        let mint = Pubkey::new_unique();
+
+        let stake = create_stake(&mut context, mint, wallet, 100);
+        let source = create_stake(&mut context, mint, wallet, 50);
+
+        update_stake(&mut context, source, |state| state.open_votes = 1);
+        assert_eq!(
+            merge(&mut context, stake, source, wallet),
+            Err(OracleError::StakeHasOpenVotes.into()),
+        );
+        assert!(context.exists(&source));
+
+        // Votes remain valid when stake is merged into their stake.
+        assert_eq!(merge(&mut context, source, stake, wallet), Ok(()));
+        assert_eq!(context.state::<StakeV1>(&source).open_votes, 1);
+    }
+
+    #[test]
+    fn merge_other_owner() {
+        let mut context = TestContext::new();
+        let wallet = context.create_wallet();
+        let other = context.create_wallet();
+        let mint = Pubkey::new_unique();
+
+        let stake = create_stake(&mut context, mint, wallet, 100);
+        let source = create_stake(&mut context, mint, other, 50);
+
+        assert_eq!(
+            merge(&mut context, stake, source, wallet),
+            Err(OracleError::StakeOwnerMismatch.into()),
+        );
+    }
+}
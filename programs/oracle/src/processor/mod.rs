@@ -4,39 +4,74 @@ use solana_program::entrypoint::ProgramResult;
 use solana_program::pubkey::Pubkey;
 use solana_utils::{log, VariantName};
 
+mod arbitrate_v1;
+mod cancel_request_v1;
+mod change_vote_v1;
 mod claim_assertion_v1;
 mod claim_dispute_v1;
+mod claim_refund_v1;
 mod claim_vote_v1;
+mod close_currency_v1;
+mod close_request_v1;
+mod close_vote_v1;
 mod close_voting_v1;
+mod commit_vote_v1;
 mod create_assertion_v1;
 mod create_config_v1;
 mod create_currency_v1;
 mod create_oracle_v1;
 mod create_request_v1;
 mod create_stake_v1;
+mod deposit_stake_v1;
 mod dispute_assertion_v1;
+mod merge_stake_v1;
 mod resolve_assertion_v1;
+mod reveal_vote_v1;
+mod set_stake_delegate_v1;
+mod slash_vote_v1;
 mod submit_vote_v1;
 mod update_config_v1;
 mod update_currency_v1;
 mod update_oracle_v1;
+mod withdraw_fees_v1;
+mod withdraw_stake_v1;
+mod withdraw_vote_v1;
 
+#[cfg(test)]
+mod tests;
+
+pub(crate) use self::arbitrate_v1::*;
+pub(crate) use self::cancel_request_v1::*;
+pub(crate) use self::change_vote_v1::*;
 pub(crate) use self::claim_assertion_v1::*;
 pub(crate) use self::claim_dispute_v1::*;
+pub(crate) use self::claim_refund_v1::*;
 pub(crate) use self::claim_vote_v1::*;
+pub(crate) use self::close_currency_v1::*;
+pub(crate) use self::close_request_v1::*;
+pub(crate) use self::close_vote_v1::*;
 pub(crate) use self::close_voting_v1::*;
+pub(crate) use self::commit_vote_v1::*;
 pub(crate) use self::create_assertion_v1::*;
 pub(crate) use self::create_config_v1::*;
 pub(crate) use self::create_currency_v1::*;
 pub(crate) use self::create_oracle_v1::*;
 pub(crate) use self::create_request_v1::*;
 pub(crate) use self::create_stake_v1::*;
+pub(crate) use self::deposit_stake_v1::*;
 pub(crate) use self::dispute_assertion_v1::*;
+pub(crate) use self::merge_stake_v1::*;
 pub(crate) use self::resolve_assertion_v1::*;
+pub(crate) use self::reveal_vote_v1::*;
+pub(crate) use self::set_stake_delegate_v1::*;
+pub(crate) use self::slash_vote_v1::*;
 pub(crate) use self::submit_vote_v1::*;
 pub(crate) use self::update_config_v1::*;
 pub(crate) use self::update_currency_v1::*;
 pub(crate) use self::update_oracle_v1::*;
+pub(crate) use self::withdraw_fees_v1::*;
+pub(crate) use self::withdraw_stake_v1::*;
+pub(crate) use self::withdraw_vote_v1::*;
 
 pub fn process_instruction<'a>(
     program_id: &'a Pubkey,
@@ -66,5 +101,21 @@ pub fn process_instruction<'a>(
         I::ClaimAssertionV1 => claim_assertion_v1(program_id, accounts),
         I::ClaimDisputeV1 => claim_dispute_v1(program_id, accounts),
         I::ClaimVoteV1 => claim_vote_v1(program_id, accounts),
+        I::WithdrawStakeV1(args) => withdraw_stake_v1(program_id, accounts, args),
+        I::DepositStakeV1(args) => deposit_stake_v1(program_id, accounts, args),
+        I::MergeStakeV1 => merge_stake_v1(program_id, accounts),
+        I::SetStakeDelegateV1(args) => set_stake_delegate_v1(program_id, accounts, args),
+        I::ArbitrateV1(args) => arbitrate_v1(program_id, accounts, args),
+        I::WithdrawFeesV1(args) => withdraw_fees_v1(program_id, accounts, args),
+        I::CancelRequestV1 => cancel_request_v1(program_id, accounts),
+        I::CloseRequestV1 => close_request_v1(program_id, accounts),
+        I::SlashVoteV1 => slash_vote_v1(program_id, accounts),
+        I::CommitVoteV1(args) => commit_vote_v1(program_id, accounts, args),
+        I::RevealVoteV1(args) => reveal_vote_v1(program_id, accounts, args),
+        I::ChangeVoteV1(args) => change_vote_v1(program_id, accounts, args),
+        I::WithdrawVoteV1 => withdraw_vote_v1(program_id, accounts),
+        I::ClaimRefundV1 => claim_refund_v1(program_id, accounts),
+        I::CloseCurrencyV1 => close_currency_v1(program_id, accounts),
+        I::CloseVoteV1 => close_vote_v1(program_id, accounts),
     }
 }
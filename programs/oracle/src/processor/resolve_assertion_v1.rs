@@ -5,6 +5,7 @@ use solana_program::pubkey::Pubkey;
 use solana_program::sysvar::Sysvar;
 
 use crate::error::OracleError;
+use crate::event::OracleEvent;
 use crate::instruction::accounts::ResolveAssertionV1Accounts;
 use crate::pda;
 use crate::state::{Account, AccountSized, AssertionV1, RequestState, RequestV1};
@@ -26,6 +27,7 @@ pub fn resolve_assertion_v1<'a>(
         RequestState::Requested => return Err(OracleError::NotAsserted.into()),
         RequestState::Disputed => return Err(OracleError::AlreadyDisputed.into()),
         RequestState::Resolved => return Err(OracleError::AlreadyResolved.into()),
+        RequestState::Cancelled => return Err(OracleError::RequestCancelled.into()),
     }
 
     // Guard assertion PDA.
@@ -44,7 +46,8 @@ pub fn resolve_assertion_v1<'a>(
 
     request.save()?;
 
-    // TODO: Emit an event?
+    OracleEvent::Resolved { request: *ctx.accounts.request.key, value: assertion.asserted_value }
+        .emit()?;
 
     Ok(())
 }
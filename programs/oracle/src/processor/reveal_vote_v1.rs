@@ -0,0 +1,118 @@
+use borsh::BorshDeserialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+
+use crate::error::OracleError;
+use crate::event::OracleEvent;
+use crate::instruction::accounts::RevealVoteV1Accounts;
+use crate::state::{Account, AccountSized, RequestState, RequestV1, StakeV1, VoteV1, VotingV1};
+use crate::{pda, utils};
+
+#[derive(Clone, BorshDeserialize)]
+pub struct RevealVoteV1Args {
+    /// Value voted for.
+    pub value: u64,
+    /// Salt used in the commitment.
+    pub salt: [u8; 32],
+}
+
+pub fn reveal_vote_v1<'a>(
+    _program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    args: RevealVoteV1Args,
+) -> ProgramResult {
+    let ctx = RevealVoteV1Accounts::context(accounts)?;
+
+    // Guard signatures.
+    utils::assert_signer(ctx.accounts.voter)?;
+    utils::assert_signer(ctx.accounts.payer)?;
+
+    // Guard programs.
+    utils::assert_system_program(ctx.accounts.system_program.key)?;
+
+    let round: u32;
+
+    // Step 1: Check voting has not yet resolved the request.
+    {
+        let request = RequestV1::from_account_info(ctx.accounts.request)?;
+
+        // Guard request.
+        request.assert_pda(ctx.accounts.request.key)?;
+
+        // If the request state is not `Disputed`,
+        // then the voting must have ended and resolved the request.
+        if request.state != RequestState::Disputed {
+            return Err(OracleError::NotDisputed.into());
+        }
+
+        // The value must be valid for the request data type, and a vote for too early would
+        // reset the request, which it may not allow.
+        request.validate_value(args.value)?;
+
+        round = request.round;
+    }
+
+    // Guard PDAs.
+    pda::voting::assert_pda(ctx.accounts.voting.key, ctx.accounts.request.key)?;
+    pda::vote::assert_pda(ctx.accounts.vote.key, ctx.accounts.voting.key, ctx.accounts.stake.key)?;
+
+    let now = Clock::get()?.unix_timestamp;
+
+    let mut voting = VotingV1::from_account_info_mut(ctx.accounts.voting)?;
+
+    // Step 2: Check the reveal window is open.
+    if now < voting.end_timestamp || now >= voting.reveal_end_timestamp()? {
+        return Err(OracleError::RevealWindowNotOpen.into());
+    }
+
+    // Step 3: Check stake voter.
+    {
+        let stake = StakeV1::from_account_info(ctx.accounts.stake)?;
+
+        // Guard stake voter.
+        stake.assert_voter(ctx.accounts.voter.key)?;
+    }
+
+    let mut vote = VoteV1::from_account_info_mut(ctx.accounts.vote)?;
+
+    // Step 4: Reveal the vote.
+    {
+        // Guard vote round.
+        vote.assert_round(round)?;
+
+        vote.reveal(args.value, &args.salt)?;
+    }
+
+    let votes = vote.votes;
+
+    // Step 5: Add votes for the revealed value.
+    {
+        voting.unrevealed_count = checked_sub!(voting.unrevealed_count, votes)?;
+        voting.add_votes(args.value, votes)?;
+
+        let voting_rent = voting.realloc(ctx.accounts.payer, ctx.accounts.system_program)?;
+
+        // The rent is refunded to the rent payer of the vote when it is closed, so must be paid
+        // by them.
+        if voting_rent > 0 {
+            vote.assert_rent_payer(ctx.accounts.payer.key)?;
+            vote.voting_rent = checked_add!(vote.voting_rent, voting_rent)?;
+        }
+    }
+
+    vote.save()?;
+    voting.save()?;
+
+    OracleEvent::VoteCast {
+        request: *ctx.accounts.request.key,
+        stake: *ctx.accounts.stake.key,
+        value: args.value,
+        votes,
+    }
+    .emit()?;
+
+    Ok(())
+}
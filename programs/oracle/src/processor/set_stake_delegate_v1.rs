@@ -0,0 +1,91 @@
+use borsh::BorshDeserialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::pubkey::Pubkey;
+
+use crate::instruction::accounts::SetStakeDelegateV1Accounts;
+use crate::state::{AccountSized, StakeV1};
+use crate::utils;
+
+#[derive(Clone, BorshDeserialize)]
+pub struct SetStakeDelegateV1Args {
+    /// The new delegate address.
+    ///
+    /// Setting the delegate to the stake owner revokes the current delegate.
+    pub new_delegate: Pubkey,
+}
+
+pub fn set_stake_delegate_v1<'a>(
+    _program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    args: SetStakeDelegateV1Args,
+) -> ProgramResult {
+    let ctx = SetStakeDelegateV1Accounts::context(accounts)?;
+
+    // Guard signatures.
+    utils::assert_signer(ctx.accounts.wallet)?;
+
+    // Step 1: Update stake delegate.
+    {
+        let mut stake = StakeV1::from_account_info_mut(ctx.accounts.stake)?;
+
+        // Only the owner can change the delegate.
+        stake.assert_owner(ctx.accounts.wallet.key)?;
+
+        stake.delegate = args.new_delegate;
+
+        stake.save()?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::instruction::AccountMeta;
+
+    use super::*;
+    use crate::error::OracleError;
+    use crate::processor::tests::TestContext;
+
+    fn set_delegate(
+        context: &mut TestContext,
+        stake: Pubkey,
+        wallet: Pubkey,
+        new_delegate: Pubkey,
+    ) -> ProgramResult {
+        let metas = [AccountMeta::new(stake, false), AccountMeta::new_readonly(wallet, true)];
+
+        context.process(&metas, |program_id, accounts| {
+            set_stake_delegate_v1(program_id, accounts, SetStakeDelegateV1Args { new_delegate })
+        })
+    }
+
+    #[test]
+    fn owner_sets_delegate() {
+        let mut context = TestContext::new();
+        let (stake, owner) = context.create_stake(&Pubkey::new_unique(), 100);
+        let delegate = Pubkey::new_unique();
+
+        assert_eq!(set_delegate(&mut context, stake, owner, delegate), Ok(()));
+        assert_eq!(context.state::<StakeV1>(&stake).delegate, delegate);
+
+        // Setting the delegate to the owner revokes the delegate.
+        assert_eq!(set_delegate(&mut context, stake, owner, owner), Ok(()));
+        assert_eq!(context.state::<StakeV1>(&stake).delegate, owner);
+    }
+
+    #[test]
+    fn delegate_cannot_set_delegate() {
+        let mut context = TestContext::new();
+        let (stake, owner) = context.create_stake(&Pubkey::new_unique(), 100);
+        let delegate = Pubkey::new_unique();
+
+        assert_eq!(set_delegate(&mut context, stake, owner, delegate), Ok(()));
+        assert_eq!(
+            set_delegate(&mut context, stake, delegate, Pubkey::new_unique()),
+            Err(OracleError::StakeOwnerMismatch.into()),
+        );
+        assert_eq!(context.state::<StakeV1>(&stake).delegate, delegate);
+    }
+}
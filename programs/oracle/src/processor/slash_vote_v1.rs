@@ -0,0 +1,247 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+use solana_utils::log;
+
+use crate::error::OracleError;
+use crate::event::OracleEvent;
+use crate::instruction::accounts::SlashVoteV1Accounts;
+use crate::pda;
+use crate::state::{
+    Account, AccountSized, OracleV1, RequestState, RequestV1, StakeV1, VoteV1, VotingV1,
+};
+use crate::utils::Bps;
+
+pub fn slash_vote_v1<'a>(
+    _program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    let ctx = SlashVoteV1Accounts::context(accounts)?;
+
+    let slash_bps: Bps;
+    let resolved_value: u64;
+    let round: u32;
+
+    // Step 1: Check request state and slash rate.
+    {
+        let request = RequestV1::from_account_info(ctx.accounts.request)?;
+
+        // Guard request.
+        request.assert_pda(ctx.accounts.request.key)?;
+        request.assert_config(ctx.accounts.config.key)?;
+
+        // The request must be resolved to know which votes are incorrect.
+        if request.state != RequestState::Resolved {
+            return Err(OracleError::NotResolved.into());
+        }
+
+        slash_bps = Bps::try_from(request.slash_bps)?;
+
+        if slash_bps.get() == 0 {
+            return Err(OracleError::SlashingDisabled.into());
+        }
+
+        // Votes are slashed before claims begin, so that every voter for the resolved value
+        // receives the same share of the slashed stake.
+        let now = Clock::get()?.unix_timestamp;
+
+        if now >= request.claim_start_timestamp()? {
+            return Err(OracleError::SlashWindowExpired.into());
+        }
+
+        resolved_value = request.value;
+        round = request.round;
+    }
+
+    // Guard PDAs.
+    pda::oracle::assert_pda(ctx.accounts.oracle.key)?;
+    pda::voting::assert_pda(ctx.accounts.voting.key, ctx.accounts.request.key)?;
+    pda::vote::assert_pda(ctx.accounts.vote.key, ctx.accounts.voting.key, ctx.accounts.stake.key)?;
+
+    let votes: u64;
+    let voting_rent: u64;
+
+    // Step 2: Check the vote is not for the resolved value.
+    {
+        let vote = VoteV1::from_account_info(ctx.accounts.vote)?;
+
+        // Guard vote round.
+        vote.assert_round(round)?;
+
+        // Guard vote rent payer.
+        vote.assert_rent_payer(ctx.accounts.vote_rent_payer.key)?;
+
+        // Votes which were never revealed are slashed regardless of the committed value.
+        if vote.revealed && vote.value == resolved_value {
+            return Err(OracleError::CorrectVote.into());
+        }
+
+        votes = vote.votes;
+        voting_rent = vote.voting_rent;
+    }
+
+    let slashed: u64;
+
+    // Step 3: Slash the stake.
+    {
+        let mut stake = StakeV1::from_account_info_mut(ctx.accounts.stake)?;
+
+        // The stake cannot be withdrawn while it has open votes, but may have been slashed for a
+        // vote on another request since voting.
+        slashed = slash_bps.calculate(votes).min(stake.amount);
+
+        stake.amount -= slashed;
+        stake.open_votes = checked_sub!(stake.open_votes, 1)?;
+
+        log!("Slashed: {slashed}");
+
+        stake.save()?;
+    }
+
+    // Step 4: Deduct the slashed stake from total staked.
+    {
+        let mut oracle = OracleV1::from_account_info_mut(ctx.accounts.oracle)?;
+
+        oracle.total_staked = checked_sub!(oracle.total_staked, slashed)?;
+
+        oracle.save()?;
+    }
+
+    // Step 5: Add the slashed stake to the voter reward pool.
+    {
+        let mut voting = VotingV1::from_account_info_mut(ctx.accounts.voting)?;
+
+        // The slashed stake remains in the stake pool until claimed by voters.
+        voting.reward_pool.slash(slashed)?;
+
+        voting.open_votes = checked_sub!(voting.open_votes, 1)?;
+        voting.refund_excess_rent(voting_rent, ctx.accounts.vote_rent_payer)?;
+
+        voting.save()?;
+    }
+
+    // Step 6: Close vote account.
+    solana_utils::close_account(ctx.accounts.vote, ctx.accounts.vote_rent_payer)?;
+
+    OracleEvent::Slashed {
+        request: *ctx.accounts.request.key,
+        stake: *ctx.accounts.stake.key,
+        amount: slashed,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::instruction::AccountMeta;
+
+    use super::*;
+    use crate::processor::tests::{request_params, TestContext, TestRequest};
+    use crate::state::{InitVote, RequestParams, RewardPool, VoteValue};
+
+    struct Accounts {
+        request: TestRequest,
+        vote: Pubkey,
+        stake: Pubkey,
+        rent_payer: Pubkey,
+    }
+
+    /// Creates a request resolved with the value 1, and a vote of 100 tokens for the value.
+    fn setup(context: &mut TestContext, value: u64) -> Accounts {
+        let request =
+            context.create_disputed_request(RequestParams { slash_bps: 1_000, ..request_params() });
+
+        let mut state = context.state::<RequestV1>(&request.request);
+        state.state = RequestState::Resolved;
+        state.value = 1;
+        state.resolve_timestamp = context.now;
+        context.set_state(request.request, &state);
+
+        context.create_oracle(&request.governance_mint, 100);
+
+        let (stake, _) = context.create_stake(&request.governance_mint, 100);
+
+        let mut state = context.state::<StakeV1>(&stake);
+        state.open_votes = 1;
+        context.set_state(stake, &state);
+
+        let mut state = context.state::<VotingV1>(&request.voting);
+        state.reward_pool = RewardPool::new(0, 100);
+        state.open_votes = 1;
+        context.set_state(request.voting, &state);
+
+        let rent_payer = context.create_wallet();
+
+        let (vote_state, _) = <(VoteV1, usize)>::from(InitVote {
+            voting: request.voting,
+            stake,
+            round: 0,
+            rent_payer,
+            value: VoteValue::Revealed(value),
+            votes: 100,
+            voting_rent: 0,
+        });
+
+        let (vote, _) = pda::vote::pda(&request.voting, &stake);
+        context.set_state(vote, &vote_state);
+
+        Accounts { request, vote, stake, rent_payer }
+    }
+
+    fn slash_vote(context: &mut TestContext, accounts: &Accounts) -> ProgramResult {
+        let metas = [
+            AccountMeta::new_readonly(accounts.request.config, false),
+            AccountMeta::new_readonly(accounts.request.request, false),
+            AccountMeta::new(accounts.request.voting, false),
+            AccountMeta::new(accounts.vote, false),
+            AccountMeta::new(accounts.stake, false),
+            AccountMeta::new(pda::oracle::pda().0, false),
+            AccountMeta::new(accounts.rent_payer, false),
+        ];
+
+        context.process(&metas, slash_vote_v1)
+    }
+
+    #[test]
+    fn slash_incorrect_vote() {
+        let mut context = TestContext::new();
+        let accounts = setup(&mut context, 0);
+
+        assert_eq!(slash_vote(&mut context, &accounts), Ok(()));
+        assert!(!context.exists(&accounts.vote));
+
+        let stake = context.state::<StakeV1>(&accounts.stake);
+        assert_eq!(stake.amount, 90);
+        assert_eq!(stake.open_votes, 0);
+
+        assert_eq!(context.state::<OracleV1>(&pda::oracle::pda().0).total_staked, 90);
+        assert_eq!(context.state::<VotingV1>(&accounts.request.voting).reward_pool.slashed, 10);
+    }
+
+    #[test]
+    fn slash_after_slash_window() {
+        let mut context = TestContext::new();
+        let accounts = setup(&mut context, 0);
+
+        // Voters can claim once the slash window has expired, so slashing is no longer allowed.
+        context.now += 3600;
+
+        assert_eq!(
+            slash_vote(&mut context, &accounts),
+            Err(OracleError::SlashWindowExpired.into()),
+        );
+        assert_eq!(context.state::<StakeV1>(&accounts.stake).amount, 100);
+    }
+
+    #[test]
+    fn slash_correct_vote() {
+        let mut context = TestContext::new();
+        let accounts = setup(&mut context, 1);
+
+        assert_eq!(slash_vote(&mut context, &accounts), Err(OracleError::CorrectVote.into()));
+    }
+}
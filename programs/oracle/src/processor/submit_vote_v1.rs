@@ -1,5 +1,3 @@
-use std::collections::btree_map::Entry;
-
 use borsh::BorshDeserialize;
 use solana_program::account_info::AccountInfo;
 use solana_program::clock::Clock;
@@ -9,10 +7,11 @@ use solana_program::sysvar::Sysvar;
 use solana_utils::log;
 
 use crate::error::OracleError;
+use crate::event::OracleEvent;
 use crate::instruction::accounts::SubmitVoteV1Accounts;
 use crate::state::{
-    Account, AccountSized, ConfigV1, InitAccount, InitContext, InitVote, RequestState, RequestV1,
-    StakeV1, VoteV1, VotingV1,
+    Account, AccountSized, InitAccount, InitContext, InitVote, RequestState, RequestV1, StakeV1,
+    VoteV1, VoteValue, VotingV1,
 };
 use crate::{pda, utils};
 
@@ -36,16 +35,10 @@ pub fn submit_vote_v1<'a>(
     // Guard programs.
     utils::assert_system_program(ctx.accounts.system_program.key)?;
 
+    let round: u32;
     let voting_window: u32;
 
-    // Step 1: Get config voting window.
-    {
-        let config = ConfigV1::from_account_info(ctx.accounts.config)?;
-
-        voting_window = config.voting_window;
-    }
-
-    // Step 2: Check voting has not yet resolved the request.
+    // Step 1: Check voting has not yet resolved the request.
     {
         let request = RequestV1::from_account_info(ctx.accounts.request)?;
 
@@ -58,6 +51,13 @@ pub fn submit_vote_v1<'a>(
         if request.state != RequestState::Disputed {
             return Err(OracleError::NotDisputed.into());
         }
+
+        // The value must be valid for the request data type, and a vote for too early would
+        // reset the request, which it may not allow.
+        request.validate_value(args.value)?;
+
+        round = request.round;
+        voting_window = request.voting_window;
     }
 
     // Guard PDAs.
@@ -67,9 +67,14 @@ pub fn submit_vote_v1<'a>(
 
     let mut voting = VotingV1::from_account_info_mut(ctx.accounts.voting)?;
 
-    // Step 3: Check the voting window hasn't expired.
+    // If commit-reveal voting is enabled, votes must be committed with `commit_vote_v1`.
+    if voting.is_commit_reveal() {
+        return Err(OracleError::CommitRevealRequired.into());
+    }
+
+    // Step 2: Check the voting window hasn't expired.
     if voting.end_timestamp <= now {
-        // TODO: We should probably require a minimum number of votes.
+        // Voting that ended without reaching quorum is extended by `close_voting_v1`.
 
         // If the vote count is non-zero, then the voting window is considered expired.
         if voting.vote_count != 0 {
@@ -85,7 +90,7 @@ pub fn submit_vote_v1<'a>(
 
     let votes: u64;
 
-    // Step 4: Get stake votes and update lock.
+    // Step 3: Get stake votes and update lock.
     {
         let mut stake = StakeV1::from_account_info_mut(ctx.accounts.stake)?;
 
@@ -93,11 +98,22 @@ pub fn submit_vote_v1<'a>(
         stake.assert_voter(ctx.accounts.voter.key)?;
 
         // Lock the stake until the end of the vote window.
-        stake.lock_timestamp = voting.end_timestamp;
+        votes = stake.lock_for_vote(voting.end_timestamp)?;
 
-        votes = stake.amount;
+        stake.save()?;
     }
 
+    // Step 4: Add votes for the submitted value.
+    let voting_rent = {
+        voting.add_votes(args.value, votes)?;
+
+        voting.open_votes = checked_add!(voting.open_votes, 1)?;
+
+        voting.realloc(ctx.accounts.payer, ctx.accounts.system_program)?
+    };
+
+    voting.save()?;
+
     // Step 5: Initialize `vote` account.
     {
         let bump = pda::vote::assert_pda(
@@ -111,8 +127,11 @@ pub fn submit_vote_v1<'a>(
         VoteV1::init(InitVote {
             voting: *ctx.accounts.voting.key,
             stake: *ctx.accounts.stake.key,
-            value: args.value,
+            round,
+            rent_payer: *ctx.accounts.payer.key,
+            value: VoteValue::Revealed(args.value),
             votes,
+            voting_rent,
         })
         .save(InitContext {
             account: ctx.accounts.vote,
@@ -123,40 +142,152 @@ pub fn submit_vote_v1<'a>(
         })?;
     }
 
-    // Step 6: Add votes for the submitted value.
-    {
-        // Add submitted votes for the voted value, the get the new amount of votes for that value.
-        let freq = match voting.votes.entry(args.value) {
-            // An entry exists for the voted value. Add the submitted votes to the entry.
-            Entry::Occupied(mut entry) => {
-                let entry = entry.get_mut();
-                let freq = checked_add!(entry, votes)?;
-
-                *entry = freq;
-
-                freq
-            }
-            // No entry exists for the voted value. Create a new entry with the submitted votes.
-            Entry::Vacant(entry) => {
-                entry.insert(votes);
-
-                votes
-            }
-        };
-
-        voting.vote_count = checked_add!(voting.vote_count, votes)?;
-
-        // If the frequency of the value voted for is greater than the frequency of
-        // the previous modal value, then update the modal value.
-        if freq > voting.votes.get(&voting.mode_value).copied().unwrap_or_default() {
-            voting.mode_value = args.value;
-        }
+    OracleEvent::VoteCast {
+        request: *ctx.accounts.request.key,
+        stake: *ctx.accounts.stake.key,
+        value: args.value,
+        votes,
     }
+    .emit()?;
 
-    voting.realloc(ctx.accounts.payer, ctx.accounts.system_program)?;
-    voting.save()?;
+    Ok(())
+}
 
-    // TODO: Emit an event?
+#[cfg(test)]
+mod tests {
+    use solana_program::instruction::AccountMeta;
+    use solana_program::system_program;
+
+    use super::*;
+    use crate::processor::tests::{request_params, TestContext, TestRequest};
+    use crate::processor::{set_stake_delegate_v1, SetStakeDelegateV1Args};
+
+    fn submit_vote(
+        context: &mut TestContext,
+        request: &TestRequest,
+        stake: Pubkey,
+        voter: Pubkey,
+        value: u64,
+    ) -> ProgramResult {
+        let (vote, _) = pda::vote::pda(&request.voting, &stake);
+
+        let metas = [
+            AccountMeta::new_readonly(request.config, false),
+            AccountMeta::new_readonly(request.request, false),
+            AccountMeta::new(request.voting, false),
+            AccountMeta::new(vote, false),
+            AccountMeta::new(stake, false),
+            AccountMeta::new_readonly(voter, true),
+            AccountMeta::new(voter, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+
+        context.process(&metas, |program_id, accounts| {
+            submit_vote_v1(program_id, accounts, SubmitVoteV1Args { value })
+        })
+    }
 
-    Ok(())
+    fn set_delegate(context: &mut TestContext, stake: Pubkey, owner: Pubkey, new_delegate: Pubkey) {
+        let metas = [AccountMeta::new(stake, false), AccountMeta::new_readonly(owner, true)];
+
+        let result = context.process(&metas, |program_id, accounts| {
+            set_stake_delegate_v1(program_id, accounts, SetStakeDelegateV1Args { new_delegate })
+        });
+
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn vote_locks_stake() {
+        let mut context = TestContext::new();
+        let request = context.create_disputed_request(request_params());
+        let (stake, owner) = context.create_stake(&request.governance_mint, 100);
+
+        assert_eq!(submit_vote(&mut context, &request, stake, owner, 1), Ok(()));
+
+        let voting = context.state::<VotingV1>(&request.voting);
+        let stake = context.state::<StakeV1>(&stake);
+
+        assert_eq!(stake.lock_timestamp, voting.end_timestamp);
+        assert_eq!(stake.open_votes, 1);
+        assert_eq!(voting.votes.get(&1), Some(&100));
+    }
+
+    #[test]
+    fn vote_after_empty_window_starts_new_window() {
+        let mut context = TestContext::new();
+        let request = context.create_disputed_request(request_params());
+        let (stake, owner) = context.create_stake(&request.governance_mint, 100);
+
+        context.now = context.state::<VotingV1>(&request.voting).end_timestamp;
+
+        assert_eq!(submit_vote(&mut context, &request, stake, owner, 1), Ok(()));
+
+        // The new window has the voting window of the request rather than the config.
+        let voting = context.state::<VotingV1>(&request.voting);
+        assert_eq!(voting.start_timestamp, context.now);
+        assert_eq!(voting.end_timestamp, context.now + 3600);
+    }
+
+    #[test]
+    fn vote_records_voting_rent() {
+        let mut context = TestContext::new();
+        let request = context.create_disputed_request(request_params());
+        let (stake, owner) = context.create_stake(&request.governance_mint, 100);
+
+        let lamports = context.account(&request.voting).unwrap().lamports;
+
+        assert_eq!(submit_vote(&mut context, &request, stake, owner, 1), Ok(()));
+
+        let (vote, _) = pda::vote::pda(&request.voting, &stake);
+        let voting_rent = context.state::<VoteV1>(&vote).voting_rent;
+
+        // The voter paid to reallocate the voting account for the new value.
+        assert!(voting_rent > 0);
+        assert_eq!(context.account(&request.voting).unwrap().lamports, lamports + voting_rent);
+        assert_eq!(context.state::<VotingV1>(&request.voting).open_votes, 1);
+    }
+
+    #[test]
+    fn delegate_can_vote() {
+        let mut context = TestContext::new();
+        let request = context.create_disputed_request(request_params());
+        let (stake, owner) = context.create_stake(&request.governance_mint, 100);
+        let delegate = context.create_wallet();
+
+        set_delegate(&mut context, stake, owner, delegate);
+
+        assert_eq!(submit_vote(&mut context, &request, stake, delegate, 1), Ok(()));
+        assert_eq!(context.state::<VotingV1>(&request.voting).vote_count, 100);
+    }
+
+    #[test]
+    fn revoked_delegate_cannot_vote() {
+        let mut context = TestContext::new();
+        let request = context.create_disputed_request(request_params());
+        let (stake, owner) = context.create_stake(&request.governance_mint, 100);
+        let delegate = context.create_wallet();
+
+        set_delegate(&mut context, stake, owner, delegate);
+        set_delegate(&mut context, stake, owner, owner);
+
+        assert_eq!(
+            submit_vote(&mut context, &request, stake, delegate, 1),
+            Err(OracleError::StakeVoterMismatch.into()),
+        );
+        assert_eq!(submit_vote(&mut context, &request, stake, owner, 1), Ok(()));
+    }
+
+    #[test]
+    fn other_wallet_cannot_vote() {
+        let mut context = TestContext::new();
+        let request = context.create_disputed_request(request_params());
+        let (stake, _) = context.create_stake(&request.governance_mint, 100);
+        let other = context.create_wallet();
+
+        assert_eq!(
+            submit_vote(&mut context, &request, stake, other, 1),
+            Err(OracleError::StakeVoterMismatch.into()),
+        );
+    }
 }
@@ -0,0 +1,633 @@
+//! In-memory runtime for processor tests.
+//!
+//! Accounts are serialized in the loader's input format so processors see the same account
+//! infos as on-chain, including the spare capacity used for reallocation. Cross-program
+//! invocations of the system and token programs are dispatched in-process.
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::sync::Once;
+
+use solana_program::account_info::AccountInfo;
+use solana_program::clock::Clock;
+use solana_program::entrypoint::{
+    self, ProgramResult, MAX_PERMITTED_DATA_INCREASE, NON_DUP_MARKER,
+};
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::program_error::ProgramError;
+use solana_program::program_option::COption;
+use solana_program::program_pack::Pack;
+use solana_program::program_stubs::{self, SyscallStubs};
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_program;
+use spl_token_2022::state::{Account as TokenAccount, AccountState, Mint};
+
+use crate::pda;
+use crate::state::{
+    Account, ConfigV1, InitConfig, InitOracle, InitRequest, InitStake, InitVoting, OracleV1,
+    RequestData, RequestDerivation, RequestParams, RequestState, RequestV1, StakeV1, TiePolicy,
+    VotingV1,
+};
+use crate::utils::tests::{init_config, init_request};
+use crate::utils::{self, Bounds};
+
+thread_local! {
+    static NOW: Cell<i64> = const { Cell::new(0) };
+    static PROGRAM: Cell<Pubkey> = const { Cell::new(crate::ID) };
+    static RETURN_DATA: RefCell<Option<(Pubkey, Vec<u8>)>> = const { RefCell::new(None) };
+}
+
+struct Stubs;
+
+impl SyscallStubs for Stubs {
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        invoke(instruction, account_infos, signers_seeds)
+    }
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = Clock { unix_timestamp: NOW.get(), ..Clock::default() };
+        unsafe { std::ptr::write(var_addr.cast::<Clock>(), clock) };
+        entrypoint::SUCCESS
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { std::ptr::write(var_addr.cast::<Rent>(), Rent::default()) };
+        entrypoint::SUCCESS
+    }
+
+    fn sol_get_return_data(&self) -> Option<(Pubkey, Vec<u8>)> {
+        RETURN_DATA.with_borrow(Clone::clone)
+    }
+
+    fn sol_set_return_data(&self, data: &[u8]) {
+        RETURN_DATA.set(Some((PROGRAM.get(), data.to_vec())));
+    }
+}
+
+fn invoke(
+    instruction: &Instruction,
+    account_infos: &[AccountInfo],
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let signers = signers_seeds
+        .iter()
+        .map(|seeds| Pubkey::create_program_address(seeds, &PROGRAM.get()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut accounts = Vec::with_capacity(instruction.accounts.len());
+
+    for meta in &instruction.accounts {
+        let Some(info) = account_infos.iter().find(|info| info.key == &meta.pubkey) else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        let is_signer = info.is_signer || signers.contains(info.key);
+
+        if meta.is_signer && !is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        if meta.is_writable && !info.is_writable {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let mut info = info.clone();
+        info.is_signer = meta.is_signer;
+        info.is_writable = meta.is_writable;
+
+        accounts.push(info);
+    }
+
+    let caller = PROGRAM.replace(instruction.program_id);
+
+    let result = match instruction.program_id {
+        id if id == system_program::ID => process_system(&accounts, &instruction.data),
+        id if id == spl_token_2022::ID || id == cpi::spl::TOKEN_ID => {
+            spl_token_2022::processor::Processor::process(&id, &accounts, &instruction.data)
+        }
+        _ => Err(ProgramError::IncorrectProgramId),
+    };
+
+    PROGRAM.set(caller);
+
+    result
+}
+
+/// Handles the subset of system program instructions used by the program.
+fn process_system(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    fn u64_at(data: &[u8], offset: usize) -> Result<u64, ProgramError> {
+        data.get(offset..offset + 8)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(u64::from_le_bytes)
+            .ok_or(ProgramError::InvalidInstructionData)
+    }
+
+    fn pubkey_at(data: &[u8], offset: usize) -> Result<Pubkey, ProgramError> {
+        data.get(offset..offset + 32)
+            .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+            .map(Pubkey::new_from_array)
+            .ok_or(ProgramError::InvalidInstructionData)
+    }
+
+    fn transfer(from: &AccountInfo, to: &AccountInfo, lamports: u64) -> ProgramResult {
+        if !from.is_signer || !from.data_is_empty() || from.owner != &system_program::ID {
+            return Err(ProgramError::InvalidArgument);
+        }
+        let balance =
+            from.lamports().checked_sub(lamports).ok_or(ProgramError::InsufficientFunds)?;
+        **from.try_borrow_mut_lamports()? = balance;
+        **to.try_borrow_mut_lamports()? += lamports;
+        Ok(())
+    }
+
+    fn allocate(account: &AccountInfo, space: u64) -> ProgramResult {
+        if !account.is_signer || !account.data_is_empty() || account.owner != &system_program::ID {
+            return Err(ProgramError::InvalidArgument);
+        }
+        #[allow(deprecated)]
+        account.realloc(space as usize, true)
+    }
+
+    fn assign(account: &AccountInfo, owner: &Pubkey) -> ProgramResult {
+        if !account.is_signer || account.owner != &system_program::ID {
+            return Err(ProgramError::InvalidArgument);
+        }
+        account.assign(owner);
+        Ok(())
+    }
+
+    let tag = data
+        .get(..4)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u32::from_le_bytes)
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match (tag, accounts) {
+        // CreateAccount { lamports, space, owner }
+        (0, [from, to, ..]) => {
+            if to.lamports() != 0 {
+                return Err(ProgramError::AccountAlreadyInitialized);
+            }
+            transfer(from, to, u64_at(data, 4)?)?;
+            allocate(to, u64_at(data, 12)?)?;
+            assign(to, &pubkey_at(data, 20)?)
+        }
+        // Assign { owner }
+        (1, [account, ..]) => assign(account, &pubkey_at(data, 4)?),
+        // Transfer { lamports }
+        (2, [from, to, ..]) => transfer(from, to, u64_at(data, 4)?),
+        // Allocate { space }
+        (8, [account, ..]) => allocate(account, u64_at(data, 4)?),
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Returns request parameters with hour long windows, and without fees, quorum, slashing or
+/// commit-reveal voting.
+pub(crate) fn request_params() -> RequestParams {
+    RequestParams {
+        dispute_window: 3600,
+        voting_window: 3600,
+        arbitration_window: 3600,
+        claim_window: 3600,
+        slash_window: 3600,
+        ..utils::tests::request_params()
+    }
+}
+
+/// The accounts of a request created by [`TestContext::create_request`].
+pub(crate) struct TestRequest {
+    pub config: Pubkey,
+    pub request: Pubkey,
+    pub voting: Pubkey,
+    pub governance_mint: Pubkey,
+}
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct TestAccount {
+    pub lamports: u64,
+    pub data: Vec<u8>,
+    pub owner: Pubkey,
+    pub executable: bool,
+}
+
+pub(crate) struct TestContext {
+    accounts: HashMap<Pubkey, TestAccount>,
+    /// The Unix timestamp reported by the clock sysvar.
+    pub now: i64,
+}
+
+impl TestContext {
+    pub fn new() -> TestContext {
+        static STUBS: Once = Once::new();
+
+        STUBS.call_once(|| {
+            program_stubs::set_syscall_stubs(Box::new(Stubs));
+        });
+
+        let mut context = TestContext { accounts: HashMap::new(), now: 1_700_000_000 };
+
+        for program in [system_program::ID, spl_token_2022::ID] {
+            context.set_account(program, TestAccount { executable: true, ..Default::default() });
+        }
+
+        context
+    }
+
+    pub fn set_account(&mut self, key: Pubkey, account: TestAccount) {
+        self.accounts.insert(key, account);
+    }
+
+    pub fn account(&self, key: &Pubkey) -> Option<&TestAccount> {
+        self.accounts.get(key)
+    }
+
+    pub fn exists(&self, key: &Pubkey) -> bool {
+        self.accounts.contains_key(key)
+    }
+
+    /// Creates a system account holding lamports, such as a wallet or payer.
+    pub fn create_wallet(&mut self) -> Pubkey {
+        let key = Pubkey::new_unique();
+        self.set_account(key, TestAccount { lamports: 1_000_000_000, ..Default::default() });
+        key
+    }
+
+    /// Stores program state in a rent exempt account owned by the program.
+    pub fn set_state<T: Account>(&mut self, key: Pubkey, state: &T) {
+        let data = borsh::to_vec(state).unwrap();
+        let lamports = Rent::default().minimum_balance(data.len());
+
+        self.set_account(key, TestAccount { lamports, data, owner: crate::ID, executable: false });
+    }
+
+    #[track_caller]
+    pub fn state<T: Account>(&self, key: &Pubkey) -> T {
+        let account = self.account(key).expect("account does not exist");
+        T::safe_deserialize(&account.data).unwrap()
+    }
+
+    /// Creates the oracle for the governance mint, with an amount already staked.
+    pub fn create_oracle(&mut self, governance_mint: &Pubkey, total_staked: u64) -> Pubkey {
+        let (mut oracle, _) = <(OracleV1, usize)>::from(InitOracle {
+            authority: Pubkey::new_unique(),
+            governance_mint: *governance_mint,
+        });
+        oracle.total_staked = total_staked;
+
+        let (key, _) = pda::oracle::pda();
+        self.set_state(key, &oracle);
+        key
+    }
+
+    /// Creates a stake account owned by a new wallet, returning the stake and wallet addresses.
+    pub fn create_stake(&mut self, mint: &Pubkey, amount: u64) -> (Pubkey, Pubkey) {
+        let owner = self.create_wallet();
+        let (stake, _) = <(StakeV1, usize)>::from(InitStake { mint: *mint, owner, amount });
+
+        let key = Pubkey::new_unique();
+        self.set_state(key, &stake);
+        (key, owner)
+    }
+
+    /// Creates a yes/no request at the current timestamp.
+    ///
+    /// The config of the request holds parameters which differ from those of the request, so tests
+    /// fail if a processor reads the current config rather than the parameters copied into the
+    /// request.
+    pub fn create_request(&mut self, params: RequestParams) -> TestRequest {
+        let config = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let governance_mint = Pubkey::new_unique();
+
+        let (request, _) = pda::creator_request::pda(&creator, &0);
+        let (voting, _) = pda::voting::pda(&request);
+
+        let (state, _) = <(ConfigV1, usize)>::from(InitConfig {
+            bond_fee_bps: 10_000,
+            dispute_window: 1,
+            voting_window: 1,
+            reveal_window: 1,
+            arbitration_window: 1,
+            claim_window: 1,
+            dispute_window_range: Bounds { start: 1, end: 2 },
+            voting_window_range: Bounds { start: 1, end: 2 },
+            arbitration_window_range: Bounds { start: 1, end: 2 },
+            quorum_amount: u64::MAX,
+            quorum_bps: 10_000,
+            slash_bps: 10_000,
+            tie_policy: TiePolicy::Unresolvable,
+            slash_window: 1,
+            ..init_config()
+        });
+
+        self.set_state(config, &state);
+
+        let (state, _) = <(RequestV1, usize)>::try_from(InitRequest {
+            config,
+            creator,
+            rent_payer: creator,
+            timestamp: self.now,
+            derivation: RequestDerivation::Creator,
+            data: RequestData::YesNo { question: "Test?".to_owned() },
+            ..init_request(params)
+        })
+        .unwrap();
+
+        self.set_state(request, &state);
+
+        TestRequest { config, request, voting, governance_mint }
+    }
+
+    /// Creates a yes/no request which was disputed at the current timestamp.
+    pub fn create_disputed_request(&mut self, params: RequestParams) -> TestRequest {
+        let request = self.create_request(params);
+
+        let mut state: RequestV1 = self.state(&request.request);
+        state.state = RequestState::Disputed;
+        self.set_state(request.request, &state);
+
+        let (state, _) = <(VotingV1, usize)>::try_from(InitVoting {
+            request: request.request,
+            governance_mint: request.governance_mint,
+            rent_payer: state.creator,
+            start_timestamp: self.now,
+            voting_window: params.voting_window,
+            reveal_window: params.reveal_window,
+        })
+        .unwrap();
+
+        self.set_state(request.voting, &state);
+
+        request
+    }
+
+    pub fn create_mint(&mut self, decimals: u8) -> Pubkey {
+        let mint = Mint {
+            mint_authority: COption::None,
+            supply: u64::MAX,
+            decimals,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        };
+
+        let mut data = vec![0; Mint::LEN];
+        Mint::pack(mint, &mut data).unwrap();
+
+        let key = Pubkey::new_unique();
+        self.set_token_state(key, data);
+        key
+    }
+
+    pub fn create_token_account(&mut self, mint: &Pubkey, owner: &Pubkey, amount: u64) -> Pubkey {
+        let key = Pubkey::new_unique();
+        self.set_token_account(key, mint, owner, amount);
+        key
+    }
+
+    /// Creates a token account at the given address, such as a PDA escrow.
+    pub fn set_token_account(&mut self, key: Pubkey, mint: &Pubkey, owner: &Pubkey, amount: u64) {
+        let account = TokenAccount {
+            mint: *mint,
+            owner: *owner,
+            amount,
+            delegate: COption::None,
+            state: AccountState::Initialized,
+            is_native: COption::None,
+            delegated_amount: 0,
+            close_authority: COption::None,
+        };
+
+        let mut data = vec![0; TokenAccount::LEN];
+        TokenAccount::pack(account, &mut data).unwrap();
+
+        self.set_token_state(key, data);
+    }
+
+    #[track_caller]
+    pub fn token_amount(&self, key: &Pubkey) -> u64 {
+        let account = self.account(key).expect("token account does not exist");
+        TokenAccount::unpack(&account.data).unwrap().amount
+    }
+
+    fn set_token_state(&mut self, key: Pubkey, data: Vec<u8>) {
+        let lamports = Rent::default().minimum_balance(data.len());
+
+        self.set_account(
+            key,
+            TestAccount { lamports, data, owner: spl_token_2022::ID, executable: false },
+        );
+    }
+
+    /// Runs a processor against the accounts, committing changes only if it succeeds.
+    pub fn process<F>(&mut self, metas: &[AccountMeta], processor: F) -> ProgramResult
+    where
+        F: for<'a> FnOnce(&'a Pubkey, &'a [AccountInfo<'a>]) -> ProgramResult,
+    {
+        NOW.set(self.now);
+        RETURN_DATA.set(None);
+
+        let mut input = self.serialize(metas);
+
+        let (program_id, accounts, _) =
+            unsafe { entrypoint::deserialize(input.as_mut_ptr().cast::<u8>()) };
+
+        processor(program_id, &accounts)?;
+
+        for info in &accounts {
+            if info.lamports() == 0 {
+                self.accounts.remove(info.key);
+                continue;
+            }
+
+            // The data is read from the input buffer as the runtime does, as writers may have
+            // advanced the slice held by the account info past the data they wrote.
+            let data = unsafe {
+                let data_len = (info.key as *const Pubkey).cast::<u8>().add(72);
+                let len = data_len.cast::<u64>().read_unaligned() as usize;
+                std::slice::from_raw_parts(data_len.add(8), len).to_vec()
+            };
+
+            let account = TestAccount {
+                lamports: info.lamports(),
+                data,
+                owner: *info.owner,
+                executable: info.executable,
+            };
+
+            self.accounts.insert(*info.key, account);
+        }
+
+        Ok(())
+    }
+
+    /// Serializes the accounts in the loader's input format, returning an aligned buffer.
+    fn serialize(&self, metas: &[AccountMeta]) -> Vec<u64> {
+        let mut bytes = Vec::new();
+
+        bytes.extend_from_slice(&(metas.len() as u64).to_le_bytes());
+
+        for (index, meta) in metas.iter().enumerate() {
+            if let Some(first) = metas[..index].iter().position(|m| m.pubkey == meta.pubkey) {
+                bytes.push(first as u8);
+                bytes.extend_from_slice(&[0; 7]);
+                continue;
+            }
+
+            let duplicates = metas.iter().filter(|m| m.pubkey == meta.pubkey);
+            let (is_signer, is_writable) =
+                duplicates.fold((false, false), |(s, w), m| (s || m.is_signer, w || m.is_writable));
+
+            let account = self.accounts.get(&meta.pubkey).cloned().unwrap_or_default();
+
+            bytes.push(NON_DUP_MARKER);
+            bytes.push(is_signer.into());
+            bytes.push(is_writable.into());
+            bytes.push(account.executable.into());
+            bytes.extend_from_slice(&[0; 4]);
+            bytes.extend_from_slice(meta.pubkey.as_ref());
+            bytes.extend_from_slice(account.owner.as_ref());
+            bytes.extend_from_slice(&account.lamports.to_le_bytes());
+            bytes.extend_from_slice(&(account.data.len() as u64).to_le_bytes());
+            bytes.extend_from_slice(&account.data);
+            bytes.resize(bytes.len() + MAX_PERMITTED_DATA_INCREASE, 0);
+            bytes.resize(bytes.len().next_multiple_of(8), 0);
+            bytes.extend_from_slice(&u64::MAX.to_le_bytes());
+        }
+
+        // Instruction data is passed to processors directly.
+        bytes.extend_from_slice(&0u64.to_le_bytes());
+        bytes.extend_from_slice(crate::ID.as_ref());
+
+        let mut input = vec![0u64; bytes.len().div_ceil(8)];
+        bytemuck::cast_slice_mut::<u64, u8>(&mut input)[..bytes.len()].copy_from_slice(&bytes);
+        input
+    }
+}
+
+/// Votes for values the request cannot resolve to are rejected by each vote processor.
+mod vote_value {
+    use solana_program::entrypoint::ProgramResult;
+    use solana_program::instruction::AccountMeta;
+    use solana_program::pubkey::Pubkey;
+    use solana_program::system_program;
+
+    use super::{request_params, TestContext, TestRequest};
+    use crate::error::OracleError;
+    use crate::pda;
+    use crate::processor::{
+        change_vote_v1, commit_vote_v1, reveal_vote_v1, submit_vote_v1, ChangeVoteV1Args,
+        CommitVoteV1Args, RevealVoteV1Args, SubmitVoteV1Args,
+    };
+    use crate::state::{RequestData, RequestParams, RequestV1, VoteV1, VotingV1};
+
+    /// Creates a disputed numeric request for values from 100 to 200, and a stake to vote with.
+    fn setup(context: &mut TestContext, params: RequestParams) -> (TestRequest, Pubkey, Pubkey) {
+        let request = context.create_disputed_request(params);
+
+        let mut state = context.state::<RequestV1>(&request.request);
+        state.data =
+            RequestData::Numeric { question: "Test?".to_owned(), min: 100, max: 200, decimals: 0 };
+        context.set_state(request.request, &state);
+
+        let (stake, owner) = context.create_stake(&request.governance_mint, 100);
+
+        (request, stake, owner)
+    }
+
+    fn vote_metas(request: &TestRequest, stake: Pubkey, owner: Pubkey) -> [AccountMeta; 8] {
+        [
+            AccountMeta::new_readonly(request.config, false),
+            AccountMeta::new_readonly(request.request, false),
+            AccountMeta::new(request.voting, false),
+            AccountMeta::new(pda::vote::pda(&request.voting, &stake).0, false),
+            AccountMeta::new(stake, false),
+            AccountMeta::new_readonly(owner, true),
+            AccountMeta::new(owner, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ]
+    }
+
+    fn update_metas(request: &TestRequest, stake: Pubkey, owner: Pubkey) -> [AccountMeta; 7] {
+        [
+            AccountMeta::new_readonly(request.request, false),
+            AccountMeta::new(request.voting, false),
+            AccountMeta::new(pda::vote::pda(&request.voting, &stake).0, false),
+            AccountMeta::new_readonly(stake, false),
+            AccountMeta::new_readonly(owner, true),
+            AccountMeta::new(owner, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ]
+    }
+
+    fn submit_vote(
+        context: &mut TestContext,
+        request: &TestRequest,
+        stake: Pubkey,
+        owner: Pubkey,
+        value: u64,
+    ) -> ProgramResult {
+        context.process(&vote_metas(request, stake, owner), |program_id, accounts| {
+            submit_vote_v1(program_id, accounts, SubmitVoteV1Args { value })
+        })
+    }
+
+    #[test]
+    fn submit_out_of_range() {
+        let mut context = TestContext::new();
+        let (request, stake, owner) = setup(&mut context, request_params());
+
+        assert_eq!(
+            submit_vote(&mut context, &request, stake, owner, 201),
+            Err(OracleError::InvalidValue.into()),
+        );
+        assert_eq!(submit_vote(&mut context, &request, stake, owner, 200), Ok(()));
+    }
+
+    #[test]
+    fn change_out_of_range() {
+        let mut context = TestContext::new();
+        let (request, stake, owner) = setup(&mut context, request_params());
+
+        assert_eq!(submit_vote(&mut context, &request, stake, owner, 150), Ok(()));
+
+        let result =
+            context.process(&update_metas(&request, stake, owner), |program_id, accounts| {
+                change_vote_v1(program_id, accounts, ChangeVoteV1Args { value: 99 })
+            });
+
+        assert_eq!(result, Err(OracleError::InvalidValue.into()));
+        assert_eq!(context.state::<VotingV1>(&request.voting).votes.get(&150), Some(&100));
+    }
+
+    #[test]
+    fn reveal_out_of_range() {
+        let mut context = TestContext::new();
+        let (request, stake, owner) =
+            setup(&mut context, RequestParams { reveal_window: 600, ..request_params() });
+
+        let salt = [7; 32];
+        let commitment = VoteV1::commitment(&request.voting, &stake, 201, &salt);
+
+        let result =
+            context.process(&vote_metas(&request, stake, owner), |program_id, accounts| {
+                commit_vote_v1(program_id, accounts, CommitVoteV1Args { commitment })
+            });
+        assert_eq!(result, Ok(()));
+
+        context.now = context.state::<VotingV1>(&request.voting).end_timestamp;
+
+        let result =
+            context.process(&update_metas(&request, stake, owner), |program_id, accounts| {
+                reveal_vote_v1(program_id, accounts, RevealVoteV1Args { value: 201, salt })
+            });
+
+        assert_eq!(result, Err(OracleError::InvalidValue.into()));
+        assert!(context.state::<VotingV1>(&request.voting).votes.is_empty());
+    }
+}
@@ -4,8 +4,9 @@ use solana_program::entrypoint::ProgramResult;
 use solana_program::pubkey::Pubkey;
 
 use crate::instruction::accounts::UpdateConfigV1Accounts;
-use crate::state::{AccountSized, ConfigV1};
+use crate::state::{AccountSized, ConfigV1, TiePolicy, TransferFeePolicy};
 use crate::utils;
+use crate::utils::{Bounds, Bps};
 
 #[derive(Clone, BorshDeserialize)]
 pub enum UpdateConfigV1Args {
@@ -17,7 +18,28 @@ pub enum UpdateConfigV1Args {
 
         new_dispute_window: u32,
         new_voting_window: u32,
+        new_reveal_window: u32,
         new_arbitration_window: u32,
+        new_claim_window: u32,
+    },
+    Quorum {
+        new_quorum_amount: u64,
+        new_quorum_bps: u16,
+    },
+    Slash {
+        new_slash_bps: u16,
+        new_slash_window: u32,
+    },
+    TiePolicy {
+        new_tie_policy: TiePolicy,
+    },
+    WindowRanges {
+        new_dispute_window_range: Bounds,
+        new_voting_window_range: Bounds,
+        new_arbitration_window_range: Bounds,
+    },
+    TransferFeePolicy {
+        new_transfer_fee_policy: TransferFeePolicy,
     },
 }
 
@@ -46,13 +68,52 @@ pub fn update_config_v1<'a>(
                 new_bond_fee_bps,
                 new_dispute_window,
                 new_voting_window,
+                new_reveal_window,
                 new_arbitration_window,
+                new_claim_window,
             } => {
+                Bps::try_from(new_bond_fee_bps)?;
+
                 config.bond_fee_bps = new_bond_fee_bps;
 
                 config.dispute_window = new_dispute_window;
                 config.voting_window = new_voting_window;
+                config.reveal_window = new_reveal_window;
                 config.arbitration_window = new_arbitration_window;
+                config.claim_window = new_claim_window;
+            }
+            UpdateConfigV1Args::Quorum { new_quorum_amount, new_quorum_bps } => {
+                Bps::try_from(new_quorum_bps)?;
+
+                config.quorum_amount = new_quorum_amount;
+                config.quorum_bps = new_quorum_bps;
+            }
+            UpdateConfigV1Args::Slash { new_slash_bps, new_slash_window } => {
+                Bps::try_from(new_slash_bps)?;
+
+                config.slash_bps = new_slash_bps;
+                config.slash_window = new_slash_window;
+            }
+            UpdateConfigV1Args::TiePolicy { new_tie_policy } => {
+                config.tie_policy = new_tie_policy;
+            }
+            UpdateConfigV1Args::WindowRanges {
+                new_dispute_window_range,
+                new_voting_window_range,
+                new_arbitration_window_range,
+            } => {
+                ConfigV1::validate_window_ranges(
+                    new_dispute_window_range,
+                    new_voting_window_range,
+                    new_arbitration_window_range,
+                )?;
+
+                config.dispute_window_range = new_dispute_window_range;
+                config.voting_window_range = new_voting_window_range;
+                config.arbitration_window_range = new_arbitration_window_range;
+            }
+            UpdateConfigV1Args::TransferFeePolicy { new_transfer_fee_policy } => {
+                config.transfer_fee_policy = new_transfer_fee_policy;
             }
         }
 
@@ -4,7 +4,7 @@ use solana_program::entrypoint::ProgramResult;
 use solana_program::pubkey::Pubkey;
 
 use crate::instruction::accounts::UpdateCurrencyV1Accounts;
-use crate::state::{Account, AccountSized, ConfigV1, CurrencyV1};
+use crate::state::{Account, AccountSized, ConfigV1, CurrencyStatus, CurrencyV1};
 use crate::utils;
 use crate::utils::Bounds;
 
@@ -12,6 +12,9 @@ use crate::utils::Bounds;
 pub struct UpdateCurrencyV1Args {
     pub new_reward_range: Bounds,
     pub new_bond_range: Bounds,
+    /// Disabling a currency prevents new requests from using it, without affecting existing
+    /// requests.
+    pub new_status: CurrencyStatus,
 }
 
 pub fn update_currency_v1<'a>(
@@ -28,7 +31,10 @@ pub fn update_currency_v1<'a>(
     ConfigV1::from_account_info(ctx.accounts.config)?
         .assert_authority(ctx.accounts.authority.key)?;
 
-    // Step 1: Update currency.
+    // Step 2: Check reward and bond ranges.
+    CurrencyV1::validate_ranges(args.new_reward_range, args.new_bond_range)?;
+
+    // Step 3: Update currency.
     {
         let mut currency = CurrencyV1::from_account_info_mut(ctx.accounts.currency)?;
 
@@ -38,6 +44,7 @@ pub fn update_currency_v1<'a>(
 
         currency.reward_range = args.new_reward_range;
         currency.bond_range = args.new_bond_range;
+        currency.status = args.new_status;
 
         currency.save()?;
     }
@@ -0,0 +1,144 @@
+use borsh::BorshDeserialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::pubkey::Pubkey;
+use solana_utils::log;
+
+use crate::error::OracleError;
+use crate::instruction::accounts::WithdrawFeesV1Accounts;
+use crate::state::{Account, ConfigV1};
+use crate::{pda, utils};
+
+#[derive(Clone, BorshDeserialize)]
+pub struct WithdrawFeesV1Args {
+    /// Amount to withdraw.
+    pub amount: u64,
+}
+
+pub fn withdraw_fees_v1<'a>(
+    _program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    args: WithdrawFeesV1Args,
+) -> ProgramResult {
+    let ctx = WithdrawFeesV1Accounts::context(accounts)?;
+
+    // Guard signatures.
+    utils::assert_signer(ctx.accounts.authority)?;
+
+    // Guard programs.
+    utils::assert_token_program(ctx.accounts.token_program.key)?;
+
+    // Step 1: Check config authority.
+    ConfigV1::from_account_info(ctx.accounts.config)?
+        .assert_authority(ctx.accounts.authority.key)?;
+
+    // Guard PDAs.
+    let bump = pda::treasury::assert_pda(
+        ctx.accounts.treasury.key,
+        ctx.accounts.config.key,
+        ctx.accounts.mint.key,
+    )?;
+
+    // Step 2: Transfer the amount from the collected fees in the treasury.
+    {
+        let signer_seeds =
+            pda::treasury::seeds_with_bump(ctx.accounts.config.key, ctx.accounts.mint.key, &bump);
+
+        let fees = cpi::spl::account_amount(ctx.accounts.treasury)?;
+        let decimals = cpi::spl::mint_decimals(ctx.accounts.mint)?;
+
+        log!("Fees: {fees}");
+
+        if args.amount > fees {
+            return Err(OracleError::InsufficientFees.into());
+        }
+
+        cpi::spl::transfer_checked(
+            args.amount,
+            decimals,
+            cpi::spl::TransferChecked {
+                source: ctx.accounts.treasury,
+                destination: ctx.accounts.destination,
+                mint: ctx.accounts.mint,
+                authority: ctx.accounts.treasury,
+                token_program: ctx.accounts.token_program,
+                remaining_accounts: ctx.remaining_accounts,
+            },
+            &[&signer_seeds],
+        )?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::instruction::AccountMeta;
+
+    use super::*;
+    use crate::processor::tests::TestContext;
+    use crate::state::InitConfig;
+    use crate::utils::tests::init_config;
+
+    struct Accounts {
+        config: Pubkey,
+        treasury: Pubkey,
+        mint: Pubkey,
+        destination: Pubkey,
+        authority: Pubkey,
+    }
+
+    fn setup(context: &mut TestContext, fees: u64) -> Accounts {
+        let authority = context.create_wallet();
+        let mint = context.create_mint(6);
+
+        let (state, _) = <(ConfigV1, usize)>::from(InitConfig { authority, ..init_config() });
+
+        let config = Pubkey::new_unique();
+        context.set_state(config, &state);
+
+        let (treasury, _) = pda::treasury::pda(&config, &mint);
+        context.set_token_account(treasury, &mint, &treasury, fees);
+
+        let destination = context.create_token_account(&mint, &authority, 0);
+
+        Accounts { config, treasury, mint, destination, authority }
+    }
+
+    fn withdraw(context: &mut TestContext, accounts: &Accounts, amount: u64) -> ProgramResult {
+        let metas = [
+            AccountMeta::new_readonly(accounts.config, false),
+            AccountMeta::new(accounts.treasury, false),
+            AccountMeta::new_readonly(accounts.mint, false),
+            AccountMeta::new(accounts.destination, false),
+            AccountMeta::new_readonly(accounts.authority, true),
+            AccountMeta::new_readonly(spl_token_2022::ID, false),
+        ];
+
+        context.process(&metas, |program_id, accounts| {
+            withdraw_fees_v1(program_id, accounts, WithdrawFeesV1Args { amount })
+        })
+    }
+
+    #[test]
+    fn withdraw_amount() {
+        let mut context = TestContext::new();
+        let accounts = setup(&mut context, 100);
+
+        assert_eq!(withdraw(&mut context, &accounts, 40), Ok(()));
+        assert_eq!(context.token_amount(&accounts.treasury), 60);
+        assert_eq!(context.token_amount(&accounts.destination), 40);
+    }
+
+    #[test]
+    fn withdraw_more_than_collected() {
+        let mut context = TestContext::new();
+        let accounts = setup(&mut context, 100);
+
+        assert_eq!(
+            withdraw(&mut context, &accounts, 101),
+            Err(OracleError::InsufficientFees.into()),
+        );
+        assert_eq!(context.token_amount(&accounts.treasury), 100);
+    }
+}
@@ -0,0 +1,240 @@
+use borsh::BorshDeserialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+use solana_utils::log;
+
+use crate::error::OracleError;
+use crate::instruction::accounts::WithdrawStakeV1Accounts;
+use crate::state::{AccountSized, OracleV1, StakeV1};
+use crate::{pda, utils};
+
+#[derive(Clone, BorshDeserialize)]
+pub struct WithdrawStakeV1Args {
+    /// Amount to withdraw.
+    pub amount: u64,
+}
+
+pub fn withdraw_stake_v1<'a>(
+    _program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    args: WithdrawStakeV1Args,
+) -> ProgramResult {
+    let ctx = WithdrawStakeV1Accounts::context(accounts)?;
+
+    // Guard signatures.
+    utils::assert_signer(ctx.accounts.wallet)?;
+
+    // Guard programs.
+    utils::assert_token_program(ctx.accounts.token_program.key)?;
+
+    // Guard PDAs.
+    let oracle_bump = pda::oracle::assert_pda(ctx.accounts.oracle.key)?;
+    pda::stake_pool::assert_pda(ctx.accounts.stake_pool.key, ctx.accounts.mint.key)?;
+
+    let now = Clock::get()?.unix_timestamp;
+
+    let remaining: u64;
+
+    // Step 1: Deduct withdrawn amount from stake.
+    {
+        let mut stake = StakeV1::from_account_info_mut(ctx.accounts.stake)?;
+
+        // Guard stake.
+        stake.assert_owner(ctx.accounts.wallet.key)?;
+        stake.assert_mint(ctx.accounts.mint.key)?;
+
+        // The stake cannot be withdrawn while it is locked for a vote.
+        stake.validate_unlocked(now)?;
+
+        // Votes remain open until claimed, slashed or withdrawn, and the voting window of an open
+        // vote may be extended past the lock, so the stake cannot be withdrawn until then.
+        stake.validate_no_open_votes()?;
+
+        if args.amount > stake.amount {
+            return Err(OracleError::InsufficientStake.into());
+        }
+
+        stake.amount -= args.amount;
+
+        remaining = stake.amount;
+
+        stake.save()?;
+    }
+
+    // Step 2: Deduct withdrawn amount from total staked.
+    {
+        let mut oracle = OracleV1::from_account_info_mut(ctx.accounts.oracle)?;
+
+        oracle.total_staked = checked_sub!(oracle.total_staked, args.amount)?;
+
+        oracle.save()?;
+    }
+
+    // Step 3: Withdraw amount from escrow account.
+    {
+        let signer_seeds = pda::oracle::seeds_with_bump(&oracle_bump);
+
+        let mint_decimals = cpi::spl::mint_decimals(ctx.accounts.mint)?;
+
+        cpi::spl::transfer_checked(
+            args.amount,
+            mint_decimals,
+            cpi::spl::TransferChecked {
+                source: ctx.accounts.stake_pool,
+                destination: ctx.accounts.stake_destination,
+                mint: ctx.accounts.mint,
+                authority: ctx.accounts.oracle,
+                token_program: ctx.accounts.token_program,
+                remaining_accounts: ctx.remaining_accounts,
+            },
+            &[&signer_seeds],
+        )?;
+    }
+
+    // Step 4: Close stake account if empty.
+    if remaining == 0 {
+        log!("Closing empty stake account");
+
+        solana_utils::close_account(ctx.accounts.stake, ctx.accounts.wallet)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::instruction::AccountMeta;
+
+    use super::*;
+    use crate::processor::tests::TestContext;
+    use crate::state::InitStake;
+
+    struct Accounts {
+        stake: Pubkey,
+        mint: Pubkey,
+        stake_pool: Pubkey,
+        destination: Pubkey,
+        wallet: Pubkey,
+    }
+
+    fn setup(context: &mut TestContext, lock_timestamp: i64) -> Accounts {
+        let wallet = context.create_wallet();
+        let mint = context.create_mint(6);
+
+        let oracle = context.create_oracle(&mint, 100);
+        let (stake_pool, _) = pda::stake_pool::pda(&mint);
+        context.set_token_account(stake_pool, &mint, &oracle, 100);
+
+        let destination = context.create_token_account(&mint, &wallet, 0);
+
+        let (mut state, _) =
+            <(StakeV1, usize)>::from(InitStake { mint, owner: wallet, amount: 100 });
+        state.lock_timestamp = lock_timestamp;
+
+        let stake = Pubkey::new_unique();
+        context.set_state(stake, &state);
+
+        Accounts { stake, mint, stake_pool, destination, wallet }
+    }
+
+    fn withdraw(context: &mut TestContext, accounts: &Accounts, amount: u64) -> ProgramResult {
+        let metas = [
+            AccountMeta::new(pda::oracle::pda().0, false),
+            AccountMeta::new(accounts.stake, false),
+            AccountMeta::new_readonly(accounts.mint, false),
+            AccountMeta::new(accounts.destination, false),
+            AccountMeta::new(accounts.stake_pool, false),
+            AccountMeta::new(accounts.wallet, true),
+            AccountMeta::new_readonly(spl_token_2022::ID, false),
+        ];
+
+        context.process(&metas, |program_id, accounts| {
+            withdraw_stake_v1(program_id, accounts, WithdrawStakeV1Args { amount })
+        })
+    }
+
+    #[test]
+    fn withdraw_locked() {
+        let mut context = TestContext::new();
+        let now = context.now;
+        let accounts = setup(&mut context, now + 1);
+
+        assert_eq!(withdraw(&mut context, &accounts, 40), Err(OracleError::StakeLocked.into()));
+        assert_eq!(context.state::<StakeV1>(&accounts.stake).amount, 100);
+        assert_eq!(context.token_amount(&accounts.destination), 0);
+    }
+
+    #[test]
+    fn withdraw_after_lock() {
+        let mut context = TestContext::new();
+        let now = context.now;
+        let accounts = setup(&mut context, now);
+
+        assert_eq!(withdraw(&mut context, &accounts, 40), Ok(()));
+        assert_eq!(context.state::<StakeV1>(&accounts.stake).amount, 60);
+        assert_eq!(context.state::<OracleV1>(&pda::oracle::pda().0).total_staked, 60);
+        assert_eq!(context.token_amount(&accounts.stake_pool), 60);
+        assert_eq!(context.token_amount(&accounts.destination), 40);
+    }
+
+    #[test]
+    fn withdraw_all_closes_stake() {
+        let mut context = TestContext::new();
+        let accounts = setup(&mut context, i64::MIN);
+
+        assert_eq!(withdraw(&mut context, &accounts, 100), Ok(()));
+        assert!(!context.exists(&accounts.stake));
+        assert_eq!(context.token_amount(&accounts.destination), 100);
+    }
+
+    #[test]
+    fn withdraw_with_open_votes() {
+        let mut context = TestContext::new();
+        let accounts = setup(&mut context, i64::MIN);
+
+        let mut state = context.state::<StakeV1>(&accounts.stake);
+        state.open_votes = 1;
+        context.set_state(accounts.stake, &state);
+
+        // The lock has expired, but the vote could still be extended into a new vote window.
+        for amount in [40, 100] {
+            assert_eq!(
+                withdraw(&mut context, &accounts, amount),
+                Err(OracleError::StakeHasOpenVotes.into()),
+            );
+        }
+        assert_eq!(context.state::<StakeV1>(&accounts.stake).amount, 100);
+    }
+
+    #[test]
+    fn delegate_cannot_withdraw() {
+        let mut context = TestContext::new();
+        let accounts = setup(&mut context, i64::MIN);
+        let delegate = context.create_wallet();
+
+        let mut state = context.state::<StakeV1>(&accounts.stake);
+        state.delegate = delegate;
+        context.set_state(accounts.stake, &state);
+
+        let accounts = Accounts { wallet: delegate, ..accounts };
+
+        assert_eq!(
+            withdraw(&mut context, &accounts, 40),
+            Err(OracleError::StakeOwnerMismatch.into()),
+        );
+    }
+
+    #[test]
+    fn withdraw_more_than_staked() {
+        let mut context = TestContext::new();
+        let accounts = setup(&mut context, i64::MIN);
+
+        assert_eq!(
+            withdraw(&mut context, &accounts, 101),
+            Err(OracleError::InsufficientStake.into()),
+        );
+    }
+}
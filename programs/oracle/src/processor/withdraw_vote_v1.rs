@@ -0,0 +1,105 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+
+use crate::error::OracleError;
+use crate::event::OracleEvent;
+use crate::instruction::accounts::WithdrawVoteV1Accounts;
+use crate::state::{Account, AccountSized, RequestState, RequestV1, StakeV1, VoteV1, VotingV1};
+use crate::{pda, utils};
+
+pub fn withdraw_vote_v1<'a>(
+    _program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    let ctx = WithdrawVoteV1Accounts::context(accounts)?;
+
+    // Guard signatures.
+    utils::assert_signer(ctx.accounts.voter)?;
+
+    let round: u32;
+
+    // Step 1: Check voting has not yet resolved the request.
+    {
+        let request = RequestV1::from_account_info(ctx.accounts.request)?;
+
+        // Guard request.
+        request.assert_pda(ctx.accounts.request.key)?;
+
+        // If the request state is not `Disputed`,
+        // then the voting must have ended and resolved the request.
+        if request.state != RequestState::Disputed {
+            return Err(OracleError::NotDisputed.into());
+        }
+
+        round = request.round;
+    }
+
+    // Guard PDAs.
+    pda::voting::assert_pda(ctx.accounts.voting.key, ctx.accounts.request.key)?;
+    pda::vote::assert_pda(ctx.accounts.vote.key, ctx.accounts.voting.key, ctx.accounts.stake.key)?;
+
+    let now = Clock::get()?.unix_timestamp;
+
+    let mut voting = VotingV1::from_account_info_mut(ctx.accounts.voting)?;
+
+    // Step 2: Check the voting window hasn't expired.
+    if now >= voting.end_timestamp {
+        return Err(OracleError::VotingWindowExpired.into());
+    }
+
+    // Step 3: Check stake voter.
+    {
+        let mut stake = StakeV1::from_account_info_mut(ctx.accounts.stake)?;
+
+        // Guard stake voter.
+        stake.assert_voter(ctx.accounts.voter.key)?;
+
+        stake.open_votes = checked_sub!(stake.open_votes, 1)?;
+
+        stake.save()?;
+    }
+
+    let votes: u64;
+
+    // Step 4: Remove the votes.
+    {
+        let vote = VoteV1::from_account_info(ctx.accounts.vote)?;
+
+        // Guard vote round.
+        vote.assert_round(round)?;
+
+        // Guard vote rent payer.
+        vote.assert_rent_payer(ctx.accounts.vote_rent_payer.key)?;
+
+        votes = vote.votes;
+
+        if vote.revealed {
+            voting.remove_votes(vote.value, votes)?;
+        } else {
+            voting.unrevealed_count = checked_sub!(voting.unrevealed_count, votes)?;
+        }
+
+        // Removing the votes may free space in the voting account, which was paid for by voters.
+        voting.shrink()?;
+
+        voting.open_votes = checked_sub!(voting.open_votes, 1)?;
+        voting.refund_excess_rent(vote.voting_rent, ctx.accounts.vote_rent_payer)?;
+
+        voting.save()?;
+    }
+
+    // Step 5: Close vote account.
+    solana_utils::close_account(ctx.accounts.vote, ctx.accounts.vote_rent_payer)?;
+
+    OracleEvent::VoteWithdrawn {
+        request: *ctx.accounts.request.key,
+        stake: *ctx.accounts.stake.key,
+        votes,
+    }
+    .emit()?;
+
+    Ok(())
+}
@@ -0,0 +1,483 @@
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::error::OracleError;
+use crate::state::{
+    AccountSizedMut, AssertionV1, RequestData, RequestSigner, RequestState, RequestV1, RewardPool,
+    VotingV1,
+};
+use crate::utils::Bps;
+use crate::{pda, utils};
+
+/// Amounts paid to each party from the escrows of a request.
+///
+/// A request escrows the reward from the creator, and the bonds from the asserter and disputer.
+/// Every instruction which pays out of an escrow uses the settlement to determine the amount.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Settlement {
+    /// Amount paid to the asserter from the asserter bond escrow.
+    pub asserter_bond: u64,
+    /// Amount paid to the asserter from the reward escrow.
+    pub asserter_reward: u64,
+
+    /// Amount paid to the disputer from the disputer bond escrow.
+    pub disputer_bond: u64,
+    /// Amount paid to the disputer from the reward escrow.
+    pub disputer_reward: u64,
+
+    /// Amount refunded to the creator from the reward escrow.
+    pub creator_reward: u64,
+
+    /// Amount paid to the treasury from the bond escrow of the incorrect party.
+    pub treasury_fee: u64,
+    /// Amount shared between voters for the resolved value from the bond escrow of the
+    /// incorrect party.
+    pub voter_pool: u64,
+}
+
+impl Settlement {
+    /// Computes the settlement of a request cancelled by the creator before a value was asserted.
+    pub fn cancelled(request: &RequestV1) -> Settlement {
+        Settlement { creator_reward: request.reward, ..Default::default() }
+    }
+
+    /// Computes the settlement of a resolved request from its assertion.
+    pub fn new(
+        request: &RequestV1,
+        assertion: Option<&AssertionV1>,
+    ) -> Result<Settlement, ProgramError> {
+        match request.state {
+            RequestState::Resolved => {
+                let assertion = assertion.ok_or(OracleError::NotAsserted)?;
+
+                // An invalid request refunds the reward to the creator, and both bonds.
+                if request.value == RequestData::INVALID_VALUE {
+                    let disputer_bond = if assertion.is_disputed() { request.bond } else { 0 };
+
+                    return Ok(Settlement {
+                        asserter_bond: request.bond,
+                        disputer_bond,
+                        creator_reward: request.reward,
+                        ..Default::default()
+                    });
+                }
+
+                // A request which is too early to resolve keeps the reward in escrow for the
+                // asserter of the eventual value.
+                let reward =
+                    if request.value == RequestData::TOO_EARLY_VALUE { 0 } else { request.reward };
+
+                let asserter_correct = assertion.asserted_value == request.value;
+
+                // An undisputed assertion is always correct.
+                if !assertion.is_disputed() {
+                    return Ok(Settlement {
+                        asserter_bond: request.bond,
+                        asserter_reward: reward,
+                        ..Default::default()
+                    });
+                }
+
+                // The incorrect party loses their bond, which is split between the treasury and
+                // the voters.
+                let treasury_fee = request.bond_fee;
+                let voter_pool = checked_sub!(request.bond, treasury_fee)?;
+
+                if asserter_correct {
+                    Ok(Settlement {
+                        asserter_bond: request.bond,
+                        asserter_reward: reward,
+                        treasury_fee,
+                        voter_pool,
+                        ..Default::default()
+                    })
+                } else {
+                    Ok(Settlement {
+                        disputer_bond: request.bond,
+                        disputer_reward: reward,
+                        treasury_fee,
+                        voter_pool,
+                        ..Default::default()
+                    })
+                }
+            }
+            RequestState::Cancelled => Ok(Settlement::cancelled(request)),
+            RequestState::Requested | RequestState::Asserted | RequestState::Disputed => {
+                Err(OracleError::NotResolved.into())
+            }
+        }
+    }
+}
+
+/// Checks the bond escrow belongs to the asserter/disputer who loses their bond when the request
+/// resolves with the value, returning `false` if neither party loses their bond as the request is
+/// invalid, in which case the escrow is not used.
+pub(crate) fn assert_incorrect_bond_escrow(
+    bond_escrow: &Pubkey,
+    request: &Pubkey,
+    value: u64,
+    asserted_value: u64,
+) -> Result<bool, ProgramError> {
+    if value == RequestData::INVALID_VALUE {
+        // Neither party loses their bond if the request is invalid.
+        return Ok(false);
+    }
+
+    if value == asserted_value {
+        // The resolved value matches the asserted value, so the disputer loses their bond.
+        pda::dispute_bond::assert_pda(bond_escrow, request)?;
+    } else {
+        // The resolved value doesn't match the asserted value, so the asserter loses their bond.
+        pda::assert_bond::assert_pda(bond_escrow, request)?;
+    }
+
+    Ok(true)
+}
+
+/// Resolves a disputed request with the value decided by voting or the arbitrator.
+///
+/// The fee is taken from the bond of the incorrect asserter/disputer, and the remainder of the
+/// bond is shared between voters for the value.
+pub(crate) fn resolve_dispute<'a, 'info>(
+    mut request: AccountSizedMut<'a, 'info, RequestV1>,
+    mut voting: AccountSizedMut<'a, 'info, VotingV1>,
+    assertion: &AssertionV1,
+    value: u64,
+    now: i64,
+    request_signer: &RequestSigner,
+    accounts: utils::TransferBondFee<'_, 'info>,
+) -> ProgramResult {
+    // Step 1: Check bond escrow for incorrect asserter/disputer.
+    let incorrect = assert_incorrect_bond_escrow(
+        accounts.bond_escrow.key,
+        accounts.request.key,
+        value,
+        assertion.asserted_value,
+    )?;
+
+    // No fee is taken if the request is invalid, as neither party loses their bond.
+    let bond_fee =
+        if incorrect { Bps::try_from(request.bond_fee_bps)?.calculate(request.bond) } else { 0 };
+
+    // Step 2: Resolve the request with the value.
+    {
+        request.resolve_timestamp = now;
+        request.state = RequestState::Resolved;
+        request.value = value;
+        request.bond_fee = bond_fee;
+
+        let settlement = Settlement::new(&request, Some(assertion))?;
+
+        // Voters for the resolved value share the bond remaining after the fee.
+        let total_votes = voting.votes.get(&value).copied().unwrap_or_default();
+
+        voting.reward_pool = RewardPool::new(settlement.voter_pool, total_votes);
+
+        // The votes are no longer needed, so the space is freed and the rent refunded to voters
+        // as their votes are closed.
+        voting.votes.clear();
+        voting.shrink()?;
+
+        request.save()?;
+        voting.save()?;
+    }
+
+    // Step 3: Take the fee from the bond of the incorrect asserter/disputer.
+    utils::transfer_bond_fee(bond_fee, accounts, &request_signer.seeds())
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use solana_program::pubkey::Pubkey;
+
+    use super::*;
+    use crate::state::{InitAssertion, InitRequest, InitVoting, RequestData, RewardPool, VotingV1};
+    use crate::utils::Bps;
+    use crate::utils::tests::{init_request, request_params};
+
+    fn request(reward: u64, bond: u64) -> RequestV1 {
+        let init = InitRequest { reward, bond, ..init_request(request_params()) };
+
+        let (request, _) = <(RequestV1, usize)>::try_from(init).unwrap();
+
+        request
+    }
+
+    fn assertion(asserted_value: u64, disputed: bool) -> AssertionV1 {
+        let init = InitAssertion {
+            request: Pubkey::new_unique(),
+            assertion_timestamp: 0,
+            asserter: Pubkey::new_unique(),
+            rent_payer: Pubkey::new_unique(),
+            asserted_value,
+            dispute_window: 0,
+        };
+
+        let (mut assertion, _) = <(AssertionV1, usize)>::try_from(init).unwrap();
+
+        if disputed {
+            assertion.disputer = Pubkey::new_unique();
+        }
+
+        assertion
+    }
+
+    #[test]
+    fn cancelled() {
+        let mut request = request(100, 10);
+        request.state = RequestState::Cancelled;
+
+        let settlement = Settlement::new(&request, None).unwrap();
+
+        assert_eq!(settlement, Settlement { creator_reward: 100, ..Default::default() });
+        assert_eq!(Settlement::cancelled(&request), settlement);
+    }
+
+    #[test]
+    fn unresolved() {
+        let request = request(100, 10);
+
+        assert_eq!(
+            Settlement::new(&request, Some(&assertion(1, false))),
+            Err(OracleError::NotResolved.into()),
+        );
+    }
+
+    #[test]
+    fn undisputed() {
+        let mut request = request(100, 10);
+        request.state = RequestState::Resolved;
+        request.value = 1;
+
+        let settlement = Settlement::new(&request, Some(&assertion(1, false))).unwrap();
+
+        assert_eq!(
+            settlement,
+            Settlement { asserter_bond: 10, asserter_reward: 100, ..Default::default() },
+        );
+    }
+
+    #[test]
+    fn disputed() {
+        let mut request = request(100, 10);
+        request.state = RequestState::Resolved;
+        request.bond_fee = 2;
+
+        // The asserter is correct, so the disputer loses their bond.
+        request.value = 1;
+
+        let settlement = Settlement::new(&request, Some(&assertion(1, true))).unwrap();
+
+        assert_eq!(
+            settlement,
+            Settlement {
+                asserter_bond: 10,
+                asserter_reward: 100,
+                treasury_fee: 2,
+                voter_pool: 8,
+                ..Default::default()
+            },
+        );
+
+        // The asserter is incorrect, so the asserter loses their bond.
+        request.value = 0;
+
+        let settlement = Settlement::new(&request, Some(&assertion(1, true))).unwrap();
+
+        assert_eq!(
+            settlement,
+            Settlement {
+                disputer_bond: 10,
+                disputer_reward: 100,
+                treasury_fee: 2,
+                voter_pool: 8,
+                ..Default::default()
+            },
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let mut request = request(100, 10);
+        request.state = RequestState::Resolved;
+        request.value = RequestData::INVALID_VALUE;
+
+        // The reward and bond are refunded.
+        let settlement = Settlement::new(&request, Some(&assertion(1, false))).unwrap();
+
+        assert_eq!(
+            settlement,
+            Settlement { asserter_bond: 10, creator_reward: 100, ..Default::default() },
+        );
+
+        // Both bonds are refunded without a fee, regardless of the asserted value.
+        for asserted_value in [1, RequestData::INVALID_VALUE] {
+            let settlement =
+                Settlement::new(&request, Some(&assertion(asserted_value, true))).unwrap();
+
+            assert_eq!(
+                settlement,
+                Settlement {
+                    asserter_bond: 10,
+                    disputer_bond: 10,
+                    creator_reward: 100,
+                    ..Default::default()
+                },
+            );
+        }
+    }
+
+    #[test]
+    fn too_early() {
+        let mut request = request(100, 10);
+        request.state = RequestState::Resolved;
+        request.value = RequestData::TOO_EARLY_VALUE;
+
+        // The reward is kept in escrow.
+        let assertion_too_early = assertion(RequestData::TOO_EARLY_VALUE, false);
+        let settlement = Settlement::new(&request, Some(&assertion_too_early)).unwrap();
+
+        assert_eq!(settlement, Settlement { asserter_bond: 10, ..Default::default() });
+
+        // The incorrect party still loses their bond.
+        request.bond_fee = 2;
+
+        let settlement = Settlement::new(&request, Some(&assertion(1, true))).unwrap();
+
+        assert_eq!(
+            settlement,
+            Settlement { disputer_bond: 10, treasury_fee: 2, voter_pool: 8, ..Default::default() },
+        );
+    }
+
+    #[test]
+    fn voters_share_incorrect_bond() {
+        let mut request = request(100, 1_000);
+        let assertion = assertion(1, true);
+
+        let (mut voting, _) = <(VotingV1, usize)>::try_from(InitVoting {
+            request: Pubkey::new_unique(),
+            governance_mint: Pubkey::new_unique(),
+            rent_payer: Pubkey::new_unique(),
+            start_timestamp: 0,
+            voting_window: 0,
+            reveal_window: 0,
+        })
+        .unwrap();
+
+        // Three voters vote for the asserted value, and one against.
+        let votes = [(1, 3), (1, 3), (1, 1), (0, 2)];
+
+        for (value, votes) in votes {
+            voting.add_votes(value, votes).unwrap();
+        }
+
+        // Voting resolves the request, taking a fee from the disputer bond.
+        request.state = RequestState::Resolved;
+        request.value = 1;
+        request.bond_fee = Bps::new(100).unwrap().calculate(request.bond);
+
+        let settlement = Settlement::new(&request, Some(&assertion)).unwrap();
+
+        voting.reward_pool = RewardPool::new(settlement.voter_pool, voting.votes[&1]);
+
+        let mut dispute_bond_escrow = request.bond - settlement.treasury_fee;
+
+        // Each correct voter claims, with the last voter claiming the remainder.
+        let rewards: Vec<u64> = votes
+            .iter()
+            .filter(|(value, _)| *value == request.value)
+            .map(|&(_, votes)| voting.reward_pool.claim(votes).unwrap().bond)
+            .collect();
+
+        assert_eq!(rewards, [424, 424, 142]);
+
+        for reward in rewards {
+            dispute_bond_escrow -= reward;
+        }
+
+        assert_eq!(dispute_bond_escrow, 0);
+        assert_eq!(voting.reward_pool.claimed, settlement.voter_pool);
+    }
+
+    proptest! {
+        #[test]
+        fn escrows_sum_to_zero(
+            reward in any::<u64>(),
+            bond in any::<u64>(),
+            bond_fee_bps in 0..=Bps::MAX,
+            disputed in any::<bool>(),
+            asserter_correct in any::<bool>(),
+            value in prop_oneof![
+                Just(1),
+                Just(RequestData::INVALID_VALUE),
+                Just(RequestData::TOO_EARLY_VALUE),
+            ],
+            votes in prop::collection::vec(1..=u64::from(u32::MAX), 0..32),
+        ) {
+            let mut request = request(reward, bond);
+            request.state = RequestState::Resolved;
+            request.value = value;
+
+            let asserted_value = if asserter_correct || !disputed { value } else { 0 };
+            let assertion = assertion(asserted_value, disputed);
+
+            if disputed {
+                request.bond_fee = Bps::new(bond_fee_bps).unwrap().calculate(bond);
+            }
+
+            let settlement = Settlement::new(&request, Some(&assertion)).unwrap();
+
+            let mut reward_escrow = reward;
+            let mut assert_bond_escrow = bond;
+            let mut dispute_bond_escrow = if disputed { bond } else { 0 };
+
+            // The asserter, disputer, and creator claim.
+            reward_escrow -= settlement.asserter_reward;
+            reward_escrow -= settlement.disputer_reward;
+            reward_escrow -= settlement.creator_reward;
+            assert_bond_escrow -= settlement.asserter_bond;
+            dispute_bond_escrow -= settlement.disputer_bond;
+
+            // The reward is kept for the next assertion if the request was too early.
+            if value == RequestData::TOO_EARLY_VALUE {
+                prop_assert_eq!(reward_escrow, reward);
+            } else {
+                prop_assert_eq!(reward_escrow, 0);
+            }
+
+            let correct = assertion.asserted_value == request.value;
+            let incorrect_bond_escrow = match (disputed, correct) {
+                (false, _) => {
+                    prop_assert_eq!(settlement.voter_pool, 0);
+                    prop_assert_eq!(settlement.treasury_fee, 0);
+                    prop_assert_eq!(dispute_bond_escrow, 0);
+
+                    &mut assert_bond_escrow
+                }
+                (true, true) => &mut dispute_bond_escrow,
+                (true, false) => &mut assert_bond_escrow,
+            };
+
+            // The fee is taken on resolution.
+            *incorrect_bond_escrow -= settlement.treasury_fee;
+
+            // Every voter for the resolved value claims from the reward pool.
+            let mut reward_pool = RewardPool::new(settlement.voter_pool, votes.iter().sum());
+
+            for &votes in &votes {
+                *incorrect_bond_escrow -= reward_pool.claim(votes).unwrap().bond;
+            }
+
+            // Without any correct voters the pool is swept when the request is closed.
+            if votes.is_empty() {
+                *incorrect_bond_escrow -= settlement.voter_pool;
+            }
+
+            prop_assert_eq!(assert_bond_escrow, 0);
+            prop_assert_eq!(dispute_bond_escrow, 0);
+        }
+    }
+}
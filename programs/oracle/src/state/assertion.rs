@@ -33,6 +33,9 @@ pub struct AssertionV1 {
     /// Disputer address.
     pub disputer: Pubkey,
 
+    /// Address which paid rent for the account, refunded when the account is closed.
+    pub rent_payer: Pubkey,
+
     /// Value submitted by the asserter.
     pub asserted_value: u64,
 }
@@ -42,6 +45,12 @@ impl AssertionV1 {
         timestamp < self.expiration_timestamp
     }
 
+    pub fn is_disputed(&self) -> bool {
+        const DEFAULT_PUBKEY: Pubkey = Pubkey::new_from_array([0; 32]);
+
+        !solana_utils::pubkeys_eq(&self.disputer, &DEFAULT_PUBKEY)
+    }
+
     pub fn assert_asserter(&self, asserter: &Pubkey) -> Result<(), OracleError> {
         if !solana_utils::pubkeys_eq(asserter, &self.asserter) {
             return Err(OracleError::AsserterMismatch);
@@ -56,6 +65,13 @@ impl AssertionV1 {
         Ok(())
     }
 
+    pub fn assert_rent_payer(&self, rent_payer: &Pubkey) -> Result<(), OracleError> {
+        if !solana_utils::pubkeys_eq(&self.rent_payer, rent_payer) {
+            return Err(OracleError::RentPayerMismatch);
+        }
+        Ok(())
+    }
+
     pub fn validate_expiration_timestamp(&self, timestamp: i64) -> Result<(), OracleError> {
         if self.in_dispute_window(timestamp) {
             return Err(OracleError::DisputeWindowNotExpired);
@@ -83,6 +99,7 @@ impl TryFrom<InitAssertion> for (AssertionV1, usize) {
             request,
             assertion_timestamp,
             asserter,
+            rent_payer,
             asserted_value,
             dispute_window,
         } = params;
@@ -97,6 +114,7 @@ impl TryFrom<InitAssertion> for (AssertionV1, usize) {
                 expiration_timestamp,
                 asserter,
                 disputer: Pubkey::default(),
+                rent_payer,
                 asserted_value,
             },
             AssertionV1::FIXED_SIZE,
@@ -109,6 +127,7 @@ pub(crate) struct InitAssertion {
 
     pub assertion_timestamp: i64,
     pub asserter: Pubkey,
+    pub rent_payer: Pubkey,
     pub asserted_value: u64,
 
     pub dispute_window: u32,
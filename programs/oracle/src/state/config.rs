@@ -4,8 +4,9 @@ use shank::ShankAccount;
 use solana_program::pubkey::Pubkey;
 
 use crate::error::OracleError;
+use crate::utils::Bounds;
 
-use super::{Account, AccountType};
+use super::{Account, AccountType, RequestParams};
 
 #[derive(Clone, BorshDeserialize, BorshSerialize, BorshSchema, BorshSize, ShankAccount)]
 pub struct ConfigV1 {
@@ -23,6 +24,93 @@ pub struct ConfigV1 {
     pub voting_window: u32,
     /// The duration of the arbitration window in seconds.
     pub arbitration_window: u32,
+
+    // Fields added since the first version of the config are appended, so that the offsets of
+    // the original fields used in GPA lookups are unchanged.
+    /// The duration of the reveal window in seconds, which follows the voting window.
+    ///
+    /// If non-zero, votes are committed as a hash during the voting window, and must be revealed
+    /// during the reveal window to be counted. Commit-reveal voting is disabled if zero.
+    pub reveal_window: u32,
+    /// The duration in seconds after resolution in which voters can claim their rewards, before
+    /// any unclaimed rewards are swept to the treasury when the request is closed.
+    pub claim_window: u32,
+
+    /// The range of dispute windows, in seconds, that a request can use in place of the dispute
+    /// window of the config.
+    pub dispute_window_range: Bounds,
+    /// The range of voting windows, in seconds, that a request can use in place of the voting
+    /// window of the config.
+    pub voting_window_range: Bounds,
+    /// The range of arbitration windows, in seconds, that a request can use in place of the
+    /// arbitration window of the config.
+    pub arbitration_window_range: Bounds,
+
+    /// The minimum amount of governance tokens that must be voted to resolve a dispute.
+    pub quorum_amount: u64,
+    /// The minimum amount of governance tokens that must be voted to resolve a dispute, in basis
+    /// points of the total staked governance tokens.
+    ///
+    /// If both quorum settings are non-zero, then the larger of the two is used.
+    pub quorum_bps: u16,
+
+    /// The fraction of stake, in basis points, slashed from voters who voted for a value other
+    /// than the resolved value. The slashed stake is shared between voters for the resolved
+    /// value.
+    ///
+    /// Slashing is disabled if zero.
+    pub slash_bps: u16,
+
+    /// How a dispute is resolved if voting is tied between several values.
+    pub tie_policy: TiePolicy,
+
+    /// Whether currencies can be created for mints with the transfer fee extension.
+    pub transfer_fee_policy: TransferFeePolicy,
+
+    /// The duration in seconds after resolution in which incorrect votes can be slashed, before
+    /// voters can claim their rewards.
+    ///
+    /// Slashing must finish before claims begin, so that every correct voter receives the same
+    /// share of the slashed stake. Ignored if slashing is disabled.
+    pub slash_window: u32,
+}
+
+/// How a dispute is resolved if several values are tied for the most votes.
+///
+/// Numeric requests resolve to the median of the voted values, so are only tied if the votes are
+/// split evenly between [`RequestData::INVALID_VALUE`] and [`RequestData::TOO_EARLY_VALUE`].
+///
+/// [`RequestData::INVALID_VALUE`]: crate::state::RequestData::INVALID_VALUE
+/// [`RequestData::TOO_EARLY_VALUE`]: crate::state::RequestData::TOO_EARLY_VALUE
+#[derive(
+    Clone, Copy, PartialEq, Eq, Debug, BorshDeserialize, BorshSerialize, BorshSchema, BorshSize,
+)]
+#[repr(u8)]
+pub enum TiePolicy {
+    /// Start a new voting window.
+    ExtendVoting,
+    /// Resolve the request with the asserted value.
+    AssertedValue,
+    /// Resolve the request as invalid with [`RequestData::INVALID_VALUE`].
+    ///
+    /// [`RequestData::INVALID_VALUE`]: crate::state::RequestData::INVALID_VALUE
+    Unresolvable,
+}
+
+/// Whether currencies can be created for Token-2022 mints with the transfer fee extension.
+///
+/// Escrows must receive the full reward or bond, so a mint which charges a fee can never be used.
+#[derive(
+    Clone, Copy, PartialEq, Eq, Debug, BorshDeserialize, BorshSerialize, BorshSchema, BorshSize,
+)]
+#[repr(u8)]
+pub enum TransferFeePolicy {
+    /// Reject mints with the transfer fee extension.
+    Reject,
+    /// Allow mints with the transfer fee extension if no fee is charged.
+    ///
+    /// If the mint authority later sets a fee, then funding escrows with the mint will fail.
+    AllowZeroFee,
 }
 
 impl ConfigV1 {
@@ -32,6 +120,80 @@ impl ConfigV1 {
         }
         Ok(())
     }
+
+    /// Checks the window ranges each contain at least one window, and only windows that fit in a
+    /// `u32`.
+    pub fn validate_window_ranges(
+        dispute_window_range: Bounds,
+        voting_window_range: Bounds,
+        arbitration_window_range: Bounds,
+    ) -> Result<(), OracleError> {
+        for range in [dispute_window_range, voting_window_range, arbitration_window_range] {
+            if range.is_empty() {
+                return Err(OracleError::EmptyBounds);
+            }
+            if range.end > u64::from(u32::MAX) + 1 {
+                return Err(OracleError::WindowBounds);
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks a mint with the given transfer fee, if the mint has the transfer fee extension, can
+    /// be used as a currency.
+    pub fn validate_transfer_fee(&self, transfer_fee_bps: Option<u16>) -> Result<(), OracleError> {
+        match (self.transfer_fee_policy, transfer_fee_bps) {
+            (_, None) => Ok(()),
+            (TransferFeePolicy::AllowZeroFee, Some(0)) => Ok(()),
+            _ => Err(OracleError::TransferFeeNotSupported),
+        }
+    }
+
+    /// Returns the parameters governing a new request, which are copied into the request so that
+    /// config updates do not affect existing requests.
+    pub(crate) fn request_params(
+        &self,
+        dispute_window: Option<u32>,
+        voting_window: Option<u32>,
+        arbitration_window: Option<u32>,
+    ) -> Result<RequestParams, OracleError> {
+        Ok(RequestParams {
+            dispute_window: self.request_dispute_window(dispute_window)?,
+            voting_window: self.request_voting_window(voting_window)?,
+            reveal_window: self.reveal_window,
+            arbitration_window: self.request_arbitration_window(arbitration_window)?,
+            claim_window: self.claim_window,
+            bond_fee_bps: self.bond_fee_bps,
+            quorum_amount: self.quorum_amount,
+            quorum_bps: self.quorum_bps,
+            slash_bps: self.slash_bps,
+            slash_window: self.slash_window,
+            tie_policy: self.tie_policy,
+        })
+    }
+
+    // The windows of a request default to the config windows, and may be overridden by the
+    // creator with a window within the matching config window range.
+
+    pub fn request_dispute_window(&self, window: Option<u32>) -> Result<u32, OracleError> {
+        request_window(self.dispute_window, self.dispute_window_range, window)
+    }
+
+    pub fn request_voting_window(&self, window: Option<u32>) -> Result<u32, OracleError> {
+        request_window(self.voting_window, self.voting_window_range, window)
+    }
+
+    pub fn request_arbitration_window(&self, window: Option<u32>) -> Result<u32, OracleError> {
+        request_window(self.arbitration_window, self.arbitration_window_range, window)
+    }
+}
+
+fn request_window(default: u32, range: Bounds, window: Option<u32>) -> Result<u32, OracleError> {
+    match window {
+        Some(window) if !range.contains(u64::from(window)) => Err(OracleError::WindowBounds),
+        Some(window) => Ok(window),
+        None => Ok(default),
+    }
 }
 
 impl Account for ConfigV1 {
@@ -45,7 +207,18 @@ impl From<InitConfig> for (ConfigV1, usize) {
             bond_fee_bps,
             dispute_window,
             voting_window,
+            reveal_window,
             arbitration_window,
+            claim_window,
+            dispute_window_range,
+            voting_window_range,
+            arbitration_window_range,
+            quorum_amount,
+            quorum_bps,
+            slash_bps,
+            tie_policy,
+            transfer_fee_policy,
+            slash_window,
         } = params;
 
         (
@@ -56,6 +229,17 @@ impl From<InitConfig> for (ConfigV1, usize) {
                 dispute_window,
                 voting_window,
                 arbitration_window,
+                reveal_window,
+                claim_window,
+                dispute_window_range,
+                voting_window_range,
+                arbitration_window_range,
+                quorum_amount,
+                quorum_bps,
+                slash_bps,
+                tie_policy,
+                transfer_fee_policy,
+                slash_window,
             },
             ConfigV1::FIXED_SIZE,
         )
@@ -69,5 +253,150 @@ pub(crate) struct InitConfig {
 
     pub dispute_window: u32,
     pub voting_window: u32,
+    pub reveal_window: u32,
     pub arbitration_window: u32,
+    pub claim_window: u32,
+
+    pub dispute_window_range: Bounds,
+    pub voting_window_range: Bounds,
+    pub arbitration_window_range: Bounds,
+
+    pub quorum_amount: u64,
+    pub quorum_bps: u16,
+
+    pub slash_bps: u16,
+
+    pub tie_policy: TiePolicy,
+
+    pub transfer_fee_policy: TransferFeePolicy,
+
+    pub slash_window: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::tests::init_config;
+
+    fn config() -> ConfigV1 {
+        let (config, _) = <(ConfigV1, usize)>::from(init_config());
+
+        config
+    }
+
+    #[test]
+    fn transfer_fee() {
+        let mut config = config();
+
+        // Mints without the transfer fee extension are always allowed.
+        assert_eq!(config.validate_transfer_fee(None), Ok(()));
+        assert_eq!(
+            config.validate_transfer_fee(Some(0)),
+            Err(OracleError::TransferFeeNotSupported)
+        );
+        assert_eq!(
+            config.validate_transfer_fee(Some(50)),
+            Err(OracleError::TransferFeeNotSupported)
+        );
+
+        config.transfer_fee_policy = TransferFeePolicy::AllowZeroFee;
+
+        assert_eq!(config.validate_transfer_fee(None), Ok(()));
+        assert_eq!(config.validate_transfer_fee(Some(0)), Ok(()));
+        assert_eq!(
+            config.validate_transfer_fee(Some(50)),
+            Err(OracleError::TransferFeeNotSupported)
+        );
+    }
+
+    #[test]
+    fn request_windows() {
+        let mut config = config();
+
+        config.dispute_window = 100;
+        config.dispute_window_range = Bounds { start: 50, end: 201 };
+        config.voting_window = 1_000;
+        config.voting_window_range = Bounds { start: 1_000, end: 1_001 };
+
+        // The config window is used if not overridden.
+        assert_eq!(config.request_dispute_window(None), Ok(100));
+        assert_eq!(config.request_voting_window(None), Ok(1_000));
+
+        // The override must be within the range.
+        assert_eq!(config.request_dispute_window(Some(50)), Ok(50));
+        assert_eq!(config.request_dispute_window(Some(200)), Ok(200));
+        assert_eq!(config.request_dispute_window(Some(49)), Err(OracleError::WindowBounds));
+        assert_eq!(config.request_dispute_window(Some(201)), Err(OracleError::WindowBounds));
+
+        // A range of one window only allows that window.
+        assert_eq!(config.request_voting_window(Some(1_000)), Ok(1_000));
+        assert_eq!(config.request_voting_window(Some(1_001)), Err(OracleError::WindowBounds));
+    }
+
+    #[test]
+    fn validate_window_ranges() {
+        let valid = Bounds { start: 1, end: 2 };
+        let max = Bounds { start: 0, end: u64::from(u32::MAX) + 1 };
+
+        assert!(ConfigV1::validate_window_ranges(valid, valid, valid).is_ok());
+        assert!(ConfigV1::validate_window_ranges(max, max, max).is_ok());
+
+        for invalid in [Bounds { start: 0, end: 0 }, Bounds { start: 2, end: 1 }] {
+            assert_eq!(
+                ConfigV1::validate_window_ranges(invalid, valid, valid),
+                Err(OracleError::EmptyBounds),
+            );
+            assert_eq!(
+                ConfigV1::validate_window_ranges(valid, invalid, valid),
+                Err(OracleError::EmptyBounds),
+            );
+            assert_eq!(
+                ConfigV1::validate_window_ranges(valid, valid, invalid),
+                Err(OracleError::EmptyBounds),
+            );
+        }
+
+        // A window must fit in a `u32`.
+        let too_long = Bounds { start: 0, end: u64::from(u32::MAX) + 2 };
+
+        assert_eq!(
+            ConfigV1::validate_window_ranges(valid, too_long, valid),
+            Err(OracleError::WindowBounds),
+        );
+    }
+
+    #[test]
+    fn request_params() {
+        let mut config = config();
+
+        config.dispute_window = 100;
+        config.voting_window_range = Bounds { start: 500, end: 1_001 };
+        config.reveal_window = 200;
+        config.claim_window = 300;
+        config.bond_fee_bps = 1_000;
+        config.quorum_amount = 400;
+        config.quorum_bps = 2_500;
+        config.slash_bps = 500;
+        config.slash_window = 600;
+        config.tie_policy = TiePolicy::AssertedValue;
+
+        let params = config.request_params(None, Some(500), None).unwrap();
+
+        assert_eq!(params.dispute_window, 100);
+        assert_eq!(params.voting_window, 500);
+        assert_eq!(params.reveal_window, 200);
+        assert_eq!(params.claim_window, 300);
+        assert_eq!(params.bond_fee_bps, 1_000);
+        assert_eq!(params.quorum_amount, 400);
+        assert_eq!(params.quorum_bps, 2_500);
+        assert_eq!(params.slash_bps, 500);
+        assert_eq!(params.slash_window, 600);
+        assert_eq!(params.tie_policy, TiePolicy::AssertedValue);
+
+        // Window overrides are checked against the config window ranges.
+        assert_eq!(
+            config.request_params(None, Some(499), None).err(),
+            Some(OracleError::WindowBounds)
+        );
+    }
 }
@@ -3,6 +3,8 @@ use borsh_size::{BorshSize, BorshSizeProperties};
 use shank::ShankAccount;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
+use solana_utils::log;
+use spl_token_2022::extension::ExtensionType;
 
 use crate::error::OracleError;
 use crate::pda;
@@ -23,6 +25,22 @@ pub struct CurrencyV1 {
     pub reward_range: Bounds,
     /// The valid bond range when creating an assertion.
     pub bond_range: Bounds,
+
+    /// Whether the currency can be used when creating a request.
+    pub status: CurrencyStatus,
+}
+
+#[derive(
+    Clone, Copy, PartialEq, Eq, Debug, BorshDeserialize, BorshSerialize, BorshSchema, BorshSize,
+)]
+#[repr(u8)]
+pub enum CurrencyStatus {
+    /// The currency can be used for new requests.
+    Active,
+    /// The currency cannot be used for new requests.
+    ///
+    /// Requests created while the currency was active are not affected.
+    Disabled,
 }
 
 impl CurrencyV1 {
@@ -43,6 +61,55 @@ impl CurrencyV1 {
         }
         Ok(())
     }
+
+    pub fn assert_active(&self) -> Result<(), OracleError> {
+        if self.status != CurrencyStatus::Active {
+            return Err(OracleError::CurrencyDisabled);
+        }
+        Ok(())
+    }
+
+    /// Checks the reward and bond ranges each contain at least one value.
+    pub fn validate_ranges(reward_range: Bounds, bond_range: Bounds) -> Result<(), OracleError> {
+        if reward_range.is_empty() || bond_range.is_empty() {
+            return Err(OracleError::EmptyBounds);
+        }
+        Ok(())
+    }
+
+    /// Checks the mint has no extensions which would allow tokens to be moved out of, or locked
+    /// in, an escrow, other than by the mint's own authorities.
+    ///
+    /// The transfer fee extension is checked separately against the config transfer fee policy.
+    /// A mint close authority cannot close the mint while escrows hold tokens, as the supply must
+    /// be zero. A transfer hook is invoked with its extra accounts on every escrow transfer, and
+    /// can reject them, so is trusted in the same way as the mint's freeze authority, which can
+    /// already freeze escrows.
+    pub fn validate_mint_extensions(extensions: &[ExtensionType]) -> Result<(), OracleError> {
+        for extension in extensions {
+            match extension {
+                ExtensionType::TransferFeeConfig
+                | ExtensionType::MintCloseAuthority
+                | ExtensionType::InterestBearingConfig
+                | ExtensionType::TransferHook
+                | ExtensionType::MetadataPointer
+                | ExtensionType::TokenMetadata
+                | ExtensionType::GroupPointer
+                | ExtensionType::TokenGroup
+                | ExtensionType::GroupMemberPointer
+                | ExtensionType::TokenGroupMember => {}
+                // Non-transferable tokens cannot be paid out, a permanent delegate can drain
+                // escrows, a default account state can freeze escrows on creation, and
+                // confidential transfers are not reflected in escrow balances. Unknown extensions
+                // are rejected as their effect on escrows is unknown.
+                _ => {
+                    log!("Error: Mint extension {extension:?} is not supported");
+                    return Err(OracleError::MintExtensionNotSupported);
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 impl Account for CurrencyV1 {
@@ -54,7 +121,14 @@ impl From<InitCurrency> for (CurrencyV1, usize) {
         let InitCurrency { config, mint, reward_range, bond_range } = params;
 
         (
-            CurrencyV1 { account_type: CurrencyV1::TYPE, config, mint, reward_range, bond_range },
+            CurrencyV1 {
+                account_type: CurrencyV1::TYPE,
+                config,
+                mint,
+                reward_range,
+                bond_range,
+                status: CurrencyStatus::Active,
+            },
             CurrencyV1::FIXED_SIZE,
         )
     }
@@ -66,3 +140,71 @@ pub(crate) struct InitCurrency {
     pub reward_range: Bounds,
     pub bond_range: Bounds,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_ranges() {
+        let valid = Bounds { start: 1, end: 2 };
+
+        assert!(CurrencyV1::validate_ranges(valid, valid).is_ok());
+        assert!(CurrencyV1::validate_ranges(Bounds { start: 0, end: u64::MAX }, valid).is_ok());
+
+        for invalid in [Bounds { start: 0, end: 0 }, Bounds { start: 2, end: 1 }] {
+            assert_eq!(
+                CurrencyV1::validate_ranges(invalid, valid).err(),
+                Some(OracleError::EmptyBounds),
+            );
+            assert_eq!(
+                CurrencyV1::validate_ranges(valid, invalid).err(),
+                Some(OracleError::EmptyBounds),
+            );
+        }
+    }
+
+    #[test]
+    fn validate_mint_extensions() {
+        assert!(CurrencyV1::validate_mint_extensions(&[]).is_ok());
+        assert!(
+            CurrencyV1::validate_mint_extensions(&[
+                ExtensionType::TransferFeeConfig,
+                ExtensionType::MintCloseAuthority,
+                ExtensionType::TransferHook,
+                ExtensionType::MetadataPointer,
+                ExtensionType::TokenMetadata,
+            ])
+            .is_ok()
+        );
+
+        for extension in [
+            ExtensionType::NonTransferable,
+            ExtensionType::PermanentDelegate,
+            ExtensionType::DefaultAccountState,
+            ExtensionType::ConfidentialTransferMint,
+        ] {
+            assert_eq!(
+                CurrencyV1::validate_mint_extensions(&[ExtensionType::TransferHook, extension])
+                    .err(),
+                Some(OracleError::MintExtensionNotSupported),
+            );
+        }
+    }
+
+    #[test]
+    fn init_active() {
+        let (currency, _) = <(CurrencyV1, usize)>::from(InitCurrency {
+            config: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            reward_range: Bounds { start: 0, end: 1 },
+            bond_range: Bounds { start: 0, end: 1 },
+        });
+
+        assert!(currency.assert_active().is_ok());
+
+        let currency = CurrencyV1 { status: CurrencyStatus::Disabled, ..currency };
+
+        assert_eq!(currency.assert_active().err(), Some(OracleError::CurrencyDisabled));
+    }
+}
@@ -155,24 +155,21 @@ pub(crate) struct AccountSizedMut<'a, 'info, T> {
 }
 
 impl<'a, 'info, T: AccountSized> AccountSizedMut<'a, 'info, T> {
-    pub fn into_inner(self) -> T {
-        self.account
-    }
-
+    /// Grows the account to fit the state, returning the additional rent paid by the payer.
     pub fn realloc(
         &mut self,
         payer: &'a AccountInfo<'info>,
         system_program: &'a AccountInfo<'info>,
-    ) -> ProgramResult {
+    ) -> Result<u64, ProgramError> {
         if T::IS_FIXED_SIZE {
-            return Ok(());
+            return Ok(0);
         }
 
         let new_size = self.account.borsh_size();
         let current_size = self.data.len();
 
         if new_size <= current_size {
-            return Ok(());
+            return Ok(0);
         }
 
         let rent = Rent::get()?;
@@ -198,9 +195,49 @@ impl<'a, 'info, T: AccountSized> AccountSizedMut<'a, 'info, T> {
             )?;
         }
 
+        Ok(rent_diff)
+    }
+
+    /// Shrinks the account to fit the state. The rent no longer required is left in the account,
+    /// to be refunded with [`AccountSizedMut::refund_excess_rent`].
+    pub fn shrink(&mut self) -> ProgramResult {
+        if T::IS_FIXED_SIZE {
+            return Ok(());
+        }
+
+        let new_size = self.account.borsh_size();
+
+        if new_size >= self.data.len() {
+            return Ok(());
+        }
+
+        log!("Reallocating {} account data", T::name());
+
+        self.data = solana_utils::realloc_account_mut(self.info, new_size)?;
+
         Ok(())
     }
 
+    /// Transfers up to `amount` of the lamports in excess of the rent exemption of the account to
+    /// the destination, returning the amount transferred.
+    pub fn refund_excess_rent(
+        &self,
+        amount: u64,
+        destination: &AccountInfo<'info>,
+    ) -> Result<u64, ProgramError> {
+        let rent = Rent::get()?.minimum_balance(self.data.len());
+        let refund = amount.min(self.info.lamports().saturating_sub(rent));
+
+        if refund > 0 {
+            log!("Refunding {refund} lamports of rent");
+
+            **self.info.try_borrow_mut_lamports()? -= refund;
+            **destination.try_borrow_mut_lamports()? += refund;
+        }
+
+        Ok(refund)
+    }
+
     pub fn save(mut self) -> Result<T, ProgramError> {
         if !T::IS_FIXED_SIZE {
             let size = self.borsh_size();
@@ -18,6 +18,11 @@ pub struct OracleV1 {
     pub authority: Pubkey,
     /// Governance token mint address.
     pub governance_mint: Pubkey,
+
+    /// The total amount staked across all stake accounts.
+    ///
+    /// Tokens transferred directly to the stake pool are not counted, so cannot inflate quorums.
+    pub total_staked: u64,
 }
 
 impl OracleV1 {
@@ -38,7 +43,13 @@ impl From<InitOracle> for (OracleV1, usize) {
         let InitOracle { authority, governance_mint } = params;
 
         (
-            OracleV1 { account_type: OracleV1::TYPE, next_index: 0, authority, governance_mint },
+            OracleV1 {
+                account_type: OracleV1::TYPE,
+                next_index: 0,
+                authority,
+                governance_mint,
+                total_staked: 0,
+            },
             OracleV1::FIXED_SIZE,
         )
     }
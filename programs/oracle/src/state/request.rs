@@ -6,14 +6,17 @@ use solana_program::pubkey::Pubkey;
 
 use crate::error::OracleError;
 use crate::pda;
+use crate::utils::Bps;
 
-use super::{Account, AccountType};
+use super::{Account, AccountType, TiePolicy};
 
 #[derive(Clone, BorshDeserialize, BorshSerialize, BorshSchema, BorshSize, ShankAccount)]
 pub struct RequestV1 {
     account_type: AccountType,
 
     /// Index of the request in the oracle.
+    ///
+    /// Requests derived from the creator are not indexed by the oracle, so the index is zero.
     pub index: u64,
 
     /// Config address.
@@ -51,6 +54,61 @@ pub struct RequestV1 {
     /// then the request is considered to have no arbitrator.
     pub arbitrator: Pubkey,
 
+    // Fields added since the first version of the account are placed after the original fields,
+    // so that the offsets used in GPA lookups are unchanged.
+    /// Number of times the request has been reset after resolving as too early.
+    pub round: u32,
+
+    // The parameters governing the request are copied from the config when the request is
+    // created, so that config updates do not affect existing requests.
+    /// The duration of the dispute window in seconds.
+    pub dispute_window: u32,
+    /// The duration of the voting window in seconds.
+    pub voting_window: u32,
+    /// The duration of the reveal window in seconds, which follows the voting window.
+    ///
+    /// Commit-reveal voting is disabled if zero.
+    pub reveal_window: u32,
+    /// The duration of the arbitration window in seconds.
+    pub arbitration_window: u32,
+    /// The duration in seconds after resolution in which voters can claim their rewards.
+    pub claim_window: u32,
+    /// The fee taken, in basis points, from the bond of the incorrect party in a dispute.
+    pub bond_fee_bps: u16,
+    /// The minimum amount of governance tokens that must be voted to resolve a dispute.
+    pub quorum_amount: u64,
+    /// The minimum amount of governance tokens that must be voted to resolve a dispute, in basis
+    /// points of the total staked governance tokens.
+    pub quorum_bps: u16,
+    /// The fraction of stake, in basis points, slashed from voters who voted for a value other
+    /// than the resolved value.
+    pub slash_bps: u16,
+    /// How a dispute is resolved if voting is tied between several values.
+    pub tie_policy: TiePolicy,
+
+    /// Fee taken from the bond of the incorrect party in a dispute on resolution.
+    pub bond_fee: u64,
+
+    /// Address which paid rent for the account, refunded when the account is closed.
+    pub rent_payer: Pubkey,
+
+    /// The duration in seconds after resolution in which incorrect votes can be slashed, before
+    /// voters can claim their rewards.
+    pub slash_window: u32,
+
+    /// How the request address is derived.
+    pub derivation: RequestDerivation,
+
+    /// Whether the request can be resolved as [`RequestData::TOO_EARLY_VALUE`], which resets it
+    /// to accept a new assertion.
+    pub allow_too_early: bool,
+
+    /// Nonce chosen by the creator, from which the request address is derived with
+    /// [`RequestDerivation::Creator`].
+    ///
+    /// Zero for requests derived from the oracle index.
+    pub nonce: u64,
+
     // Request data may have varying layouts when serialized. It is at the end
     // of the account to avoid interfering with GPA lookups.
     /// Request data.
@@ -68,6 +126,24 @@ pub enum RequestState {
     Disputed,
     /// Request with a resolved value.
     Resolved,
+    /// Request cancelled by the creator before a value was asserted.
+    Cancelled,
+}
+
+#[derive(
+    Clone, Copy, PartialEq, Eq, Debug, BorshDeserialize, BorshSerialize, BorshSchema, BorshSize,
+)]
+#[repr(u8)]
+pub enum RequestDerivation {
+    /// Derived from the index of the request in the oracle.
+    Index,
+    /// Derived from the creator and a nonce chosen by the creator.
+    ///
+    /// Creating the request does not write to the oracle, so requests can be created in parallel.
+    /// The address is known before the request is created, so retrying a transaction cannot
+    /// create a duplicate request while the request exists. A nonce can be reused once the request
+    /// is cancelled or closed, so a retried transaction may then create a new request.
+    Creator,
 }
 
 #[derive(Clone, BorshDeserialize, BorshSerialize, BorshSchema, BorshSize)]
@@ -79,6 +155,33 @@ pub enum RequestData {
         /// Question.
         question: String,
     },
+    /// Multiple choice request:
+    /// - value = index of the selected option
+    MultipleChoice {
+        /// Question.
+        question: String,
+        /// Options to choose from.
+        options: Vec<String>,
+    },
+    /// Numeric request:
+    /// - value = number scaled by `10^decimals`, within `min..=max`
+    ///
+    /// Disputes are resolved to the stake-weighted median of the voted values, or the lower of the
+    /// two middle values if the votes are split evenly. Votes for invalid or too early are not
+    /// part of the median, and only resolve the request with a majority of the votes. There is no
+    /// tolerance: only votes for exactly the resolved value are rewarded, and votes for any other
+    /// value are incorrect, so can be slashed. The question should state how the value is to be
+    /// rounded.
+    Numeric {
+        /// Question.
+        question: String,
+        /// Minimum valid value.
+        min: u64,
+        /// Maximum valid value.
+        max: u64,
+        /// Number of decimal places of the value.
+        decimals: u8,
+    },
 }
 
 impl RequestV1 {
@@ -89,7 +192,19 @@ impl RequestV1 {
     }
 
     pub fn assert_pda(&self, request: &Pubkey) -> Result<u8, ProgramError> {
-        pda::request::assert_pda(request, &self.index)
+        match self.derivation {
+            RequestDerivation::Index => pda::request::assert_pda(request, &self.index),
+            RequestDerivation::Creator => {
+                pda::creator_request::assert_pda(request, &self.creator, &self.nonce)
+            }
+        }
+    }
+
+    /// Returns the signer of the request address, for the bump returned by [`assert_pda`].
+    ///
+    /// [`assert_pda`]: RequestV1::assert_pda
+    pub fn signer(&self, bump: u8) -> RequestSigner {
+        RequestSigner::new(self.derivation, self.index, self.nonce, self.creator, bump)
     }
 
     pub fn assert_config(&self, config: &Pubkey) -> Result<(), OracleError> {
@@ -99,6 +214,27 @@ impl RequestV1 {
         Ok(())
     }
 
+    pub fn assert_creator(&self, creator: &Pubkey) -> Result<(), OracleError> {
+        if !solana_utils::pubkeys_eq(&self.creator, creator) {
+            return Err(OracleError::CreatorMismatch);
+        }
+        Ok(())
+    }
+
+    pub fn assert_rent_payer(&self, rent_payer: &Pubkey) -> Result<(), OracleError> {
+        if !solana_utils::pubkeys_eq(&self.rent_payer, rent_payer) {
+            return Err(OracleError::RentPayerMismatch);
+        }
+        Ok(())
+    }
+
+    pub fn assert_arbitrator(&self, arbitrator: &Pubkey) -> Result<(), OracleError> {
+        if !self.has_arbitrator() || !solana_utils::pubkeys_eq(&self.arbitrator, arbitrator) {
+            return Err(OracleError::ArbitratorMismatch);
+        }
+        Ok(())
+    }
+
     pub fn assert_reward_mint(&self, mint: &Pubkey) -> Result<(), OracleError> {
         if !solana_utils::pubkeys_eq(&self.reward_mint, mint) {
             return Err(OracleError::RewardMintMismatch);
@@ -113,22 +249,136 @@ impl RequestV1 {
         Ok(())
     }
 
+    /// Returns the minimum amount of votes required to resolve a dispute given the total amount
+    /// of staked governance tokens.
+    pub fn quorum(&self, total_staked: u64) -> Result<u64, OracleError> {
+        let quorum_bps = Bps::try_from(self.quorum_bps)?;
+
+        Ok(self.quorum_amount.max(quorum_bps.calculate(total_staked)))
+    }
+
+    /// Returns the Unix timestamp from which voters can claim their rewards.
+    ///
+    /// If slashing is enabled, claims begin after the slash window, so that every correct voter
+    /// receives the same share of the slashed stake.
+    pub fn claim_start_timestamp(&self) -> Result<i64, ProgramError> {
+        let slash_window = if self.slash_bps > 0 { self.slash_window } else { 0 };
+
+        checked_add!(self.resolve_timestamp, i64::from(slash_window))
+    }
+
+    /// Returns the Unix timestamp at which the claim window ends, after which unclaimed rewards
+    /// can be swept to the treasury.
+    pub fn claim_end_timestamp(&self) -> Result<i64, ProgramError> {
+        checked_add!(self.claim_start_timestamp()?, i64::from(self.claim_window))
+    }
+
     pub fn validate_assertion_timestamp(&self, timestamp: i64) -> Result<(), OracleError> {
         if timestamp < self.assertion_timestamp {
             return Err(OracleError::AssertionTooEarly);
         }
         Ok(())
     }
+
+    /// Checks the value can resolve the request.
+    pub fn validate_value(&self, value: u64) -> Result<(), OracleError> {
+        self.data.validate_value(value)?;
+        self.validate_too_early(value)
+    }
+
+    /// Checks the value is not [`RequestData::TOO_EARLY_VALUE`] unless the request allows it, as
+    /// resolving as too early resets the request.
+    pub fn validate_too_early(&self, value: u64) -> Result<(), OracleError> {
+        if value == RequestData::TOO_EARLY_VALUE && !self.allow_too_early {
+            return Err(OracleError::TooEarlyNotAllowed);
+        }
+        Ok(())
+    }
 }
 
 impl Account for RequestV1 {
     const TYPE: AccountType = AccountType::RequestV1;
 }
 
+/// The seeds of a request address, which signs for the request escrows.
+#[derive(Clone, Copy)]
+pub struct RequestSigner {
+    derivation: RequestDerivation,
+    index: u64,
+    nonce: u64,
+    creator: Pubkey,
+    bump: u8,
+}
+
+impl RequestSigner {
+    pub(crate) fn new(
+        derivation: RequestDerivation,
+        index: u64,
+        nonce: u64,
+        creator: Pubkey,
+        bump: u8,
+    ) -> Self {
+        RequestSigner { derivation, index, nonce, creator, bump }
+    }
+
+    /// Returns the signer seeds of the request address.
+    ///
+    /// Addresses derived from the index have one seed fewer than those derived from the creator,
+    /// so are padded with an empty seed, which does not change the derived address.
+    pub fn seeds(&self) -> [&[u8]; 4] {
+        match self.derivation {
+            RequestDerivation::Index => {
+                let [prefix, index, bump] = pda::request::seeds_with_bump(&self.index, &self.bump);
+
+                [prefix, index, bump, &[]]
+            }
+            RequestDerivation::Creator => {
+                pda::creator_request::seeds_with_bump(&self.creator, &self.nonce, &self.bump)
+            }
+        }
+    }
+}
+
 impl RequestData {
+    /// The minimum number of options in a multiple choice request.
+    pub const MIN_OPTIONS: usize = 2;
+
+    /// Reserved value for a request which is invalid, e.g. if the question is ambiguous.
+    ///
+    /// A request resolved as invalid refunds the reward to the creator, and both bonds to the
+    /// asserter and disputer.
+    pub const INVALID_VALUE: u64 = u64::MAX;
+    /// Reserved value for a request which cannot be answered yet.
+    ///
+    /// A request resolved as too early is reset to accept a new assertion once the request is
+    /// closed, keeping the reward for the asserter of the eventual value.
+    pub const TOO_EARLY_VALUE: u64 = u64::MAX - 1;
+
+    /// Returns true if the value is reserved for an outcome other than an answer to the request.
+    pub fn is_sentinel_value(value: u64) -> bool {
+        matches!(value, Self::INVALID_VALUE | Self::TOO_EARLY_VALUE)
+    }
+
+    pub fn validate(&self) -> Result<(), OracleError> {
+        let valid = match self {
+            Self::YesNo { .. } => true,
+            Self::MultipleChoice { options, .. } => options.len() >= Self::MIN_OPTIONS,
+            // The range must not include the reserved values.
+            Self::Numeric { min, max, .. } => min < max && !Self::is_sentinel_value(*max),
+        };
+        if valid { Ok(()) } else { Err(OracleError::InvalidRequestData) }
+    }
+
     pub fn validate_value(&self, value: u64) -> Result<(), OracleError> {
+        // The reserved values are valid for every request.
+        if Self::is_sentinel_value(value) {
+            return Ok(());
+        }
+
         let valid = match self {
             Self::YesNo { .. } => matches!(value, 0 | 1),
+            Self::MultipleChoice { options, .. } => value < options.len() as u64,
+            Self::Numeric { min, max, .. } => (*min..=*max).contains(&value),
         };
         if valid { Ok(()) } else { Err(OracleError::InvalidValue) }
     }
@@ -140,17 +390,37 @@ impl TryFrom<InitRequest> for (RequestV1, usize) {
     fn try_from(params: InitRequest) -> Result<(RequestV1, usize), Self::Error> {
         let InitRequest {
             index,
+            nonce,
             config,
             creator,
+            rent_payer,
             reward,
             reward_mint,
             bond,
             bond_mint,
             timestamp,
+            params:
+                RequestParams {
+                    dispute_window,
+                    voting_window,
+                    reveal_window,
+                    arbitration_window,
+                    claim_window,
+                    bond_fee_bps,
+                    quorum_amount,
+                    quorum_bps,
+                    slash_bps,
+                    slash_window,
+                    tie_policy,
+                },
             arbitrator,
+            derivation,
+            allow_too_early,
             data,
         } = params;
 
+        data.validate()?;
+
         let account = RequestV1 {
             account_type: RequestV1::TYPE,
             index,
@@ -165,6 +435,23 @@ impl TryFrom<InitRequest> for (RequestV1, usize) {
             state: RequestState::Requested,
             value: 0,
             arbitrator,
+            round: 0,
+            dispute_window,
+            voting_window,
+            reveal_window,
+            arbitration_window,
+            claim_window,
+            bond_fee_bps,
+            quorum_amount,
+            quorum_bps,
+            slash_bps,
+            tie_policy,
+            bond_fee: 0,
+            rent_payer,
+            slash_window,
+            derivation,
+            allow_too_early,
+            nonce,
             data,
         };
         let space = account.borsh_size();
@@ -175,9 +462,11 @@ impl TryFrom<InitRequest> for (RequestV1, usize) {
 
 pub(crate) struct InitRequest {
     pub index: u64,
+    pub nonce: u64,
 
     pub config: Pubkey,
     pub creator: Pubkey,
+    pub rent_payer: Pubkey,
 
     pub reward: u64,
     pub reward_mint: Pubkey,
@@ -186,15 +475,44 @@ pub(crate) struct InitRequest {
     pub bond_mint: Pubkey,
 
     pub timestamp: i64,
+    pub params: RequestParams,
     pub arbitrator: Pubkey,
+    pub derivation: RequestDerivation,
+    pub allow_too_early: bool,
 
     pub data: RequestData,
 }
 
+/// The parameters governing a request, copied from the config when the request is created.
+#[derive(Clone, Copy)]
+pub(crate) struct RequestParams {
+    pub dispute_window: u32,
+    pub voting_window: u32,
+    pub reveal_window: u32,
+    pub arbitration_window: u32,
+    pub claim_window: u32,
+
+    pub bond_fee_bps: u16,
+
+    pub quorum_amount: u64,
+    pub quorum_bps: u16,
+
+    pub slash_bps: u16,
+    pub slash_window: u32,
+
+    pub tie_policy: TiePolicy,
+}
+
 #[cfg(test)]
 mod tests {
-
     use super::*;
+    use crate::utils::tests::{init_request, request_params};
+
+    fn request(params: RequestParams) -> RequestV1 {
+        let (request, _) = <(RequestV1, usize)>::try_from(init_request(params)).unwrap();
+
+        request
+    }
 
     #[test]
     fn data_size() {
@@ -206,24 +524,256 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn multiple_choice_data_size() {
+        let data = RequestData::MultipleChoice {
+            question: "example question?".to_owned(),
+            options: vec!["first".to_owned(), "second".to_owned(), "third option".to_owned()],
+        };
+
+        let expected = data.borsh_size();
+        let actual = borsh::object_length(&data).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn multiple_choice_value() {
+        let data = RequestData::MultipleChoice {
+            question: "example question?".to_owned(),
+            options: vec!["first".to_owned(), "second".to_owned(), "third".to_owned()],
+        };
+
+        assert!(data.validate().is_ok());
+
+        for value in 0..3 {
+            assert!(data.validate_value(value).is_ok());
+        }
+        assert_eq!(data.validate_value(3), Err(OracleError::InvalidValue));
+        assert_eq!(data.validate_value(u64::MAX - 2), Err(OracleError::InvalidValue));
+    }
+
+    #[test]
+    fn multiple_choice_options() {
+        let data = RequestData::MultipleChoice {
+            question: "example question?".to_owned(),
+            options: vec!["only".to_owned()],
+        };
+
+        assert_eq!(data.validate(), Err(OracleError::InvalidRequestData));
+    }
+
+    #[test]
+    fn numeric_data_size() {
+        let data = RequestData::Numeric {
+            question: "example question?".to_owned(),
+            min: 0,
+            max: 1_000_000,
+            decimals: 2,
+        };
+
+        let expected = data.borsh_size();
+        let actual = borsh::object_length(&data).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn numeric_value() {
+        let data = RequestData::Numeric {
+            question: "example question?".to_owned(),
+            min: 100,
+            max: 200,
+            decimals: 0,
+        };
+
+        assert!(data.validate().is_ok());
+
+        assert!(data.validate_value(100).is_ok());
+        assert!(data.validate_value(150).is_ok());
+        assert!(data.validate_value(200).is_ok());
+        assert_eq!(data.validate_value(99), Err(OracleError::InvalidValue));
+        assert_eq!(data.validate_value(201), Err(OracleError::InvalidValue));
+    }
+
+    #[test]
+    fn sentinel_value() {
+        let data = RequestData::YesNo { question: "example question?".to_owned() };
+
+        assert!(data.validate_value(RequestData::INVALID_VALUE).is_ok());
+        assert!(data.validate_value(RequestData::TOO_EARLY_VALUE).is_ok());
+
+        let data = RequestData::Numeric {
+            question: "example question?".to_owned(),
+            min: 0,
+            max: RequestData::TOO_EARLY_VALUE - 1,
+            decimals: 0,
+        };
+
+        assert!(data.validate().is_ok());
+        assert!(data.validate_value(RequestData::INVALID_VALUE).is_ok());
+        assert!(data.validate_value(RequestData::TOO_EARLY_VALUE).is_ok());
+
+        // A numeric range cannot include the reserved values.
+        let data = RequestData::Numeric {
+            question: "example question?".to_owned(),
+            min: 0,
+            max: RequestData::TOO_EARLY_VALUE,
+            decimals: 0,
+        };
+
+        assert_eq!(data.validate(), Err(OracleError::InvalidRequestData));
+    }
+
+    #[test]
+    fn numeric_range() {
+        let data = RequestData::Numeric {
+            question: "example question?".to_owned(),
+            min: 200,
+            max: 100,
+            decimals: 0,
+        };
+
+        assert_eq!(data.validate(), Err(OracleError::InvalidRequestData));
+    }
+
     #[test]
     fn account_size() {
         let init = InitRequest {
-            index: 0,
-            config: Pubkey::new_unique(),
-            creator: Pubkey::new_unique(),
-            reward: 0,
-            reward_mint: Pubkey::new_unique(),
-            bond: 0,
-            bond_mint: Pubkey::new_unique(),
-            timestamp: 0,
             arbitrator: Pubkey::new_unique(),
             data: RequestData::YesNo { question: "another example question?".to_owned() },
+            ..init_request(request_params())
         };
 
         let (request, expected) = <(RequestV1, usize)>::try_from(init).unwrap();
         let actual = borsh::object_length(&request).unwrap();
 
         assert_eq!(expected, actual);
+
+        let init = InitRequest {
+            arbitrator: Pubkey::new_unique(),
+            data: RequestData::MultipleChoice {
+                question: "which team wins the group?".to_owned(),
+                options: vec!["a".to_owned(), "b".to_owned(), "c".to_owned(), "d".to_owned()],
+            },
+            ..init_request(request_params())
+        };
+
+        let (request, expected) = <(RequestV1, usize)>::try_from(init).unwrap();
+        let actual = borsh::object_length(&request).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn rent_payer() {
+        let rent_payer = Pubkey::new_unique();
+
+        let init = InitRequest { rent_payer, ..init_request(request_params()) };
+
+        let (request, _) = <(RequestV1, usize)>::try_from(init).unwrap();
+
+        assert!(request.assert_rent_payer(&rent_payer).is_ok());
+        assert_eq!(
+            request.assert_rent_payer(&request.creator),
+            Err(OracleError::RentPayerMismatch),
+        );
+    }
+
+    #[test]
+    fn arbitrator() {
+        let mut request = request(request_params());
+
+        // A request without an arbitrator cannot be arbitrated.
+        assert!(!request.has_arbitrator());
+        assert_eq!(
+            request.assert_arbitrator(&Pubkey::default()),
+            Err(OracleError::ArbitratorMismatch),
+        );
+
+        let arbitrator = Pubkey::new_unique();
+        request.arbitrator = arbitrator;
+
+        assert!(request.has_arbitrator());
+        assert!(request.assert_arbitrator(&arbitrator).is_ok());
+        assert_eq!(
+            request.assert_arbitrator(&Pubkey::new_unique()),
+            Err(OracleError::ArbitratorMismatch),
+        );
+    }
+
+    #[test]
+    fn validate_too_early() {
+        let mut request = request(request_params());
+
+        // Too early is only valid if the request allows it.
+        assert_eq!(
+            request.validate_value(RequestData::TOO_EARLY_VALUE),
+            Err(OracleError::TooEarlyNotAllowed),
+        );
+        assert!(request.validate_value(RequestData::INVALID_VALUE).is_ok());
+        assert!(request.validate_value(1).is_ok());
+        assert_eq!(request.validate_value(2), Err(OracleError::InvalidValue));
+
+        request.allow_too_early = true;
+
+        assert!(request.validate_value(RequestData::TOO_EARLY_VALUE).is_ok());
+    }
+
+    #[test]
+    fn request_signer() {
+        let creator = Pubkey::new_unique();
+
+        for derivation in [RequestDerivation::Index, RequestDerivation::Creator] {
+            let init = InitRequest {
+                index: 7,
+                nonce: 9,
+                creator,
+                derivation,
+                ..init_request(request_params())
+            };
+
+            let (request, _) = <(RequestV1, usize)>::try_from(init).unwrap();
+
+            let (address, bump) = match derivation {
+                RequestDerivation::Index => pda::request::pda(&7),
+                RequestDerivation::Creator => pda::creator_request::pda(&creator, &9),
+            };
+
+            assert_eq!(request.assert_pda(&address).unwrap(), bump);
+
+            let signer = request.signer(bump);
+            let seeds = signer.seeds();
+
+            assert_eq!(Pubkey::create_program_address(&seeds, &crate::ID).unwrap(), address);
+        }
+
+        // The same index and nonce derive different addresses.
+        assert_ne!(pda::request::pda(&7).0, pda::creator_request::pda(&creator, &7).0);
+    }
+
+    #[test]
+    fn quorum() {
+        let quorum = |quorum_amount, quorum_bps, total_staked| {
+            request(RequestParams { quorum_amount, quorum_bps, ..request_params() })
+                .quorum(total_staked)
+        };
+
+        // No quorum.
+        assert_eq!(quorum(0, 0, 1_000), Ok(0));
+
+        // Absolute amount.
+        assert_eq!(quorum(250, 0, 1_000), Ok(250));
+
+        // Basis points of the total staked amount.
+        assert_eq!(quorum(0, 2_500, 1_000), Ok(250));
+        assert_eq!(quorum(0, 2_500, 0), Ok(0));
+
+        // The larger of the two.
+        assert_eq!(quorum(100, 2_500, 1_000), Ok(250));
+        assert_eq!(quorum(400, 2_500, 1_000), Ok(400));
+
+        // Invalid basis points.
+        assert_eq!(quorum(0, 10_001, 1_000), Err(OracleError::InvalidBps));
     }
 }
@@ -1,17 +1,13 @@
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use borsh_size::{BorshSize, BorshSizeProperties};
 use shank::ShankAccount;
+use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 
 use crate::error::OracleError;
 
 use super::{Account, AccountType};
 
-// TODO:
-// - unstaking
-// - merging staking accounts
-//   - cannot be done if an account has a lock for an active vote
-
 #[derive(Clone, BorshDeserialize, BorshSerialize, BorshSchema, BorshSize, ShankAccount)]
 pub struct StakeV1 {
     account_type: AccountType,
@@ -30,9 +26,28 @@ pub struct StakeV1 {
 
     /// The Unix timestamp the stake is locked until.
     pub lock_timestamp: i64,
+
+    /// The number of votes cast with the stake which are yet to be claimed, slashed or withdrawn.
+    ///
+    /// The stake account cannot be closed while it has open votes, as they refer to it.
+    pub open_votes: u32,
 }
 
 impl StakeV1 {
+    pub fn assert_mint(&self, mint: &Pubkey) -> Result<(), OracleError> {
+        if !solana_utils::pubkeys_eq(&self.mint, mint) {
+            return Err(OracleError::StakeMintMismatch);
+        }
+        Ok(())
+    }
+
+    pub fn assert_owner(&self, owner: &Pubkey) -> Result<(), OracleError> {
+        if !solana_utils::pubkeys_eq(&self.owner, owner) {
+            return Err(OracleError::StakeOwnerMismatch);
+        }
+        Ok(())
+    }
+
     pub fn assert_voter(&self, voter: &Pubkey) -> Result<(), OracleError> {
         if !solana_utils::pubkeys_eq(&self.owner, voter)
             && !solana_utils::pubkeys_eq(&self.delegate, voter)
@@ -41,6 +56,32 @@ impl StakeV1 {
         }
         Ok(())
     }
+
+    pub fn validate_unlocked(&self, timestamp: i64) -> Result<(), OracleError> {
+        if timestamp < self.lock_timestamp {
+            return Err(OracleError::StakeLocked);
+        }
+        Ok(())
+    }
+
+    pub fn validate_no_open_votes(&self) -> Result<(), OracleError> {
+        if self.open_votes > 0 {
+            return Err(OracleError::StakeHasOpenVotes);
+        }
+        Ok(())
+    }
+
+    /// Locks the stake for a vote until `lock_timestamp`, returning the votes it represents.
+    ///
+    /// The stake may already be locked for longer by a vote on another request. It also cannot be
+    /// withdrawn while it has open votes, so remains locked until the vote is claimed, slashed or
+    /// closed.
+    pub fn lock_for_vote(&mut self, lock_timestamp: i64) -> Result<u64, ProgramError> {
+        self.lock_timestamp = self.lock_timestamp.max(lock_timestamp);
+        self.open_votes = checked_add!(self.open_votes, 1)?;
+
+        Ok(self.amount)
+    }
 }
 
 impl Account for StakeV1 {
@@ -59,6 +100,7 @@ impl From<InitStake> for (StakeV1, usize) {
                 delegate: owner,
                 amount,
                 lock_timestamp: i64::MIN,
+                open_votes: 0,
             },
             StakeV1::FIXED_SIZE,
         )
@@ -70,3 +112,50 @@ pub(crate) struct InitStake {
     pub owner: Pubkey,
     pub amount: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stake(owner: Pubkey) -> StakeV1 {
+        let init = InitStake { mint: Pubkey::new_unique(), owner, amount: 100 };
+
+        let (stake, _) = <(StakeV1, usize)>::from(init);
+
+        stake
+    }
+
+    #[test]
+    fn delegate_defaults_to_owner() {
+        let owner = Pubkey::new_unique();
+        let stake = stake(owner);
+
+        assert_eq!(stake.delegate, owner);
+        assert!(stake.assert_owner(&owner).is_ok());
+        assert!(stake.assert_voter(&owner).is_ok());
+    }
+
+    #[test]
+    fn locked_until_timestamp() {
+        let mut stake = stake(Pubkey::new_unique());
+        stake.lock_timestamp = 100;
+
+        assert_eq!(stake.validate_unlocked(99), Err(OracleError::StakeLocked));
+        assert!(stake.validate_unlocked(100).is_ok());
+        assert!(stake.validate_unlocked(101).is_ok());
+    }
+
+    #[test]
+    fn lock_for_vote() {
+        let mut stake = stake(Pubkey::new_unique());
+
+        assert_eq!(stake.lock_for_vote(200), Ok(100));
+        assert_eq!(stake.lock_timestamp, 200);
+        assert_eq!(stake.open_votes, 1);
+
+        // A shorter lock does not shorten the existing lock.
+        assert_eq!(stake.lock_for_vote(150), Ok(100));
+        assert_eq!(stake.lock_timestamp, 200);
+        assert_eq!(stake.open_votes, 2);
+    }
+}
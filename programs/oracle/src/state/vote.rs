@@ -1,8 +1,11 @@
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use borsh_size::{BorshSize, BorshSizeProperties};
 use shank::ShankAccount;
+use solana_program::hash::hashv;
 use solana_program::pubkey::Pubkey;
 
+use crate::error::OracleError;
+
 use super::{Account, AccountType};
 
 #[derive(Clone, BorshDeserialize, BorshSerialize, BorshSchema, BorshSize, ShankAccount)]
@@ -13,11 +16,71 @@ pub struct VoteV1 {
     pub voting: Pubkey,
     /// The address of the stake the votes represent.
     pub stake: Pubkey,
+    /// The round of the request the vote was cast in.
+    ///
+    /// A request which is reset after resolving as too early has the same voting address in the
+    /// next round, so votes from previous rounds are not counted.
+    pub round: u32,
+
+    /// Address which paid rent for the account, refunded when the account is closed.
+    pub rent_payer: Pubkey,
 
     /// The value voted for.
+    ///
+    /// If the vote was committed, then this is only set once the vote is revealed.
     pub value: u64,
     /// The amount of votes.
     pub votes: u64,
+
+    /// The hash of the voting address, stake address, value and salt committed to, or zero if the
+    /// vote was not committed.
+    pub commitment: [u8; 32],
+    /// Whether the value voted for has been revealed, which is always true if the vote was not
+    /// committed.
+    pub revealed: bool,
+
+    /// Lamports paid by the rent payer to reallocate the voting account for the vote, refunded
+    /// from the voting account when the vote is closed.
+    pub voting_rent: u64,
+}
+
+impl VoteV1 {
+    /// Returns the commitment for a vote for `value` hidden by `salt`.
+    ///
+    /// The voting and stake addresses are included, so that a commitment copied by another voter
+    /// cannot be revealed by them.
+    pub fn commitment(voting: &Pubkey, stake: &Pubkey, value: u64, salt: &[u8; 32]) -> [u8; 32] {
+        hashv(&[voting.as_ref(), stake.as_ref(), &value.to_le_bytes(), salt]).to_bytes()
+    }
+
+    /// Reveals a committed vote, checking the value and salt match the commitment.
+    pub fn reveal(&mut self, value: u64, salt: &[u8; 32]) -> Result<(), OracleError> {
+        if self.revealed {
+            return Err(OracleError::VoteAlreadyRevealed);
+        }
+        if VoteV1::commitment(&self.voting, &self.stake, value, salt) != self.commitment {
+            return Err(OracleError::CommitmentMismatch);
+        }
+
+        self.value = value;
+        self.revealed = true;
+
+        Ok(())
+    }
+
+    pub fn assert_round(&self, round: u32) -> Result<(), OracleError> {
+        if self.round != round {
+            return Err(OracleError::VoteRoundMismatch);
+        }
+        Ok(())
+    }
+
+    pub fn assert_rent_payer(&self, rent_payer: &Pubkey) -> Result<(), OracleError> {
+        if !solana_utils::pubkeys_eq(&self.rent_payer, rent_payer) {
+            return Err(OracleError::RentPayerMismatch);
+        }
+        Ok(())
+    }
 }
 
 impl Account for VoteV1 {
@@ -26,15 +89,110 @@ impl Account for VoteV1 {
 
 impl From<InitVote> for (VoteV1, usize) {
     fn from(params: InitVote) -> (VoteV1, usize) {
-        let InitVote { voting, stake, value, votes } = params;
+        let InitVote { voting, stake, round, rent_payer, value, votes, voting_rent } = params;
+
+        let (value, commitment, revealed) = match value {
+            VoteValue::Revealed(value) => (value, [0; 32], true),
+            VoteValue::Committed(commitment) => (0, commitment, false),
+        };
 
-        (VoteV1 { account_type: VoteV1::TYPE, voting, stake, value, votes }, VoteV1::FIXED_SIZE)
+        (
+            VoteV1 {
+                account_type: VoteV1::TYPE,
+                voting,
+                stake,
+                round,
+                rent_payer,
+                value,
+                votes,
+                commitment,
+                revealed,
+                voting_rent,
+            },
+            VoteV1::FIXED_SIZE,
+        )
     }
 }
 
 pub(crate) struct InitVote {
     pub voting: Pubkey,
     pub stake: Pubkey,
-    pub value: u64,
+    pub round: u32,
+    pub rent_payer: Pubkey,
+    pub value: VoteValue,
     pub votes: u64,
+    pub voting_rent: u64,
+}
+
+pub(crate) enum VoteValue {
+    /// The value voted for.
+    Revealed(u64),
+    /// The commitment to the value voted for.
+    Committed([u8; 32]),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn init(round: u32, value: VoteValue) -> InitVote {
+        InitVote {
+            voting: Pubkey::new_unique(),
+            stake: Pubkey::new_unique(),
+            round,
+            rent_payer: Pubkey::new_unique(),
+            value,
+            votes: 10,
+            voting_rent: 0,
+        }
+    }
+
+    fn vote(value: VoteValue) -> VoteV1 {
+        let (vote, _) = <(VoteV1, usize)>::from(init(0, value));
+
+        vote
+    }
+
+    #[test]
+    fn reveal() {
+        let salt = [7; 32];
+        let mut vote = vote(VoteValue::Committed([0; 32]));
+        vote.commitment = VoteV1::commitment(&vote.voting, &vote.stake, 42, &salt);
+
+        assert!(!vote.revealed);
+
+        // The value and salt must both match.
+        assert_eq!(vote.reveal(41, &salt), Err(OracleError::CommitmentMismatch));
+        assert_eq!(vote.reveal(42, &[0; 32]), Err(OracleError::CommitmentMismatch));
+
+        assert_eq!(vote.reveal(42, &salt), Ok(()));
+        assert_eq!(vote.value, 42);
+        assert!(vote.revealed);
+
+        assert_eq!(vote.reveal(42, &salt), Err(OracleError::VoteAlreadyRevealed));
+    }
+
+    #[test]
+    fn reveal_copied_commitment() {
+        let salt = [7; 32];
+        let mut vote = vote(VoteValue::Committed([0; 32]));
+
+        // A commitment copied from a vote with another stake does not match.
+        vote.commitment = VoteV1::commitment(&vote.voting, &Pubkey::new_unique(), 42, &salt);
+        assert_eq!(vote.reveal(42, &salt), Err(OracleError::CommitmentMismatch));
+
+        // Nor does one copied from a vote on another request.
+        vote.commitment = VoteV1::commitment(&Pubkey::new_unique(), &vote.stake, 42, &salt);
+        assert_eq!(vote.reveal(42, &salt), Err(OracleError::CommitmentMismatch));
+    }
+
+    #[test]
+    fn revealed() {
+        let mut vote = vote(VoteValue::Revealed(42));
+
+        assert_eq!(vote.value, 42);
+        assert!(vote.revealed);
+
+        assert_eq!(vote.reveal(42, &[0; 32]), Err(OracleError::VoteAlreadyRevealed));
+    }
 }
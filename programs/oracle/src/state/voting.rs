@@ -1,3 +1,4 @@
+use std::collections::btree_map::Entry;
 use std::collections::BTreeMap;
 
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
@@ -6,7 +7,9 @@ use shank::ShankAccount;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 
-use super::{Account, AccountType};
+use crate::error::OracleError;
+
+use super::{Account, AccountType, RequestData};
 
 #[derive(Clone, BorshDeserialize, BorshSerialize, BorshSchema, BorshSize, ShankAccount)]
 pub struct VotingV1 {
@@ -31,7 +34,284 @@ pub struct VotingV1 {
     pub mode_value: u64,
 
     /// The votes for different values.
+    ///
+    /// The votes are cleared once the request is resolved, freeing the space paid for by voters
+    /// so that it can be refunded as their votes are closed.
     pub votes: BTreeMap<u64, u64>,
+
+    // New fields follow the fields of the first version of the account, so that existing GPA
+    // lookups by offset still match.
+    /// Address which paid rent for the account, refunded when the account is closed.
+    pub rent_payer: Pubkey,
+
+    /// The duration of the reveal window in seconds, which follows the voting window.
+    ///
+    /// If non-zero, votes are committed during the voting window and revealed during the reveal
+    /// window.
+    pub reveal_window: u32,
+    /// The number of votes that have been committed but not yet revealed.
+    pub unrevealed_count: u64,
+
+    /// The reward shared between voters for the resolved value, set when the request resolves.
+    pub reward_pool: RewardPool,
+
+    /// The number of vote accounts which are yet to be closed.
+    ///
+    /// The voting account holds the rent paid by voters to reallocate it, so cannot be closed
+    /// while it has open votes.
+    pub open_votes: u32,
+}
+
+impl VotingV1 {
+    pub fn is_commit_reveal(&self) -> bool {
+        self.reveal_window > 0
+    }
+
+    /// Returns the Unix timestamp when the reveal window ends, which is the end of voting if
+    /// commit-reveal voting is disabled.
+    pub fn reveal_end_timestamp(&self) -> Result<i64, ProgramError> {
+        checked_add!(self.end_timestamp, i64::from(self.reveal_window))
+    }
+
+    /// Adds votes for a value, updating the modal value.
+    pub fn add_votes(&mut self, value: u64, votes: u64) -> Result<(), ProgramError> {
+        // Add submitted votes for the voted value, the get the new amount of votes for that value.
+        let freq = match self.votes.entry(value) {
+            // An entry exists for the voted value. Add the submitted votes to the entry.
+            Entry::Occupied(mut entry) => {
+                let entry = entry.get_mut();
+                let freq = checked_add!(entry, votes)?;
+
+                *entry = freq;
+
+                freq
+            }
+            // No entry exists for the voted value. Create a new entry with the submitted votes.
+            Entry::Vacant(entry) => {
+                entry.insert(votes);
+
+                votes
+            }
+        };
+
+        self.vote_count = checked_add!(self.vote_count, votes)?;
+
+        // If the frequency of the value voted for is greater than the frequency of
+        // the previous modal value, then update the modal value.
+        if freq > self.votes.get(&self.mode_value).copied().unwrap_or_default() {
+            self.mode_value = value;
+        }
+
+        Ok(())
+    }
+
+    /// Removes votes for a value, updating the modal value.
+    pub fn remove_votes(&mut self, value: u64, votes: u64) -> Result<(), ProgramError> {
+        let freq = checked_sub!(self.votes.get(&value).copied().unwrap_or_default(), votes)?;
+
+        // Values without any votes are removed, so that every value in the map has been voted for.
+        if freq == 0 {
+            self.votes.remove(&value);
+        } else {
+            self.votes.insert(value, freq);
+        }
+
+        self.vote_count = checked_sub!(self.vote_count, votes)?;
+
+        // If the modal value lost votes, then another value may now have the most votes.
+        if value == self.mode_value {
+            self.update_mode_value();
+        }
+
+        Ok(())
+    }
+
+    /// Sets the modal value to the value with the most votes.
+    ///
+    /// The current modal value is kept if it is tied for the most votes, otherwise the lowest of
+    /// the tied values is used.
+    fn update_mode_value(&mut self) {
+        let mut mode_value = self.mode_value;
+        let mut mode_votes = self.votes.get(&mode_value).copied().unwrap_or_default();
+
+        for (&value, &votes) in &self.votes {
+            if votes > mode_votes {
+                mode_value = value;
+                mode_votes = votes;
+            }
+        }
+
+        self.mode_value = mode_value;
+    }
+
+    /// Returns the value with the most votes.
+    ///
+    /// Returns `None` if no votes have been cast, or if several values are tied for the most
+    /// votes. The modal value of a tie depends on the order votes were cast, so is not used.
+    pub fn mode(&self) -> Option<u64> {
+        let mode_votes = self.votes.get(&self.mode_value).copied()?;
+
+        let tied = self
+            .votes
+            .iter()
+            .any(|(&value, &votes)| value != self.mode_value && votes == mode_votes);
+
+        if tied { None } else { Some(self.mode_value) }
+    }
+
+    pub fn assert_rent_payer(&self, rent_payer: &Pubkey) -> Result<(), OracleError> {
+        if !solana_utils::pubkeys_eq(&self.rent_payer, rent_payer) {
+            return Err(OracleError::RentPayerMismatch);
+        }
+        Ok(())
+    }
+
+    /// Returns the stake-weighted median of the voted values.
+    ///
+    /// Votes for [`RequestData::INVALID_VALUE`] and [`RequestData::TOO_EARLY_VALUE`] are not
+    /// numbers, so are tallied separately and only resolve the request with a majority of the
+    /// votes. Otherwise the median is taken over the votes for other values. If these are split
+    /// evenly the lower of the two middle values is used, so the median is always a value that was
+    /// voted for. Returns `None` if no votes have been cast for values other than the sentinels.
+    pub fn median_value(&self) -> Option<u64> {
+        let mut value_count = u128::from(self.vote_count);
+
+        for sentinel in [RequestData::INVALID_VALUE, RequestData::TOO_EARLY_VALUE] {
+            let votes = u128::from(self.votes.get(&sentinel).copied().unwrap_or_default());
+
+            if votes * 2 > u128::from(self.vote_count) {
+                return Some(sentinel);
+            }
+
+            value_count = value_count.saturating_sub(votes);
+        }
+
+        let mut cumulative: u128 = 0;
+
+        for (&value, &votes) in &self.votes {
+            if RequestData::is_sentinel_value(value) {
+                continue;
+            }
+
+            cumulative += u128::from(votes);
+
+            if cumulative * 2 >= value_count {
+                return Some(value);
+            }
+        }
+
+        None
+    }
+}
+
+/// The bond of the incorrect asserter/disputer, less the fee, and the stake slashed from voters
+/// for other values, shared between voters for the resolved value in proportion to their votes.
+#[derive(
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Debug,
+    BorshDeserialize,
+    BorshSerialize,
+    BorshSchema,
+    BorshSize,
+)]
+pub struct RewardPool {
+    /// The amount of the bond shared between voters.
+    pub amount: u64,
+    /// The total votes for the resolved value.
+    pub votes: u64,
+
+    /// The amount already claimed by voters.
+    pub claimed: u64,
+    /// The votes for which the reward has already been claimed.
+    pub claimed_votes: u64,
+
+    /// The amount of stake slashed from voters for other values.
+    pub slashed: u64,
+    /// The amount of slashed stake already claimed by voters.
+    pub slashed_claimed: u64,
+}
+
+/// The reward claimed by a voter for the resolved value.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct VoterReward {
+    /// The amount paid from the bond of the incorrect asserter/disputer.
+    pub bond: u64,
+    /// The amount of slashed stake added to the voter's stake.
+    pub slashed: u64,
+}
+
+impl RewardPool {
+    pub fn new(amount: u64, votes: u64) -> RewardPool {
+        RewardPool { amount, votes, ..Default::default() }
+    }
+
+    /// Adds stake slashed from a voter for another value to the pool.
+    ///
+    /// Slashed stake can only be added before any voter has claimed, so that it is shared between
+    /// every voter for the resolved value. If there are no such voters, it remains unclaimed.
+    pub fn slash(&mut self, amount: u64) -> Result<(), ProgramError> {
+        if self.claimed_votes > 0 {
+            return Err(OracleError::SlashWindowExpired.into());
+        }
+
+        self.slashed = checked_add!(self.slashed, amount)?;
+
+        Ok(())
+    }
+
+    /// Returns the amount of slashed stake which has not been, and can no longer be, claimed.
+    pub fn unclaimed_slashed(&self) -> Result<u64, ProgramError> {
+        checked_sub!(self.slashed, self.slashed_claimed)
+    }
+
+    /// Claims the reward for `votes` votes for the resolved value.
+    ///
+    /// Each claim is rounded down, and the final claim receives the remainder of the pool, so the
+    /// pool is always fully paid out once every correct voter has claimed.
+    pub fn claim(&mut self, votes: u64) -> Result<VoterReward, ProgramError> {
+        if votes == 0 {
+            return Ok(VoterReward::default());
+        }
+
+        let remaining_votes = checked_sub!(self.votes, self.claimed_votes)?;
+
+        if votes > remaining_votes {
+            return Err(OracleError::RewardPoolExhausted.into());
+        }
+
+        let last = votes == remaining_votes;
+
+        let reward = VoterReward {
+            bond: self.share(self.amount, self.claimed, votes, last)?,
+            slashed: self.share(self.slashed, self.slashed_claimed, votes, last)?,
+        };
+
+        self.claimed = checked_add!(self.claimed, reward.bond)?;
+        self.slashed_claimed = checked_add!(self.slashed_claimed, reward.slashed)?;
+        self.claimed_votes = checked_add!(self.claimed_votes, votes)?;
+
+        Ok(reward)
+    }
+
+    fn share(
+        &self,
+        amount: u64,
+        claimed: u64,
+        votes: u64,
+        last: bool,
+    ) -> Result<u64, ProgramError> {
+        if last {
+            return checked_sub!(amount, claimed);
+        }
+
+        let share = u128::from(amount) * u128::from(votes) / u128::from(self.votes);
+
+        u64::try_from(share).map_err(|_| ProgramError::ArithmeticOverflow)
+    }
 }
 
 impl Account for VotingV1 {
@@ -42,7 +322,14 @@ impl TryFrom<InitVoting> for (VotingV1, usize) {
     type Error = ProgramError;
 
     fn try_from(params: InitVoting) -> Result<(VotingV1, usize), Self::Error> {
-        let InitVoting { request, governance_mint, start_timestamp, voting_window } = params;
+        let InitVoting {
+            request,
+            governance_mint,
+            rent_payer,
+            start_timestamp,
+            voting_window,
+            reveal_window,
+        } = params;
 
         let end_timestamp = checked_add!(start_timestamp, i64::from(voting_window))?;
 
@@ -55,6 +342,11 @@ impl TryFrom<InitVoting> for (VotingV1, usize) {
             vote_count: 0,
             mode_value: 0,
             votes: BTreeMap::new(),
+            rent_payer,
+            reveal_window,
+            unrevealed_count: 0,
+            reward_pool: RewardPool::default(),
+            open_votes: 0,
         };
         let space = account.borsh_size();
 
@@ -65,9 +357,11 @@ impl TryFrom<InitVoting> for (VotingV1, usize) {
 pub(crate) struct InitVoting {
     pub request: Pubkey,
     pub governance_mint: Pubkey,
+    pub rent_payer: Pubkey,
 
     pub start_timestamp: i64,
     pub voting_window: u32,
+    pub reveal_window: u32,
 }
 
 #[cfg(test)]
@@ -79,8 +373,10 @@ mod tests {
         let init = InitVoting {
             request: Pubkey::new_unique(),
             governance_mint: Pubkey::new_unique(),
+            rent_payer: Pubkey::new_unique(),
             start_timestamp: 0,
             voting_window: 0,
+            reveal_window: 0,
         };
 
         let (mut account, expected) = <(VotingV1, usize)>::try_from(init).unwrap();
@@ -96,4 +392,296 @@ mod tests {
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn reveal_end_timestamp() {
+        let init = InitVoting {
+            request: Pubkey::new_unique(),
+            governance_mint: Pubkey::new_unique(),
+            rent_payer: Pubkey::new_unique(),
+            start_timestamp: 100,
+            voting_window: 50,
+            reveal_window: 0,
+        };
+
+        let (voting, _) = <(VotingV1, usize)>::try_from(init).unwrap();
+
+        // Voting ends at the end of the voting window if commit-reveal voting is disabled.
+        assert!(!voting.is_commit_reveal());
+        assert_eq!(voting.reveal_end_timestamp(), Ok(150));
+
+        let init = InitVoting {
+            request: Pubkey::new_unique(),
+            governance_mint: Pubkey::new_unique(),
+            rent_payer: Pubkey::new_unique(),
+            start_timestamp: 100,
+            voting_window: 50,
+            reveal_window: 25,
+        };
+
+        let (voting, _) = <(VotingV1, usize)>::try_from(init).unwrap();
+
+        assert!(voting.is_commit_reveal());
+        assert_eq!(voting.end_timestamp, 150);
+        assert_eq!(voting.reveal_end_timestamp(), Ok(175));
+    }
+
+    fn voting(votes: &[(u64, u64)]) -> VotingV1 {
+        let init = InitVoting {
+            request: Pubkey::new_unique(),
+            governance_mint: Pubkey::new_unique(),
+            rent_payer: Pubkey::new_unique(),
+            start_timestamp: 0,
+            voting_window: 0,
+            reveal_window: 0,
+        };
+
+        let (mut account, _) = <(VotingV1, usize)>::try_from(init).unwrap();
+
+        for &(value, votes) in votes {
+            account.add_votes(value, votes).unwrap();
+        }
+
+        account
+    }
+
+    #[test]
+    fn add_votes() {
+        let mut voting = voting(&[(1, 5)]);
+
+        assert_eq!(voting.mode_value, 1);
+
+        // A value must have strictly more votes to become the modal value.
+        voting.add_votes(2, 5).unwrap();
+
+        assert_eq!(voting.mode_value, 1);
+
+        voting.add_votes(2, 1).unwrap();
+
+        assert_eq!(voting.mode_value, 2);
+        assert_eq!(voting.vote_count, 11);
+    }
+
+    #[test]
+    fn remove_votes() {
+        let mut voting = voting(&[(1, 5), (2, 4), (3, 4)]);
+
+        assert_eq!(voting.mode_value, 1);
+
+        // Removing votes from another value doesn't change the modal value.
+        voting.remove_votes(3, 1).unwrap();
+
+        assert_eq!(voting.mode_value, 1);
+
+        // The modal value loses its lead, so the value with the most votes becomes the mode.
+        voting.remove_votes(1, 2).unwrap();
+
+        assert_eq!(voting.mode_value, 2);
+        assert_eq!(voting.votes, BTreeMap::from([(1, 3), (2, 4), (3, 3)]));
+        assert_eq!(voting.vote_count, 10);
+
+        // Values without votes are removed.
+        voting.remove_votes(2, 4).unwrap();
+
+        assert_eq!(voting.mode_value, 1);
+        assert_eq!(voting.votes, BTreeMap::from([(1, 3), (3, 3)]));
+
+        // Cannot remove more votes than were added.
+        assert_eq!(voting.remove_votes(3, 4), Err(ProgramError::ArithmeticOverflow));
+        assert_eq!(voting.remove_votes(2, 1), Err(ProgramError::ArithmeticOverflow));
+    }
+
+    #[test]
+    fn remove_votes_tied() {
+        // The modal value is kept if it is still tied for the most votes.
+        let mut kept = voting(&[(2, 5), (1, 4)]);
+
+        kept.remove_votes(2, 1).unwrap();
+
+        assert_eq!(kept.mode_value, 2);
+
+        // Otherwise the lowest of the tied values is used.
+        let mut lowest = voting(&[(1, 3), (2, 3), (3, 5)]);
+
+        lowest.remove_votes(3, 3).unwrap();
+
+        assert_eq!(lowest.mode_value, 1);
+    }
+
+    #[test]
+    fn mode() {
+        assert_eq!(voting(&[]).mode(), None);
+        assert_eq!(voting(&[(3, 1)]).mode(), Some(3));
+        assert_eq!(voting(&[(0, 2), (1, 3)]).mode(), Some(1));
+
+        // Several values tied for the most votes.
+        assert_eq!(voting(&[(0, 3), (1, 3)]).mode(), None);
+        assert_eq!(voting(&[(0, 1), (1, 3), (2, 3)]).mode(), None);
+
+        // Values tied for fewer votes don't affect the mode.
+        assert_eq!(voting(&[(0, 1), (1, 1), (2, 3)]).mode(), Some(2));
+    }
+
+    #[test]
+    fn mode_tied_vote_order() {
+        // A tie is detected regardless of the order votes were cast.
+        assert_eq!(voting(&[(1, 5), (2, 5)]).mode(), None);
+        assert_eq!(voting(&[(2, 5), (1, 5)]).mode(), None);
+        assert_eq!(voting(&[(2, 3), (1, 2), (1, 1)]).mode(), None);
+        assert_eq!(voting(&[(1, 2), (2, 3), (1, 1)]).mode(), None);
+    }
+
+    #[test]
+    fn mode_no_votes() {
+        // Removing every vote leaves no mode.
+        let mut voting = voting(&[(4, 3)]);
+
+        voting.remove_votes(4, 3).unwrap();
+
+        assert_eq!(voting.mode(), None);
+    }
+
+    #[test]
+    fn mode_vote_changes() {
+        let mut voting = voting(&[(0, 4), (1, 2)]);
+
+        assert_eq!(voting.mode(), Some(0));
+
+        // A voter changes their vote, tying the values.
+        voting.remove_votes(0, 1).unwrap();
+        voting.add_votes(1, 1).unwrap();
+
+        assert_eq!(voting.mode(), None);
+
+        // Another voter changes their vote, breaking the tie.
+        voting.remove_votes(0, 1).unwrap();
+        voting.add_votes(1, 1).unwrap();
+
+        assert_eq!(voting.mode(), Some(1));
+
+        // A voter withdraws their vote, tying the values again.
+        voting.remove_votes(1, 2).unwrap();
+
+        assert_eq!(voting.votes, BTreeMap::from([(0, 2), (1, 2)]));
+        assert_eq!(voting.mode(), None);
+    }
+
+    #[test]
+    fn median_value() {
+        assert_eq!(voting(&[]).median_value(), None);
+        assert_eq!(voting(&[(42, 1)]).median_value(), Some(42));
+
+        // Each value has an equal weight.
+        assert_eq!(voting(&[(10, 1), (20, 1), (30, 1)]).median_value(), Some(20));
+
+        // Votes are weighted by stake, not by number of voters.
+        assert_eq!(voting(&[(10, 1), (20, 1), (30, 5)]).median_value(), Some(30));
+        assert_eq!(voting(&[(10, 5), (20, 1), (30, 1)]).median_value(), Some(10));
+
+        // A large outlier does not move the median.
+        assert_eq!(voting(&[(100, 3), (101, 3), (1_000_000, 2)]).median_value(), Some(101));
+    }
+
+    #[test]
+    fn median_value_even_split() {
+        // An even split resolves to the lower middle value.
+        assert_eq!(voting(&[(10, 5), (20, 5)]).median_value(), Some(10));
+        assert_eq!(voting(&[(10, 2), (20, 3), (30, 5)]).median_value(), Some(20));
+    }
+
+    #[test]
+    fn median_value_large_votes() {
+        assert_eq!(voting(&[(1, u64::MAX / 2), (2, u64::MAX / 2)]).median_value(), Some(1));
+    }
+
+    #[test]
+    fn median_value_sentinels() {
+        const INVALID: u64 = RequestData::INVALID_VALUE;
+        const TOO_EARLY: u64 = RequestData::TOO_EARLY_VALUE;
+
+        // Sentinel votes are excluded from the median rather than counted as large values.
+        assert_eq!(voting(&[(100, 3), (200, 3), (INVALID, 4)]).median_value(), Some(100));
+        assert_eq!(voting(&[(100, 2), (200, 3), (TOO_EARLY, 4)]).median_value(), Some(200));
+        assert_eq!(
+            voting(&[(100, 1), (200, 2), (INVALID, 2), (TOO_EARLY, 2)]).median_value(),
+            Some(200),
+        );
+
+        // A sentinel resolves the request only with a majority of the votes.
+        assert_eq!(voting(&[(100, 2), (200, 2), (INVALID, 5)]).median_value(), Some(INVALID));
+        assert_eq!(voting(&[(100, 4), (TOO_EARLY, 5)]).median_value(), Some(TOO_EARLY));
+        assert_eq!(voting(&[(100, 5), (INVALID, 5)]).median_value(), Some(100));
+
+        // Without a majority or any other votes, no value is resolved.
+        assert_eq!(voting(&[(INVALID, 5), (TOO_EARLY, 5)]).median_value(), None);
+    }
+
+    #[test]
+    fn reward_pool_claim() {
+        let mut pool = RewardPool::new(100, 3);
+
+        // Each claim is rounded down.
+        assert_eq!(pool.claim(1).unwrap().bond, 33);
+        assert_eq!(pool.claim(1).unwrap().bond, 33);
+
+        // The last claim receives the remainder.
+        assert_eq!(pool.claim(1).unwrap().bond, 34);
+
+        assert_eq!(pool.claimed, 100);
+        assert_eq!(pool.claimed_votes, 3);
+    }
+
+    #[test]
+    fn reward_pool_claim_order() {
+        // The remainder goes to whoever claims last, regardless of their share.
+        let mut pool = RewardPool::new(10, 7);
+
+        assert_eq!(pool.claim(5).unwrap().bond, 7);
+        assert_eq!(pool.claim(2).unwrap().bond, 3);
+
+        let mut pool = RewardPool::new(10, 7);
+
+        assert_eq!(pool.claim(2).unwrap().bond, 2);
+        assert_eq!(pool.claim(5).unwrap().bond, 8);
+    }
+
+    #[test]
+    fn reward_pool_exhausted() {
+        let mut pool = RewardPool::new(100, 3);
+
+        assert_eq!(pool.claim(3), Ok(VoterReward { bond: 100, slashed: 0 }));
+        assert_eq!(pool.claim(1), Err(OracleError::RewardPoolExhausted.into()));
+
+        // A pool with no correct votes pays nothing.
+        let mut pool = RewardPool::new(100, 0);
+
+        assert_eq!(pool.claim(0), Ok(VoterReward::default()));
+        assert_eq!(pool.claim(1), Err(OracleError::RewardPoolExhausted.into()));
+    }
+
+    #[test]
+    fn reward_pool_slash() {
+        let mut pool = RewardPool::new(100, 3);
+
+        pool.slash(10).unwrap();
+        pool.slash(20).unwrap();
+
+        // Every voter receives the same share of the slashed stake.
+        assert_eq!(pool.claim(1), Ok(VoterReward { bond: 33, slashed: 10 }));
+
+        // Stake cannot be slashed once voters have started to claim.
+        assert_eq!(pool.slash(10), Err(OracleError::SlashWindowExpired.into()));
+
+        assert_eq!(pool.claim(1), Ok(VoterReward { bond: 33, slashed: 10 }));
+        assert_eq!(pool.claim(1), Ok(VoterReward { bond: 34, slashed: 10 }));
+
+        assert_eq!(pool.unclaimed_slashed(), Ok(0));
+
+        // Stake slashed when there are no voters to share it remains unclaimed.
+        let mut pool = RewardPool::new(100, 0);
+
+        pool.slash(10).unwrap();
+
+        assert_eq!(pool.unclaimed_slashed(), Ok(10));
+    }
 }
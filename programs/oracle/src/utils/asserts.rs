@@ -29,3 +29,11 @@ pub fn assert_signer(account_info: &AccountInfo) -> Result<(), ProgramError> {
     }
     Ok(())
 }
+
+pub fn assert_owner(account_info: &AccountInfo, owner: &Pubkey) -> Result<(), ProgramError> {
+    if !solana_utils::pubkeys_eq(account_info.owner, owner) {
+        log!("Error: Account {} is expected to be owned by {}", account_info.key, owner);
+        return Err(ProgramError::IllegalOwner);
+    }
+    Ok(())
+}
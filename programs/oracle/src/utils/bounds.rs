@@ -15,6 +15,11 @@ impl Bounds {
     pub fn contains(&self, value: u64) -> bool {
         self.start <= value && value < self.end
     }
+
+    /// Returns true if no value is contained in the range, which includes inverted ranges.
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
 }
 
 impl RangeBounds<u64> for Bounds {
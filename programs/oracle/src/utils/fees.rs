@@ -0,0 +1,130 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_utils::log;
+
+use crate::pda;
+
+pub struct TransferBondFee<'a, 'info> {
+    pub config: &'a AccountInfo<'info>,
+    pub request: &'a AccountInfo<'info>,
+    pub bond_mint: &'a AccountInfo<'info>,
+    pub bond_escrow: &'a AccountInfo<'info>,
+    pub treasury: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+/// Transfers the fee from the bond escrow of the incorrect party into the config treasury,
+/// creating the treasury token account if necessary.
+pub fn transfer_bond_fee(
+    fee: u64,
+    accounts: TransferBondFee,
+    request_signer_seeds: &[&[u8]],
+) -> ProgramResult {
+    if fee == 0 {
+        return Ok(());
+    }
+
+    log!("Bond fee: {fee}");
+
+    let TransferBondFee {
+        config,
+        request,
+        bond_mint,
+        bond_escrow,
+        treasury,
+        payer,
+        token_program,
+        system_program,
+        remaining_accounts,
+    } = accounts;
+
+    transfer_to_treasury(
+        fee,
+        TransferToTreasury {
+            config,
+            mint: bond_mint,
+            source: bond_escrow,
+            authority: request,
+            treasury,
+            payer,
+            token_program,
+            system_program,
+            remaining_accounts,
+        },
+        request_signer_seeds,
+    )
+}
+
+pub struct TransferToTreasury<'a, 'info> {
+    pub config: &'a AccountInfo<'info>,
+    pub mint: &'a AccountInfo<'info>,
+    pub source: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+    pub treasury: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+/// Transfers tokens into the config treasury for the mint, creating the treasury token account if
+/// necessary.
+pub fn transfer_to_treasury(
+    amount: u64,
+    accounts: TransferToTreasury,
+    authority_signer_seeds: &[&[u8]],
+) -> ProgramResult {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let TransferToTreasury {
+        config,
+        mint,
+        source,
+        authority,
+        treasury,
+        payer,
+        token_program,
+        system_program,
+        remaining_accounts,
+    } = accounts;
+
+    let bump = pda::treasury::assert_pda(treasury.key, config.key, mint.key)?;
+
+    // Create treasury account if necessary. The treasury is its own token account authority.
+    if treasury.data_is_empty() {
+        let signer_seeds = pda::treasury::seeds_with_bump(config.key, mint.key, &bump);
+
+        cpi::spl::create_token_account(
+            treasury.key,
+            cpi::spl::CreateTokenAccount {
+                account: treasury,
+                mint,
+                payer,
+                token_program,
+                system_program,
+            },
+            &[&signer_seeds],
+        )?;
+    }
+
+    let decimals = cpi::spl::mint_decimals(mint)?;
+
+    cpi::spl::transfer_checked(
+        amount,
+        decimals,
+        cpi::spl::TransferChecked {
+            source,
+            destination: treasury,
+            mint,
+            authority,
+            token_program,
+            remaining_accounts,
+        },
+        &[authority_signer_seeds],
+    )
+}
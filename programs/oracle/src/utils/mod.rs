@@ -1,10 +1,12 @@
 mod asserts;
 mod bounds;
-// mod bps;
+mod bps;
+mod fees;
 
 #[cfg(test)]
 pub mod tests;
 
 pub use self::asserts::*;
 pub use self::bounds::*;
-// pub use self::bps::*;
+pub use self::bps::*;
+pub use self::fees::*;
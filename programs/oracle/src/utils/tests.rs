@@ -1,8 +1,15 @@
 use std::num::NonZeroUsize;
 
-use borsh::schema::{BorshSchemaContainer, Declaration, Definition, Fields};
 use borsh::BorshSchema;
+use borsh::schema::{BorshSchemaContainer, Declaration, Definition, Fields};
 use borsh_size::BorshSize;
+use solana_program::pubkey::Pubkey;
+
+use crate::state::{
+    InitConfig, InitRequest, RequestData, RequestDerivation, RequestParams, TiePolicy,
+    TransferFeePolicy,
+};
+use crate::utils::Bounds;
 
 #[track_caller]
 pub fn validate_schema<T: BorshSchema + ?Sized>() {
@@ -186,3 +193,65 @@ pub fn validate_max_size<T: BorshSize + BorshSchema + ?Sized>() {
 
     assert_eq!(schema_max_size, T::MAX_SIZE);
 }
+
+/// Returns config parameters without windows, fees, quorum or slashing. Tests override the fields
+/// they depend on with struct update syntax.
+pub(crate) fn init_config() -> InitConfig {
+    InitConfig {
+        authority: Pubkey::new_unique(),
+        bond_fee_bps: 0,
+        dispute_window: 0,
+        voting_window: 0,
+        reveal_window: 0,
+        arbitration_window: 0,
+        claim_window: 0,
+        dispute_window_range: Bounds { start: 0, end: 0 },
+        voting_window_range: Bounds { start: 0, end: 0 },
+        arbitration_window_range: Bounds { start: 0, end: 0 },
+        quorum_amount: 0,
+        quorum_bps: 0,
+        slash_bps: 0,
+        tie_policy: TiePolicy::ExtendVoting,
+        transfer_fee_policy: TransferFeePolicy::Reject,
+        slash_window: 0,
+    }
+}
+
+/// Returns request parameters without windows, fees, quorum or slashing.
+pub(crate) fn request_params() -> RequestParams {
+    RequestParams {
+        dispute_window: 0,
+        voting_window: 0,
+        reveal_window: 0,
+        arbitration_window: 0,
+        claim_window: 0,
+        bond_fee_bps: 0,
+        quorum_amount: 0,
+        quorum_bps: 0,
+        slash_bps: 0,
+        slash_window: 0,
+        tie_policy: TiePolicy::ExtendVoting,
+    }
+}
+
+/// Returns a yes/no request derived from the oracle index, with the given parameters and no
+/// reward, bond or arbitrator. Tests override the fields they depend on with struct update syntax.
+pub(crate) fn init_request(params: RequestParams) -> InitRequest {
+    InitRequest {
+        index: 0,
+        nonce: 0,
+        config: Pubkey::new_unique(),
+        creator: Pubkey::new_unique(),
+        rent_payer: Pubkey::new_unique(),
+        reward: 0,
+        reward_mint: Pubkey::new_unique(),
+        bond: 0,
+        bond_mint: Pubkey::new_unique(),
+        timestamp: 0,
+        params,
+        arbitrator: Pubkey::default(),
+        derivation: RequestDerivation::Index,
+        allow_too_early: false,
+        data: RequestData::YesNo { question: "example question?".to_owned() },
+    }
+}